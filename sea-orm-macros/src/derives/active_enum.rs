@@ -253,6 +253,8 @@ impl ActiveEnum {
                     .map(|string_value| string_value.value())
                     .or(variant
                         .rename
+                        .or(*rename_all)
+                        .filter(|_| variant.num_value.is_none())
                         .map(|rename| variant.ident.convert_case(Some(rename))))
             })
             .collect();
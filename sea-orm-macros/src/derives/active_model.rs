@@ -1,10 +1,11 @@
 use super::util::{
-    escape_rust_keyword, field_not_ignored, format_field_ident, trim_starting_raw_identifier,
+    escape_rust_keyword, field_converter, field_not_ignored, format_field_ident,
+    trim_starting_raw_identifier,
 };
 use heck::ToUpperCamelCase;
 use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote, quote_spanned};
-use syn::{punctuated::IntoIter, Data, DataStruct, Expr, Field, Fields, LitStr, Type};
+use syn::{punctuated::IntoIter, Data, DataStruct, Expr, Field, Fields, LitStr, Path, Type};
 
 /// Method to derive an [ActiveModel](sea_orm::ActiveModel)
 pub fn expand_derive_active_model(ident: Ident, data: Data) -> syn::Result<TokenStream> {
@@ -66,6 +67,33 @@ fn derive_active_model(all_fields: IntoIter<Field>) -> syn::Result<TokenStream>
         })
         .collect::<Result<_, _>>()?;
 
+    let converters: Vec<Option<Path>> = fields
+        .clone()
+        .map(|field| field_converter(&field))
+        .collect::<syn::Result<_>>()?;
+    let take_value: Vec<TokenStream> = converters
+        .iter()
+        .map(|converter| wrapped_value(quote!(value), converter))
+        .collect();
+    let get_value: Vec<TokenStream> = field
+        .iter()
+        .zip(&converters)
+        .map(|(field, converter)| wrapped_value(quote!(self.#field.clone()), converter))
+        .collect();
+    let set_value: Vec<TokenStream> = converters
+        .iter()
+        .map(|converter| {
+            let value = quote!(sea_orm::sea_query::ValueType::try_from(v)
+                .map_err(|e| sea_orm::DbErr::Type(e.to_string()))?);
+            match converter {
+                Some(converter) => {
+                    quote!(<#converter as sea_orm::ValueConverter>::from_db(#value)?)
+                }
+                None => value,
+            }
+        })
+        .collect();
+
     let ty: Vec<Type> = fields.into_iter().map(|Field { ty, .. }| ty).collect();
 
     Ok(quote!(
@@ -111,7 +139,7 @@ fn derive_active_model(all_fields: IntoIter<Field>) -> syn::Result<TokenStream>
                     #(<Self::Entity as sea_orm::EntityTrait>::Column::#name => {
                         let mut value = sea_orm::ActiveValue::not_set();
                         std::mem::swap(&mut value, &mut self.#field);
-                        value.into_wrapped_value()
+                        #take_value
                     },)*
                     _ => sea_orm::ActiveValue::not_set(),
                 }
@@ -119,16 +147,27 @@ fn derive_active_model(all_fields: IntoIter<Field>) -> syn::Result<TokenStream>
 
             fn get(&self, c: <Self::Entity as sea_orm::EntityTrait>::Column) -> sea_orm::ActiveValue<sea_orm::Value> {
                 match c {
-                    #(<Self::Entity as sea_orm::EntityTrait>::Column::#name => self.#field.clone().into_wrapped_value(),)*
+                    #(<Self::Entity as sea_orm::EntityTrait>::Column::#name => #get_value,)*
                     _ => sea_orm::ActiveValue::not_set(),
                 }
             }
 
             fn set(&mut self, c: <Self::Entity as sea_orm::EntityTrait>::Column, v: sea_orm::Value) {
+                self.try_set(c, v)
+                    .unwrap_or_else(|e| panic!("Failed to set the value: {e}"))
+            }
+
+            fn try_set(&mut self, c: <Self::Entity as sea_orm::EntityTrait>::Column, v: sea_orm::Value) -> Result<(), sea_orm::DbErr> {
+                if let sea_orm::ActiveValue::Unchanged(current) = self.get(c) {
+                    if current == v {
+                        return Ok(());
+                    }
+                }
                 match c {
-                    #(<Self::Entity as sea_orm::EntityTrait>::Column::#name => self.#field = sea_orm::ActiveValue::set(v.unwrap()),)*
+                    #(<Self::Entity as sea_orm::EntityTrait>::Column::#name => self.#field = sea_orm::ActiveValue::set(#set_value),)*
                     _ => panic!("This ActiveModel does not have this field"),
                 }
+                Ok(())
             }
 
             fn not_set(&mut self, c: <Self::Entity as sea_orm::EntityTrait>::Column) {
@@ -161,6 +200,24 @@ fn derive_active_model(all_fields: IntoIter<Field>) -> syn::Result<TokenStream>
     ))
 }
 
+/// The value of a field wrapped into an `ActiveValue<Value>`, converted with its converter if any
+fn wrapped_value(value: TokenStream, converter: &Option<Path>) -> TokenStream {
+    match converter {
+        Some(converter) => quote!(
+            match #value {
+                sea_orm::ActiveValue::Set(value) => sea_orm::ActiveValue::set(
+                    <#converter as sea_orm::ValueConverter>::to_db(value).into(),
+                ),
+                sea_orm::ActiveValue::Unchanged(value) => sea_orm::ActiveValue::unchanged(
+                    <#converter as sea_orm::ValueConverter>::to_db(value).into(),
+                ),
+                sea_orm::ActiveValue::NotSet => sea_orm::ActiveValue::not_set(),
+            }
+        ),
+        None => quote!(#value.into_wrapped_value()),
+    }
+}
+
 fn derive_into_model(model_fields: IntoIter<Field>) -> syn::Result<TokenStream> {
     let active_model_fields = model_fields.clone().filter(field_not_ignored);
 
@@ -16,6 +16,8 @@ pub mod derive_attr {
         pub comment: Option<syn::Lit>,
         pub table_iden: Option<()>,
         pub rename_all: Option<syn::Lit>,
+        pub serde_rename_all: Option<syn::Lit>,
+        pub view: Option<()>,
     }
 }
 
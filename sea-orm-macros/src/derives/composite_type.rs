@@ -0,0 +1,181 @@
+use heck::ToSnakeCase;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{spanned::Spanned, Lit};
+
+struct DeriveCompositeType {
+    name: syn::Ident,
+    type_name: String,
+    fields: Vec<syn::Ident>,
+}
+
+impl DeriveCompositeType {
+    fn new(input: syn::DeriveInput) -> syn::Result<Self> {
+        let input_span = input.span();
+        let fields = match input.data {
+            syn::Data::Struct(syn::DataStruct {
+                fields: syn::Fields::Named(syn::FieldsNamed { named, .. }),
+                ..
+            }) => named,
+            _ => {
+                return Err(syn::Error::new(
+                    input_span,
+                    "DeriveCompositeType only supports structs with named fields",
+                ))
+            }
+        };
+
+        let name = input.ident;
+        let mut type_name = None;
+
+        for attr in input.attrs.iter() {
+            if !attr.path().is_ident("sea_orm") {
+                continue;
+            }
+
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("type_name") {
+                    let lit = meta.value()?.parse()?;
+                    if let Lit::Str(litstr) = lit {
+                        type_name = Some(litstr.value());
+                    } else {
+                        return Err(meta.error(format!("Invalid type_name {:?}", lit)));
+                    }
+                } else {
+                    // received other attribute
+                    return Err(meta.error(format!("Invalid attribute {:?}", meta.path)));
+                }
+
+                Ok(())
+            })?;
+        }
+
+        let type_name = type_name.unwrap_or_else(|| name.to_string().to_snake_case());
+        let fields = fields
+            .into_iter()
+            .map(|field| field.ident.expect("field has a name"))
+            .collect();
+
+        Ok(DeriveCompositeType {
+            name,
+            type_name,
+            fields,
+        })
+    }
+
+    fn expand(&self) -> TokenStream {
+        let name = &self.name;
+        let type_name = &self.type_name;
+        let fields = &self.fields;
+        let num_fields = fields.len();
+
+        quote!(
+            #[automatically_derived]
+            impl sea_orm::PgComposite for #name {
+                fn composite_type() -> &'static str {
+                    #type_name
+                }
+
+                fn to_composite_fields(&self) -> std::vec::Vec<std::option::Option<std::string::String>> {
+                    vec![
+                        #(sea_orm::PgCompositeField::to_composite_field(&self.#fields)),*
+                    ]
+                }
+
+                fn from_composite_fields(
+                    fields: std::vec::Vec<std::option::Option<std::string::String>>,
+                ) -> std::result::Result<Self, sea_orm::DbErr> {
+                    if fields.len() != #num_fields {
+                        return Err(sea_orm::DbErr::Type(format!(
+                            "Expected {} fields for composite type {}, got {}",
+                            #num_fields,
+                            #type_name,
+                            fields.len()
+                        )));
+                    }
+                    let mut fields = fields.into_iter();
+                    Ok(Self {
+                        #(#fields: sea_orm::PgCompositeField::from_composite_field(
+                            fields.next().flatten().as_deref(),
+                        )?),*
+                    })
+                }
+            }
+
+            #[automatically_derived]
+            impl sea_orm::PgCompositeField for #name {
+                fn to_composite_field(&self) -> std::option::Option<std::string::String> {
+                    Some(sea_orm::PgComposite::to_pg_composite(self))
+                }
+
+                fn from_composite_field(
+                    field: std::option::Option<&str>,
+                ) -> std::result::Result<Self, sea_orm::DbErr> {
+                    match field {
+                        Some(field) => sea_orm::PgComposite::from_pg_composite(field),
+                        None => Err(sea_orm::DbErr::Type(format!(
+                            "A null value was encountered while decoding {}",
+                            #type_name
+                        ))),
+                    }
+                }
+            }
+
+            #[automatically_derived]
+            impl std::convert::From<#name> for sea_orm::Value {
+                fn from(source: #name) -> Self {
+                    sea_orm::Value::String(Some(std::boxed::Box::new(
+                        sea_orm::PgComposite::to_pg_composite(&source),
+                    )))
+                }
+            }
+
+            #[automatically_derived]
+            impl sea_orm::sea_query::Nullable for #name {
+                fn null() -> sea_orm::Value {
+                    sea_orm::Value::String(None)
+                }
+            }
+
+            #[automatically_derived]
+            impl sea_orm::TryGetable for #name {
+                fn try_get_by<I: sea_orm::ColIdx>(res: &sea_orm::QueryResult, idx: I)
+                    -> std::result::Result<Self, sea_orm::TryGetError> {
+                    let s = <std::string::String as sea_orm::TryGetable>::try_get_by(res, idx)?;
+                    sea_orm::PgComposite::from_pg_composite(&s).map_err(sea_orm::TryGetError::DbErr)
+                }
+            }
+
+            #[automatically_derived]
+            impl sea_orm::sea_query::ValueType for #name {
+                fn try_from(v: sea_orm::Value) -> std::result::Result<Self, sea_orm::sea_query::ValueTypeErr> {
+                    match v {
+                        sea_orm::Value::String(Some(s)) => sea_orm::PgComposite::from_pg_composite(&s)
+                            .map_err(|_| sea_orm::sea_query::ValueTypeErr),
+                        _ => Err(sea_orm::sea_query::ValueTypeErr),
+                    }
+                }
+
+                fn type_name() -> std::string::String {
+                    stringify!(#name).to_owned()
+                }
+
+                fn array_type() -> sea_orm::sea_query::ArrayType {
+                    sea_orm::sea_query::ArrayType::String
+                }
+
+                fn column_type() -> sea_orm::sea_query::ColumnType {
+                    sea_orm::sea_query::ColumnType::Custom(
+                        sea_orm::sea_query::IntoIden::into_iden(
+                            sea_orm::sea_query::Alias::new(#type_name),
+                        ),
+                    )
+                }
+            }
+        )
+    }
+}
+
+pub fn expand_derive_composite_type(input: syn::DeriveInput) -> syn::Result<TokenStream> {
+    Ok(DeriveCompositeType::new(input)?.expand())
+}
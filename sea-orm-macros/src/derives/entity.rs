@@ -14,6 +14,7 @@ struct DeriveEntity {
     relation_ident: syn::Ident,
     schema_name: Option<syn::Lit>,
     table_name: Option<syn::Lit>,
+    view: bool,
 }
 
 impl DeriveEntity {
@@ -35,6 +36,7 @@ impl DeriveEntity {
 
         let table_name = sea_attr.table_name;
         let schema_name = sea_attr.schema_name;
+        let view = sea_attr.view.is_some();
 
         Ok(DeriveEntity {
             column_ident,
@@ -45,18 +47,21 @@ impl DeriveEntity {
             relation_ident,
             schema_name,
             table_name,
+            view,
         })
     }
 
     fn expand(&self) -> TokenStream {
         let expanded_impl_entity_name = self.impl_entity_name();
         let expanded_impl_entity_trait = self.impl_entity_trait();
+        let expanded_impl_entity_writable = self.impl_entity_writable();
         let expanded_impl_iden = self.impl_iden();
         let expanded_impl_iden_static = self.impl_iden_static();
 
         TokenStream::from_iter([
             expanded_impl_entity_name,
             expanded_impl_entity_trait,
+            expanded_impl_entity_writable,
             expanded_impl_iden,
             expanded_impl_iden_static,
         ])
@@ -115,6 +120,18 @@ impl DeriveEntity {
         )
     }
 
+    fn impl_entity_writable(&self) -> TokenStream {
+        let ident = &self.ident;
+        if self.view {
+            return TokenStream::new(); // A view is read-only
+        }
+
+        quote!(
+            #[automatically_derived]
+            impl sea_orm::entity::EntityWritable for #ident {}
+        )
+    }
+
     fn impl_iden(&self) -> TokenStream {
         let ident = &self.ident;
 
@@ -1,10 +1,11 @@
 use super::case_style::{CaseStyle, CaseStyleHelpers};
-use super::util::{escape_rust_keyword, trim_starting_raw_identifier};
+use super::util::{escape_rust_keyword, field_converter, trim_starting_raw_identifier};
 use heck::{ToSnakeCase, ToUpperCamelCase};
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
 use syn::{
-    punctuated::Punctuated, spanned::Spanned, token::Comma, Attribute, Data, Expr, Fields, Lit,
+    meta::ParseNestedMeta, punctuated::Punctuated, spanned::Spanned, token::Comma, Attribute, Data,
+    Expr, Fields, Lit, Token,
 };
 
 /// Method to derive an Model
@@ -14,7 +15,9 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
     let mut comment = quote! {None};
     let mut schema_name = quote! { None };
     let mut table_iden = false;
+    let mut view = false;
     let mut rename_all: Option<CaseStyle> = None;
+    let mut serde_rename_all: Option<CaseStyle> = None;
 
     attrs
         .iter()
@@ -31,8 +34,12 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                     schema_name = quote! { Some(#name) };
                 } else if meta.path.is_ident("table_iden") {
                     table_iden = true;
+                } else if meta.path.is_ident("view") {
+                    view = true;
                 } else if meta.path.is_ident("rename_all") {
                     rename_all = Some((&meta).try_into()?);
+                } else if meta.path.is_ident("serde_rename_all") {
+                    serde_rename_all = Some((&meta).try_into()?);
                 } else {
                     // Reads the value expression to advance the parse stream.
                     // Some parameters, such as `primary_key`, do not have any value,
@@ -44,12 +51,18 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
             })
         })?;
 
+    let view_attr = if view {
+        quote! { #[sea_orm(view)] }
+    } else {
+        quote! {}
+    };
     let entity_def = table_name
         .as_ref()
         .map(|table_name| {
             quote! {
                 #[doc = " Generated by sea-orm-macros"]
                 #[derive(Copy, Clone, Default, Debug, sea_orm::prelude::DeriveEntity)]
+                #view_attr
                 pub struct Entity;
 
                 #[automatically_derived]
@@ -75,6 +88,7 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
     let mut columns_trait: Punctuated<_, Comma> = Punctuated::new();
     let mut columns_select_as: Punctuated<_, Comma> = Punctuated::new();
     let mut columns_save_as: Punctuated<_, Comma> = Punctuated::new();
+    let mut columns_json_key: Punctuated<_, Comma> = Punctuated::new();
     let mut primary_keys: Punctuated<_, Comma> = Punctuated::new();
     let mut primary_key_types: Punctuated<_, Comma> = Punctuated::new();
     let mut auto_increment = true;
@@ -90,6 +104,9 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
             columns_trait.push(
                 quote! { Self::#table_field_name => panic!("Table cannot be used as a column") },
             );
+            columns_json_key.push(
+                quote! { Self::#table_field_name => panic!("Table cannot be used as a column") },
+            );
         }
     }
     if let Data::Struct(item_struct) = data {
@@ -105,12 +122,20 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                     let mut nullable = false;
                     let mut default_value = None;
                     let mut comment = None;
+                    let mut collation = None;
                     let mut default_expr = None;
                     let mut select_as = None;
                     let mut save_as = None;
                     let mut indexed = false;
                     let mut ignore = false;
                     let mut unique = false;
+                    let mut version = false;
+                    let mut soft_delete = false;
+                    let mut generated_expr = None;
+                    let mut check_expr = None;
+                    let mut stored = false;
+                    let mut created_timestamp = None;
+                    let mut updated_timestamp = None;
                     let mut sql_type = None;
                     let mut column_name = if let Some(case_style) = rename_all {
                         Some(field_name.convert_case(Some(case_style)))
@@ -123,9 +148,16 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                         None
                     };
 
+                    // the key of the field in the serialized Model
+                    let mut json_key = match serde_rename_all {
+                        Some(case_style) => Ident::new(&original_field_name, Span::call_site())
+                            .convert_case(Some(case_style)),
+                        None => original_field_name.clone(),
+                    };
+
                     let mut enum_name = None;
                     let mut is_primary_key = false;
-                    // search for #[sea_orm(primary_key, auto_increment = false, column_type = "String(Some(255))", default_value = "new user", default_expr = "gen_random_uuid()", column_name = "name", enum_name = "Name", nullable, indexed, unique)]
+                    // search for #[sea_orm(primary_key, auto_increment = false, column_type = "String(Some(255))", default_value = "new user", default_expr = "gen_random_uuid()", column_name = "name", enum_name = "Name", serde_rename = "name", nullable, indexed, unique, version, soft_delete, created_timestamp, updated_timestamp = "rust", generated_expr = "Expr::cust(\"a + b\")", stored, check_expr = "Expr::col(Column::Price).gte(0)", collation = "NOCASE", with = "Converter")]
                     for attr in field.attrs.iter() {
                         if !attr.path().is_ident("sea_orm") {
                             continue;
@@ -154,6 +186,8 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                                 }
                             } else if meta.path.is_ident("comment") {
                                 comment = Some(meta.value()?.parse::<Lit>()?);
+                            } else if meta.path.is_ident("collation") {
+                                collation = Some(meta.value()?.parse::<Lit>()?);
                             } else if meta.path.is_ident("default_value") {
                                 default_value = Some(meta.value()?.parse::<Lit>()?);
                             } else if meta.path.is_ident("default_expr") {
@@ -166,6 +200,24 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                                         meta.error(format!("Invalid column_type {:?}", lit))
                                     );
                                 }
+                            } else if meta.path.is_ident("generated_expr") {
+                                let lit = meta.value()?.parse()?;
+                                if let Lit::Str(litstr) = lit {
+                                    let value_expr: TokenStream = syn::parse_str(&litstr.value())?;
+                                    generated_expr = Some(value_expr);
+                                } else {
+                                    return Err(
+                                        meta.error(format!("Invalid generated_expr {:?}", lit))
+                                    );
+                                }
+                            } else if meta.path.is_ident("check_expr") {
+                                let lit = meta.value()?.parse()?;
+                                if let Lit::Str(litstr) = lit {
+                                    let value_expr: TokenStream = syn::parse_str(&litstr.value())?;
+                                    check_expr = Some(value_expr);
+                                } else {
+                                    return Err(meta.error(format!("Invalid check_expr {:?}", lit)));
+                                }
                             } else if meta.path.is_ident("column_name") {
                                 let lit = meta.value()?.parse()?;
                                 if let Lit::Str(litstr) = lit {
@@ -197,6 +249,15 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                                 } else {
                                     return Err(meta.error(format!("Invalid save_as {:?}", lit)));
                                 }
+                            } else if meta.path.is_ident("serde_rename") {
+                                let lit = meta.value()?.parse()?;
+                                if let Lit::Str(litstr) = lit {
+                                    json_key = litstr.value();
+                                } else {
+                                    return Err(
+                                        meta.error(format!("Invalid serde_rename {:?}", lit))
+                                    );
+                                }
                             } else if meta.path.is_ident("ignore") {
                                 ignore = true;
                             } else if meta.path.is_ident("primary_key") {
@@ -208,6 +269,16 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                                 indexed = true;
                             } else if meta.path.is_ident("unique") {
                                 unique = true;
+                            } else if meta.path.is_ident("version") {
+                                version = true;
+                            } else if meta.path.is_ident("soft_delete") {
+                                soft_delete = true;
+                            } else if meta.path.is_ident("stored") {
+                                stored = true;
+                            } else if meta.path.is_ident("created_timestamp") {
+                                created_timestamp = Some(parse_timestamp_source(&meta)?);
+                            } else if meta.path.is_ident("updated_timestamp") {
+                                updated_timestamp = Some(parse_timestamp_source(&meta)?);
                             } else {
                                 // Reads the value expression to advance the parse stream.
                                 // Some parameters, such as `primary_key`, do not have any value,
@@ -261,6 +332,7 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                             Self::#field_name => val.cast_as(sea_orm::sea_query::Alias::new(#save_as))
                         });
                     }
+                    columns_json_key.push(quote! { Self::#field_name => #json_key });
 
                     let field_type = &field.ty;
                     let field_type = quote! { #field_type }
@@ -274,9 +346,17 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                     };
                     let field_span = field.span();
 
-                    let sea_query_col_type = crate::derives::sql_type_match::col_type_match(
-                        sql_type, field_type, field_span,
-                    );
+                    let sea_query_col_type = match field_converter(&field)? {
+                        // the column stores the values of the converter
+                        Some(converter) if sql_type.is_none() => quote! {
+                            std::convert::Into::<sea_orm::sea_query::ColumnType>::into(
+                                <<#converter as sea_orm::ValueConverter>::DbValue as sea_orm::sea_query::ValueType>::column_type()
+                            )
+                        },
+                        _ => crate::derives::sql_type_match::col_type_match(
+                            sql_type, field_type, field_span,
+                        ),
+                    };
 
                     let col_def =
                         quote! { sea_orm::prelude::ColumnTypeTrait::def(#sea_query_col_type) };
@@ -291,15 +371,36 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                     if unique {
                         match_row = quote! { #match_row.unique() };
                     }
+                    if version {
+                        match_row = quote! { #match_row.version() };
+                    }
+                    if soft_delete {
+                        match_row = quote! { #match_row.soft_delete() };
+                    }
+                    if let Some(source) = created_timestamp {
+                        match_row = quote! { #match_row.created_timestamp(#source) };
+                    }
+                    if let Some(source) = updated_timestamp {
+                        match_row = quote! { #match_row.updated_timestamp(#source) };
+                    }
                     if let Some(default_value) = default_value {
                         match_row = quote! { #match_row.default_value(#default_value) };
                     }
                     if let Some(comment) = comment {
                         match_row = quote! { #match_row.comment(#comment) };
                     }
+                    if let Some(collation) = collation {
+                        match_row = quote! { #match_row.collation(#collation) };
+                    }
                     if let Some(default_expr) = default_expr {
                         match_row = quote! { #match_row.default(#default_expr) };
                     }
+                    if let Some(generated_expr) = generated_expr {
+                        match_row = quote! { #match_row.generated(#generated_expr, #stored) };
+                    }
+                    if let Some(check_expr) = check_expr {
+                        match_row = quote! { #match_row.check(#check_expr) };
+                    }
                     // match_row = quote! { #match_row.comment() };
                     columns_trait.push(match_row);
                 }
@@ -371,6 +472,12 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                     _ => sea_orm::prelude::ColumnTrait::save_enum_as(self, val),
                 }
             }
+
+            fn json_key(&self) -> &str {
+                match self {
+                    #columns_json_key
+                }
+            }
         }
 
         #entity_def
@@ -378,3 +485,21 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
         #primary_key
     })
 }
+
+/// Parse the source of an auto-managed timestamp, e.g. `created_timestamp = "rust"`,
+/// defaulting to the database clock
+fn parse_timestamp_source(meta: &ParseNestedMeta) -> syn::Result<TokenStream> {
+    if !meta.input.peek(Token![=]) {
+        return Ok(quote! { sea_orm::TimestampSource::Database });
+    }
+    let lit: Lit = meta.value()?.parse()?;
+    match &lit {
+        Lit::Str(litstr) if litstr.value() == "database" => {
+            Ok(quote! { sea_orm::TimestampSource::Database })
+        }
+        Lit::Str(litstr) if litstr.value() == "rust" => {
+            Ok(quote! { sea_orm::TimestampSource::Rust })
+        }
+        _ => Err(meta.error(format!("Invalid timestamp source {:?}", lit))),
+    }
+}
@@ -5,6 +5,7 @@ mod active_model_behavior;
 mod attributes;
 mod case_style;
 mod column;
+mod composite_type;
 mod derive_iden;
 mod entity;
 mod entity_model;
@@ -26,6 +27,7 @@ pub use active_enum_display::*;
 pub use active_model::*;
 pub use active_model_behavior::*;
 pub use column::*;
+pub use composite_type::*;
 pub use derive_iden::*;
 pub use entity::*;
 pub use entity_model::*;
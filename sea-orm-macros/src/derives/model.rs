@@ -1,12 +1,12 @@
 use super::{
     attributes::derive_attr,
-    util::{escape_rust_keyword, field_not_ignored, trim_starting_raw_identifier},
+    util::{escape_rust_keyword, field_converter, field_not_ignored, trim_starting_raw_identifier},
 };
 use heck::ToUpperCamelCase;
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote, quote_spanned};
 use std::iter::FromIterator;
-use syn::{Expr, Ident, LitStr};
+use syn::{Expr, Ident, LitStr, Path};
 
 enum Error {
     InputNotStruct,
@@ -15,6 +15,7 @@ enum Error {
 
 struct DeriveModel {
     column_idents: Vec<syn::Ident>,
+    converters: Vec<Option<Path>>,
     entity_ident: syn::Ident,
     field_idents: Vec<syn::Ident>,
     ident: syn::Ident,
@@ -74,6 +75,12 @@ impl DeriveModel {
             })
             .collect::<Result<_, _>>()?;
 
+        let converters = fields
+            .iter()
+            .map(field_converter)
+            .collect::<syn::Result<_>>()
+            .map_err(Error::Syn)?;
+
         let ignore_attrs = fields
             .iter()
             .map(|field| !field_not_ignored(field))
@@ -81,6 +88,7 @@ impl DeriveModel {
 
         Ok(DeriveModel {
             column_idents,
+            converters,
             entity_ident,
             field_idents,
             ident,
@@ -105,14 +113,24 @@ impl DeriveModel {
         let field_values: Vec<TokenStream> = column_idents
             .iter()
             .zip(&self.ignore_attrs)
-            .map(|(column_ident, ignore)| {
+            .zip(&self.converters)
+            .map(|((column_ident, ignore), converter)| {
+                let column = quote! {
+                    sea_orm::IdenStatic::as_str(&<<Self as sea_orm::ModelTrait>::Entity as sea_orm::entity::EntityTrait>::Column::#column_ident).into()
+                };
                 if *ignore {
                     quote! {
                         Default::default()
                     }
+                } else if let Some(converter) = converter {
+                    quote! {
+                        <#converter as sea_orm::ValueConverter>::from_db(
+                            row.try_get::<<#converter as sea_orm::ValueConverter>::DbValue>(pre, #column)?
+                        )?
+                    }
                 } else {
                     quote! {
-                        row.try_get(pre, sea_orm::IdenStatic::as_str(&<<Self as sea_orm::ModelTrait>::Entity as sea_orm::entity::EntityTrait>::Column::#column_ident).into())?
+                        row.try_get(pre, #column)?
                     }
                 }
             })
@@ -153,6 +171,33 @@ impl DeriveModel {
             .zip(ignore_attrs)
             .filter_map(ignore)
             .collect();
+        let (get_values, set_values): (Vec<TokenStream>, Vec<TokenStream>) = self
+            .field_idents
+            .iter()
+            .zip(&self.converters)
+            .zip(ignore_attrs)
+            .filter(|(_, ignore)| !**ignore)
+            .map(|((field_ident, converter), _)| match converter {
+                Some(converter) => (
+                    quote! {
+                        <#converter as sea_orm::ValueConverter>::to_db(self.#field_ident.clone()).into()
+                    },
+                    quote! {
+                        <#converter as sea_orm::ValueConverter>::from_db(
+                            sea_orm::sea_query::ValueType::try_from(v)
+                                .map_err(|e| sea_orm::DbErr::Type(e.to_string()))?
+                        )?
+                    },
+                ),
+                None => (
+                    quote! { self.#field_ident.clone().into() },
+                    quote! {
+                        sea_orm::sea_query::ValueType::try_from(v)
+                            .map_err(|e| sea_orm::DbErr::Type(e.to_string()))?
+                    },
+                ),
+            })
+            .unzip();
 
         let missing_field_msg = format!("field does not exist on {ident}");
 
@@ -163,16 +208,22 @@ impl DeriveModel {
 
                 fn get(&self, c: <Self::Entity as sea_orm::entity::EntityTrait>::Column) -> sea_orm::Value {
                     match c {
-                        #(<Self::Entity as sea_orm::entity::EntityTrait>::Column::#column_idents => self.#field_idents.clone().into(),)*
+                        #(<Self::Entity as sea_orm::entity::EntityTrait>::Column::#column_idents => #get_values,)*
                         _ => panic!(#missing_field_msg),
                     }
                 }
 
                 fn set(&mut self, c: <Self::Entity as sea_orm::entity::EntityTrait>::Column, v: sea_orm::Value) {
+                    self.try_set(c, v)
+                        .unwrap_or_else(|e| panic!("Failed to set the value: {e}"))
+                }
+
+                fn try_set(&mut self, c: <Self::Entity as sea_orm::entity::EntityTrait>::Column, v: sea_orm::Value) -> Result<(), sea_orm::DbErr> {
                     match c {
-                        #(<Self::Entity as sea_orm::entity::EntityTrait>::Column::#column_idents => self.#field_idents = v.unwrap(),)*
+                        #(<Self::Entity as sea_orm::entity::EntityTrait>::Column::#column_idents => self.#field_idents = #set_values,)*
                         _ => panic!(#missing_field_msg),
                     }
+                    Ok(())
                 }
             }
         )
@@ -19,6 +19,7 @@ enum Error {
     EntityNotSpecific,
     NotSupportGeneric(Span),
     BothFromColAndFromExpr(Span),
+    BothFromAndFromExpr(Span),
     Syn(syn::Error),
 }
 #[derive(Debug, PartialEq, Eq)]
@@ -27,6 +28,12 @@ enum ColumnAs {
     Col(syn::Ident),
     /// alias from a column in model
     ColAlias { col: syn::Ident, field: String },
+    /// alias from a column in a joined entity
+    JoinedCol {
+        entity: syn::Type,
+        col: syn::Ident,
+        field: String,
+    },
     /// from an expr
     Expr { expr: syn::Expr, field_name: String },
 }
@@ -63,10 +70,9 @@ impl DerivePartialModel {
 
             if let Ok(list) = attr.parse_args_with(Punctuated::<Meta, Comma>::parse_terminated) {
                 for meta in list {
-                    entity = meta
-                        .get_as_kv("entity")
-                        .map(|s| syn::parse_str::<syn::Type>(&s).map_err(Error::Syn))
-                        .transpose()?;
+                    if let Some(s) = meta.get_as_kv("entity") {
+                        entity = Some(syn::parse_str::<syn::Type>(&s).map_err(Error::Syn)?);
+                    }
                 }
             }
         }
@@ -78,6 +84,7 @@ impl DerivePartialModel {
 
             let mut from_col = None;
             let mut from_expr = None;
+            let mut from_entity = None;
 
             for attr in field.attrs.iter() {
                 if !attr.path().is_ident("sea_orm") {
@@ -87,13 +94,16 @@ impl DerivePartialModel {
                 if let Ok(list) = attr.parse_args_with(Punctuated::<Meta, Comma>::parse_terminated)
                 {
                     for meta in list.iter() {
-                        from_col = meta
-                            .get_as_kv("from_col")
-                            .map(|s| format_ident!("{}", s.to_upper_camel_case()));
-                        from_expr = meta
-                            .get_as_kv("from_expr")
-                            .map(|s| syn::parse_str::<Expr>(&s).map_err(Error::Syn))
-                            .transpose()?;
+                        if let Some(s) = meta.get_as_kv("from_col") {
+                            from_col = Some(format_ident!("{}", s.to_upper_camel_case()));
+                        }
+                        if let Some(s) = meta.get_as_kv("from_expr") {
+                            from_expr = Some(syn::parse_str::<Expr>(&s).map_err(Error::Syn)?);
+                        }
+                        if let Some(s) = meta.get_as_kv("from") {
+                            from_entity =
+                                Some(syn::parse_str::<syn::Type>(&s).map_err(Error::Syn)?);
+                        }
                     }
                 }
             }
@@ -101,6 +111,16 @@ impl DerivePartialModel {
             let field_name = field.ident.unwrap();
 
             let col_as = match (from_col, from_expr) {
+                (_, Some(_)) if from_entity.is_some() => {
+                    return Err(Error::BothFromAndFromExpr(field_span))
+                }
+                (col, None) if from_entity.is_some() => ColumnAs::JoinedCol {
+                    entity: from_entity.unwrap(),
+                    col: col.unwrap_or_else(|| {
+                        format_ident!("{}", field_name.to_string().to_upper_camel_case())
+                    }),
+                    field: field_name.to_string(),
+                },
                 (None, None) => {
                     if entity.is_none() {
                         return Err(Error::EntityNotSpecific);
@@ -156,6 +176,10 @@ impl DerivePartialModel {
                 let col_value = quote!( <#entity as sea_orm::EntityTrait>::Column:: #col);
                 quote!(let #select_ident =  sea_orm::SelectColumns::select_column_as(#select_ident, #col_value, #field);)
             },
+            ColumnAs::JoinedCol { entity, col, field } => {
+                let col_value = quote!( <#entity as sea_orm::EntityTrait>::Column:: #col);
+                quote!(let #select_ident =  sea_orm::SelectColumns::select_column_as(#select_ident, #col_value, #field);)
+            },
             ColumnAs::Expr { expr, field_name } => {
                 quote!(let #select_ident =  sea_orm::SelectColumns::select_column_as(#select_ident, #expr, #field_name);)
             },
@@ -184,6 +208,9 @@ pub fn expand_derive_partial_model(input: syn::DeriveInput) -> syn::Result<Token
         Err(Error::BothFromColAndFromExpr(span)) => Ok(quote_spanned! {
             span => compile_error!("you can only use one of `from_col` or `from_expr`");
         }),
+        Err(Error::BothFromAndFromExpr(span)) => Ok(quote_spanned! {
+            span => compile_error!("you cannot use `from` together with `from_expr`");
+        }),
         Err(Error::EntityNotSpecific) => Ok(quote_spanned! {
             ident_span => compile_error!("you need specific which entity you are using")
         }),
@@ -245,7 +272,11 @@ struct PartialModel{
     #[sea_orm(from_col = "bar")]
     alias_field: i32,
     #[sea_orm(from_expr = "Expr::val(1).add(1)")]
-    expr_field : i32
+    expr_field : i32,
+    #[sea_orm(from = "cake::Entity")]
+    name: String,
+    #[sea_orm(from = "cake::Entity", from_col = "id")]
+    cake_id: i32,
 }
 "#;
     #[test]
@@ -255,7 +286,7 @@ struct PartialModel{
         let middle = DerivePartialModel::new(input).unwrap();
         assert_eq!(middle.entity, Some(parse_str::<Type>("Entity").unwrap()));
         assert_eq!(middle.ident, format_ident!("PartialModel"));
-        assert_eq!(middle.fields.len(), 3);
+        assert_eq!(middle.fields.len(), 5);
         assert_eq!(
             middle.fields[0],
             ColumnAs::Col(format_ident!("DefaultField"))
@@ -274,6 +305,22 @@ struct PartialModel{
                 field_name: "expr_field".to_string()
             }
         );
+        assert_eq!(
+            middle.fields[3],
+            ColumnAs::JoinedCol {
+                entity: parse_str::<Type>("cake::Entity").unwrap(),
+                col: format_ident!("Name"),
+                field: "name".to_string()
+            }
+        );
+        assert_eq!(
+            middle.fields[4],
+            ColumnAs::JoinedCol {
+                entity: parse_str::<Type>("cake::Entity").unwrap(),
+                col: format_ident!("Id"),
+                field: "cake_id".to_string()
+            }
+        );
 
         Ok(())
     }
@@ -1,6 +1,6 @@
 use heck::ToUpperCamelCase;
 use quote::format_ident;
-use syn::{punctuated::Punctuated, token::Comma, Field, Ident, Meta};
+use syn::{punctuated::Punctuated, token::Comma, Expr, Field, Ident, LitStr, Meta, Path};
 
 pub(crate) fn field_not_ignored(field: &Field) -> bool {
     for attr in field.attrs.iter() {
@@ -27,6 +27,35 @@ pub(crate) fn field_not_ignored(field: &Field) -> bool {
     true
 }
 
+/// The converter of a field, given with `#[sea_orm(with = "path::to::Converter")]`
+/// or `#[sea_orm(with = path::to::Converter)]`
+pub(crate) fn field_converter(field: &Field) -> syn::Result<Option<Path>> {
+    let mut converter = None;
+    for attr in field.attrs.iter() {
+        if !attr.path().is_ident("sea_orm") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("with") {
+                let value = meta.value()?;
+                converter = Some(if value.peek(LitStr) {
+                    value.parse::<LitStr>()?.parse()?
+                } else {
+                    value.parse()?
+                });
+            } else {
+                // Reads the value expression to advance the parse stream.
+                // Some parameters, such as `primary_key`, do not have any value,
+                // so ignoring an error occurred here.
+                let _: Option<Expr> = meta.value().and_then(|v| v.parse()).ok();
+            }
+
+            Ok(())
+        })?;
+    }
+    Ok(converter)
+}
+
 pub(crate) fn format_field_ident(field: Field) -> Ident {
     format_ident!("{}", field.ident.unwrap().to_string())
 }
@@ -73,6 +73,9 @@ mod strum;
 /// #
 /// # impl ActiveModelBehavior for ActiveModel {}
 /// ```
+///
+/// The Entity implements [EntityWritable](sea_orm::EntityWritable), unless it is a read-only
+/// view marked with `#[sea_orm(view)]`.
 #[cfg(feature = "derive")]
 #[proc_macro_derive(DeriveEntity, attributes(sea_orm))]
 pub fn derive_entity(input: TokenStream) -> TokenStream {
@@ -113,6 +116,88 @@ pub fn derive_entity(input: TokenStream) -> TokenStream {
 /// # impl ActiveModelBehavior for ActiveModel {}
 /// ```
 ///
+/// The Model can derive `Serialize` and `Deserialize` to be sent over an API. When serde renames
+/// its fields, the same names are to be given with `#[sea_orm(serde_rename = "...")]` on a field,
+/// or `#[sea_orm(serde_rename_all = "...")]` on the Model, as serde's own attributes are not read.
+/// They are the keys [ActiveModel::from_json](sea_orm::ActiveModelTrait::from_json) looks for.
+/// The ActiveModel is not serialized.
+///
+/// ```
+/// use sea_orm::entity::prelude::*;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+/// #[sea_orm(table_name = "users", serde_rename_all = "camelCase")]
+/// #[serde(rename_all = "camelCase")]
+/// pub struct Model {
+///     #[sea_orm(primary_key)]
+///     pub id: i32,
+///     pub display_name: String,
+///     #[sea_orm(serde_rename = "mail")]
+///     #[serde(rename = "mail")]
+///     pub email: String,
+/// }
+///
+/// # #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// # pub enum Relation {}
+/// #
+/// # impl ActiveModelBehavior for ActiveModel {}
+/// assert_eq!(Column::DisplayName.json_key(), "displayName");
+/// assert_eq!(Column::Email.json_key(), "mail");
+/// ```
+///
+/// A name which is not a string literal is a compile error.
+///
+/// ```compile_fail
+/// # use sea_orm::entity::prelude::*;
+/// #
+/// #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+/// #[sea_orm(table_name = "users")]
+/// pub struct Model {
+///     #[sea_orm(primary_key)]
+///     pub id: i32,
+///     #[sea_orm(serde_rename = 1)]
+///     pub email: String,
+/// }
+/// #
+/// # #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// # pub enum Relation {}
+/// #
+/// # impl ActiveModelBehavior for ActiveModel {}
+/// ```
+///
+/// A field can be converted to and from the value stored in the column, like text encrypted
+/// at rest, with `#[sea_orm(with = "path::to::Converter")]`, where `Converter` implements
+/// [ValueConverter](sea_orm::ValueConverter).
+///
+/// The columns are named after the fields in snake_case. For a database with another naming
+/// convention, `#[sea_orm(rename_all = "camelCase")]` on the Model names every column
+/// in that case instead, while `#[sea_orm(column_name = "...")]` on a field still overrides it.
+/// The conventions are `camelCase`, `PascalCase`, `snake_case`, `SCREAMING_SNAKE_CASE`,
+/// `kebab-case`, `SCREAMING-KEBAB-CASE`, `lowercase`, `UPPERCASE`, `title_case` and `mixed_case`.
+///
+/// ```
+/// use sea_orm::entity::prelude::*;
+///
+/// #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+/// #[sea_orm(table_name = "Posts", rename_all = "PascalCase")]
+/// pub struct Model {
+///     #[sea_orm(primary_key)]
+///     pub id: i32,
+///     pub published_at: String,
+///     #[sea_orm(column_name = "URL")]
+///     pub url: String,
+/// }
+///
+/// # #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// # pub enum Relation {}
+/// #
+/// # impl ActiveModelBehavior for ActiveModel {}
+/// #
+/// assert_eq!(Column::PublishedAt.as_str(), "PublishedAt");
+/// assert_eq!(Column::Url.as_str(), "URL");
+/// ```
+///
 /// Entity should always have a primary key.
 /// Or, it will result in a compile error.
 /// See <https://github.com/SeaQL/sea-orm/issues/485> for details.
@@ -133,6 +218,101 @@ pub fn derive_entity(input: TokenStream) -> TokenStream {
 /// #
 /// # impl ActiveModelBehavior for ActiveModel {}
 /// ```
+///
+/// A database view, or any other relation which can not be written to, is mapped with
+/// `#[sea_orm(view)]`. It can be selected, filtered and joined like a table, but its Entity
+/// does not implement [EntityWritable](sea_orm::EntityWritable), so inserting, updating
+/// or deleting rows is a compile error.
+///
+/// ```
+/// use sea_orm::entity::prelude::*;
+///
+/// #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+/// #[sea_orm(table_name = "recent_posts", view)]
+/// pub struct Model {
+///     #[sea_orm(primary_key)]
+///     pub id: i32,
+///     pub title: String,
+/// }
+///
+/// # #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// # pub enum Relation {}
+/// #
+/// # impl ActiveModelBehavior for ActiveModel {}
+/// #
+/// async fn find(db: &DatabaseConnection) -> Result<Vec<Model>, DbErr> {
+///     Entity::find().filter(Column::Title.contains("sea")).all(db).await
+/// }
+/// ```
+///
+/// ```compile_fail
+/// # use sea_orm::entity::prelude::*;
+/// #
+/// # #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+/// # #[sea_orm(table_name = "recent_posts", view)]
+/// # pub struct Model {
+/// #     #[sea_orm(primary_key)]
+/// #     pub id: i32,
+/// #     pub title: String,
+/// # }
+/// #
+/// # #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// # pub enum Relation {}
+/// #
+/// # impl ActiveModelBehavior for ActiveModel {}
+/// #
+/// async fn delete(db: &DatabaseConnection, model: Model) -> Result<(), DbErr> {
+///     model.delete(db).await?;
+///     Ok(())
+/// }
+/// ```
+///
+/// ```compile_fail
+/// # use sea_orm::entity::prelude::*;
+/// #
+/// # #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+/// # #[sea_orm(table_name = "recent_posts", view)]
+/// # pub struct Model {
+/// #     #[sea_orm(primary_key)]
+/// #     pub id: i32,
+/// #     pub title: String,
+/// # }
+/// #
+/// # #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// # pub enum Relation {}
+/// #
+/// # impl ActiveModelBehavior for ActiveModel {}
+/// #
+/// async fn insert(db: &DatabaseConnection, model: Model) -> Result<(), DbErr> {
+///     Entity::insert(ActiveModel::from(model)).exec(db).await?;
+///     Ok(())
+/// }
+/// ```
+///
+/// ```compile_fail
+/// # use sea_orm::entity::prelude::*;
+/// #
+/// # #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+/// # #[sea_orm(table_name = "recent_posts", view)]
+/// # pub struct Model {
+/// #     #[sea_orm(primary_key)]
+/// #     pub id: i32,
+/// #     pub title: String,
+/// # }
+/// #
+/// # #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// # pub enum Relation {}
+/// #
+/// # impl ActiveModelBehavior for ActiveModel {}
+/// #
+/// async fn update(db: &DatabaseConnection) -> Result<(), DbErr> {
+///     Entity::update_many()
+///         .col_expr(Column::Title, Expr::value("sea"))
+///         .exec(db)
+///         .await?;
+///     Ok(())
+/// }
+/// ```
 #[cfg(feature = "derive")]
 #[proc_macro_derive(DeriveEntityModel, attributes(sea_orm))]
 pub fn derive_entity_model(input: TokenStream) -> TokenStream {
@@ -567,6 +747,29 @@ pub fn derive_active_model_behavior(input: TokenStream) -> TokenStream {
 ///     White = 1,
 /// }
 /// ```
+///
+/// A compact status column can be stored as a small integer, with a `num_value` for every variant.
+/// Reading an integer which is not the value of any variant fails with a [DbErr::Type](sea_orm::DbErr::Type).
+///
+/// ```
+/// use sea_orm::{entity::prelude::*, DeriveActiveEnum};
+///
+/// #[derive(Debug, PartialEq, EnumIter, DeriveActiveEnum)]
+/// #[sea_orm(rs_type = "i16", db_type = "SmallInteger")]
+/// pub enum Status {
+///     #[sea_orm(num_value = 1)]
+///     Active,
+///     #[sea_orm(num_value = 2)]
+///     Suspended,
+/// }
+///
+/// assert_eq!(Status::Suspended.to_value(), 2);
+/// assert_eq!(Status::try_from_value(&1), Ok(Status::Active));
+/// assert_eq!(
+///     Status::try_from_value(&3),
+///     Err(DbErr::Type("unexpected value for Status enum: 3".to_owned()))
+/// );
+/// ```
 #[cfg(feature = "derive")]
 #[proc_macro_derive(DeriveActiveEnum, attributes(sea_orm))]
 pub fn derive_active_enum(input: TokenStream) -> TokenStream {
@@ -773,6 +976,24 @@ pub fn derive_from_json_query_result(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// A field can be selected from a joined entity with `from`, optionally along with `from_col`.
+/// The column is selected under the name of the field.
+/// ```
+/// use sea_orm::{entity::prelude::*, tests_cfg::{cake, fruit}, DerivePartialModel, FromQueryResult};
+///
+/// #[derive(Debug, FromQueryResult, DerivePartialModel)]
+/// #[sea_orm(entity = "fruit::Entity")]
+/// struct FruitWithCake {
+///     name: String,
+///     #[sea_orm(from = "cake::Entity", from_col = "name")]
+///     cake_name: String,
+/// }
+///
+/// let select = fruit::Entity::find()
+///     .left_join(cake::Entity)
+///     .into_partial_model::<FruitWithCake>();
+/// ```
+///
 /// A field cannot have attributes `from_col` and `from_expr` at the same time.
 /// Or, it will result in a compile error.
 ///
@@ -866,6 +1087,46 @@ pub fn derive_value_type(input: TokenStream) -> TokenStream {
     }
 }
 
+/// Implements traits for types that map to a Postgres composite type.
+///
+/// This procedure macro implements `sea_orm::PgComposite`, `sea_orm::PgCompositeField`,
+/// `From<T> for Value`, `sea_orm::TryGetable` and `sea_query::ValueType` for the struct `T`.
+/// The fields of the struct are those of the composite type, in the same order.
+///
+/// The name of the composite type is given with `#[sea_orm(type_name = "..")]`,
+/// and defaults to the name of the struct in snake case.
+///
+/// ## Usage
+///
+/// ```rust
+/// use sea_orm::DeriveCompositeType;
+///
+/// // CREATE TYPE address AS (street text, city text, zip text)
+/// #[derive(Clone, Debug, PartialEq, DeriveCompositeType)]
+/// #[sea_orm(type_name = "address")]
+/// struct Address {
+///     street: String,
+///     city: String,
+///     zip: Option<String>,
+/// }
+/// ```
+///
+/// The columns have to be cast from and to the composite type on Postgres:
+///
+/// ```ignore
+/// #[sea_orm(select_as = "text", save_as = "address")]
+/// pub address: Address,
+/// ```
+#[cfg(feature = "derive")]
+#[proc_macro_derive(DeriveCompositeType, attributes(sea_orm))]
+pub fn derive_composite_type(input: TokenStream) -> TokenStream {
+    let derive_input = parse_macro_input!(input as DeriveInput);
+    match derives::expand_derive_composite_type(derive_input) {
+        Ok(token_stream) => token_stream.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
 #[cfg(feature = "derive")]
 #[proc_macro_derive(DeriveDisplay, attributes(sea_orm))]
 pub fn derive_active_enum_display(input: TokenStream) -> TokenStream {
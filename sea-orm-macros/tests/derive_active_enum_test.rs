@@ -137,3 +137,53 @@ fn derive_active_enum_value_2() {
 
     assert_eq!(TestEnum3::HelloWorld.to_value(), "hello_world");
 }
+
+// Labels of an existing database enum, which do not follow the Rust naming
+#[derive(Debug, EnumIter, DeriveActiveEnum, Eq, PartialEq)]
+#[sea_orm(
+    rs_type = "String",
+    db_type = "Enum",
+    enum_name = "task_status",
+    rename_all = "SCREAMING_SNAKE_CASE"
+)]
+pub enum TaskStatus {
+    Pending,
+    InProgress,
+    #[sea_orm(string_value = "DONE!")]
+    Completed,
+}
+
+#[test]
+fn derive_active_enum_mismatched_names() {
+    use sea_orm::{sea_query::ColumnType, Iterable};
+
+    assert_eq!(TaskStatus::Pending.to_value(), "PENDING");
+    assert_eq!(TaskStatus::InProgress.to_value(), "IN_PROGRESS");
+    assert_eq!(TaskStatus::Completed.to_value(), "DONE!");
+
+    assert_eq!(
+        TaskStatus::try_from_value(&"IN_PROGRESS".to_owned()),
+        Ok(TaskStatus::InProgress)
+    );
+    assert_eq!(
+        TaskStatus::try_from_value(&"DONE!".to_owned()),
+        Ok(TaskStatus::Completed)
+    );
+    assert!(TaskStatus::try_from_value(&"InProgress".to_owned()).is_err());
+    assert!(TaskStatus::try_from_value(&"COMPLETED".to_owned()).is_err());
+
+    assert_eq!(
+        TaskStatus::iter().map(|v| v.to_value()).collect::<Vec<_>>(),
+        ["PENDING", "IN_PROGRESS", "DONE!"]
+    );
+    match TaskStatus::db_type().get_column_type() {
+        ColumnType::Enum { name, variants } => {
+            assert_eq!(name.to_string(), "task_status");
+            assert_eq!(
+                variants.iter().map(|v| v.to_string()).collect::<Vec<_>>(),
+                ["PENDING", "IN_PROGRESS", "DONE!"]
+            );
+        }
+        col_type => panic!("Unexpected column type {col_type:?}"),
+    }
+}
@@ -37,3 +37,86 @@ fn test_column_names() {
         ]
     );
 }
+
+macro_rules! naming_convention {
+    ( $module: ident, $case: literal, [ $( $column: literal ),* ] ) => {
+        mod $module {
+            use sea_orm::entity::prelude::*;
+            use sea_orm::Iterable;
+
+            #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
+            #[sea_orm(table_name = "user", rename_all = $case)]
+            pub struct Model {
+                #[sea_orm(primary_key)]
+                id: i32,
+                first_name: String,
+                #[sea_orm(column_name = "last_name")]
+                last_name: String,
+                orders_count: i32,
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+            pub enum Relation {}
+
+            impl ActiveModelBehavior for ActiveModel {}
+
+            #[test]
+            fn test_column_names() {
+                let columns: Vec<String> = Column::iter().map(|item| item.to_string()).collect();
+
+                assert_eq!(columns, [ $( $column ),* ]);
+            }
+        }
+    };
+}
+
+naming_convention!(
+    camel_case,
+    "camelCase",
+    ["id", "firstName", "last_name", "ordersCount"]
+);
+naming_convention!(
+    pascal_case,
+    "PascalCase",
+    ["Id", "FirstName", "last_name", "OrdersCount"]
+);
+naming_convention!(
+    snake_case,
+    "snake_case",
+    ["id", "first_name", "last_name", "orders_count"]
+);
+naming_convention!(
+    screaming_snake_case,
+    "SCREAMING_SNAKE_CASE",
+    ["ID", "FIRST_NAME", "last_name", "ORDERS_COUNT"]
+);
+naming_convention!(
+    kebab_case,
+    "kebab-case",
+    ["id", "first-name", "last_name", "orders-count"]
+);
+naming_convention!(
+    screaming_kebab_case,
+    "SCREAMING-KEBAB-CASE",
+    ["ID", "FIRST-NAME", "last_name", "ORDERS-COUNT"]
+);
+naming_convention!(
+    lowercase,
+    "lowercase",
+    ["id", "firstname", "last_name", "orderscount"]
+);
+naming_convention!(
+    uppercase,
+    "UPPERCASE",
+    ["ID", "FIRSTNAME", "last_name", "ORDERSCOUNT"]
+);
+naming_convention!(
+    title_case,
+    "title_case",
+    ["Id", "First Name", "last_name", "Orders Count"]
+);
+naming_convention!(
+    mixed_case,
+    "mixed_case",
+    ["id", "firstName", "last_name", "ordersCount"]
+);
@@ -5,6 +5,7 @@ use sea_orm::{
 };
 use std::pin::Pin;
 
+#[derive(Clone, Copy)]
 pub enum SchemaManagerConnection<'c> {
     Connection(&'c DatabaseConnection),
     Transaction(&'c DatabaseTransaction),
@@ -27,8 +27,79 @@ pub trait MigrationTrait: MigrationName + Send + Sync {
     /// Define actions to perform when applying the migration
     async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr>;
 
-    /// Define actions to perform when rolling back the migration
-    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+    /// Define actions to perform when rolling back the migration.
+    /// It is derived from `up` for [reversible](MigrationTrait::reversible) migrations.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if self.reversible() {
+            return manager.exec_reverse(self).await;
+        }
         Err(DbErr::Migration("We Don't Do That Here".to_owned()))
     }
+
+    /// Whether `down` can be derived from `up`, it is `false` by default.
+    ///
+    /// To roll back the migration, `up` is replayed on a dry-run [`SchemaManager`] recording
+    /// the tables, indexes and foreign keys it creates, which are then dropped in reverse order.
+    /// If `up` executes any other statement, it is deemed irreversible and rolling back fails,
+    /// so `down` has to be implemented manually. During the replay, schema inspection methods
+    /// like `has_table` return `false` and the connection cannot be used.
+    ///
+    /// ```
+    /// use sea_orm_migration::{prelude::*, schema::*};
+    ///
+    /// #[derive(DeriveMigrationName)]
+    /// pub struct Migration;
+    ///
+    /// #[async_trait::async_trait]
+    /// impl MigrationTrait for Migration {
+    ///     async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+    ///         manager
+    ///             .create_table(
+    ///                 Table::create()
+    ///                     .table(Post::Table)
+    ///                     .col(pk_auto(Post::Id))
+    ///                     .col(string(Post::Title))
+    ///                     .to_owned(),
+    ///             )
+    ///             .await?;
+    ///         manager
+    ///             .create_index(
+    ///                 Index::create()
+    ///                     .name("idx-post-title")
+    ///                     .table(Post::Table)
+    ///                     .col(Post::Title)
+    ///                     .to_owned(),
+    ///             )
+    ///             .await
+    ///     }
+    ///
+    ///     // `down` drops the index, then the table
+    ///     fn reversible(&self) -> bool {
+    ///         true
+    ///     }
+    /// }
+    ///
+    /// #[derive(DeriveIden)]
+    /// enum Post {
+    ///     Table,
+    ///     Id,
+    ///     Title,
+    /// }
+    /// ```
+    fn reversible(&self) -> bool {
+        false
+    }
+
+    /// Whether to run this migration inside a transaction when
+    /// [`MigratorTrait::transaction_per_migration`] is enabled, it is `true` by default.
+    /// Opt out for statements that cannot run in a transaction, like `CREATE INDEX CONCURRENTLY` on Postgres.
+    fn transactional(&self) -> bool {
+        true
+    }
+
+    /// Statement timeout of this migration, overriding [`MigratorTrait::statement_timeout`]
+    /// for migrations known to be slow or to contend for locks. It is `None` by default.
+    fn statement_timeout(&self) -> Option<std::time::Duration> {
+        None
+    }
 }
@@ -1,16 +1,87 @@
-use super::{IntoSchemaManagerConnection, SchemaManagerConnection};
+use super::{IntoSchemaManagerConnection, MigrationTrait, SchemaManagerConnection};
+use crate::migrator::{get_current_schema, query_tables, PgNamespace, PgType};
+use crate::util::split_sql_statements;
 use sea_orm::sea_query::{
     extension::postgres::{TypeAlterStatement, TypeCreateStatement, TypeDropStatement},
-    ForeignKeyCreateStatement, ForeignKeyDropStatement, IndexCreateStatement, IndexDropStatement,
-    TableAlterStatement, TableCreateStatement, TableDropStatement, TableRenameStatement,
-    TableTruncateStatement,
+    Alias, Condition, ConditionalStatement, Expr, ForeignKey, ForeignKeyCreateStatement,
+    ForeignKeyDropStatement, Index, IndexCreateStatement, IndexDropStatement, IntoIden, JoinType,
+    LikeExpr, Query, SelectStatement, SimpleExpr, Table, TableAlterStatement, TableCreateStatement,
+    TableDropStatement, TableRef, TableRenameStatement, TableTruncateStatement,
+};
+use sea_orm::{
+    ConnectionTrait, DatabaseConnection, DbBackend, DbErr, DeriveIden, Schema, Statement,
+    StatementBuilder, TransactionTrait,
 };
-use sea_orm::{ConnectionTrait, DbBackend, DbErr, StatementBuilder};
 use sea_schema::probe::SchemaProbe;
+use std::path::Path;
+use std::sync::Mutex;
+use tracing::warn;
+
+pub use sea_orm::{ColumnInfo, DiscoveredColumn, DiscoveredTable, ForeignKeyInfo, IndexInfo};
 
 /// Helper struct for writing migration scripts in migration file
 pub struct SchemaManager<'c> {
     conn: SchemaManagerConnection<'c>,
+    dry_run: Option<Mutex<DryRun>>,
+    checksum: Mutex<Checksum>,
+}
+
+/// The connection of a dry-run schema manager, which fails to execute anything
+static DISCONNECTED: DatabaseConnection = DatabaseConnection::Disconnected;
+
+struct DryRun {
+    backend: DbBackend,
+    statements: Vec<String>,
+    /// Statements undoing the collected ones, `None` once an irreversible statement is collected
+    inverse: Option<Vec<InverseStatement>>,
+}
+
+enum InverseStatement {
+    DropTable(TableDropStatement),
+    DropIndex(IndexDropStatement),
+    DropForeignKey(ForeignKeyDropStatement),
+}
+
+/// 64-bit FNV-1a hash of the statements executed or collected by a schema manager,
+/// which is stable across platforms and Rust versions
+struct Checksum(u64);
+
+impl Default for Checksum {
+    fn default() -> Self {
+        Self(0xcbf29ce484222325)
+    }
+}
+
+impl Checksum {
+    fn push(&mut self, statement: &str) {
+        for byte in statement.bytes().chain([b';']) {
+            self.0 ^= byte as u64;
+            self.0 = self.0.wrapping_mul(0x100000001b3);
+        }
+    }
+
+    fn finish(&self) -> String {
+        format!("{:016x}", self.0)
+    }
+}
+
+impl DryRun {
+    fn push(&mut self, statement: String, inverse: Option<InverseStatement>) {
+        self.statements.push(statement);
+        match (self.inverse.as_mut(), inverse) {
+            (Some(statements), Some(inverse)) => statements.push(inverse),
+            _ => self.inverse = None,
+        }
+    }
+}
+
+/// Options of [`SchemaManager::truncate_table_with`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TruncateOptions {
+    /// Truncate the tables referencing the table with a foreign key as well
+    pub cascade: bool,
+    /// Reset the sequences of the identity and auto increment columns of the table
+    pub restart_identity: bool,
 }
 
 impl<'c> SchemaManager<'c> {
@@ -20,19 +91,207 @@ impl<'c> SchemaManager<'c> {
     {
         Self {
             conn: conn.into_schema_manager_connection(),
+            dry_run: None,
+            checksum: Mutex::default(),
         }
     }
 
+    /// Create a schema manager that does not touch any database. Every statement passed to
+    /// `exec_stmt`, `create_*`, `alter_*`, `drop_*`, etc. is built for the given backend and
+    /// collected, to be retrieved with [`SchemaManager::take_statements`].
+    ///
+    /// Schema inspection methods cannot look into a database, so `has_*` methods return
+    /// `false` and `get_*` methods return an empty list. The connection returned by
+    /// [`SchemaManager::get_connection`] is disconnected: accessing the database through it
+    /// results in an error, and the backend has to be taken from
+    /// [`SchemaManager::get_database_backend`] instead.
+    pub fn new_dry_run(backend: DbBackend) -> Self {
+        Self::new_dry_run_on(SchemaManagerConnection::Connection(&DISCONNECTED), backend)
+    }
+
+    fn new_dry_run_on(conn: SchemaManagerConnection<'c>, backend: DbBackend) -> Self {
+        Self {
+            conn,
+            dry_run: Some(Mutex::new(DryRun {
+                backend,
+                statements: Vec::new(),
+                inverse: Some(Vec::new()),
+            })),
+            checksum: Mutex::default(),
+        }
+    }
+
+    /// Whether this schema manager was created with [`SchemaManager::new_dry_run`]
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run.is_some()
+    }
+
+    /// Take the SQL statements collected so far in dry-run mode.
+    /// It is always empty when not in dry-run mode.
+    pub fn take_statements(&self) -> Vec<String> {
+        match &self.dry_run {
+            Some(dry_run) => std::mem::take(&mut dry_run.lock().expect("poisoned lock").statements),
+            None => Vec::new(),
+        }
+    }
+
+    /// Take the checksum of the statements executed, or collected in dry-run mode, so far
+    /// and start over
+    pub(crate) fn take_checksum(&self) -> String {
+        std::mem::take(&mut *self.checksum.lock().expect("poisoned lock")).finish()
+    }
+
+    fn push_checksum(&self, statement: &str) {
+        self.checksum.lock().expect("poisoned lock").push(statement);
+    }
+
     pub async fn exec_stmt<S>(&self, stmt: S) -> Result<(), DbErr>
     where
         S: StatementBuilder,
     {
-        let builder = self.conn.get_database_backend();
-        self.conn.execute(builder.build(&stmt)).await.map(|_| ())
+        self.exec_reversible_stmt(stmt, None).await
+    }
+
+    async fn exec_reversible_stmt<S>(
+        &self,
+        stmt: S,
+        inverse: Option<InverseStatement>,
+    ) -> Result<(), DbErr>
+    where
+        S: StatementBuilder,
+    {
+        let stmt = self.get_database_backend().build(&stmt);
+        self.push_checksum(&stmt.to_string());
+        if let Some(dry_run) = &self.dry_run {
+            dry_run
+                .lock()
+                .expect("poisoned lock")
+                .push(stmt.to_string(), inverse);
+            return Ok(());
+        }
+        self.conn.execute(stmt).await.map(|_| ())
+    }
+
+    /// Undo the `up` of a migration, see [`MigrationTrait::reversible`]
+    pub(crate) async fn exec_reverse<M>(&self, migration: &M) -> Result<(), DbErr>
+    where
+        M: MigrationTrait + ?Sized,
+    {
+        let db = match self.is_dry_run() {
+            true => None,
+            false => Some(&self.conn),
+        };
+        let (replayed, dry_run) = replay_up(db, self.get_database_backend(), migration).await?;
+        replayed.map_err(|e| {
+            DbErr::Migration(format!(
+                "Failed to replay migration '{}' to derive its `down`: {e}",
+                migration.name()
+            ))
+        })?;
+        let inverse = dry_run.inverse.ok_or_else(|| {
+            DbErr::Migration(format!(
+                "Migration '{}' is not reversible, `down` has to be implemented manually",
+                migration.name()
+            ))
+        })?;
+
+        for stmt in inverse.into_iter().rev() {
+            match stmt {
+                InverseStatement::DropTable(stmt) => self.drop_table(stmt).await?,
+                InverseStatement::DropIndex(stmt) => self.drop_index(stmt).await?,
+                InverseStatement::DropForeignKey(stmt) => self.drop_foreign_key(stmt).await?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Run the `up` of a migration on a dry-run schema manager, returning the checksum of the
+    /// collected statements, see [`MigratorTrait::verify_checksums`](crate::MigratorTrait::verify_checksums)
+    pub(crate) async fn dry_run_checksum<C, M>(db: &C, migration: &M) -> Result<String, DbErr>
+    where
+        C: ConnectionTrait + TransactionTrait,
+        M: MigrationTrait + ?Sized,
+    {
+        let (replayed, dry_run) = replay_up(Some(db), db.get_database_backend(), migration).await?;
+        replayed?;
+        let mut checksum = Checksum::default();
+        for statement in &dry_run.statements {
+            checksum.push(statement);
+        }
+        Ok(checksum.finish())
+    }
+
+    /// Execute the statements in order within a single transaction,
+    /// which is rolled back as soon as one of them fails.
+    ///
+    /// Note that MySQL implicitly commits DDL statements, so those cannot be rolled back.
+    pub async fn exec_stmts<I, S>(&self, stmts: I) -> Result<(), DbErr>
+    where
+        I: IntoIterator<Item = S>,
+        S: StatementBuilder,
+    {
+        if self.is_dry_run() {
+            for stmt in stmts {
+                self.exec_stmt(stmt).await?;
+            }
+            return Ok(());
+        }
+        let builder = self.get_database_backend();
+        let transaction = self.conn.begin().await?;
+        for stmt in stmts {
+            let stmt = builder.build(&stmt);
+            self.push_checksum(&stmt.to_string());
+            transaction.execute(stmt).await?;
+        }
+        transaction.commit().await
+    }
+
+    /// Execute a SQL script, statement by statement.
+    /// See [`split_sql_statements`] on how the script is split into statements.
+    pub async fn exec_sql(&self, sql: &str) -> Result<(), DbErr> {
+        for stmt in split_sql_statements(sql, self.get_database_backend()) {
+            self.push_checksum(&stmt);
+            if let Some(dry_run) = &self.dry_run {
+                dry_run.lock().expect("poisoned lock").push(stmt, None);
+                continue;
+            }
+            self.conn.execute_unprepared(&stmt).await?;
+        }
+        Ok(())
+    }
+
+    /// Read a SQL script from a file and execute it, see [`SchemaManager::exec_sql`]
+    pub async fn exec_sql_file(&self, path: &Path) -> Result<(), DbErr> {
+        let sql = std::fs::read_to_string(path).map_err(|e| {
+            DbErr::Migration(format!("Failed to read SQL file '{}': {e}", path.display()))
+        })?;
+        self.exec_sql(&sql).await
+    }
+
+    /// Set the Postgres `search_path` of the connection with `SET search_path`, so that
+    /// unqualified tables, types, etc. are created in and looked up from the given schemas,
+    /// e.g. `"my_schema, public"`, for the lifetime of the connection.
+    ///
+    /// On a pool it is set for a single connection only: use
+    /// [`DatabaseConnection::set_schema_search_path`](sea_orm::DatabaseConnection::set_schema_search_path)
+    /// to open a connection of its own, as the migrator does for its
+    /// [`search_path`](crate::MigratorTrait::search_path), or
+    /// [`ConnectOptions::set_schema_search_path`](sea_orm::ConnectOptions::set_schema_search_path)
+    /// to set it for every connection. It is ignored with a warning on MySQL and SQLite.
+    pub async fn set_search_path(&self, search_path: &str) -> Result<(), DbErr> {
+        if self.get_database_backend() != DbBackend::Postgres {
+            warn!("Ignoring search_path '{search_path}', it is only supported by Postgres");
+            return Ok(());
+        }
+        self.exec_sql(&format!("SET search_path = {search_path}"))
+            .await
     }
 
     pub fn get_database_backend(&self) -> DbBackend {
-        self.conn.get_database_backend()
+        match &self.dry_run {
+            Some(dry_run) => dry_run.lock().expect("poisoned lock").backend,
+            None => self.conn.get_database_backend(),
+        }
     }
 
     pub fn get_connection(&self) -> &SchemaManagerConnection<'c> {
@@ -43,15 +302,81 @@ impl<'c> SchemaManager<'c> {
 /// Schema Creation
 impl<'c> SchemaManager<'c> {
     pub async fn create_table(&self, stmt: TableCreateStatement) -> Result<(), DbErr> {
+        let inverse = stmt.get_table_name().map(|table| {
+            InverseStatement::DropTable(Table::drop().table(table.clone()).to_owned())
+        });
+        self.exec_reversible_stmt(stmt, inverse).await
+    }
+
+    /// Create a table unless a table of the same name already exists, in the schema the table
+    /// is qualified with, or the attached database on SQLite, or else in the current schema
+    pub async fn create_table_if_not_exists(
+        &self,
+        stmt: TableCreateStatement,
+    ) -> Result<(), DbErr> {
+        let exists = match stmt.get_table_name() {
+            Some(TableRef::SchemaTable(schema, table))
+            | Some(TableRef::DatabaseSchemaTable(_, schema, table)) => {
+                self.has_table_in_schema(schema.to_string(), table.to_string())
+                    .await?
+            }
+            table => self.has_table(get_table_name(table)?).await?,
+        };
+        if exists {
+            return Ok(());
+        }
+        // The table may exist beforehand, so it is not safe to drop it on rollback
         self.exec_stmt(stmt).await
     }
 
     pub async fn create_index(&self, stmt: IndexCreateStatement) -> Result<(), DbErr> {
-        self.exec_stmt(stmt).await
+        let inverse = match (stmt.get_index_spec().get_name(), stmt.get_table()) {
+            (Some(name), Some(table)) => Some(InverseStatement::DropIndex(
+                Index::drop().name(name).table(table.clone()).to_owned(),
+            )),
+            _ => None,
+        };
+        self.exec_reversible_stmt(stmt, inverse).await
+    }
+
+    /// Create a partial index, covering only the rows for which the condition is true,
+    /// with `CREATE INDEX .. WHERE ..`. With [`IndexCreateStatement::unique`], the values
+    /// only have to be unique among those rows, like the emails of the rows not deleted.
+    ///
+    /// It is supported by Postgres and SQLite; an error is returned on MySQL.
+    pub async fn create_partial_index(
+        &self,
+        mut stmt: IndexCreateStatement,
+        condition: SimpleExpr,
+    ) -> Result<(), DbErr> {
+        let db_backend = self.get_database_backend();
+        if db_backend == DbBackend::MySql {
+            return Err(DbErr::Migration(
+                "MySQL does not support partial indexes".to_owned(),
+            ));
+        }
+        let inverse = match (stmt.get_index_spec().get_name(), stmt.get_table()) {
+            (Some(name), Some(table)) => Some(InverseStatement::DropIndex(
+                Index::drop().name(name).table(table.clone()).to_owned(),
+            )),
+            _ => None,
+        };
+        stmt.and_where(condition);
+        self.exec_reversible_stmt(stmt, inverse).await
     }
 
     pub async fn create_foreign_key(&self, stmt: ForeignKeyCreateStatement) -> Result<(), DbErr> {
-        self.exec_stmt(stmt).await
+        let foreign_key = stmt.get_foreign_key();
+        let inverse = match (foreign_key.get_name(), foreign_key.get_table()) {
+            (Some(name), Some(table)) => Some(InverseStatement::DropForeignKey(
+                ForeignKey::drop()
+                    .name(name)
+                    .table(table.clone())
+                    .to_owned(),
+            )),
+            _ => None,
+        };
+        self.exec_reversible_stmt(stmt, inverse).await
     }
 
     pub async fn create_type(&self, stmt: TypeCreateStatement) -> Result<(), DbErr> {
@@ -65,10 +390,59 @@ impl<'c> SchemaManager<'c> {
         self.exec_stmt(stmt).await
     }
 
+    /// Rename a column of a table with `ALTER TABLE ... RENAME COLUMN`.
+    ///
+    /// SQLite only supports this syntax since 3.25.0, so an error naming the version is returned
+    /// on older versions, or when the version cannot be parsed.
+    pub async fn rename_column<T, A, B>(&self, table: T, from: A, to: B) -> Result<(), DbErr>
+    where
+        T: IntoIden,
+        A: IntoIden,
+        B: IntoIden,
+    {
+        if self.get_database_backend() == DbBackend::Sqlite && !self.is_dry_run() {
+            let version: String = self
+                .conn
+                .query_one(Statement::from_string(
+                    DbBackend::Sqlite,
+                    "SELECT sqlite_version() AS version",
+                ))
+                .await?
+                .ok_or_else(|| DbErr::Custom("Failed to get SQLite version".to_owned()))?
+                .try_get("", "version")?;
+            if parse_sqlite_version(&version)? < (3, 25) {
+                return Err(DbErr::Migration(format!(
+                    "SQLite {version} does not support renaming columns, version 3.25.0 or later is required"
+                )));
+            }
+        }
+
+        self.alter_table(
+            Table::alter()
+                .table(table)
+                .rename_column(from, to)
+                .to_owned(),
+        )
+        .await
+    }
+
     pub async fn drop_table(&self, stmt: TableDropStatement) -> Result<(), DbErr> {
         self.exec_stmt(stmt).await
     }
 
+    /// Drop a table unless it does not exist in the current schema.
+    /// In dry-run mode the `DROP TABLE` statement is always collected.
+    pub async fn drop_table_if_exists<T>(&self, table: T) -> Result<(), DbErr>
+    where
+        T: IntoIden,
+    {
+        let table = table.into_iden();
+        if !self.is_dry_run() && !self.has_table(table.to_string()).await? {
+            return Ok(());
+        }
+        self.drop_table(Table::drop().table(table).to_owned()).await
+    }
+
     pub async fn rename_table(&self, stmt: TableRenameStatement) -> Result<(), DbErr> {
         self.exec_stmt(stmt).await
     }
@@ -77,14 +451,127 @@ impl<'c> SchemaManager<'c> {
         self.exec_stmt(stmt).await
     }
 
+    /// Remove all the rows of a table, with the given [`TruncateOptions`].
+    ///
+    /// - Postgres: `TRUNCATE TABLE` with `RESTART IDENTITY` and `CASCADE`
+    /// - MySQL: `TRUNCATE TABLE`, which always resets the auto increment counter;
+    ///   an error is returned for `cascade`, as tables referenced by a foreign key
+    ///   cannot be truncated
+    /// - SQLite: `DELETE FROM`, as there is no `TRUNCATE`, and the row of the table in
+    ///   `sqlite_sequence` is deleted for `restart_identity`; the rows of the referencing
+    ///   tables are only deleted by their `ON DELETE CASCADE` foreign keys
+    pub async fn truncate_table_with<T>(
+        &self,
+        table: T,
+        options: TruncateOptions,
+    ) -> Result<(), DbErr>
+    where
+        T: IntoIden,
+    {
+        let db_backend = self.get_database_backend();
+        let name = table.into_iden().to_string();
+        let table = quote_iden(db_backend, &name);
+        match db_backend {
+            DbBackend::Postgres => {
+                let mut sql = format!("TRUNCATE TABLE {table}");
+                if options.restart_identity {
+                    sql.push_str(" RESTART IDENTITY");
+                }
+                if options.cascade {
+                    sql.push_str(" CASCADE");
+                }
+                self.exec_sql(&sql).await
+            }
+            DbBackend::MySql => {
+                if options.cascade {
+                    return Err(DbErr::Migration(
+                        "MySQL does not support truncating the referencing tables".to_owned(),
+                    ));
+                }
+                self.exec_sql(&format!("TRUNCATE TABLE {table}")).await
+            }
+            DbBackend::Sqlite => {
+                self.exec_sql(&format!("DELETE FROM {table}")).await?;
+                // The sequence table only exists once a table with `AUTOINCREMENT` is created
+                if options.restart_identity
+                    && (self.is_dry_run() || self.has_table("sqlite_sequence").await?)
+                {
+                    let name = name.replace('\'', "''");
+                    self.exec_sql(&format!(
+                        "DELETE FROM sqlite_sequence WHERE name = '{name}'"
+                    ))
+                    .await?;
+                }
+                Ok(())
+            }
+        }
+    }
+
     pub async fn drop_index(&self, stmt: IndexDropStatement) -> Result<(), DbErr> {
         self.exec_stmt(stmt).await
     }
 
+    /// Drop an index of a table unless it does not exist.
+    /// In dry-run mode the `DROP INDEX` statement is always collected.
+    pub async fn drop_index_if_exists<T, I>(&self, table: T, index: I) -> Result<(), DbErr>
+    where
+        T: IntoIden,
+        I: AsRef<str>,
+    {
+        let table = table.into_iden();
+        if !self.is_dry_run() && !self.has_index(table.to_string(), index.as_ref()).await? {
+            return Ok(());
+        }
+        self.drop_index(Index::drop().name(index.as_ref()).table(table).to_owned())
+            .await
+    }
+
     pub async fn drop_foreign_key(&self, stmt: ForeignKeyDropStatement) -> Result<(), DbErr> {
         self.exec_stmt(stmt).await
     }
 
+    /// Add a named `CHECK` constraint to an existing table, with
+    /// `ALTER TABLE .. ADD CONSTRAINT .. CHECK (..)`.
+    ///
+    /// SQLite cannot alter the constraints of a table, so an error is returned there;
+    /// its checks have to be defined with the columns when creating the table.
+    pub async fn add_check<T, N>(&self, table: T, name: N, check: SimpleExpr) -> Result<(), DbErr>
+    where
+        T: IntoIden,
+        N: AsRef<str>,
+    {
+        let db_backend = self.get_database_backend();
+        if db_backend == DbBackend::Sqlite {
+            return Err(alter_constraint_err());
+        }
+        let table = quote_iden(db_backend, &table.into_iden().to_string());
+        let name = quote_iden(db_backend, name.as_ref());
+        let check = build_expr(db_backend, check);
+        self.exec_sql(&format!(
+            "ALTER TABLE {table} ADD CONSTRAINT {name} CHECK ({check})"
+        ))
+        .await
+    }
+
+    /// Drop a named `CHECK` constraint of a table, with `ALTER TABLE .. DROP CONSTRAINT ..`.
+    ///
+    /// MySQL supports this syntax since 8.0.19. SQLite cannot alter the constraints of a table,
+    /// so an error is returned there.
+    pub async fn drop_check<T, N>(&self, table: T, name: N) -> Result<(), DbErr>
+    where
+        T: IntoIden,
+        N: AsRef<str>,
+    {
+        let db_backend = self.get_database_backend();
+        if db_backend == DbBackend::Sqlite {
+            return Err(alter_constraint_err());
+        }
+        let table = quote_iden(db_backend, &table.into_iden().to_string());
+        let name = quote_iden(db_backend, name.as_ref());
+        self.exec_sql(&format!("ALTER TABLE {table} DROP CONSTRAINT {name}"))
+            .await
+    }
+
     pub async fn alter_type(&self, stmt: TypeAlterStatement) -> Result<(), DbErr> {
         self.exec_stmt(stmt).await
     }
@@ -94,62 +581,123 @@ impl<'c> SchemaManager<'c> {
     }
 }
 
+/// Replay the `up` of a migration on a dry-run schema manager, returning its result along with
+/// what has been collected. As the `up` may use the connection directly, it is given a
+/// transaction on `db` which is rolled back afterwards, or a disconnected connection without.
+async fn replay_up<C, M>(
+    db: Option<&C>,
+    backend: DbBackend,
+    migration: &M,
+) -> Result<(Result<(), DbErr>, DryRun), DbErr>
+where
+    C: TransactionTrait,
+    M: MigrationTrait + ?Sized,
+{
+    let transaction = match db {
+        Some(db) => Some(db.begin().await?),
+        None => None,
+    };
+    let conn = match &transaction {
+        Some(transaction) => SchemaManagerConnection::Transaction(transaction),
+        None => SchemaManagerConnection::Connection(&DISCONNECTED),
+    };
+    let recorder = SchemaManager::new_dry_run_on(conn, backend);
+    let replayed = migration.up(&recorder).await;
+    let SchemaManager { dry_run, .. } = recorder;
+    let dry_run = dry_run
+        .expect("a dry-run schema manager")
+        .into_inner()
+        .expect("poisoned lock");
+    if let Some(transaction) = transaction {
+        transaction.rollback().await?;
+    }
+    Ok((replayed, dry_run))
+}
+
+fn quote_iden(db_backend: DbBackend, iden: &str) -> String {
+    let quote = match db_backend {
+        DbBackend::MySql => '`',
+        DbBackend::Postgres | DbBackend::Sqlite => '"',
+    };
+    let escaped = iden.replace(quote, &format!("{quote}{quote}"));
+    format!("{quote}{escaped}{quote}")
+}
+
+/// Render an expression with its values inlined
+fn build_expr(db_backend: DbBackend, expr: SimpleExpr) -> String {
+    let sql = db_backend
+        .build(&Query::select().expr(expr).to_owned())
+        .to_string();
+    match sql.strip_prefix("SELECT ") {
+        Some(expr) => expr.to_owned(),
+        None => sql,
+    }
+}
+
+/// Parse the major and minor numbers of a version returned by `sqlite_version()`, like `3.45.1`
+fn parse_sqlite_version(version: &str) -> Result<(u32, u32), DbErr> {
+    let mut parts = version.split('.').map(|part| part.parse::<u32>().ok());
+    match (parts.next().flatten(), parts.next().flatten()) {
+        (Some(major), Some(minor)) => Ok((major, minor)),
+        _ => Err(DbErr::Migration(format!(
+            "Failed to parse SQLite version '{version}'"
+        ))),
+    }
+}
+
+fn alter_constraint_err() -> DbErr {
+    DbErr::Migration("Sqlite does not support altering the constraints of a table".to_owned())
+}
+
 /// Schema Inspection.
 impl<'c> SchemaManager<'c> {
     pub async fn has_table<T>(&self, table: T) -> Result<bool, DbErr>
     where
         T: AsRef<str>,
     {
+        if self.is_dry_run() {
+            return Ok(false);
+        }
         has_table(&self.conn, table).await
     }
 
-    pub async fn has_column<T, C>(&self, table: T, column: C) -> Result<bool, DbErr>
-    where
-        T: AsRef<str>,
-        C: AsRef<str>,
-    {
-        let stmt = match self.conn.get_database_backend() {
-            DbBackend::MySql => {
-                #[cfg(feature = "sqlx-mysql")]
-                {
-                    sea_schema::mysql::MySql.has_column(table, column)
-                }
-                #[cfg(not(feature = "sqlx-mysql"))]
-                {
-                    panic!("mysql feature is off")
-                }
-            }
-            #[cfg(feature = "sqlx-postgres")]
-            DbBackend::Postgres => {
-                #[cfg(feature = "sqlx-postgres")]
-                {
-                    sea_schema::postgres::Postgres.has_column(table, column)
-                }
-                #[cfg(not(feature = "sqlx-postgres"))]
-                {
-                    panic!("postgres feature is off")
-                }
-            }
-            DbBackend::Sqlite => {
-                #[cfg(feature = "sqlx-sqlite")]
-                {
-                    sea_schema::sqlite::Sqlite.has_column(table, column)
-                }
-                #[cfg(not(feature = "sqlx-sqlite"))]
-                {
-                    panic!("sqlite feature is off")
-                }
-            }
+    /// Check if a table exists in the given schema, or the attached database on SQLite
+    async fn has_table_in_schema(&self, schema: String, table: String) -> Result<bool, DbErr> {
+        if self.is_dry_run() {
+            return Ok(false);
+        }
+        let builder = self.get_database_backend();
+        let mut stmt = Query::select();
+        stmt.expr_as(Expr::cust("COUNT(*) > 0"), Alias::new("has_table"));
+        match builder {
+            DbBackend::MySql | DbBackend::Postgres => stmt
+                .from((InformationSchema::Schema, InformationSchema::Tables))
+                .and_where(Expr::col(InformationSchema::TableSchema).eq(schema))
+                .and_where(Expr::col(InformationSchema::TableName).eq(table)),
+            DbBackend::Sqlite => stmt
+                .from((Alias::new(schema), SqliteMaster::Table))
+                .and_where(Expr::col(SqliteMaster::Type).eq("table"))
+                .and_where(Expr::col(SqliteMaster::Name).eq(table)),
         };
 
-        let builder = self.conn.get_database_backend();
         let res = self
             .conn
             .query_one(builder.build(&stmt))
             .await?
-            .ok_or_else(|| DbErr::Custom("Failed to check column exists".to_owned()))?;
+            .ok_or_else(|| DbErr::Custom("Failed to check table exists".to_owned()))?;
 
-        res.try_get("", "has_column")
+        res.try_get("", "has_table")
+    }
+
+    pub async fn has_column<T, C>(&self, table: T, column: C) -> Result<bool, DbErr>
+    where
+        T: AsRef<str>,
+        C: AsRef<str>,
+    {
+        if self.is_dry_run() {
+            return Ok(false);
+        }
+        has_column(&self.conn, table, column).await
     }
 
     pub async fn has_index<T, I>(&self, table: T, index: I) -> Result<bool, DbErr>
@@ -157,7 +705,10 @@ impl<'c> SchemaManager<'c> {
         T: AsRef<str>,
         I: AsRef<str>,
     {
-        let stmt = match self.conn.get_database_backend() {
+        if self.is_dry_run() {
+            return Ok(false);
+        }
+        let stmt = match self.get_database_backend() {
             DbBackend::MySql => {
                 #[cfg(feature = "sqlx-mysql")]
                 {
@@ -165,10 +716,9 @@ impl<'c> SchemaManager<'c> {
                 }
                 #[cfg(not(feature = "sqlx-mysql"))]
                 {
-                    panic!("mysql feature is off")
+                    return Err(backend_not_enabled_err("MySQL", "sqlx-mysql"));
                 }
             }
-            #[cfg(feature = "sqlx-postgres")]
             DbBackend::Postgres => {
                 #[cfg(feature = "sqlx-postgres")]
                 {
@@ -176,7 +726,7 @@ impl<'c> SchemaManager<'c> {
                 }
                 #[cfg(not(feature = "sqlx-postgres"))]
                 {
-                    panic!("postgres feature is off")
+                    return Err(backend_not_enabled_err("Postgres", "sqlx-postgres"));
                 }
             }
             DbBackend::Sqlite => {
@@ -186,12 +736,12 @@ impl<'c> SchemaManager<'c> {
                 }
                 #[cfg(not(feature = "sqlx-sqlite"))]
                 {
-                    panic!("sqlite feature is off")
+                    return Err(backend_not_enabled_err("SQLite", "sqlx-sqlite"));
                 }
             }
         };
 
-        let builder = self.conn.get_database_backend();
+        let builder = self.get_database_backend();
         let res = self
             .conn
             .query_one(builder.build(&stmt))
@@ -200,6 +750,235 @@ impl<'c> SchemaManager<'c> {
 
         res.try_get("", "has_index")
     }
+
+    /// Check if a foreign key constraint with the given name exists on the table.
+    ///
+    /// SQLite does not keep foreign key names in its catalog, so the `CREATE TABLE`
+    /// statement stored in `sqlite_master` is searched for the named constraint instead.
+    pub async fn has_foreign_key<T, F>(&self, table: T, fk_name: F) -> Result<bool, DbErr>
+    where
+        T: AsRef<str>,
+        F: AsRef<str>,
+    {
+        if self.is_dry_run() {
+            return Ok(false);
+        }
+        let stmt = match self.get_database_backend() {
+            DbBackend::MySql | DbBackend::Postgres => {
+                query_has_foreign_key(&self.conn, table, fk_name)?
+            }
+            DbBackend::Sqlite => query_sqlite_has_foreign_key(table, fk_name),
+        };
+
+        let builder = self.get_database_backend();
+        let res = self
+            .conn
+            .query_one(builder.build(&stmt))
+            .await?
+            .ok_or_else(|| DbErr::Custom("Failed to check foreign key exists".to_owned()))?;
+
+        res.try_get("", "has_foreign_key")
+    }
+
+    /// Check if a user-defined type exists in the current schema.
+    ///
+    /// Only Postgres has user-defined types, so this always returns `false` on MySQL and SQLite.
+    pub async fn has_type<T>(&self, type_name: T) -> Result<bool, DbErr>
+    where
+        T: AsRef<str>,
+    {
+        if self.is_dry_run() {
+            return Ok(false);
+        }
+        let stmt = match self.get_database_backend() {
+            DbBackend::MySql | DbBackend::Sqlite => return Ok(false),
+            DbBackend::Postgres => query_pg_has_type(&self.conn, type_name)?,
+        };
+
+        let builder = self.get_database_backend();
+        let res = self
+            .conn
+            .query_one(builder.build(&stmt))
+            .await?
+            .ok_or_else(|| DbErr::Custom("Failed to check type exists".to_owned()))?;
+
+        res.try_get("", "has_type")
+    }
+
+    /// Check if a schema exists. On MySQL, a schema is a database.
+    ///
+    /// SQLite has no schemas, so this always returns `false`.
+    pub async fn has_schema<T>(&self, schema: T) -> Result<bool, DbErr>
+    where
+        T: AsRef<str>,
+    {
+        if self.is_dry_run() {
+            return Ok(false);
+        }
+        let stmt = match self.get_database_backend() {
+            DbBackend::MySql | DbBackend::Postgres => Query::select()
+                .expr_as(Expr::cust("COUNT(*) > 0"), Alias::new("has_schema"))
+                .from((InformationSchema::Schema, InformationSchema::Schemata))
+                .and_where(Expr::col(InformationSchema::SchemaName).eq(schema.as_ref()))
+                .to_owned(),
+            DbBackend::Sqlite => return Ok(false),
+        };
+
+        let builder = self.get_database_backend();
+        let res = self
+            .conn
+            .query_one(builder.build(&stmt))
+            .await?
+            .ok_or_else(|| DbErr::Custom("Failed to check schema exists".to_owned()))?;
+
+        res.try_get("", "has_schema")
+    }
+
+    /// Get names of all tables in the current schema, except the `seaql_migrations` table
+    /// used for bookkeeping. A migration table with an overridden name is not excluded.
+    pub async fn get_tables(&self) -> Result<Vec<String>, DbErr> {
+        let tables = self.get_all_tables().await?;
+        Ok(tables
+            .into_iter()
+            .filter(|table| table != "seaql_migrations")
+            .collect())
+    }
+
+    /// Get names of all tables in the current schema, including the migration table
+    pub async fn get_all_tables(&self) -> Result<Vec<String>, DbErr> {
+        if self.is_dry_run() {
+            return Ok(Vec::new());
+        }
+        let stmt = query_tables(&self.conn).await?;
+        let builder = self.get_database_backend();
+        self.conn
+            .query_all(builder.build(&stmt))
+            .await?
+            .into_iter()
+            .map(|row| row.try_get("", "table_name"))
+            .collect()
+    }
+
+    /// Get definitions of all indexes of a table, see [`Schema::get_indexes`]
+    pub async fn get_indexes<T>(&self, table: T) -> Result<Vec<IndexInfo>, DbErr>
+    where
+        T: AsRef<str>,
+    {
+        if self.is_dry_run() {
+            return Ok(Vec::new());
+        }
+        Schema::get_indexes(&self.conn, table).await
+    }
+
+    /// Get metadata of all columns of a table, see [`Schema::get_columns`]
+    pub async fn get_columns<T>(&self, table: T) -> Result<Vec<ColumnInfo>, DbErr>
+    where
+        T: AsRef<str>,
+    {
+        if self.is_dry_run() {
+            return Ok(Vec::new());
+        }
+        Schema::get_columns(&self.conn, table).await
+    }
+
+    /// Get the columns of the primary key of a table, see [`Schema::get_primary_key`]
+    pub async fn get_primary_key<T>(&self, table: T) -> Result<Vec<String>, DbErr>
+    where
+        T: AsRef<str>,
+    {
+        if self.is_dry_run() {
+            return Ok(Vec::new());
+        }
+        Schema::get_primary_key(&self.conn, table).await
+    }
+
+    /// Get the foreign keys of a table, see [`Schema::get_foreign_keys`]
+    pub async fn get_foreign_keys<T>(&self, table: T) -> Result<Vec<ForeignKeyInfo>, DbErr>
+    where
+        T: AsRef<str>,
+    {
+        if self.is_dry_run() {
+            return Ok(Vec::new());
+        }
+        Schema::get_foreign_keys(&self.conn, table).await
+    }
+
+    /// Describe all tables of the current schema, except the `seaql_migrations` table,
+    /// see [`Schema::discover`]
+    pub async fn discover(&self) -> Result<Vec<DiscoveredTable>, DbErr> {
+        if self.is_dry_run() {
+            return Ok(Vec::new());
+        }
+        let mut tables = Schema::discover(&self.conn).await?;
+        tables.retain(|table| table.name != "seaql_migrations");
+        Ok(tables)
+    }
+}
+
+fn get_table_name(table_ref: Option<&TableRef>) -> Result<String, DbErr> {
+    match table_ref {
+        Some(TableRef::Table(table))
+        | Some(TableRef::SchemaTable(_, table))
+        | Some(TableRef::DatabaseSchemaTable(_, _, table)) => Ok(table.to_string()),
+        _ => Err(DbErr::Migration(
+            "Table name is missing in the statement".to_owned(),
+        )),
+    }
+}
+
+#[allow(dead_code)]
+pub(crate) fn backend_not_enabled_err(backend: &str, feature: &str) -> DbErr {
+    DbErr::Custom(format!(
+        "{backend} backend is not enabled, please enable the `{feature}` feature of sea-orm-migration"
+    ))
+}
+
+pub(crate) async fn has_column<C, T, N>(conn: &C, table: T, column: N) -> Result<bool, DbErr>
+where
+    C: ConnectionTrait,
+    T: AsRef<str>,
+    N: AsRef<str>,
+{
+    let stmt = match conn.get_database_backend() {
+        DbBackend::MySql => {
+            #[cfg(feature = "sqlx-mysql")]
+            {
+                sea_schema::mysql::MySql.has_column(table, column)
+            }
+            #[cfg(not(feature = "sqlx-mysql"))]
+            {
+                return Err(backend_not_enabled_err("MySQL", "sqlx-mysql"));
+            }
+        }
+        DbBackend::Postgres => {
+            #[cfg(feature = "sqlx-postgres")]
+            {
+                sea_schema::postgres::Postgres.has_column(table, column)
+            }
+            #[cfg(not(feature = "sqlx-postgres"))]
+            {
+                return Err(backend_not_enabled_err("Postgres", "sqlx-postgres"));
+            }
+        }
+        DbBackend::Sqlite => {
+            #[cfg(feature = "sqlx-sqlite")]
+            {
+                sea_schema::sqlite::Sqlite.has_column(table, column)
+            }
+            #[cfg(not(feature = "sqlx-sqlite"))]
+            {
+                return Err(backend_not_enabled_err("SQLite", "sqlx-sqlite"));
+            }
+        }
+    };
+
+    let builder = conn.get_database_backend();
+    let res = conn
+        .query_one(builder.build(&stmt))
+        .await?
+        .ok_or_else(|| DbErr::Custom("Failed to check column exists".to_owned()))?;
+
+    res.try_get("", "has_column")
 }
 
 pub(crate) async fn has_table<C, T>(conn: &C, table: T) -> Result<bool, DbErr>
@@ -215,10 +994,9 @@ where
             }
             #[cfg(not(feature = "sqlx-mysql"))]
             {
-                panic!("mysql feature is off")
+                return Err(backend_not_enabled_err("MySQL", "sqlx-mysql"));
             }
         }
-        #[cfg(feature = "sqlx-postgres")]
         DbBackend::Postgres => {
             #[cfg(feature = "sqlx-postgres")]
             {
@@ -226,7 +1004,7 @@ where
             }
             #[cfg(not(feature = "sqlx-postgres"))]
             {
-                panic!("postgres feature is off")
+                return Err(backend_not_enabled_err("Postgres", "sqlx-postgres"));
             }
         }
         DbBackend::Sqlite => {
@@ -236,7 +1014,7 @@ where
             }
             #[cfg(not(feature = "sqlx-sqlite"))]
             {
-                panic!("sqlite feature is off")
+                return Err(backend_not_enabled_err("SQLite", "sqlx-sqlite"));
             }
         }
     };
@@ -249,3 +1027,449 @@ where
 
     res.try_get("", "has_table")
 }
+
+/// Lowercase identifiers work on both MySQL and Postgres,
+/// whereas Postgres rejects the quoted uppercase form.
+#[derive(DeriveIden)]
+enum InformationSchema {
+    #[sea_orm(iden = "information_schema")]
+    Schema,
+    Tables,
+    TableConstraints,
+    Schemata,
+    SchemaName,
+    TableSchema,
+    TableName,
+    ConstraintName,
+    ConstraintType,
+}
+
+fn query_has_foreign_key<C, T, F>(conn: &C, table: T, fk_name: F) -> Result<SelectStatement, DbErr>
+where
+    C: ConnectionTrait,
+    T: AsRef<str>,
+    F: AsRef<str>,
+{
+    let mut stmt = Query::select();
+    stmt.expr_as(Expr::cust("COUNT(*) > 0"), Alias::new("has_foreign_key"))
+        .from((
+            InformationSchema::Schema,
+            InformationSchema::TableConstraints,
+        ))
+        .cond_where(
+            Condition::all()
+                .add(Expr::expr(get_current_schema(conn)?).equals((
+                    InformationSchema::TableConstraints,
+                    InformationSchema::TableSchema,
+                )))
+                .add(
+                    Expr::col((
+                        InformationSchema::TableConstraints,
+                        InformationSchema::TableName,
+                    ))
+                    .eq(table.as_ref()),
+                )
+                .add(
+                    Expr::col((
+                        InformationSchema::TableConstraints,
+                        InformationSchema::ConstraintName,
+                    ))
+                    .eq(fk_name.as_ref()),
+                )
+                .add(
+                    Expr::col((
+                        InformationSchema::TableConstraints,
+                        InformationSchema::ConstraintType,
+                    ))
+                    .eq("FOREIGN KEY"),
+                ),
+        );
+    Ok(stmt)
+}
+
+fn query_pg_has_type<C, T>(conn: &C, type_name: T) -> Result<SelectStatement, DbErr>
+where
+    C: ConnectionTrait,
+    T: AsRef<str>,
+{
+    let mut stmt = Query::select();
+    stmt.expr_as(Expr::cust("COUNT(*) > 0"), Alias::new("has_type"))
+        .from(PgType::Table)
+        .join(
+            JoinType::LeftJoin,
+            PgNamespace::Table,
+            Expr::col((PgNamespace::Table, PgNamespace::Oid))
+                .equals((PgType::Table, PgType::Typnamespace)),
+        )
+        .cond_where(
+            Condition::all()
+                .add(
+                    Expr::expr(get_current_schema(conn)?)
+                        .equals((PgNamespace::Table, PgNamespace::Nspname)),
+                )
+                .add(Expr::col((PgType::Table, PgType::Typname)).eq(type_name.as_ref())),
+        );
+    Ok(stmt)
+}
+
+#[derive(DeriveIden)]
+enum SqliteMaster {
+    Table,
+    Type,
+    Name,
+    TblName,
+    Sql,
+}
+
+fn query_sqlite_has_foreign_key<T, F>(table: T, fk_name: F) -> SelectStatement
+where
+    T: AsRef<str>,
+    F: AsRef<str>,
+{
+    Query::select()
+        .expr_as(Expr::cust("COUNT(*) > 0"), Alias::new("has_foreign_key"))
+        .from(SqliteMaster::Table)
+        .cond_where(
+            Condition::all()
+                .add(Expr::col(SqliteMaster::Type).eq("table"))
+                .add(Expr::col(SqliteMaster::TblName).eq(table.as_ref()))
+                .add(Expr::col(SqliteMaster::Sql).like(like_foreign_key(fk_name.as_ref()))),
+        )
+        .to_owned()
+}
+
+/// Pattern matching the constraint of the foreign key in the SQL of a table,
+/// with the wildcards of the name escaped
+fn like_foreign_key(fk_name: &str) -> LikeExpr {
+    let constraint = quote_iden(DbBackend::Sqlite, fk_name)
+        .replace('\\', r"\\")
+        .replace('%', r"\%")
+        .replace('_', r"\_");
+    LikeExpr::new(format!("%CONSTRAINT {constraint}%")).escape('\\')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MigrationName;
+    use sea_orm::sea_query::ColumnDef;
+
+    #[async_std::test]
+    async fn dry_run_collects_statements() -> Result<(), DbErr> {
+        let manager = SchemaManager::new_dry_run(DbBackend::Sqlite);
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(Alias::new("cake"))
+                    .col(ColumnDef::new(Alias::new("id")).integer().primary_key())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("cake_id_index")
+                    .table(Alias::new("cake"))
+                    .col(Alias::new("id"))
+                    .to_owned(),
+            )
+            .await?;
+
+        assert!(!manager.has_table("cake").await?);
+        assert!(manager.get_columns("cake").await?.is_empty());
+        assert!(manager
+            .get_connection()
+            .execute_unprepared("SELECT 1")
+            .await
+            .is_err());
+
+        assert_eq!(
+            manager.take_statements(),
+            [
+                r#"CREATE TABLE "cake" ( "id" integer PRIMARY KEY )"#,
+                r#"CREATE INDEX "cake_id_index" ON "cake" ("id")"#,
+            ]
+        );
+        assert!(manager.take_statements().is_empty());
+
+        manager
+            .exec_sql("CREATE VIEW v AS SELECT ';' AS s; DROP VIEW v;")
+            .await?;
+        assert_eq!(
+            manager.take_statements(),
+            ["CREATE VIEW v AS SELECT ';' AS s", "DROP VIEW v"]
+        );
+
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn reverse_of_reversible_migration() -> Result<(), DbErr> {
+        struct Migration;
+
+        impl MigrationName for Migration {
+            fn name(&self) -> &str {
+                "m20220101_000001_create_cake_table"
+            }
+        }
+
+        #[async_trait::async_trait]
+        impl MigrationTrait for Migration {
+            async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+                manager
+                    .create_table(
+                        Table::create()
+                            .table(Alias::new("cake"))
+                            .col(ColumnDef::new(Alias::new("id")).integer().primary_key())
+                            .to_owned(),
+                    )
+                    .await?;
+                manager
+                    .create_index(
+                        Index::create()
+                            .name("cake_id_index")
+                            .table(Alias::new("cake"))
+                            .col(Alias::new("id"))
+                            .to_owned(),
+                    )
+                    .await
+            }
+
+            fn reversible(&self) -> bool {
+                true
+            }
+        }
+
+        let manager = SchemaManager::new_dry_run(DbBackend::Postgres);
+        Migration.down(&manager).await?;
+        assert_eq!(
+            manager.take_statements(),
+            [r#"DROP INDEX "cake_id_index""#, r#"DROP TABLE "cake""#]
+        );
+
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn drop_if_exists_in_dry_run() -> Result<(), DbErr> {
+        let manager = SchemaManager::new_dry_run(DbBackend::Sqlite);
+
+        manager
+            .drop_index_if_exists(Alias::new("cake"), "cake_id_index")
+            .await?;
+        manager.drop_table_if_exists(Alias::new("cake")).await?;
+        assert_eq!(
+            manager.take_statements(),
+            [r#"DROP INDEX "cake_id_index""#, r#"DROP TABLE "cake""#]
+        );
+
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn set_search_path_in_dry_run() -> Result<(), DbErr> {
+        let manager = SchemaManager::new_dry_run(DbBackend::Postgres);
+        manager.set_search_path("my_schema, public").await?;
+        assert_eq!(
+            manager.take_statements(),
+            ["SET search_path = my_schema, public"]
+        );
+
+        let manager = SchemaManager::new_dry_run(DbBackend::Sqlite);
+        manager.set_search_path("my_schema").await?;
+        assert!(manager.take_statements().is_empty());
+
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn check_in_dry_run() -> Result<(), DbErr> {
+        let manager = SchemaManager::new_dry_run(DbBackend::Postgres);
+        manager
+            .add_check(
+                Alias::new("cake"),
+                "price_positive",
+                Expr::col(Alias::new("price")).gt(0),
+            )
+            .await?;
+        manager
+            .drop_check(Alias::new("cake"), "price_positive")
+            .await?;
+        assert_eq!(
+            manager.take_statements(),
+            [
+                r#"ALTER TABLE "cake" ADD CONSTRAINT "price_positive" CHECK ("price" > 0)"#,
+                r#"ALTER TABLE "cake" DROP CONSTRAINT "price_positive""#,
+            ]
+        );
+
+        let manager = SchemaManager::new_dry_run(DbBackend::MySql);
+        manager
+            .add_check(
+                Alias::new("cake"),
+                "price_positive",
+                Expr::col(Alias::new("price")).gt(0),
+            )
+            .await?;
+        assert_eq!(
+            manager.take_statements(),
+            ["ALTER TABLE `cake` ADD CONSTRAINT `price_positive` CHECK (`price` > 0)"]
+        );
+
+        let manager = SchemaManager::new_dry_run(DbBackend::Sqlite);
+        assert!(manager
+            .drop_check(Alias::new("cake"), "price_positive")
+            .await
+            .is_err());
+
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn truncate_table_with_in_dry_run() -> Result<(), DbErr> {
+        let options = TruncateOptions {
+            cascade: true,
+            restart_identity: true,
+        };
+
+        let manager = SchemaManager::new_dry_run(DbBackend::Postgres);
+        manager
+            .truncate_table_with(Alias::new("cake"), options)
+            .await?;
+        manager
+            .truncate_table_with(Alias::new("cake"), TruncateOptions::default())
+            .await?;
+        assert_eq!(
+            manager.take_statements(),
+            [
+                r#"TRUNCATE TABLE "cake" RESTART IDENTITY CASCADE"#,
+                r#"TRUNCATE TABLE "cake""#,
+            ]
+        );
+
+        let manager = SchemaManager::new_dry_run(DbBackend::MySql);
+        manager
+            .truncate_table_with(
+                Alias::new("cake"),
+                TruncateOptions {
+                    restart_identity: true,
+                    ..Default::default()
+                },
+            )
+            .await?;
+        assert_eq!(manager.take_statements(), ["TRUNCATE TABLE `cake`"]);
+        assert!(manager
+            .truncate_table_with(Alias::new("cake"), options)
+            .await
+            .is_err());
+
+        let manager = SchemaManager::new_dry_run(DbBackend::Sqlite);
+        manager
+            .truncate_table_with(Alias::new("cake"), options)
+            .await?;
+        manager
+            .truncate_table_with(Alias::new("cake"), TruncateOptions::default())
+            .await?;
+        assert_eq!(
+            manager.take_statements(),
+            [
+                r#"DELETE FROM "cake""#,
+                "DELETE FROM sqlite_sequence WHERE name = 'cake'",
+                r#"DELETE FROM "cake""#,
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn create_partial_index_in_dry_run() -> Result<(), DbErr> {
+        let index = Index::create()
+            .name("idx-account-email")
+            .table(Alias::new("account"))
+            .col(Alias::new("email"))
+            .unique()
+            .to_owned();
+        let condition = Expr::col(Alias::new("deleted_at")).is_null();
+
+        let manager = SchemaManager::new_dry_run(DbBackend::Postgres);
+        manager
+            .create_partial_index(index.clone(), condition.clone())
+            .await?;
+        assert_eq!(
+            manager.take_statements(),
+            [
+                r#"CREATE UNIQUE INDEX "idx-account-email" ON "account" ("email") WHERE "deleted_at" IS NULL"#
+            ]
+        );
+
+        let manager = SchemaManager::new_dry_run(DbBackend::Sqlite);
+        manager
+            .create_partial_index(index.clone(), condition.clone())
+            .await?;
+        assert_eq!(
+            manager.take_statements(),
+            [
+                r#"CREATE UNIQUE INDEX "idx-account-email" ON "account" ("email") WHERE "deleted_at" IS NULL"#
+            ]
+        );
+
+        let manager = SchemaManager::new_dry_run(DbBackend::MySql);
+        assert!(manager
+            .create_partial_index(index, condition)
+            .await
+            .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_sqlite_versions() {
+        assert_eq!(parse_sqlite_version("3.45.1").unwrap(), (3, 45));
+        assert_eq!(parse_sqlite_version("3.24").unwrap(), (3, 24));
+        assert!(parse_sqlite_version("3").is_err());
+        assert!(parse_sqlite_version("3.x.0").is_err());
+        assert!(parse_sqlite_version("").is_err());
+    }
+
+    #[async_std::test]
+    async fn create_table_if_not_exists_in_dry_run() -> Result<(), DbErr> {
+        let manager = SchemaManager::new_dry_run(DbBackend::Postgres);
+
+        manager
+            .create_table_if_not_exists(
+                Table::create()
+                    .table(Alias::new("cake"))
+                    .col(ColumnDef::new(Alias::new("id")).integer().primary_key())
+                    .to_owned(),
+            )
+            .await?;
+        assert_eq!(
+            manager.take_statements(),
+            [r#"CREATE TABLE "cake" ( "id" integer PRIMARY KEY )"#]
+        );
+
+        assert!(manager
+            .create_table_if_not_exists(Table::create().to_owned())
+            .await
+            .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn sqlite_has_foreign_key_escapes_wildcards() {
+        assert_eq!(
+            DbBackend::Sqlite
+                .build(&query_sqlite_has_foreign_key("cake", r#"fk_"cake"%"#))
+                .to_string(),
+            [
+                r#"SELECT COUNT(*) > 0 AS "has_foreign_key" FROM "sqlite_master""#,
+                r#"WHERE "type" = 'table' AND "tbl_name" = 'cake'"#,
+                r#"AND "sql" LIKE '%CONSTRAINT "fk\_""cake""\%"%' ESCAPE '\'"#,
+            ]
+            .join(" ")
+        );
+    }
+}
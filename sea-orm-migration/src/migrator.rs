@@ -1,22 +1,26 @@
 use futures::Future;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fmt::Display;
 use std::pin::Pin;
-use std::time::SystemTime;
+use std::time::{Duration, SystemTime};
 use tracing::info;
 
 use sea_orm::sea_query::{
-    self, extension::postgres::Type, Alias, Expr, ForeignKey, IntoIden, JoinType, Order, Query,
-    SelectStatement, SimpleExpr, Table,
+    self, extension::postgres::Type, Alias, ColumnDef, Expr, ForeignKey, IntoIden, JoinType, Order,
+    Query, SelectStatement, SimpleExpr, Table,
 };
 use sea_orm::{
     ActiveModelTrait, ActiveValue, Condition, ConnectionTrait, DbBackend, DbErr, DeriveIden,
-    DynIden, EntityTrait, FromQueryResult, Iterable, QueryFilter, Schema, Statement,
+    DynIden, EntityTrait, FromQueryResult, Iterable, QueryFilter, Schema, SqlErr, Statement,
     TransactionTrait,
 };
 use sea_schema::probe::SchemaProbe;
 
-use super::{seaql_migrations, IntoSchemaManagerConnection, MigrationTrait, SchemaManager};
+use super::{
+    seaql_migrations, IntoSchemaManagerConnection, MigrationTrait, SchemaManager,
+    SchemaManagerConnection,
+};
+use crate::manager::{backend_not_enabled_err, has_column};
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 /// Status of migration
@@ -54,6 +58,17 @@ impl Migration {
     }
 }
 
+/// Status report of a migration, see [`MigratorTrait::get_status`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MigrationInfo {
+    /// Migration name
+    pub name: String,
+    /// Migration status
+    pub status: MigrationStatus,
+    /// Time the migration was applied at, in seconds since the Unix epoch
+    pub applied_at: Option<i64>,
+}
+
 /// Performing migrations on a database
 #[async_trait::async_trait]
 pub trait MigratorTrait: Send {
@@ -65,6 +80,50 @@ pub trait MigratorTrait: Send {
         seaql_migrations::Entity.into_iden()
     }
 
+    /// Run each migration in its own transaction, so a failing migration is rolled back
+    /// without affecting the ones applied before it. Migrations can opt out with
+    /// [`MigrationTrait::transactional`].
+    ///
+    /// It is `false` by default, in which case the whole run is wrapped in a single
+    /// transaction on Postgres, and no transaction is used on MySQL and SQLite.
+    fn transaction_per_migration() -> bool {
+        false
+    }
+
+    /// Statement timeout applied around each migration, so a migration blocked on a lock
+    /// fails instead of hanging, it is `None` by default. Migrations can override it with
+    /// [`MigrationTrait::statement_timeout`].
+    ///
+    /// How the timeout is honored depends on the backend:
+    /// - Postgres: `statement_timeout`, limiting the run time of every statement
+    /// - MySQL: `lock_wait_timeout`, limiting the time spent waiting for metadata locks,
+    ///   rounded down to whole seconds with a minimum of one second
+    /// - SQLite: `busy_timeout`, limiting the time spent waiting for a locked database
+    ///
+    /// These are settings of the connection, so the timeout is only set within a transaction,
+    /// which holds on to its connection: that of the whole run on Postgres, or that of each
+    /// migration when [`MigratorTrait::transaction_per_migration`] is enabled. A migration with
+    /// a timeout running outside of a transaction fails without being applied. The previous
+    /// setting is restored once the migration completes.
+    ///
+    /// When the timeout fires, the migration fails with an error naming it
+    /// and its transaction is rolled back.
+    fn statement_timeout() -> Option<Duration> {
+        None
+    }
+
+    /// Postgres `search_path` to run the migrations with, so that tables and types
+    /// created without an explicit schema land in the given schema, it is `None` by default.
+    /// It is ignored with a warning on MySQL and SQLite.
+    ///
+    /// It is set once with `SET search_path`, see [`SchemaManager::set_search_path`], on a
+    /// connection of its own opened for the run, which the migration table and every migration
+    /// use, transactional or not. The connections of the pool passed to the migrator are left
+    /// untouched. Given a transaction instead, it is set on the connection of the transaction.
+    fn search_path() -> Option<String> {
+        None
+    }
+
     /// Get list of migrations wrapped in `Migration` struct
     fn get_migration_files() -> Vec<Migration> {
         Self::migrations()
@@ -168,9 +227,97 @@ pub trait MigratorTrait: Send {
         let schema = Schema::new(builder);
         let mut stmt = schema
             .create_table_from_entity(seaql_migrations::Entity)
-            .table_name(table_name);
+            .table_name(table_name.clone());
         stmt.if_not_exists();
-        db.execute(builder.build(&stmt)).await.map(|_| ())
+        db.execute(builder.build(&stmt)).await?;
+
+        // Migration tables created before checksums were introduced lack the column
+        if !has_column(db, table_name.to_string(), "checksum").await? {
+            let stmt = Table::alter()
+                .table(table_name)
+                .add_column(
+                    ColumnDef::new(seaql_migrations::Column::Checksum)
+                        .string()
+                        .null(),
+                )
+                .to_owned();
+            db.execute(builder.build(&stmt)).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Verify that every applied migration still emits the same SQL as when it was applied,
+    /// returning an error listing the migrations that have been edited since, or fail to run.
+    /// It is meant to be run on its own, e.g. in CI, as every applied migration is run again.
+    ///
+    /// The checksum of the statements executed by the [`SchemaManager`] is recorded when a
+    /// migration is applied. It is compared with the checksum of the statements collected by
+    /// running the `up` of the migration on a [dry-run](SchemaManager::new_dry_run) schema
+    /// manager, whose connection is a transaction rolled back afterwards, so that changes made
+    /// through the connection directly are discarded. As the inspection methods of a dry-run
+    /// schema manager return a default, a migration whose statements depend on the state of the
+    /// database may be reported as modified.
+    ///
+    /// MySQL implicitly commits DDL statements, so statements executed through the connection
+    /// directly could not be rolled back and the check is refused there.
+    ///
+    /// Applied migrations recorded before checksums were introduced get theirs backfilled.
+    async fn verify_checksums<C>(db: &C) -> Result<(), DbErr>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        if db.get_database_backend() == DbBackend::MySql {
+            return Err(DbErr::Migration(
+                "Checksums cannot be verified on MySQL, as it does not support transactional DDL"
+                    .to_owned(),
+            ));
+        }
+        let migrations: HashMap<String, Box<dyn MigrationTrait>> = Self::migrations()
+            .into_iter()
+            .map(|migration| (migration.name().to_owned(), migration))
+            .collect();
+
+        let mut errors = Vec::new();
+        for model in Self::get_migration_models(db).await? {
+            let migration = match migrations.get(&model.version) {
+                Some(migration) => migration,
+                None => continue,
+            };
+            let checksum = match SchemaManager::dry_run_checksum(db, migration.as_ref()).await {
+                Ok(checksum) => checksum,
+                Err(err) => {
+                    errors.push(format!(
+                        "Migration '{}' failed to run to verify its checksum: {err}",
+                        model.version
+                    ));
+                    continue;
+                }
+            };
+            match model.checksum {
+                Some(recorded) if recorded != checksum => {
+                    errors.push(format!(
+                        "Migration '{}' has been modified after it was applied, its checksum was '{recorded}' but now is '{checksum}'",
+                        model.version
+                    ));
+                }
+                None => {
+                    seaql_migrations::Entity::update_many()
+                        .col_expr(seaql_migrations::Column::Checksum, Expr::value(checksum))
+                        .filter(Expr::col(seaql_migrations::Column::Version).eq(model.version))
+                        .table_name(Self::migration_table_name())
+                        .exec(db)
+                        .await?;
+                }
+                _ => {}
+            }
+        }
+
+        if !errors.is_empty() {
+            Err(DbErr::Migration(errors.join("\n")))
+        } else {
+            Ok(())
+        }
     }
 
     /// Check the status of all migrations
@@ -182,19 +329,41 @@ pub trait MigratorTrait: Send {
 
         info!("Checking migration status");
 
-        for Migration { migration, status } in Self::get_migration_with_status(db).await? {
-            info!("Migration '{}'... {}", migration.name(), status);
+        for MigrationInfo { name, status, .. } in Self::get_status(db).await? {
+            info!("Migration '{}'... {}", name, status);
         }
 
         Ok(())
     }
 
+    /// Get the status of all migrations, in time sequence
+    async fn get_status<C>(db: &C) -> Result<Vec<MigrationInfo>, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let migrations = Self::get_migration_with_status(db).await?;
+        let applied_at: HashMap<String, i64> = Self::get_migration_models(db)
+            .await?
+            .into_iter()
+            .map(|model| (model.version, model.applied_at))
+            .collect();
+
+        Ok(migrations
+            .into_iter()
+            .map(|migration| MigrationInfo {
+                name: migration.name().to_owned(),
+                status: migration.status(),
+                applied_at: applied_at.get(migration.name()).copied(),
+            })
+            .collect())
+    }
+
     /// Drop all tables from the database, then reapply all migrations
     async fn fresh<'c, C>(db: C) -> Result<(), DbErr>
     where
         C: IntoSchemaManagerConnection<'c>,
     {
-        exec_with_connection::<'_, _, _>(db, move |manager| {
+        exec_with_connection::<'_, Self, _, _>(db, move |manager| {
             Box::pin(async move { exec_fresh::<Self>(manager).await })
         })
         .await
@@ -205,7 +374,7 @@ pub trait MigratorTrait: Send {
     where
         C: IntoSchemaManagerConnection<'c>,
     {
-        exec_with_connection::<'_, _, _>(db, move |manager| {
+        exec_with_connection::<'_, Self, _, _>(db, move |manager| {
             Box::pin(async move {
                 exec_down::<Self>(manager, None).await?;
                 exec_up::<Self>(manager, None).await
@@ -219,7 +388,7 @@ pub trait MigratorTrait: Send {
     where
         C: IntoSchemaManagerConnection<'c>,
     {
-        exec_with_connection::<'_, _, _>(db, move |manager| {
+        exec_with_connection::<'_, Self, _, _>(db, move |manager| {
             Box::pin(async move { exec_down::<Self>(manager, None).await })
         })
         .await
@@ -230,7 +399,7 @@ pub trait MigratorTrait: Send {
     where
         C: IntoSchemaManagerConnection<'c>,
     {
-        exec_with_connection::<'_, _, _>(db, move |manager| {
+        exec_with_connection::<'_, Self, _, _>(db, move |manager| {
             Box::pin(async move { exec_up::<Self>(manager, steps).await })
         })
         .await
@@ -241,30 +410,72 @@ pub trait MigratorTrait: Send {
     where
         C: IntoSchemaManagerConnection<'c>,
     {
-        exec_with_connection::<'_, _, _>(db, move |manager| {
+        exec_with_connection::<'_, Self, _, _>(db, move |manager| {
             Box::pin(async move { exec_down::<Self>(manager, steps).await })
         })
         .await
     }
+
+    /// Rollback applied migrations until the given migration, which is kept applied
+    async fn down_to<'c, C>(db: C, target: &str) -> Result<(), DbErr>
+    where
+        C: IntoSchemaManagerConnection<'c>,
+    {
+        let target = target.to_owned();
+        exec_with_connection::<'_, Self, _, _>(db, move |manager| {
+            let target = target.clone();
+            Box::pin(async move { exec_down_to::<Self>(manager, &target).await })
+        })
+        .await
+    }
 }
 
-async fn exec_with_connection<'c, C, F>(db: C, f: F) -> Result<(), DbErr>
+async fn exec_with_connection<'c, M, C, F>(db: C, f: F) -> Result<(), DbErr>
 where
+    M: MigratorTrait + ?Sized,
     C: IntoSchemaManagerConnection<'c>,
     F: for<'b> Fn(
         &'b SchemaManager<'_>,
     ) -> Pin<Box<dyn Future<Output = Result<(), DbErr>> + Send + 'b>>,
 {
     let db = db.into_schema_manager_connection();
+    let search_path = match M::search_path() {
+        Some(search_path) => search_path,
+        None => return exec_on_connection::<M, _>(db, f).await,
+    };
+
+    match db {
+        SchemaManagerConnection::Connection(conn)
+            if conn.get_database_backend() == DbBackend::Postgres =>
+        {
+            let conn = conn.set_schema_search_path(&search_path).await?;
+            let res = exec_on_connection::<M, _>((&conn).into_schema_manager_connection(), f).await;
+            conn.close().await?;
+            res
+        }
+        _ => {
+            SchemaManager::new(db).set_search_path(&search_path).await?;
+            exec_on_connection::<M, _>(db, f).await
+        }
+    }
+}
 
+/// Run the migrations on a connection, whose `search_path` is already set if need be
+async fn exec_on_connection<M, F>(db: SchemaManagerConnection<'_>, f: F) -> Result<(), DbErr>
+where
+    M: MigratorTrait + ?Sized,
+    F: for<'b> Fn(
+        &'b SchemaManager<'_>,
+    ) -> Pin<Box<dyn Future<Output = Result<(), DbErr>> + Send + 'b>>,
+{
     match db.get_database_backend() {
-        DbBackend::Postgres => {
+        DbBackend::Postgres if !M::transaction_per_migration() => {
             let transaction = db.begin().await?;
             let manager = SchemaManager::new(&transaction);
             f(&manager).await?;
             transaction.commit().await
         }
-        DbBackend::MySql | DbBackend::Sqlite => {
+        _ => {
             let manager = SchemaManager::new(db);
             f(&manager).await
         }
@@ -294,7 +505,7 @@ where
     // Drop all foreign keys
     if db_backend == DbBackend::MySql {
         info!("Dropping all foreign keys");
-        let stmt = query_mysql_foreign_keys(db);
+        let stmt = query_mysql_foreign_keys(db)?;
         let rows = db.query_all(db_backend.build(&stmt)).await?;
         for row in rows.into_iter() {
             let constraint_name: String = row.try_get("", "CONSTRAINT_NAME")?;
@@ -313,7 +524,7 @@ where
     }
 
     // Drop all tables
-    let stmt = query_tables(db).await;
+    let stmt = query_tables(db).await?;
     let rows = db.query_all(db_backend.build(&stmt)).await?;
     for row in rows.into_iter() {
         let table_name: String = row.try_get("", "table_name")?;
@@ -329,7 +540,7 @@ where
     // Drop all types
     if db_backend == DbBackend::Postgres {
         info!("Dropping all types");
-        let stmt = query_pg_types(db);
+        let stmt = query_pg_types(db)?;
         let rows = db.query_all(db_backend.build(&stmt)).await?;
         for row in rows {
             let type_name: String = row.try_get("", "typname")?;
@@ -382,18 +593,29 @@ where
             *steps -= 1;
         }
         info!("Applying migration '{}'", migration.name());
-        migration.up(manager).await?;
+        let timeout = migration.statement_timeout().or_else(M::statement_timeout);
+        if M::transaction_per_migration() && migration.transactional() {
+            let transaction = db.begin().await?;
+            let manager = SchemaManager::new(&transaction);
+            // Only the statements of the migration itself are part of its checksum
+            manager.take_checksum();
+            with_statement_timeout(
+                manager.get_connection(),
+                migration.name(),
+                timeout,
+                migration.up(&manager),
+            )
+            .await?;
+            let checksum = manager.take_checksum();
+            insert_migration::<M, _>(&transaction, migration.name(), checksum).await?;
+            transaction.commit().await?;
+        } else {
+            manager.take_checksum();
+            with_statement_timeout(db, migration.name(), timeout, migration.up(manager)).await?;
+            let checksum = manager.take_checksum();
+            insert_migration::<M, _>(db, migration.name(), checksum).await?;
+        }
         info!("Migration '{}' has been applied", migration.name());
-        let now = SystemTime::now()
-            .duration_since(SystemTime::UNIX_EPOCH)
-            .expect("SystemTime before UNIX EPOCH!");
-        seaql_migrations::Entity::insert(seaql_migrations::ActiveModel {
-            version: ActiveValue::Set(migration.name().to_owned()),
-            applied_at: ActiveValue::Set(now.as_secs() as i64),
-        })
-        .table_name(M::migration_table_name())
-        .exec(db)
-        .await?;
     }
 
     Ok(())
@@ -425,23 +647,219 @@ where
             *steps -= 1;
         }
         info!("Rolling back migration '{}'", migration.name());
-        migration.down(manager).await?;
-        info!("Migration '{}' has been rollbacked", migration.name());
-        seaql_migrations::Entity::delete_many()
-            .filter(Expr::col(seaql_migrations::Column::Version).eq(migration.name()))
-            .table_name(M::migration_table_name())
-            .exec(db)
+        let timeout = migration.statement_timeout().or_else(M::statement_timeout);
+        if M::transaction_per_migration() && migration.transactional() {
+            let transaction = db.begin().await?;
+            let manager = SchemaManager::new(&transaction);
+            with_statement_timeout(
+                manager.get_connection(),
+                migration.name(),
+                timeout,
+                migration.down(&manager),
+            )
             .await?;
+            delete_migration::<M, _>(&transaction, migration.name()).await?;
+            transaction.commit().await?;
+        } else {
+            with_statement_timeout(db, migration.name(), timeout, migration.down(manager)).await?;
+            delete_migration::<M, _>(db, migration.name()).await?;
+        }
+        info!("Migration '{}' has been rollbacked", migration.name());
     }
 
     Ok(())
 }
 
-async fn query_tables<C>(db: &C) -> SelectStatement
+async fn exec_down_to<M>(manager: &SchemaManager<'_>, target: &str) -> Result<(), DbErr>
+where
+    M: MigratorTrait + ?Sized,
+{
+    let db = manager.get_connection();
+
+    if !M::migrations()
+        .iter()
+        .any(|migration| migration.name() == target)
+    {
+        return Err(DbErr::Migration(format!(
+            "Migration '{target}' does not exist"
+        )));
+    }
+
+    let migrations = M::get_applied_migrations(db).await?;
+    let position = migrations
+        .iter()
+        .position(|migration| migration.name() == target)
+        .ok_or_else(|| DbErr::Migration(format!("Migration '{target}' has not been applied")))?;
+
+    info!("Rolling back applied migrations after '{}'", target);
+    exec_down::<M>(manager, Some((migrations.len() - position - 1) as u32)).await
+}
+
+/// Run a migration with a statement timeout, set on the connection of the transaction `db`
+async fn with_statement_timeout<F>(
+    db: &SchemaManagerConnection<'_>,
+    migration: &str,
+    timeout: Option<Duration>,
+    fut: F,
+) -> Result<(), DbErr>
+where
+    F: Future<Output = Result<(), DbErr>>,
+{
+    let timeout = match timeout {
+        Some(timeout) => timeout,
+        None => return fut.await,
+    };
+    // The setting would apply to any connection of the pool, but not to those running the migration
+    if !matches!(db, SchemaManagerConnection::Transaction(_)) {
+        return Err(DbErr::Migration(format!(
+            "Migration '{migration}' has a statement timeout, which can only be set within a transaction"
+        )));
+    }
+
+    let previous = get_statement_timeout(db).await?;
+    let value = match db.get_database_backend() {
+        DbBackend::Postgres => timeout.as_millis().to_string(),
+        DbBackend::MySql => timeout.as_secs().max(1).to_string(),
+        DbBackend::Sqlite => timeout.as_millis().to_string(),
+    };
+    set_statement_timeout(db, &value).await?;
+
+    let res = fut.await.map_err(|err| {
+        if is_statement_timeout(&err) {
+            DbErr::Migration(format!(
+                "Migration '{}' failed with a statement timeout of {} ms: {}",
+                migration,
+                timeout.as_millis(),
+                err
+            ))
+        } else {
+            err
+        }
+    });
+
+    match (res, db.get_database_backend()) {
+        // A failed transaction of Postgres accepts no more statements, and discards the setting
+        (Err(err), DbBackend::Postgres) => Err(err),
+        (res, _) => {
+            let restored = set_statement_timeout(db, &previous).await;
+            res.and(restored)
+        }
+    }
+}
+
+/// Get the statement timeout of the connection, see [`MigratorTrait::statement_timeout`]
+async fn get_statement_timeout<C>(db: &C) -> Result<String, DbErr>
 where
     C: ConnectionTrait,
 {
-    match db.get_database_backend() {
+    let backend = db.get_database_backend();
+    let sql = match backend {
+        DbBackend::Postgres => "SELECT current_setting('statement_timeout') AS setting",
+        DbBackend::MySql => "SELECT CAST(@@SESSION.lock_wait_timeout AS CHAR) AS setting",
+        DbBackend::Sqlite => {
+            r#"SELECT CAST("timeout" AS TEXT) AS setting FROM pragma_busy_timeout"#
+        }
+    };
+    db.query_one(Statement::from_string(backend, sql))
+        .await?
+        .ok_or_else(|| DbErr::Migration("Failed to get the statement timeout".to_owned()))?
+        .try_get("", "setting")
+}
+
+/// Set the statement timeout of the connection, scoped to the transaction on Postgres
+async fn set_statement_timeout<C>(db: &C, value: &str) -> Result<(), DbErr>
+where
+    C: ConnectionTrait,
+{
+    let backend = db.get_database_backend();
+    let stmt = match backend {
+        DbBackend::Postgres => Statement::from_sql_and_values(
+            backend,
+            "SELECT set_config('statement_timeout', $1, true)",
+            [value.into()],
+        ),
+        // The settings of MySQL and SQLite take no parameters, the value is a number
+        DbBackend::MySql => Statement::from_string(
+            backend,
+            format!("SET SESSION lock_wait_timeout = {}", parse_timeout(value)?),
+        ),
+        DbBackend::Sqlite => Statement::from_string(
+            backend,
+            format!("PRAGMA busy_timeout = {}", parse_timeout(value)?),
+        ),
+    };
+    db.execute(stmt).await?;
+    Ok(())
+}
+
+fn parse_timeout(value: &str) -> Result<u64, DbErr> {
+    value
+        .parse()
+        .map_err(|_| DbErr::Migration(format!("Invalid statement timeout: {value}")))
+}
+
+/// Whether the error is a statement cancelled by the [`MigratorTrait::statement_timeout`],
+/// or a lock which could not be acquired within it
+fn is_statement_timeout(err: &DbErr) -> bool {
+    if let Some(SqlErr::LockNotAvailable(_)) = err.sql_err() {
+        return true;
+    }
+    #[cfg(any(feature = "sqlx-postgres", feature = "sqlx-sqlite"))]
+    if let DbErr::Exec(sea_orm::RuntimeErr::SqlxError(sea_orm::sqlx::Error::Database(e)))
+    | DbErr::Query(sea_orm::RuntimeErr::SqlxError(sea_orm::sqlx::Error::Database(e))) = err
+    {
+        let code = e.code().unwrap_or_default();
+        // 57014 `query_canceled`
+        #[cfg(feature = "sqlx-postgres")]
+        if e.try_downcast_ref::<sea_orm::SqlxPostgresError>().is_some() {
+            return code == "57014";
+        }
+        // 5 `SQLITE_BUSY`, possibly with an extended code
+        #[cfg(feature = "sqlx-sqlite")]
+        if e.try_downcast_ref::<sea_orm::SqlxSqliteError>().is_some() {
+            return code.parse::<u32>().map_or(false, |code| code & 0xff == 5);
+        }
+    }
+    false
+}
+
+async fn insert_migration<M, C>(db: &C, name: &str, checksum: String) -> Result<(), DbErr>
+where
+    M: MigratorTrait + ?Sized,
+    C: ConnectionTrait,
+{
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("SystemTime before UNIX EPOCH!");
+    seaql_migrations::Entity::insert(seaql_migrations::ActiveModel {
+        version: ActiveValue::Set(name.to_owned()),
+        applied_at: ActiveValue::Set(now.as_secs() as i64),
+        checksum: ActiveValue::Set(Some(checksum)),
+    })
+    .table_name(M::migration_table_name())
+    .exec(db)
+    .await?;
+    Ok(())
+}
+
+async fn delete_migration<M, C>(db: &C, name: &str) -> Result<(), DbErr>
+where
+    M: MigratorTrait + ?Sized,
+    C: ConnectionTrait,
+{
+    seaql_migrations::Entity::delete_many()
+        .filter(Expr::col(seaql_migrations::Column::Version).eq(name))
+        .table_name(M::migration_table_name())
+        .exec(db)
+        .await?;
+    Ok(())
+}
+
+pub(crate) async fn query_tables<C>(db: &C) -> Result<SelectStatement, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let stmt = match db.get_database_backend() {
         DbBackend::MySql => {
             #[cfg(feature = "sqlx-mysql")]
             {
@@ -449,10 +867,9 @@ where
             }
             #[cfg(not(feature = "sqlx-mysql"))]
             {
-                panic!("mysql feature is off")
+                return Err(backend_not_enabled_err("MySQL", "sqlx-mysql"));
             }
         }
-        #[cfg(feature = "sqlx-postgres")]
         DbBackend::Postgres => {
             #[cfg(feature = "sqlx-postgres")]
             {
@@ -460,7 +877,7 @@ where
             }
             #[cfg(not(feature = "sqlx-postgres"))]
             {
-                panic!("postgres feature is off")
+                return Err(backend_not_enabled_err("Postgres", "sqlx-postgres"));
             }
         }
         DbBackend::Sqlite => {
@@ -470,17 +887,18 @@ where
             }
             #[cfg(not(feature = "sqlx-sqlite"))]
             {
-                panic!("sqlite feature is off")
+                return Err(backend_not_enabled_err("SQLite", "sqlx-sqlite"));
             }
         }
-    }
+    };
+    Ok(stmt)
 }
 
-fn get_current_schema<C>(db: &C) -> SimpleExpr
+pub(crate) fn get_current_schema<C>(db: &C) -> Result<SimpleExpr, DbErr>
 where
     C: ConnectionTrait,
 {
-    match db.get_database_backend() {
+    let expr = match db.get_database_backend() {
         DbBackend::MySql => {
             #[cfg(feature = "sqlx-mysql")]
             {
@@ -488,10 +906,9 @@ where
             }
             #[cfg(not(feature = "sqlx-mysql"))]
             {
-                panic!("mysql feature is off")
+                return Err(backend_not_enabled_err("MySQL", "sqlx-mysql"));
             }
         }
-        #[cfg(feature = "sqlx-postgres")]
         DbBackend::Postgres => {
             #[cfg(feature = "sqlx-postgres")]
             {
@@ -499,7 +916,7 @@ where
             }
             #[cfg(not(feature = "sqlx-postgres"))]
             {
-                panic!("postgres feature is off")
+                return Err(backend_not_enabled_err("Postgres", "sqlx-postgres"));
             }
         }
         DbBackend::Sqlite => {
@@ -509,10 +926,11 @@ where
             }
             #[cfg(not(feature = "sqlx-sqlite"))]
             {
-                panic!("sqlite feature is off")
+                return Err(backend_not_enabled_err("SQLite", "sqlx-sqlite"));
             }
         }
-    }
+    };
+    Ok(expr)
 }
 
 #[derive(DeriveIden)]
@@ -528,7 +946,7 @@ enum InformationSchema {
     ConstraintType,
 }
 
-fn query_mysql_foreign_keys<C>(db: &C) -> SelectStatement
+fn query_mysql_foreign_keys<C>(db: &C) -> Result<SelectStatement, DbErr>
 where
     C: ConnectionTrait,
 {
@@ -543,7 +961,7 @@ where
     ))
     .cond_where(
         Condition::all()
-            .add(Expr::expr(get_current_schema(db)).equals((
+            .add(Expr::expr(get_current_schema(db)?).equals((
                 InformationSchema::TableConstraints,
                 InformationSchema::TableSchema,
             )))
@@ -555,11 +973,11 @@ where
                 .eq("FOREIGN KEY"),
             ),
     );
-    stmt
+    Ok(stmt)
 }
 
 #[derive(DeriveIden)]
-enum PgType {
+pub(crate) enum PgType {
     Table,
     Typname,
     Typnamespace,
@@ -567,13 +985,13 @@ enum PgType {
 }
 
 #[derive(DeriveIden)]
-enum PgNamespace {
+pub(crate) enum PgNamespace {
     Table,
     Oid,
     Nspname,
 }
 
-fn query_pg_types<C>(db: &C) -> SelectStatement
+fn query_pg_types<C>(db: &C) -> Result<SelectStatement, DbErr>
 where
     C: ConnectionTrait,
 {
@@ -589,12 +1007,12 @@ where
         .cond_where(
             Condition::all()
                 .add(
-                    Expr::expr(get_current_schema(db))
+                    Expr::expr(get_current_schema(db)?)
                         .equals((PgNamespace::Table, PgNamespace::Nspname)),
                 )
                 .add(Expr::col((PgType::Table, PgType::Typelem)).eq(0)),
         );
-    stmt
+    Ok(stmt)
 }
 
 trait QueryTable {
@@ -633,6 +1051,18 @@ where
     }
 }
 
+impl<E> QueryTable for sea_orm::UpdateMany<E>
+where
+    E: EntityTrait,
+{
+    type Statement = sea_orm::UpdateMany<E>;
+
+    fn table_name(mut self, table_name: DynIden) -> sea_orm::UpdateMany<E> {
+        sea_orm::QueryTrait::query(&mut self).table(table_name);
+        self
+    }
+}
+
 impl<E> QueryTable for sea_orm::DeleteMany<E>
 where
     E: EntityTrait,
@@ -3,7 +3,7 @@ pub use crate::cli;
 
 pub use crate::{
     IntoSchemaManagerConnection, MigrationName, MigrationTrait, MigratorTrait, SchemaManager,
-    SchemaManagerConnection,
+    SchemaManagerConnection, TruncateOptions,
 };
 pub use async_trait;
 pub use sea_orm::{
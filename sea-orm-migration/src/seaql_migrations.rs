@@ -7,6 +7,8 @@ pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
     pub version: String,
     pub applied_at: i64,
+    /// Checksum of the SQL emitted by the migration, see `MigratorTrait::verify_checksums`
+    pub checksum: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
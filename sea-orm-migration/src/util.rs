@@ -1,3 +1,5 @@
+use sea_orm::DbBackend;
+
 pub fn get_file_stem(path: &str) -> &str {
     std::path::Path::new(path)
         .file_stem()
@@ -5,6 +7,121 @@ pub fn get_file_stem(path: &str) -> &str {
         .unwrap()
 }
 
+/// Split a SQL script into statements on `;`, skipping the semicolons that appear inside
+/// string literals, quoted identifiers, comments and, on Postgres, dollar-quoted strings.
+/// Empty statements are omitted. MySQL `DELIMITER` commands are not supported.
+pub fn split_sql_statements(sql: &str, backend: DbBackend) -> Vec<String> {
+    let chars: Vec<char> = sql.chars().collect();
+    let mut statements = Vec::new();
+    let mut current = String::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        match c {
+            ';' => {
+                push_statement(&mut statements, &mut current);
+                i += 1;
+                continue;
+            }
+            '\'' | '"' | '`' => {
+                let end = find_quote_end(&chars, i, backend);
+                current.extend(&chars[i..end]);
+                i = end;
+                continue;
+            }
+            '-' if next == Some('-') => {
+                i = find_line_end(&chars, i);
+                continue;
+            }
+            '#' if backend == DbBackend::MySql => {
+                i = find_line_end(&chars, i);
+                continue;
+            }
+            '/' if next == Some('*') => {
+                i = chars[i + 2..]
+                    .windows(2)
+                    .position(|w| w == ['*', '/'])
+                    .map_or(chars.len(), |p| i + 2 + p + 2);
+                continue;
+            }
+            '$' if backend == DbBackend::Postgres => {
+                if let Some(end) = find_dollar_quote_end(&chars, i) {
+                    current.extend(&chars[i..end]);
+                    i = end;
+                    continue;
+                }
+            }
+            _ => {}
+        }
+        current.push(c);
+        i += 1;
+    }
+    push_statement(&mut statements, &mut current);
+
+    statements
+}
+
+fn push_statement(statements: &mut Vec<String>, current: &mut String) {
+    let statement = current.trim();
+    if !statement.is_empty() {
+        statements.push(statement.to_owned());
+    }
+    current.clear();
+}
+
+/// Index past the closing quote of the literal opening at `start`
+fn find_quote_end(chars: &[char], start: usize, backend: DbBackend) -> usize {
+    let quote = chars[start];
+    let mut i = start + 1;
+    while i < chars.len() {
+        if chars[i] == '\\' && backend == DbBackend::MySql && quote != '`' {
+            i += 2;
+        } else if chars[i] == quote {
+            // A doubled quote is an escaped quote
+            if chars.get(i + 1) == Some(&quote) {
+                i += 2;
+            } else {
+                return i + 1;
+            }
+        } else {
+            i += 1;
+        }
+    }
+    chars.len()
+}
+
+fn find_line_end(chars: &[char], start: usize) -> usize {
+    chars[start..]
+        .iter()
+        .position(|c| *c == '\n')
+        .map_or(chars.len(), |p| start + p)
+}
+
+/// Index past the closing tag of the dollar-quoted string opening at `start`,
+/// `None` if there is no dollar quote tag at `start`, e.g. a `$1` parameter
+fn find_dollar_quote_end(chars: &[char], start: usize) -> Option<usize> {
+    let tag_len = chars[start + 1..]
+        .iter()
+        .position(|c| *c == '$')
+        .filter(|len| {
+            chars[start + 1..start + 1 + len]
+                .iter()
+                .enumerate()
+                .all(|(i, c)| c.is_alphabetic() || *c == '_' || (i > 0 && c.is_ascii_digit()))
+        })?
+        + 2;
+    let tag = &chars[start..start + tag_len];
+    let body = start + tag_len;
+    Some(
+        chars[body..]
+            .windows(tag_len)
+            .position(|w| w == tag)
+            .map_or(chars.len(), |p| body + p + tag_len),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -33,4 +150,32 @@ mod tests {
             assert_eq!(get_file_stem(path), expect);
         }
     }
+
+    #[test]
+    fn test_split_sql_statements() {
+        assert_eq!(
+            split_sql_statements(
+                "CREATE TABLE a (x text DEFAULT 'a;b');\n-- c;d\nINSERT INTO \"a;\" VALUES ('it''s;'); /* e;f */ ;",
+                DbBackend::Sqlite
+            ),
+            [
+                "CREATE TABLE a (x text DEFAULT 'a;b')",
+                "INSERT INTO \"a;\" VALUES ('it''s;')",
+            ]
+        );
+        assert_eq!(
+            split_sql_statements("SELECT 'a\\';b'; # c;d\nSELECT `e;f`", DbBackend::MySql),
+            ["SELECT 'a\\';b'", "SELECT `e;f`"]
+        );
+        assert_eq!(
+            split_sql_statements(
+                "CREATE FUNCTION f() RETURNS int AS $body$ BEGIN RETURN 1; END; $body$ LANGUAGE plpgsql;\nSELECT $$a;b$$, $1;",
+                DbBackend::Postgres
+            ),
+            [
+                "CREATE FUNCTION f() RETURNS int AS $body$ BEGIN RETURN 1; END; $body$ LANGUAGE plpgsql",
+                "SELECT $$a;b$$, $1",
+            ]
+        );
+    }
 }
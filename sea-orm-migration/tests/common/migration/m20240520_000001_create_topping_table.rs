@@ -0,0 +1,49 @@
+use super::m20220118_000001_create_cake_table::Cake;
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Topping::Table)
+                    .col(pk_auto(Topping::Id))
+                    .col(string(Topping::Name))
+                    .col(integer(Topping::CakeId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-topping-cake_id")
+                            .from(Topping::Table, Topping::CakeId)
+                            .to(Cake::Table, Cake::Id),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-topping-name")
+                    .table(Topping::Table)
+                    .col(Topping::Name)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    // `down` drops the index, then the table
+    fn reversible(&self) -> bool {
+        true
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Topping {
+    Table,
+    Id,
+    Name,
+    CakeId,
+}
@@ -1,2 +1,4 @@
 pub mod default;
 pub mod override_migration_table_name;
+pub mod reversible;
+pub mod transaction_per_migration;
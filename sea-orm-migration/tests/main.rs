@@ -1,7 +1,9 @@
 mod common;
 
 use common::migrator::*;
-use sea_orm::{ConnectOptions, ConnectionTrait, Database, DbBackend, DbErr, Statement};
+use sea_orm::{
+    ConnectOptions, ConnectionTrait, Database, DatabaseConnection, DbBackend, DbErr, Statement,
+};
 use sea_orm_migration::{migrator::MigrationStatus, prelude::*};
 
 #[async_std::test]
@@ -37,18 +39,14 @@ async fn main() -> Result<(), DbErr> {
     )
     .await?;
 
+    run_transaction_per_migration(url, "sea_orm_migration_transaction_per_migration").await?;
+
+    run_reversible_migration(url, "sea_orm_migration_reversible").await?;
+
     Ok(())
 }
 
-async fn run_migration<Migrator>(
-    url: &str,
-    _: Migrator,
-    db_name: &str,
-    schema: &str,
-) -> Result<(), DbErr>
-where
-    Migrator: MigratorTrait,
-{
+async fn connect_db(url: &str, db_name: &str, schema: &str) -> Result<DatabaseConnection, DbErr> {
     let db_connect = |url: String| async {
         let connect_options = ConnectOptions::new(url)
             .set_schema_search_path(format!("{schema},public"))
@@ -59,7 +57,7 @@ where
 
     let db = db_connect(url.to_owned()).await?;
 
-    let db = &match db.get_database_backend() {
+    match db.get_database_backend() {
         DbBackend::MySql => {
             db.execute(Statement::from_string(
                 db.get_database_backend(),
@@ -68,7 +66,7 @@ where
             .await?;
 
             let url = format!("{url}/{db_name}");
-            db_connect(url).await?
+            db_connect(url).await
         }
         DbBackend::Postgres => {
             db.execute(Statement::from_string(
@@ -91,10 +89,22 @@ where
             ))
             .await?;
 
-            db
+            Ok(db)
         }
-        DbBackend::Sqlite => db,
-    };
+        DbBackend::Sqlite => Ok(db),
+    }
+}
+
+async fn run_migration<Migrator>(
+    url: &str,
+    _: Migrator,
+    db_name: &str,
+    schema: &str,
+) -> Result<(), DbErr>
+where
+    Migrator: MigratorTrait,
+{
+    let db = &connect_db(url, db_name, schema).await?;
     let manager = SchemaManager::new(db);
 
     println!("\nMigrator::status");
@@ -182,22 +192,417 @@ where
     let migrations = Migrator::get_applied_migrations(db).await?;
     assert_eq!(migrations.len(), 6);
 
+    println!("\nMigrator::verify_checksums");
+    if db.get_database_backend() == DbBackend::MySql {
+        // DDL cannot be rolled back on MySQL
+        assert!(matches!(
+            Migrator::verify_checksums(db).await,
+            Err(DbErr::Migration(msg)) if msg.contains("MySQL")
+        ));
+    } else {
+        Migrator::verify_checksums(db).await?;
+
+        let set_checksum = |checksum: Option<&str>| {
+            db.get_database_backend().build(
+                &Query::update()
+                    .table(Alias::new(migration_table_name))
+                    .value(Alias::new("checksum"), checksum)
+                    .and_where(
+                        Expr::col(Alias::new("version")).eq("m20220118_000001_create_cake_table"),
+                    )
+                    .to_owned(),
+            )
+        };
+
+        // Pretend the migration has been edited after it was applied
+        db.execute(set_checksum(Some("edited"))).await?;
+        assert!(matches!(
+            Migrator::verify_checksums(db).await,
+            Err(DbErr::Migration(msg)) if msg.contains("m20220118_000001_create_cake_table")
+        ));
+
+        // Rows recorded without a checksum get it backfilled
+        db.execute(set_checksum(None)).await?;
+        Migrator::verify_checksums(db).await?;
+        let models = Migrator::get_migration_models(db).await?;
+        assert!(models[0].checksum.is_some());
+    }
+
     assert!(!manager.has_index("cake", "non_existent_index").await?);
     assert!(manager.has_index("cake", "cake_name_index").await?);
 
+    // Absent objects are skipped
+    manager
+        .drop_index_if_exists(Alias::new("cake"), "non_existent_index")
+        .await?;
+    manager
+        .drop_table_if_exists(Alias::new("non_existent_table"))
+        .await?;
+
+    let indexes = manager.get_indexes("cake").await?;
+    let cake_name_index = indexes
+        .iter()
+        .find(|index| index.name == "cake_name_index")
+        .unwrap();
+    assert_eq!(cake_name_index.columns, ["name"]);
+    assert!(!cake_name_index.unique);
+
     let migration = migrations.get(0).unwrap();
     assert_eq!(migration.name(), "m20220118_000001_create_cake_table");
     assert_eq!(migration.status(), MigrationStatus::Applied);
 
+    println!("\nMigrator::get_status");
+    let statuses = Migrator::get_status(db).await?;
+    assert_eq!(statuses.len(), 6);
+    assert_eq!(statuses[0].name, "m20220118_000001_create_cake_table");
+    assert!(statuses
+        .iter()
+        .all(|s| s.status == MigrationStatus::Applied && s.applied_at.is_some()));
+
     println!("\nMigrator::status");
     Migrator::status(db).await?;
 
     assert!(manager.has_table("cake").await?);
     assert!(manager.has_table("fruit").await?);
 
+    // The table already exists, thus it is a no-op
+    manager
+        .create_table_if_not_exists(
+            Table::create()
+                .table(Alias::new("cake"))
+                .col(ColumnDef::new(Alias::new("id")).integer().primary_key())
+                .to_owned(),
+        )
+        .await?;
+    // Likewise when the table is qualified with its schema
+    let cake_schema = match db.get_database_backend() {
+        DbBackend::MySql => db_name,
+        DbBackend::Postgres => schema,
+        DbBackend::Sqlite => "main",
+    };
+    manager
+        .create_table_if_not_exists(
+            Table::create()
+                .table((Alias::new(cake_schema), Alias::new("cake")))
+                .col(ColumnDef::new(Alias::new("id")).integer().primary_key())
+                .to_owned(),
+        )
+        .await?;
+
+    match db.get_database_backend() {
+        DbBackend::MySql => assert!(manager.has_schema(db_name).await?),
+        DbBackend::Postgres => assert!(manager.has_schema(schema).await?),
+        DbBackend::Sqlite => assert!(!manager.has_schema("main").await?),
+    }
+    assert!(!manager.has_schema("non_existent_schema").await?);
+
+    // The second statement fails, thus the first one is rolled back as well
+    if !matches!(db.get_database_backend(), DbBackend::MySql) {
+        assert!(manager
+            .exec_stmts([
+                Table::create()
+                    .table(Alias::new("tea"))
+                    .col(ColumnDef::new(Alias::new("id")).integer().primary_key())
+                    .to_owned(),
+                Table::create()
+                    .table(Alias::new("cake"))
+                    .col(ColumnDef::new(Alias::new("id")).integer().primary_key())
+                    .to_owned(),
+            ])
+            .await
+            .is_err());
+        assert!(!manager.has_table("tea").await?);
+    }
+
+    let mut tables = manager.get_tables().await?;
+    tables.retain(|table| table != migration_table_name);
+    tables.sort();
+    assert_eq!(tables, ["cake", "fruit"]);
+    assert!(manager
+        .get_all_tables()
+        .await?
+        .contains(&migration_table_name.to_owned()));
+
     assert!(manager.has_column("cake", "name").await?);
     assert!(manager.has_column("fruit", "cake_id").await?);
 
+    let columns = manager.get_columns("fruit").await?;
+    assert_eq!(
+        columns.iter().map(|c| c.name.as_str()).collect::<Vec<_>>(),
+        ["id", "name", "cake_id"]
+    );
+    assert!(columns.iter().all(|c| !c.nullable));
+    assert!(manager.get_columns("non_existent_table").await?.is_empty());
+
+    let discovered = manager.discover().await?;
+    let mut names: Vec<_> = discovered.iter().map(|t| t.name.as_str()).collect();
+    names.retain(|name| *name != migration_table_name);
+    names.sort();
+    assert_eq!(names, ["cake", "fruit"]);
+    let fruit = discovered.iter().find(|t| t.name == "fruit").unwrap();
+    assert_eq!(fruit.primary_key, ["id"]);
+    assert_eq!(
+        fruit
+            .columns
+            .iter()
+            .map(|c| (c.name.as_str(), c.primary_key))
+            .collect::<Vec<_>>(),
+        [("id", true), ("name", false), ("cake_id", false)]
+    );
+    assert_eq!(fruit.columns[0].column_type, ColumnType::Integer);
+    assert_eq!(
+        fruit.columns[1].column_type,
+        ColumnType::String(match db.get_database_backend() {
+            DbBackend::MySql => StringLen::N(255),
+            _ => StringLen::None,
+        })
+    );
+    assert_eq!(fruit.foreign_keys.len(), 1);
+    assert_eq!(fruit.foreign_keys[0].columns, ["cake_id"]);
+    assert_eq!(fruit.foreign_keys[0].referenced_table, "cake");
+    assert_eq!(fruit.foreign_keys[0].referenced_columns, ["id"]);
+    if !matches!(db.get_database_backend(), DbBackend::Sqlite) {
+        assert_eq!(
+            fruit.foreign_keys[0].name.as_deref(),
+            Some("fk-fruit-cake_id")
+        );
+    }
+
+    manager
+        .rename_column(Alias::new("fruit"), Alias::new("name"), Alias::new("title"))
+        .await?;
+    assert!(!manager.has_column("fruit", "name").await?);
+    assert!(manager.has_column("fruit", "title").await?);
+    manager
+        .rename_column(Alias::new("fruit"), Alias::new("title"), Alias::new("name"))
+        .await?;
+    assert!(manager.has_column("fruit", "name").await?);
+
+    assert!(manager.has_foreign_key("fruit", "fk-fruit-cake_id").await?);
+    assert!(
+        !manager
+            .has_foreign_key("fruit", "non_existent_foreign_key")
+            .await?
+    );
+
+    assert_eq!(
+        manager.has_type("tea").await?,
+        matches!(db.get_database_backend(), DbBackend::Postgres)
+    );
+    assert!(!manager.has_type("non_existent_type").await?);
+
+    // The enums and arrays are discovered with their variants and element type
+    if !matches!(db.get_database_backend(), DbBackend::Sqlite) {
+        let mut drink = Table::create();
+        drink
+            .table(Alias::new("drink"))
+            .col(ColumnDef::new(Alias::new("id")).integer().primary_key())
+            .col(
+                ColumnDef::new(Alias::new("kind"))
+                    .enumeration(
+                        Alias::new("tea"),
+                        [Alias::new("EverydayTea"), Alias::new("BreakfastTea")],
+                    )
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(Alias::new("label"))
+                    .string_len(40)
+                    .not_null(),
+            );
+        if matches!(db.get_database_backend(), DbBackend::Postgres) {
+            drink.col(
+                ColumnDef::new(Alias::new("tags"))
+                    .array(ColumnType::Text)
+                    .not_null(),
+            );
+        }
+        manager.create_table(drink).await?;
+
+        let discovered = manager.discover().await?;
+        let drink = discovered.iter().find(|t| t.name == "drink").unwrap();
+        assert_eq!(
+            drink.columns[1].column_type,
+            ColumnType::Enum {
+                name: Alias::new(match db.get_database_backend() {
+                    DbBackend::Postgres => "tea",
+                    _ => "kind",
+                })
+                .into_iden(),
+                variants: vec![
+                    Alias::new("EverydayTea").into_iden(),
+                    Alias::new("BreakfastTea").into_iden(),
+                ],
+            }
+        );
+        assert_eq!(
+            drink.columns[2].column_type,
+            ColumnType::String(StringLen::N(40))
+        );
+        if matches!(db.get_database_backend(), DbBackend::Postgres) {
+            assert_eq!(
+                drink.columns[3].column_type,
+                ColumnType::Array(RcOrArc::new(ColumnType::Text))
+            );
+        }
+
+        manager
+            .drop_table(Table::drop().table(Alias::new("drink")).to_owned())
+            .await?;
+    }
+
+    // An out-of-range value is rejected by the check of the column
+    manager
+        .create_table(
+            Table::create()
+                .table(Alias::new("product"))
+                .col(
+                    ColumnDef::new(Alias::new("id"))
+                        .integer()
+                        .not_null()
+                        .auto_increment()
+                        .primary_key(),
+                )
+                .col(
+                    ColumnDef::new(Alias::new("price"))
+                        .integer()
+                        .not_null()
+                        .check(Expr::col(Alias::new("price")).gte(0)),
+                )
+                .to_owned(),
+        )
+        .await?;
+    assert!(db
+        .execute_unprepared("INSERT INTO product (id, price) VALUES (1, -1)")
+        .await
+        .is_err());
+    db.execute_unprepared("INSERT INTO product (id, price) VALUES (1, 0)")
+        .await?;
+
+    if matches!(db.get_database_backend(), DbBackend::Sqlite) {
+        assert!(manager
+            .add_check(
+                Alias::new("product"),
+                "price_max",
+                Expr::col(Alias::new("price")).lt(100)
+            )
+            .await
+            .is_err());
+        assert!(manager
+            .drop_check(Alias::new("product"), "price_max")
+            .await
+            .is_err());
+    } else {
+        manager
+            .add_check(
+                Alias::new("product"),
+                "price_max",
+                Expr::col(Alias::new("price")).lt(100),
+            )
+            .await?;
+        assert!(db
+            .execute_unprepared("INSERT INTO product (id, price) VALUES (2, 100)")
+            .await
+            .is_err());
+        manager
+            .drop_check(Alias::new("product"), "price_max")
+            .await?;
+        db.execute_unprepared("INSERT INTO product (id, price) VALUES (2, 100)")
+            .await?;
+    }
+
+    // The sequence of the ids is only reset with `restart_identity`
+    let max_id = || async {
+        db.query_one(Statement::from_string(
+            db.get_database_backend(),
+            "SELECT MAX(id) AS id FROM product",
+        ))
+        .await?
+        .unwrap()
+        .try_get::<Option<i32>>("", "id")
+    };
+    manager
+        .truncate_table_with(Alias::new("product"), TruncateOptions::default())
+        .await?;
+    assert_eq!(max_id().await?, None);
+    db.execute_unprepared("INSERT INTO product (price) VALUES (1)")
+        .await?;
+    db.execute_unprepared("INSERT INTO product (price) VALUES (1)")
+        .await?;
+    manager
+        .truncate_table_with(
+            Alias::new("product"),
+            TruncateOptions {
+                restart_identity: true,
+                ..Default::default()
+            },
+        )
+        .await?;
+    db.execute_unprepared("INSERT INTO product (price) VALUES (1)")
+        .await?;
+    assert_eq!(max_id().await?, Some(1));
+
+    let cascade = TruncateOptions {
+        cascade: true,
+        ..Default::default()
+    };
+    if matches!(db.get_database_backend(), DbBackend::MySql) {
+        assert!(manager
+            .truncate_table_with(Alias::new("product"), cascade)
+            .await
+            .is_err());
+    } else {
+        manager
+            .truncate_table_with(Alias::new("product"), cascade)
+            .await?;
+        assert_eq!(max_id().await?, None);
+    }
+
+    manager
+        .drop_table(Table::drop().table(Alias::new("product")).to_owned())
+        .await?;
+
+    // The emails only have to be unique among the accounts not deleted
+    manager
+        .create_table(
+            Table::create()
+                .table(Alias::new("account"))
+                .col(ColumnDef::new(Alias::new("id")).integer().primary_key())
+                .col(ColumnDef::new(Alias::new("email")).string().not_null())
+                .col(ColumnDef::new(Alias::new("deleted")).boolean().not_null())
+                .to_owned(),
+        )
+        .await?;
+    let index = Index::create()
+        .name("idx-account-email")
+        .table(Alias::new("account"))
+        .col(Alias::new("email"))
+        .unique()
+        .to_owned();
+    let condition = Expr::col(Alias::new("deleted")).eq(false);
+    if matches!(db.get_database_backend(), DbBackend::MySql) {
+        assert!(manager
+            .create_partial_index(index, condition)
+            .await
+            .is_err());
+    } else {
+        manager.create_partial_index(index, condition).await?;
+        assert!(manager.has_index("account", "idx-account-email").await?);
+        db.execute_unprepared(
+            "INSERT INTO account (id, email, deleted) VALUES (1, 'a@b.c', true), (2, 'a@b.c', true), (3, 'a@b.c', false)",
+        )
+        .await?;
+        assert!(db
+            .execute_unprepared(
+                "INSERT INTO account (id, email, deleted) VALUES (4, 'a@b.c', false)"
+            )
+            .await
+            .is_err());
+    }
+    manager
+        .drop_table(Table::drop().table(Alias::new("account")).to_owned())
+        .await?;
+
     // Tests rolling back changes of "migrate down" when running migration on Postgres
     if matches!(db.get_database_backend(), DbBackend::Postgres) {
         println!("\nRoll back changes when encounter errors");
@@ -248,6 +653,30 @@ where
     assert!(manager.has_table("cake").await?);
     assert!(manager.has_table("fruit").await?);
 
+    println!("\nMigrator::down_to");
+    Migrator::reset(db).await?;
+    Migrator::up(db, Some(3)).await?;
+    Migrator::down_to(db, "m20220118_000001_create_cake_table").await?;
+
+    let migrations = Migrator::get_applied_migrations(db).await?;
+    assert_eq!(migrations.len(), 1);
+    assert_eq!(migrations[0].name(), "m20220118_000001_create_cake_table");
+    assert!(manager.has_table("cake").await?);
+    assert!(!manager.has_table("fruit").await?);
+
+    assert_eq!(
+        Migrator::down_to(db, "m20220118_000002_create_fruit_table").await,
+        Err(DbErr::Migration(
+            "Migration 'm20220118_000002_create_fruit_table' has not been applied".into()
+        ))
+    );
+    assert_eq!(
+        Migrator::down_to(db, "m20000101_000001_non_existent").await,
+        Err(DbErr::Migration(
+            "Migration 'm20000101_000001_non_existent' does not exist".into()
+        ))
+    );
+
     println!("\nMigrator::reset");
     Migrator::reset(db).await?;
 
@@ -259,3 +688,80 @@ where
 
     Ok(())
 }
+
+async fn run_transaction_per_migration(url: &str, db_name: &str) -> Result<(), DbErr> {
+    use transaction_per_migration::Migrator;
+
+    let db = &connect_db(url, db_name, "public").await?;
+    let manager = SchemaManager::new(db);
+
+    // MySQL implicitly commits DDL statements, so a rollback cannot undo them
+    if matches!(db.get_database_backend(), DbBackend::MySql) {
+        return Ok(());
+    }
+
+    println!("\nMigrator::reset");
+    Migrator::reset(db).await?;
+
+    // Set a flag to throw error inside `m20230109_000001_seed_cake_table.rs`
+    std::env::set_var("ABORT_MIGRATION", "YES");
+
+    println!("\nMigrator::up");
+    assert_eq!(
+        Migrator::up(db, None).await,
+        Err(DbErr::Migration(
+            "Abort migration and rollback changes".into()
+        ))
+    );
+
+    // Only the failing migration has been rolled back
+    assert!(manager.has_table("cake").await?);
+    assert!(manager.has_table("fruit").await?);
+    let migrations = Migrator::get_pending_migrations(db).await?;
+    assert_eq!(migrations.len(), 1);
+    assert_eq!(migrations[0].name(), "m20230109_000001_seed_cake_table");
+
+    // Unset the flag
+    std::env::remove_var("ABORT_MIGRATION");
+
+    println!("\nMigrator::up");
+    Migrator::up(db, None).await?;
+    assert!(Migrator::get_pending_migrations(db).await?.is_empty());
+
+    println!("\nMigrator::reset");
+    Migrator::reset(db).await?;
+
+    assert!(!manager.has_table("cake").await?);
+    assert!(!manager.has_table("fruit").await?);
+
+    Ok(())
+}
+
+async fn run_reversible_migration(url: &str, db_name: &str) -> Result<(), DbErr> {
+    use reversible::Migrator;
+
+    let db = &connect_db(url, db_name, "public").await?;
+    let manager = SchemaManager::new(db);
+
+    println!("\nMigrator::fresh");
+    Migrator::fresh(db).await?;
+    assert!(manager.has_table("topping").await?);
+    assert!(manager.has_index("topping", "idx-topping-name").await?);
+
+    // `down` of the topping migration is derived from its `up`
+    println!("\nMigrator::down");
+    Migrator::down(db, Some(1)).await?;
+    assert!(manager.has_table("cake").await?);
+    assert!(!manager.has_table("topping").await?);
+
+    println!("\nMigrator::up");
+    Migrator::up(db, None).await?;
+    assert!(manager.has_table("topping").await?);
+
+    println!("\nMigrator::reset");
+    Migrator::reset(db).await?;
+    assert!(!manager.has_table("cake").await?);
+    assert!(!manager.has_table("topping").await?);
+
+    Ok(())
+}
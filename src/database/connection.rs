@@ -1,8 +1,16 @@
 use crate::{
-    DatabaseTransaction, DbBackend, DbErr, ExecResult, QueryResult, Statement, TransactionError,
+    error::query_err, DatabaseTransaction, DbBackend, DbErr, ExecResult, QueryResult, SqlErr,
+    Statement, TransactionError,
 };
 use futures::Stream;
-use std::{future::Future, pin::Pin};
+use sea_query::IntoIden;
+use std::{
+    collections::hash_map::RandomState,
+    future::Future,
+    hash::{BuildHasher, Hasher},
+    pin::Pin,
+    time::Duration,
+};
 
 /// The generic API for a database connection that can perform query or execute statements.
 /// It abstracts database connection and transaction
@@ -16,6 +24,16 @@ pub trait ConnectionTrait: Sync {
     async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr>;
 
     /// Execute a unprepared [Statement]
+    ///
+    /// The SQL is sent as is, without parameters, and may contain several statements separated
+    /// by `;`, which are all executed in order on every backend; the rows affected by them are
+    /// summed up in the [ExecResult]. Execution stops at the first failing statement:
+    ///
+    /// - Postgres runs the statements in an implicit transaction, so none of them is applied,
+    ///   unless the SQL contains explicit transaction control statements
+    /// - MySQL and SQLite keep the changes of the statements before it
+    ///
+    /// On a [MockDatabase](crate::MockDatabase), the SQL is recorded as a single statement.
     async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr>;
 
     /// Execute a [Statement] and return a query
@@ -24,6 +42,43 @@ pub trait ConnectionTrait: Sync {
     /// Execute a [Statement] and return a collection Vec<[QueryResult]> on success
     async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr>;
 
+    /// Execute a [Statement], failing with [DbErr::Timeout] if it does not complete within `timeout`.
+    ///
+    /// On a [DatabaseConnection](crate::DatabaseConnection) to Postgres or MySQL, the statement is
+    /// cancelled on the server on expiry, with `pg_cancel_backend` or `KILL QUERY` sent over a
+    /// separate connection. Elsewhere, including within a transaction and on SQLite, the future is
+    /// only dropped: the statement keeps running until the database is done with it, and the next
+    /// statement of a transaction waits for it.
+    /// A [MockDatabase](crate::MockDatabase) answers at once, so the timeout never expires.
+    async fn execute_with_timeout(
+        &self,
+        stmt: Statement,
+        timeout: Duration,
+    ) -> Result<ExecResult, DbErr> {
+        crate::with_timeout(timeout, self.execute(stmt)).await
+    }
+
+    /// Execute a [Statement] and return a query, failing with [DbErr::Timeout] if it does not
+    /// complete within `timeout`, see [ConnectionTrait::execute_with_timeout]
+    async fn query_one_with_timeout(
+        &self,
+        stmt: Statement,
+        timeout: Duration,
+    ) -> Result<Option<QueryResult>, DbErr> {
+        crate::with_timeout(timeout, self.query_one(stmt)).await
+    }
+
+    /// Execute a [Statement] and return a collection Vec<[QueryResult]>, failing with
+    /// [DbErr::Timeout] if it does not complete within `timeout`,
+    /// see [ConnectionTrait::execute_with_timeout]
+    async fn query_all_with_timeout(
+        &self,
+        stmt: Statement,
+        timeout: Duration,
+    ) -> Result<Vec<QueryResult>, DbErr> {
+        crate::with_timeout(timeout, self.query_all(stmt)).await
+    }
+
     /// Check if the connection supports `RETURNING` syntax on insert and update
     fn support_returning(&self) -> bool {
         let db_backend = self.get_database_backend();
@@ -34,6 +89,90 @@ pub trait ConnectionTrait: Sync {
     fn is_mock_connection(&self) -> bool {
         false
     }
+
+    /// Begin a transaction to run several statements as a unit, unless the connection is one
+    /// already: `None` is returned then, and the statements are to be run on it directly.
+    ///
+    /// A [DatabaseConnection](crate::DatabaseConnection) begins one, a
+    /// [DatabaseTransaction] does not. By default, no transaction is begun either.
+    async fn begin_unless_in_transaction(&self) -> Result<Option<DatabaseTransaction>, DbErr> {
+        Ok(None)
+    }
+
+    /// Lock the tables within a transaction, like `LOCK TABLE "cake" IN EXCLUSIVE MODE` on Postgres,
+    /// or ``LOCK TABLES `cake` WRITE`` on MySQL. SQLite has no table locks, and returns an error.
+    ///
+    /// Outside of a transaction, the locks would be released at once, or held by another
+    /// connection of the pool: an error is returned by default, and by a
+    /// [DatabaseConnection](crate::DatabaseConnection).
+    ///
+    /// On Postgres, the tables stay locked until the end of the transaction, whether it is
+    /// committed or rolled back; there is no way to unlock them before.
+    ///
+    /// MySQL is very different: the locks belong to the connection, not to the transaction.
+    /// - All the tables used until they are unlocked have to be locked by one call,
+    ///   as locking tables again releases the locks held so far.
+    /// - Locking the tables implicitly commits the statements run in the transaction so far,
+    ///   and the rest of them are no longer rolled back: lock the tables first.
+    /// - Committing the transaction does not release the locks: call
+    ///   [unlock_tables](Self::unlock_tables) before returning the connection to the pool.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_exec_results([MockExecResult {
+    /// #         last_insert_id: 0,
+    /// #         rows_affected: 0,
+    /// #     }])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, tests_cfg::cake, ConnectionTrait, TableLockMode, TransactionTrait};
+    ///
+    /// let txn = db.begin().await?;
+    /// txn.lock_tables([cake::Entity], TableLockMode::Exclusive).await?;
+    /// // .. the other transactions can not write to the table until this one ends
+    /// txn.unlock_tables().await?;
+    /// txn.commit().await?;
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     [Transaction::many([
+    ///         Statement::from_string(DbBackend::Postgres, "BEGIN"),
+    ///         Statement::from_string(
+    ///             DbBackend::Postgres,
+    ///             r#"LOCK TABLE "cake" IN EXCLUSIVE MODE"#
+    ///         ),
+    ///         Statement::from_string(DbBackend::Postgres, "COMMIT"),
+    ///     ])]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn lock_tables<I, T>(&self, tables: I, mode: TableLockMode) -> Result<(), DbErr>
+    where
+        Self: Sized,
+        I: IntoIterator<Item = T> + Send,
+        T: IntoIden + Send,
+    {
+        let _ = (tables, mode);
+        Err(query_err("Tables can only be locked within a transaction"))
+    }
+
+    /// Release the table locks of the connection, with `UNLOCK TABLES` on MySQL.
+    /// On Postgres, where the locks are released at the end of the transaction only,
+    /// this does nothing; SQLite returns an error.
+    /// See [lock_tables](Self::lock_tables).
+    async fn unlock_tables(&self) -> Result<(), DbErr> {
+        Err(query_err(
+            "Tables can only be unlocked within a transaction",
+        ))
+    }
 }
 
 /// Stream query results
@@ -48,6 +187,20 @@ pub trait StreamTrait: Send + Sync {
         &'a self,
         stmt: Statement,
     ) -> Pin<Box<dyn Future<Output = Result<Self::Stream<'a>, DbErr>> + 'a + Send>>;
+
+    /// Execute a [Statement] and return a stream of results,
+    /// fetching at most `fetch_size` rows from the database at a time.
+    ///
+    /// It is only honored by Postgres, which reads the rows through a server-side cursor.
+    /// Other backends behave as [StreamTrait::stream].
+    fn stream_with_fetch_size<'a>(
+        &'a self,
+        stmt: Statement,
+        fetch_size: u64,
+    ) -> Pin<Box<dyn Future<Output = Result<Self::Stream<'a>, DbErr>> + 'a + Send>> {
+        let _ = fetch_size;
+        self.stream(stmt)
+    }
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -92,15 +245,99 @@ impl std::fmt::Display for AccessMode {
     }
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// Table lock mode, see [ConnectionTrait::lock_tables]
+pub enum TableLockMode {
+    /// Other transactions can read the tables, but not write to them:
+    /// `SHARE` on Postgres, `READ` on MySQL
+    Share,
+    /// Other transactions can not write to the tables; they can still read them on Postgres,
+    /// but not on MySQL: `EXCLUSIVE` on Postgres, `WRITE` on MySQL
+    Exclusive,
+}
+
+/// The statement locking the tables, or an error on SQLite, which has no table locks
+pub(crate) fn lock_tables_statement(
+    db_backend: DbBackend,
+    tables: Vec<String>,
+    mode: TableLockMode,
+) -> Result<String, DbErr> {
+    let quote = match db_backend {
+        DbBackend::MySql => '`',
+        DbBackend::Postgres => '"',
+        DbBackend::Sqlite => {
+            return Err(query_err("Table locks are not supported by Sqlite"));
+        }
+    };
+    if tables.is_empty() {
+        return Err(query_err("No table to lock"));
+    }
+    let tables: Vec<String> = tables
+        .into_iter()
+        .map(|table| {
+            let escaped = table.replace(quote, &format!("{quote}{quote}"));
+            format!("{quote}{escaped}{quote}")
+        })
+        .collect();
+    let sql = if db_backend == DbBackend::MySql {
+        let mode = match mode {
+            TableLockMode::Share => "READ",
+            TableLockMode::Exclusive => "WRITE",
+        };
+        let tables: Vec<String> = tables
+            .into_iter()
+            .map(|table| format!("{table} {mode}"))
+            .collect();
+        format!("LOCK TABLES {}", tables.join(", "))
+    } else {
+        let mode = match mode {
+            TableLockMode::Share => "SHARE",
+            TableLockMode::Exclusive => "EXCLUSIVE",
+        };
+        format!("LOCK TABLE {} IN {mode} MODE", tables.join(", "))
+    };
+    Ok(sql)
+}
+
+/// The statements setting the isolation level and access mode of a transaction.
+/// There is none for SQLite, where they are global settings.
+#[cfg(any(feature = "sqlx-mysql", feature = "sqlx-postgres", feature = "mock"))]
+pub(crate) fn transaction_config_statements(
+    db_backend: DbBackend,
+    isolation_level: Option<IsolationLevel>,
+    access_mode: Option<AccessMode>,
+) -> Vec<Statement> {
+    if db_backend == DbBackend::Sqlite {
+        return vec![];
+    }
+    let isolation_level = isolation_level
+        .map(|isolation_level| format!("SET TRANSACTION ISOLATION LEVEL {isolation_level}"));
+    let access_mode = access_mode.map(|access_mode| format!("SET TRANSACTION {access_mode}"));
+    isolation_level
+        .into_iter()
+        .chain(access_mode)
+        .map(|sql| Statement::from_string(db_backend, sql))
+        .collect()
+}
+
 /// Spawn database transaction
 #[async_trait::async_trait]
 pub trait TransactionTrait {
     /// Execute SQL `BEGIN` transaction.
     /// Returns a Transaction that can be committed or rolled back
+    ///
+    /// Inside of a [`DatabaseTransaction`], a nested transaction is started with a `SAVEPOINT`.
+    /// Committing it releases the savepoint with `RELEASE SAVEPOINT`, while rolling it back,
+    /// or dropping it, undoes its changes with `ROLLBACK TO SAVEPOINT`: the outer transaction
+    /// stays open in both cases.
     async fn begin(&self) -> Result<DatabaseTransaction, DbErr>;
 
     /// Execute SQL `BEGIN` transaction with isolation level and/or access mode.
     /// Returns a Transaction that can be committed or rolled back
+    ///
+    /// They are set with `SET TRANSACTION` statements, right before `BEGIN` on MySQL
+    /// and right after it on Postgres. SQLite does not support them per transaction:
+    /// they are ignored, with a warning.
     async fn begin_with_config(
         &self,
         isolation_level: Option<IsolationLevel>,
@@ -133,4 +370,102 @@ pub trait TransactionTrait {
             + Send,
         T: Send,
         E: std::error::Error + Send;
+
+    /// Execute the function inside a transaction, like [`TransactionTrait::transaction`],
+    /// and run it again in a new transaction when it fails because of a serialization failure
+    /// or a deadlock, up to `max_attempts` times in total. Any other error is returned immediately.
+    ///
+    /// A serialization failure or a deadlock is what [`DbErr::sql_err`] reports as
+    /// [`SqlErr::SerializationFailure`] or [`SqlErr::Deadlock`], found in the error returned
+    /// by the function or in the chain of its sources.
+    ///
+    /// Before each new attempt, it waits for a random delay, so that the transactions which
+    /// conflicted do not run into each other again. The delay is at most 10ms after the first
+    /// attempt, and the bound doubles with every attempt up to 1s.
+    ///
+    /// The function may be called several times, so it must be idempotent: any effect it has
+    /// outside of the transaction, like sending a message, is repeated on every attempt.
+    async fn transaction_with_retry<F, T, E>(
+        &self,
+        max_attempts: u32,
+        callback: F,
+    ) -> Result<T, TransactionError<E>>
+    where
+        F: for<'c> Fn(
+                &'c DatabaseTransaction,
+            ) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'c>>
+            + Send
+            + Sync,
+        T: Send,
+        E: std::error::Error + Send + 'static,
+    {
+        let mut attempt = 1;
+        loop {
+            match self.transaction(|txn| callback(txn)).await {
+                Err(err) if attempt < max_attempts && is_retryable(&err) => {
+                    crate::sleep(retry_delay(attempt)).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+/// The bound of the delay before the second attempt of [`TransactionTrait::transaction_with_retry`]
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(10);
+
+/// The bound of the delay before any attempt of [`TransactionTrait::transaction_with_retry`]
+const RETRY_MAX_DELAY: Duration = Duration::from_secs(1);
+
+/// A random delay before the attempt after `attempt`, up to a bound doubling with every attempt
+fn retry_delay(attempt: u32) -> Duration {
+    let bound = RETRY_BASE_DELAY
+        .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+        .min(RETRY_MAX_DELAY);
+    // A randomly keyed hasher is a good enough source of jitter, without a dependency on `rand`
+    let random = RandomState::new().build_hasher().finish();
+    bound.mul_f64(random as f64 / u64::MAX as f64)
+}
+
+fn is_retryable<E>(err: &TransactionError<E>) -> bool
+where
+    E: std::error::Error + 'static,
+{
+    let mut source: Option<&(dyn std::error::Error + 'static)> = match err {
+        TransactionError::Connection(err) => Some(err),
+        TransactionError::Transaction(err) => Some(err),
+    };
+    while let Some(err) = source {
+        if let Some(err) = err.downcast_ref::<DbErr>() {
+            return matches!(
+                err.sql_err(),
+                Some(SqlErr::SerializationFailure(_) | SqlErr::Deadlock(_))
+            );
+        }
+        source = err.source();
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retry_delay_is_bounded() {
+        for (attempt, bound) in [
+            (1, 10),
+            (2, 20),
+            (3, 40),
+            (7, 640),
+            (8, 1000),
+            (u32::MAX, 1000),
+        ] {
+            for _ in 0..100 {
+                assert!(retry_delay(attempt) <= Duration::from_millis(bound));
+            }
+        }
+        assert!((0..100).any(|_| retry_delay(1) != retry_delay(1)));
+    }
 }
@@ -3,7 +3,7 @@ use crate::{
     QueryResult, Statement, StatementBuilder, StreamTrait, TransactionError, TransactionTrait,
 };
 use sea_query::{MysqlQueryBuilder, PostgresQueryBuilder, QueryBuilder, SqliteQueryBuilder};
-use std::{future::Future, pin::Pin};
+use std::{future::Future, pin::Pin, time::Duration};
 use tracing::instrument;
 use url::Url;
 
@@ -45,6 +45,14 @@ pub enum DatabaseConnection {
 /// The same as a [DatabaseConnection]
 pub type DbConn = DatabaseConnection;
 
+/// Statistics of the prepared statement cache of a connection,
+/// see [DatabaseConnection::statement_cache_stats]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StatementCacheStats {
+    /// The number of prepared statements held in the cache
+    pub cached_statements: usize,
+}
+
 impl Default for DatabaseConnection {
     fn default() -> Self {
         Self::Disconnected
@@ -204,10 +212,78 @@ impl ConnectionTrait for DatabaseConnection {
         }
     }
 
+    #[instrument(level = "trace")]
+    async fn execute_with_timeout(
+        &self,
+        stmt: Statement,
+        timeout: Duration,
+    ) -> Result<ExecResult, DbErr> {
+        match self {
+            #[cfg(feature = "sqlx-mysql")]
+            DatabaseConnection::SqlxMySqlPoolConnection(conn) => {
+                conn.execute_with_timeout(stmt, timeout).await
+            }
+            #[cfg(feature = "sqlx-postgres")]
+            DatabaseConnection::SqlxPostgresPoolConnection(conn) => {
+                conn.execute_with_timeout(stmt, timeout).await
+            }
+            #[cfg(feature = "mock")]
+            DatabaseConnection::MockDatabaseConnection(conn) => conn.execute(stmt),
+            _ => crate::with_timeout(timeout, self.execute(stmt)).await,
+        }
+    }
+
+    #[instrument(level = "trace")]
+    async fn query_one_with_timeout(
+        &self,
+        stmt: Statement,
+        timeout: Duration,
+    ) -> Result<Option<QueryResult>, DbErr> {
+        match self {
+            #[cfg(feature = "sqlx-mysql")]
+            DatabaseConnection::SqlxMySqlPoolConnection(conn) => {
+                conn.query_one_with_timeout(stmt, timeout).await
+            }
+            #[cfg(feature = "sqlx-postgres")]
+            DatabaseConnection::SqlxPostgresPoolConnection(conn) => {
+                conn.query_one_with_timeout(stmt, timeout).await
+            }
+            #[cfg(feature = "mock")]
+            DatabaseConnection::MockDatabaseConnection(conn) => conn.query_one(stmt),
+            _ => crate::with_timeout(timeout, self.query_one(stmt)).await,
+        }
+    }
+
+    #[instrument(level = "trace")]
+    async fn query_all_with_timeout(
+        &self,
+        stmt: Statement,
+        timeout: Duration,
+    ) -> Result<Vec<QueryResult>, DbErr> {
+        match self {
+            #[cfg(feature = "sqlx-mysql")]
+            DatabaseConnection::SqlxMySqlPoolConnection(conn) => {
+                conn.query_all_with_timeout(stmt, timeout).await
+            }
+            #[cfg(feature = "sqlx-postgres")]
+            DatabaseConnection::SqlxPostgresPoolConnection(conn) => {
+                conn.query_all_with_timeout(stmt, timeout).await
+            }
+            #[cfg(feature = "mock")]
+            DatabaseConnection::MockDatabaseConnection(conn) => conn.query_all(stmt),
+            _ => crate::with_timeout(timeout, self.query_all(stmt)).await,
+        }
+    }
+
     #[cfg(feature = "mock")]
     fn is_mock_connection(&self) -> bool {
         matches!(self, DatabaseConnection::MockDatabaseConnection(_))
     }
+
+    #[instrument(level = "trace")]
+    async fn begin_unless_in_transaction(&self) -> Result<Option<DatabaseTransaction>, DbErr> {
+        self.begin().await.map(Some)
+    }
 }
 
 #[async_trait::async_trait]
@@ -240,6 +316,22 @@ impl StreamTrait for DatabaseConnection {
             }
         })
     }
+
+    #[instrument(level = "trace")]
+    #[allow(unused_variables)]
+    fn stream_with_fetch_size<'a>(
+        &'a self,
+        stmt: Statement,
+        fetch_size: u64,
+    ) -> Pin<Box<dyn Future<Output = Result<Self::Stream<'a>, DbErr>> + 'a + Send>> {
+        match self {
+            #[cfg(feature = "sqlx-postgres")]
+            DatabaseConnection::SqlxPostgresPoolConnection(conn) => {
+                Box::pin(async move { conn.stream_with_fetch_size(stmt, fetch_size).await })
+            }
+            _ => self.stream(stmt),
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -255,7 +347,7 @@ impl TransactionTrait for DatabaseConnection {
             DatabaseConnection::SqlxSqlitePoolConnection(conn) => conn.begin(None, None).await,
             #[cfg(feature = "mock")]
             DatabaseConnection::MockDatabaseConnection(conn) => {
-                DatabaseTransaction::new_mock(Arc::clone(conn), None).await
+                DatabaseTransaction::new_mock(Arc::clone(conn), None, None, None).await
             }
             #[cfg(feature = "proxy")]
             DatabaseConnection::ProxyDatabaseConnection(conn) => {
@@ -286,7 +378,13 @@ impl TransactionTrait for DatabaseConnection {
             }
             #[cfg(feature = "mock")]
             DatabaseConnection::MockDatabaseConnection(conn) => {
-                DatabaseTransaction::new_mock(Arc::clone(conn), None).await
+                DatabaseTransaction::new_mock(
+                    Arc::clone(conn),
+                    None,
+                    _isolation_level,
+                    _access_mode,
+                )
+                .await
             }
             #[cfg(feature = "proxy")]
             DatabaseConnection::ProxyDatabaseConnection(conn) => {
@@ -323,7 +421,7 @@ impl TransactionTrait for DatabaseConnection {
             }
             #[cfg(feature = "mock")]
             DatabaseConnection::MockDatabaseConnection(conn) => {
-                let transaction = DatabaseTransaction::new_mock(Arc::clone(conn), None)
+                let transaction = DatabaseTransaction::new_mock(Arc::clone(conn), None, None, None)
                     .await
                     .map_err(TransactionError::Connection)?;
                 transaction.run(_callback).await
@@ -374,9 +472,14 @@ impl TransactionTrait for DatabaseConnection {
             }
             #[cfg(feature = "mock")]
             DatabaseConnection::MockDatabaseConnection(conn) => {
-                let transaction = DatabaseTransaction::new_mock(Arc::clone(conn), None)
-                    .await
-                    .map_err(TransactionError::Connection)?;
+                let transaction = DatabaseTransaction::new_mock(
+                    Arc::clone(conn),
+                    None,
+                    _isolation_level,
+                    _access_mode,
+                )
+                .await
+                .map_err(TransactionError::Connection)?;
                 transaction.run(_callback).await
             }
             #[cfg(feature = "proxy")]
@@ -458,7 +561,24 @@ impl DatabaseConnection {
         }
     }
 
+    /// Run the statements within a span carrying contextual fields, such as a request id or a
+    /// tenant, see [SpannedConnection](crate::SpannedConnection)
+    pub fn with_span_fields<I, K, V>(&self, fields: I) -> crate::SpannedConnection<'_, Self>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        crate::SpannedConnection::new(self, fields)
+    }
+
     /// Checks if a connection to the database is still valid.
+    ///
+    /// A connection is acquired from the pool, so that an exhausted or closed pool is reported
+    /// as well, and the database is sent the cheapest round trip of its protocol: a `COM_PING`
+    /// on MySQL, a `Sync` message on Postgres, and a no-op command to the worker thread of the
+    /// connection on SQLite. It is meant for readiness probes, and does not guarantee that the
+    /// database accepts writes, e.g. it succeeds on a read-only replica.
     pub async fn ping(&self) -> Result<(), DbErr> {
         match self {
             #[cfg(feature = "sqlx-mysql")]
@@ -475,6 +595,81 @@ impl DatabaseConnection {
         }
     }
 
+    /// Get the statistics of the prepared statement cache, as seen by a connection acquired
+    /// from the pool: each connection of the pool has a cache of its own.
+    ///
+    /// The drivers do not count cache hits and misses. A number of cached statements that keeps
+    /// growing up to the [capacity](crate::ConnectOptions::statement_cache_capacity) of the cache
+    /// means that statements are evicted and prepared again.
+    ///
+    /// Returns `None` for Mock and Proxy connections, which do not cache statements.
+    pub async fn statement_cache_stats(&self) -> Result<Option<StatementCacheStats>, DbErr> {
+        match self {
+            #[cfg(feature = "sqlx-mysql")]
+            DatabaseConnection::SqlxMySqlPoolConnection(conn) => {
+                conn.statement_cache_stats().await.map(Some)
+            }
+            #[cfg(feature = "sqlx-postgres")]
+            DatabaseConnection::SqlxPostgresPoolConnection(conn) => {
+                conn.statement_cache_stats().await.map(Some)
+            }
+            #[cfg(feature = "sqlx-sqlite")]
+            DatabaseConnection::SqlxSqlitePoolConnection(conn) => {
+                conn.statement_cache_stats().await.map(Some)
+            }
+            #[cfg(feature = "mock")]
+            DatabaseConnection::MockDatabaseConnection(_) => Ok(None),
+            #[cfg(feature = "proxy")]
+            DatabaseConnection::ProxyDatabaseConnection(_) => Ok(None),
+            DatabaseConnection::Disconnected => Err(conn_err("Disconnected")),
+        }
+    }
+
+    /// Open a connection of its own, on which the schema search path, like `tenant_a, public`,
+    /// replaces the one of the pool (Postgres only). The connection is the guard of the search
+    /// path: run the statements on it, then close it.
+    ///
+    /// The search path is set once with `SET search_path` when the connection is established,
+    /// and lasts for the lifetime of the connection. It is a pool of a single connection,
+    /// with the options of this one, so that the search path never leaks into the connections
+    /// of this pool, used by concurrent requests with the search path set by
+    /// [ConnectOptions::set_schema_search_path](crate::ConnectOptions::set_schema_search_path).
+    ///
+    /// The search path is sent as is, and must not come from user input.
+    pub async fn set_schema_search_path(
+        &self,
+        search_path: &str,
+    ) -> Result<DatabaseConnection, DbErr> {
+        check_schema_search_path_support(self.get_database_backend())?;
+        match self {
+            #[cfg(feature = "sqlx-postgres")]
+            DatabaseConnection::SqlxPostgresPoolConnection(conn) => {
+                conn.with_search_path(search_path).await
+            }
+            // There is no pool to take a connection from, the search path is set on this one
+            #[cfg(feature = "mock")]
+            DatabaseConnection::MockDatabaseConnection(conn) => {
+                self.execute_unprepared(&format!("SET search_path = {search_path}"))
+                    .await?;
+                Ok(DatabaseConnection::MockDatabaseConnection(Arc::clone(conn)))
+            }
+            #[cfg(feature = "proxy")]
+            DatabaseConnection::ProxyDatabaseConnection(conn) => {
+                self.execute_unprepared(&format!("SET search_path = {search_path}"))
+                    .await?;
+                Ok(DatabaseConnection::ProxyDatabaseConnection(Arc::clone(
+                    conn,
+                )))
+            }
+            _ => Err(conn_err("Disconnected")),
+        }
+    }
+
+    /// Get the schema search path of a connection acquired from the pool (Postgres only)
+    pub async fn schema_search_path(&self) -> Result<String, DbErr> {
+        query_schema_search_path(self).await
+    }
+
     /// Explicitly close the database connection
     pub async fn close(self) -> Result<(), DbErr> {
         match self {
@@ -585,6 +780,28 @@ impl DbBackend {
     }
 }
 
+fn check_schema_search_path_support(db_backend: DbBackend) -> Result<(), DbErr> {
+    match db_backend {
+        DbBackend::Postgres => Ok(()),
+        _ => Err(query_err(format!(
+            "Schema search path is only supported by Postgres, not {db_backend:?}"
+        ))),
+    }
+}
+
+pub(crate) async fn query_schema_search_path<C>(conn: &C) -> Result<String, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let db_backend = conn.get_database_backend();
+    check_schema_search_path_support(db_backend)?;
+    let res = conn
+        .query_one(Statement::from_string(db_backend, "SHOW search_path"))
+        .await?
+        .ok_or_else(|| query_err("SHOW search_path returned no row"))?;
+    res.try_get("", "search_path")
+}
+
 #[cfg(test)]
 mod tests {
     use crate::DatabaseConnection;
@@ -595,4 +812,54 @@ mod tests {
 
         assert_send_sync::<DatabaseConnection>();
     }
+
+    #[cfg(feature = "mock")]
+    #[smol_potat::test]
+    async fn mock_statement_cache_stats() {
+        let db = crate::MockDatabase::new(crate::DbBackend::Postgres).into_connection();
+        assert_eq!(db.statement_cache_stats().await, Ok(None));
+    }
+
+    #[cfg(feature = "mock")]
+    #[smol_potat::test]
+    async fn mock_schema_search_path() -> Result<(), crate::DbErr> {
+        use crate::{
+            DbBackend, IntoMockRow, MockDatabase, MockExecResult, Statement, Transaction, Value,
+        };
+        use pretty_assertions::assert_eq;
+        use std::collections::BTreeMap;
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_exec_results([MockExecResult::default()])
+            .append_query_results([[BTreeMap::from([(
+                "search_path",
+                Value::from("tenant_a, public"),
+            )])
+            .into_mock_row()]])
+            .into_connection();
+
+        let conn = db.set_schema_search_path("tenant_a, public").await?;
+        assert_eq!(conn.schema_search_path().await?, "tenant_a, public");
+        drop(conn);
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                Transaction::one(Statement::from_string(
+                    DbBackend::Postgres,
+                    "SET search_path = tenant_a, public"
+                )),
+                Transaction::one(Statement::from_string(
+                    DbBackend::Postgres,
+                    "SHOW search_path"
+                )),
+            ]
+        );
+
+        let db = MockDatabase::new(DbBackend::MySql).into_connection();
+        assert!(db.set_schema_search_path("public").await.is_err());
+        assert!(db.schema_search_path().await.is_err());
+
+        Ok(())
+    }
 }
@@ -1,7 +1,8 @@
 use crate::{
-    error::*, DatabaseConnection, DbBackend, EntityTrait, ExecResult, ExecResultHolder, Iden,
-    IdenStatic, Iterable, MockDatabaseConnection, MockDatabaseTrait, ModelTrait, QueryResult,
-    QueryResultRow, SelectA, SelectB, Statement,
+    error::*, transaction_config_statements, AccessMode, DatabaseConnection, DbBackend,
+    EntityTrait, ExecResult, ExecResultHolder, Iden, IdenStatic, IsolationLevel, Iterable,
+    MockDatabaseConnection, MockDatabaseTrait, ModelTrait, QueryResult, QueryResultRow, SelectA,
+    SelectB, Statement,
 };
 use sea_query::{Value, ValueType, Values};
 use std::{collections::BTreeMap, sync::Arc};
@@ -15,6 +16,10 @@ pub struct MockDatabase {
     transaction_log: Vec<Transaction>,
     exec_results: Vec<Result<MockExecResult, DbErr>>,
     query_results: Vec<Result<Vec<MockRow>, DbErr>>,
+    exec_matches: Vec<(String, MockExecResult)>,
+    query_matches: Vec<(String, Vec<MockRow>)>,
+    matched_execs: usize,
+    matched_queries: usize,
 }
 
 /// Defines the results obtained from a [MockDatabase]
@@ -63,6 +68,10 @@ impl MockDatabase {
             transaction_log: Vec::new(),
             exec_results: Vec::new(),
             query_results: Vec::new(),
+            exec_matches: Vec::new(),
+            query_matches: Vec::new(),
+            matched_execs: 0,
+            matched_queries: 0,
         }
     }
 
@@ -111,16 +120,120 @@ impl MockDatabase {
         self.query_results.extend(vec.into_iter().map(Result::Err));
         self
     }
+
+    /// Return the [MockExecResult] for every statement whose SQL matches `pattern`,
+    /// whatever the order the statements are executed in
+    ///
+    /// The pattern has to match the whole SQL, where `%` matches any sequence of characters,
+    /// like in a `LIKE` expression: `DELETE FROM "cake"` only matches the statements deleting
+    /// every cake, while `DELETE FROM "cake" WHERE %` matches the other ones.
+    ///
+    /// The first registered pattern a statement matches is used. The other statements
+    /// are given the `exec_results` in turn, and fail once they are consumed.
+    pub fn match_exec<P>(mut self, pattern: P, result: MockExecResult) -> Self
+    where
+        P: Into<String>,
+    {
+        self.exec_matches.push((pattern.into(), result));
+        self
+    }
+
+    /// Return the rows for every query whose SQL matches `pattern`,
+    /// whatever the order the queries are run in
+    ///
+    /// The pattern has to match the whole SQL, where `%` matches any sequence of characters,
+    /// like in a `LIKE` expression, see [match_exec](Self::match_exec).
+    ///
+    /// The first registered pattern a query matches is used. The other queries
+    /// are given the `query_results` in turn, and fail once they are consumed.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// let db = MockDatabase::new(DbBackend::Postgres)
+    ///     .match_query(
+    ///         r#"SELECT % FROM "fruit"%"#,
+    ///         [fruit::Model {
+    ///             id: 1,
+    ///             name: "Apple".to_owned(),
+    ///             cake_id: None,
+    ///         }],
+    ///     )
+    ///     .match_query(
+    ///         r#"SELECT % FROM "cake"%"#,
+    ///         [cake::Model {
+    ///             id: 1,
+    ///             name: "New York Cheese".to_owned(),
+    ///         }],
+    ///     )
+    ///     .into_connection();
+    ///
+    /// let cake = cake::Entity::find().one(&db).await?;
+    /// let fruits = fruit::Entity::find().all(&db).await?;
+    /// assert_eq!(cake.map(|cake| cake.id), Some(1));
+    /// assert_eq!(fruits.len(), 1);
+    ///
+    /// assert!(filling::Entity::find().all(&db).await.is_err());
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn match_query<P, T, I>(mut self, pattern: P, rows: I) -> Self
+    where
+        P: Into<String>,
+        T: IntoMockRow,
+        I: IntoIterator<Item = T>,
+    {
+        let rows = rows.into_iter().map(IntoMockRow::into_mock_row).collect();
+        self.query_matches.push((pattern.into(), rows));
+        self
+    }
+}
+
+/// Whether the whole SQL matches the pattern, where `%` matches any sequence of characters
+fn sql_matches(sql: &str, pattern: &str) -> bool {
+    let mut parts = pattern.split('%');
+    let Some(mut rest) = sql.strip_prefix(parts.next().unwrap_or_default()) else {
+        return false;
+    };
+    let parts: Vec<&str> = parts.collect();
+    let Some((last, middle)) = parts.split_last() else {
+        return rest.is_empty();
+    };
+    for part in middle {
+        match rest.find(part) {
+            Some(i) => rest = &rest[i + part.len()..],
+            None => return false,
+        }
+    }
+    rest.ends_with(last)
 }
 
 impl MockDatabaseTrait for MockDatabase {
     #[instrument(level = "trace")]
     fn execute(&mut self, counter: usize, statement: Statement) -> Result<ExecResult, DbErr> {
+        let matched = self
+            .exec_matches
+            .iter()
+            .find(|(pattern, _)| sql_matches(&statement.sql, pattern))
+            .map(|(_, result)| result.clone());
+        let sql = statement.sql.clone();
         if let Some(transaction) = &mut self.transaction {
             transaction.push(statement);
         } else {
             self.transaction_log.push(Transaction::one(statement));
         }
+        if let Some(result) = matched {
+            self.matched_execs += 1;
+            return Ok(ExecResult {
+                result: ExecResultHolder::Mock(result),
+            });
+        }
+        let counter = counter - self.matched_execs;
         if counter < self.exec_results.len() {
             match std::mem::replace(
                 &mut self.exec_results[counter],
@@ -131,6 +244,10 @@ impl MockDatabaseTrait for MockDatabase {
                 }),
                 Err(err) => Err(err),
             }
+        } else if !self.exec_matches.is_empty() {
+            Err(exec_err(format!(
+                "no registered exec result matches `{sql}`"
+            )))
         } else {
             Err(exec_err("`exec_results` buffer is empty"))
         }
@@ -138,11 +255,27 @@ impl MockDatabaseTrait for MockDatabase {
 
     #[instrument(level = "trace")]
     fn query(&mut self, counter: usize, statement: Statement) -> Result<Vec<QueryResult>, DbErr> {
+        let matched = self
+            .query_matches
+            .iter()
+            .find(|(pattern, _)| sql_matches(&statement.sql, pattern))
+            .map(|(_, rows)| rows.clone());
+        let sql = statement.sql.clone();
         if let Some(transaction) = &mut self.transaction {
             transaction.push(statement);
         } else {
             self.transaction_log.push(Transaction::one(statement));
         }
+        if let Some(rows) = matched {
+            self.matched_queries += 1;
+            return Ok(rows
+                .into_iter()
+                .map(|row| QueryResult {
+                    row: QueryResultRow::Mock(row),
+                })
+                .collect());
+        }
+        let counter = counter - self.matched_queries;
         if counter < self.query_results.len() {
             match std::mem::replace(
                 &mut self.query_results[counter],
@@ -156,6 +289,10 @@ impl MockDatabaseTrait for MockDatabase {
                     .collect()),
                 Err(err) => Err(err),
             }
+        } else if !self.query_matches.is_empty() {
+            Err(query_err(format!(
+                "no registered query result matches `{sql}`"
+            )))
         } else {
             Err(query_err("`query_results` buffer is empty."))
         }
@@ -165,7 +302,29 @@ impl MockDatabaseTrait for MockDatabase {
     fn begin(&mut self) {
         match self.transaction.as_mut() {
             Some(transaction) => transaction.begin_nested(self.db_backend),
-            None => self.transaction = Some(OpenTransaction::init()),
+            None => self.transaction = Some(OpenTransaction::init(self.db_backend)),
+        }
+    }
+
+    #[instrument(level = "trace")]
+    fn begin_with_config(
+        &mut self,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) {
+        let stmts = transaction_config_statements(self.db_backend, isolation_level, access_mode);
+        // in MySQL SET TRANSACTION operations are executed before transaction start
+        let position = match (self.db_backend, &self.transaction) {
+            (DbBackend::MySql, Some(transaction)) => Some(transaction.stmts.len()),
+            (DbBackend::MySql, None) => Some(0),
+            _ => None,
+        };
+        self.begin();
+        if let Some(transaction) = self.transaction.as_mut() {
+            let position = position.unwrap_or(transaction.stmts.len());
+            for (i, stmt) in stmts.into_iter().enumerate() {
+                transaction.stmts.insert(position + i, stmt);
+            }
         }
     }
 
@@ -359,12 +518,33 @@ impl Transaction {
     {
         stmts.into_iter().map(Self::one).collect()
     }
+
+    /// The statements of the Transaction, including `BEGIN` and `COMMIT` or `ROLLBACK`
+    pub fn statements(&self) -> &[Statement] {
+        &self.stmts
+    }
+
+    /// Whether the Transaction was committed
+    pub fn is_committed(&self) -> bool {
+        self.ends_with("COMMIT")
+    }
+
+    /// Whether the Transaction was rolled back, explicitly or by being dropped
+    pub fn is_rolled_back(&self) -> bool {
+        self.ends_with("ROLLBACK")
+    }
+
+    fn ends_with(&self, sql: &str) -> bool {
+        self.stmts.len() > 1
+            && self.stmts.first().map(|stmt| stmt.sql.as_str()) == Some("BEGIN")
+            && self.stmts.last().map(|stmt| stmt.sql.as_str()) == Some(sql)
+    }
 }
 
 impl OpenTransaction {
-    fn init() -> Self {
+    fn init(db_backend: DbBackend) -> Self {
         Self {
-            stmts: vec![Statement::from_string(DbBackend::Postgres, "BEGIN")],
+            stmts: vec![Statement::from_string(db_backend, "BEGIN")],
             transaction_depth: 0,
         }
     }
@@ -420,9 +600,10 @@ impl OpenTransaction {
 #[cfg(test)]
 #[cfg(feature = "mock")]
 mod tests {
+    use super::sql_matches;
     use crate::{
-        entity::*, error::*, tests_cfg::*, DbBackend, DbErr, IntoMockRow, MockDatabase, Statement,
-        Transaction, TransactionError, TransactionTrait,
+        entity::*, error::*, tests_cfg::*, DbBackend, DbErr, IntoMockRow, MockDatabase,
+        MockExecResult, Statement, Transaction, TransactionError, TransactionTrait,
     };
     use pretty_assertions::assert_eq;
 
@@ -480,6 +661,67 @@ mod tests {
         );
     }
 
+    #[smol_potat::test]
+    async fn test_transaction_with_config() {
+        use crate::{AccessMode, IsolationLevel};
+
+        let db = MockDatabase::new(DbBackend::Postgres).into_connection();
+        let txn = db
+            .begin_with_config(
+                Some(IsolationLevel::Serializable),
+                Some(AccessMode::ReadOnly),
+            )
+            .await
+            .unwrap();
+        txn.commit().await.unwrap();
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::many([
+                Statement::from_string(DbBackend::Postgres, "BEGIN"),
+                Statement::from_string(
+                    DbBackend::Postgres,
+                    "SET TRANSACTION ISOLATION LEVEL SERIALIZABLE"
+                ),
+                Statement::from_string(DbBackend::Postgres, "SET TRANSACTION READ ONLY"),
+                Statement::from_string(DbBackend::Postgres, "COMMIT"),
+            ])]
+        );
+
+        // in MySQL the transaction is configured before it starts
+        let db = MockDatabase::new(DbBackend::MySql).into_connection();
+        let txn = db
+            .begin_with_config(Some(IsolationLevel::ReadCommitted), None)
+            .await
+            .unwrap();
+        txn.commit().await.unwrap();
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::many([
+                Statement::from_string(
+                    DbBackend::MySql,
+                    "SET TRANSACTION ISOLATION LEVEL READ COMMITTED"
+                ),
+                Statement::from_string(DbBackend::MySql, "BEGIN"),
+                Statement::from_string(DbBackend::MySql, "COMMIT"),
+            ])]
+        );
+
+        // in SQLite the configuration is not supported
+        let db = MockDatabase::new(DbBackend::Sqlite).into_connection();
+        let txn = db
+            .begin_with_config(None, Some(AccessMode::ReadOnly))
+            .await
+            .unwrap();
+        txn.commit().await.unwrap();
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::many([
+                Statement::from_string(DbBackend::Sqlite, "BEGIN"),
+                Statement::from_string(DbBackend::Sqlite, "COMMIT"),
+            ])]
+        );
+    }
+
     #[smol_potat::test]
     async fn test_transaction_2() {
         let db = MockDatabase::new(DbBackend::Postgres).into_connection();
@@ -559,6 +801,49 @@ mod tests {
         );
     }
 
+    #[smol_potat::test]
+    async fn test_nested_transaction_rollback() {
+        let db = MockDatabase::new(DbBackend::Postgres).into_connection();
+
+        let txn = db.begin().await.unwrap();
+        let _ = cake::Entity::find().one(&txn).await;
+        {
+            let nested = txn.begin().await.unwrap();
+            let _ = fruit::Entity::find().all(&nested).await;
+            nested.rollback().await.unwrap();
+        }
+        {
+            // dropping the nested transaction rolls it back too
+            let nested = txn.begin().await.unwrap();
+            let _ = fruit::Entity::find().all(&nested).await;
+        }
+        txn.commit().await.unwrap();
+
+        let select_fruits = Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit""#,
+            [],
+        );
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::many([
+                Statement::from_string(DbBackend::Postgres, "BEGIN"),
+                Statement::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"SELECT "cake"."id", "cake"."name" FROM "cake" LIMIT $1"#,
+                    [1u64.into()]
+                ),
+                Statement::from_string(DbBackend::Postgres, "SAVEPOINT savepoint_1"),
+                select_fruits.clone(),
+                Statement::from_string(DbBackend::Postgres, "ROLLBACK TO SAVEPOINT savepoint_1"),
+                Statement::from_string(DbBackend::Postgres, "SAVEPOINT savepoint_1"),
+                select_fruits,
+                Statement::from_string(DbBackend::Postgres, "ROLLBACK TO SAVEPOINT savepoint_1"),
+                Statement::from_string(DbBackend::Postgres, "COMMIT"),
+            ]),]
+        );
+    }
+
     #[smol_potat::test]
     async fn test_nested_transaction_2() {
         let db = MockDatabase::new(DbBackend::Postgres).into_connection();
@@ -876,4 +1161,108 @@ mod tests {
             Err(exec_err("this is a mock exec error"))
         );
     }
+
+    #[smol_potat::test]
+    async fn test_transaction_outcome() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Sqlite).into_connection();
+
+        let result = db
+            .transaction::<_, (), DbErr>(|txn| {
+                Box::pin(async move {
+                    cake::Entity::find().one(txn).await?;
+                    Ok(())
+                })
+            })
+            .await;
+        assert!(result.is_err());
+
+        db.transaction::<_, (), DbErr>(|_| Box::pin(async move { Ok(()) }))
+            .await
+            .unwrap();
+
+        let txn = db.begin().await?;
+        drop(txn);
+
+        let log = db.into_transaction_log();
+        assert_eq!(log.len(), 3);
+        assert!(log[0].is_rolled_back());
+        assert_eq!(
+            log[0].statements(),
+            [
+                Statement::from_string(DbBackend::Sqlite, "BEGIN"),
+                Statement::from_sql_and_values(
+                    DbBackend::Sqlite,
+                    r#"SELECT "cake"."id", "cake"."name" FROM "cake" LIMIT ?"#,
+                    [1u64.into()]
+                ),
+                Statement::from_string(DbBackend::Sqlite, "ROLLBACK"),
+            ]
+        );
+        assert!(log[1].is_committed());
+        assert!(!log[1].is_rolled_back());
+        assert!(log[2].is_rolled_back());
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn test_match_query() -> Result<(), DbErr> {
+        let apple = fruit::Model {
+            id: 1,
+            name: "Apple".to_owned(),
+            cake_id: Some(1),
+        };
+        let cheese = cake::Model {
+            id: 1,
+            name: "New York Cheese".to_owned(),
+        };
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .match_query(r#"SELECT % FROM "fruit"%"#, [apple.clone()])
+            .append_query_results([[cheese.clone()]])
+            .match_exec(
+                r#"DELETE FROM "fruit""#,
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 3,
+                },
+            )
+            .into_connection();
+
+        assert_eq!(
+            fruit::Entity::find().all(&db).await?,
+            std::slice::from_ref(&apple)
+        );
+        assert_eq!(cake::Entity::find().one(&db).await?, Some(cheese));
+        assert_eq!(fruit::Entity::find().one(&db).await?, Some(apple));
+        assert!(cake::Entity::find().all(&db).await.is_err());
+
+        assert_eq!(
+            fruit::Entity::delete_many().exec(&db).await?.rows_affected,
+            3
+        );
+        assert_eq!(
+            cake::Entity::delete_many().exec(&db).await,
+            Err(exec_err(
+                r#"no registered exec result matches `DELETE FROM "cake"`"#
+            ))
+        );
+        // The pattern has to match the whole SQL
+        assert!(fruit::Entity::delete_by_id(1).exec(&db).await.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sql_matches() {
+        let sql = r#"SELECT "cake"."id" FROM "cake" WHERE "cake"."id" = $1"#;
+        assert!(sql_matches(sql, sql));
+        assert!(sql_matches(sql, "%"));
+        assert!(sql_matches(sql, r#"SELECT % FROM "cake"%"#));
+        assert!(sql_matches(sql, r#"% WHERE "cake"."id" = $1"#));
+        assert!(sql_matches(sql, "SELECT %id%id% = $1"));
+        assert!(!sql_matches(sql, r#"SELECT "cake"."id" FROM "cake""#));
+        assert!(!sql_matches(sql, r#"FROM "cake"%"#));
+        assert!(!sql_matches(sql, r#"SELECT % FROM "fruit"%"#));
+        assert!(!sql_matches(sql, "SELECT %id%id%id%id% = $1"));
+    }
 }
@@ -8,8 +8,14 @@ mod mock;
 #[cfg(feature = "proxy")]
 #[cfg_attr(docsrs, doc(cfg(feature = "proxy")))]
 mod proxy;
+#[cfg(feature = "mock")]
+#[cfg_attr(docsrs, doc(cfg(feature = "mock")))]
+mod recording;
+mod replicated;
+mod spanned;
 mod statement;
 mod stream;
+mod timeout;
 mod transaction;
 
 pub use connection::*;
@@ -20,9 +26,15 @@ pub use mock::*;
 #[cfg(feature = "proxy")]
 #[cfg_attr(docsrs, doc(cfg(feature = "proxy")))]
 pub use proxy::*;
+#[cfg(feature = "mock")]
+#[cfg_attr(docsrs, doc(cfg(feature = "mock")))]
+pub use recording::*;
+pub use replicated::*;
+pub use spanned::*;
 pub use statement::*;
 use std::borrow::Cow;
 pub use stream::*;
+pub(crate) use timeout::*;
 use tracing::instrument;
 pub use transaction::*;
 
@@ -67,6 +79,12 @@ pub struct ConnectOptions {
     /// be created using SQLx's [connect_lazy](https://docs.rs/sqlx/latest/sqlx/struct.Pool.html#method.connect_lazy)
     /// method.
     pub(crate) connect_lazy: bool,
+    /// Duration from which a statement is reported to the slow query callback
+    pub(crate) slow_query_threshold: Option<Duration>,
+    /// Callback receiving the SQL and the duration of slow statements
+    pub(crate) slow_query_callback: Option<fn(&str, Duration)>,
+    /// Maximum number of prepared statements cached by each connection
+    pub(crate) statement_cache_capacity: Option<usize>,
 }
 
 impl Database {
@@ -102,6 +120,20 @@ impl Database {
         )))
     }
 
+    /// Create a [DatabaseConnection] on a throwaway in-memory SQLite database, such as for tests
+    ///
+    /// Every connection to `sqlite::memory:` opens a database of its own, so a pool of many
+    /// connections would not see the tables created on another one. The pool is therefore
+    /// limited to a single connection, kept open for as long as the pool lives, and the
+    /// database is gone once the [DatabaseConnection] is closed or dropped. Statements and
+    /// transactions run one at a time, waiting for the connection to be released.
+    #[cfg(feature = "sqlx-sqlite")]
+    pub async fn connect_in_memory() -> Result<DatabaseConnection, DbErr> {
+        let mut opt = ConnectOptions::new("sqlite::memory:");
+        opt.max_connections(1).min_connections(1);
+        Self::connect(opt).await
+    }
+
     /// Method to create a [DatabaseConnection] on a proxy database
     #[cfg(feature = "proxy")]
     #[instrument(level = "trace", skip(proxy_func_arc))]
@@ -163,6 +195,9 @@ impl ConnectOptions {
             schema_search_path: None,
             test_before_acquire: true,
             connect_lazy: false,
+            slow_query_threshold: None,
+            slow_query_callback: None,
+            statement_cache_capacity: None,
         }
     }
 
@@ -183,6 +218,11 @@ impl ConnectOptions {
     }
 
     /// Set the minimum number of connections of the pool
+    ///
+    /// Unless the pool is [lazy](Self::connect_lazy), they are established when it is created,
+    /// so that the first queries do not wait for them: [Database::connect](crate::Database::connect)
+    /// only returns once they are, or fails after the [acquire timeout](Self::acquire_timeout).
+    /// The pool then keeps at least as many connections open, reopening those which were closed.
     pub fn min_connections(&mut self, value: u32) -> &mut Self {
         self.min_connections = Some(value);
         self
@@ -216,6 +256,9 @@ impl ConnectOptions {
     }
 
     /// Set the maximum amount of time to spend waiting for acquiring a connection
+    ///
+    /// Once it has elapsed, the operation fails with [DbErr::ConnAcquireTimeout], which reports
+    /// how many connections of the pool were in use at the time.
     pub fn acquire_timeout(&mut self, value: Duration) -> &mut Self {
         self.acquire_timeout = Some(value);
         self
@@ -280,6 +323,42 @@ impl ConnectOptions {
         )
     }
 
+    /// Set the duration from which a statement is reported to the
+    /// [slow query callback](Self::on_slow_query)
+    pub fn slow_query_threshold(&mut self, threshold: Duration) -> &mut Self {
+        self.slow_query_threshold = Some(threshold);
+        self
+    }
+
+    /// Get the duration from which a statement is reported to the slow query callback, if set
+    pub fn get_slow_query_threshold(&self) -> Option<Duration> {
+        self.slow_query_threshold
+    }
+
+    /// Set a callback receiving the SQL and the duration of every statement taking at least the
+    /// [slow query threshold](Self::slow_query_threshold) to complete.
+    ///
+    /// The SQL is the statement as sent to the database, with placeholders for its values.
+    /// The callback is independent of SQLx logging, and is kept along a
+    /// [metric callback](crate::DatabaseConnection::set_metric_callback).
+    /// It has no effect on Mock and Proxy connections.
+    pub fn on_slow_query(&mut self, callback: fn(&str, Duration)) -> &mut Self {
+        self.slow_query_callback = Some(callback);
+        self
+    }
+
+    #[cfg(any(
+        feature = "sqlx-mysql",
+        feature = "sqlx-postgres",
+        feature = "sqlx-sqlite"
+    ))]
+    pub(crate) fn slow_query(&self) -> Option<crate::metric::SlowQuery> {
+        Some(crate::metric::SlowQuery {
+            threshold: self.slow_query_threshold?,
+            callback: self.slow_query_callback?,
+        })
+    }
+
     /// set key for sqlcipher
     pub fn sqlcipher_key<T>(&mut self, value: T) -> &mut Self
     where
@@ -299,11 +378,19 @@ impl ConnectOptions {
     }
 
     /// If true, the connection will be pinged upon acquiring from the pool (default true).
+    ///
+    /// An idle connection which was closed, like by the database server, is then replaced
+    /// by a new one instead of failing the query, at the cost of a round-trip per acquire.
     pub fn test_before_acquire(&mut self, value: bool) -> &mut Self {
         self.test_before_acquire = value;
         self
     }
 
+    /// Get whether the connections are pinged upon acquiring from the pool
+    pub fn get_test_before_acquire(&self) -> bool {
+        self.test_before_acquire
+    }
+
     /// If set to `true`, the db connection pool will be created using SQLx's
     /// [connect_lazy](https://docs.rs/sqlx/latest/sqlx/struct.Pool.html#method.connect_lazy) method.
     pub fn connect_lazy(&mut self, value: bool) -> &mut Self {
@@ -315,4 +402,18 @@ impl ConnectOptions {
     pub fn get_connect_lazy(&self) -> bool {
         self.connect_lazy
     }
+
+    /// Set the maximum number of prepared statements cached by each connection
+    /// (default `100`, as set by SQLx). The least recently used statement is closed when the
+    /// cache is full, and `0` disables the cache. See
+    /// [statement_cache_stats](crate::DatabaseConnection::statement_cache_stats).
+    pub fn statement_cache_capacity(&mut self, capacity: usize) -> &mut Self {
+        self.statement_cache_capacity = Some(capacity);
+        self
+    }
+
+    /// Get the maximum number of prepared statements cached by each connection, if set
+    pub fn get_statement_cache_capacity(&self) -> Option<usize> {
+        self.statement_cache_capacity
+    }
 }
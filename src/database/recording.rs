@@ -0,0 +1,298 @@
+use crate::{
+    lock_tables_statement, AccessMode, ConnectionTrait, DatabaseTransaction, DbBackend, DbErr,
+    ExecResult, IsolationLevel, QueryResult, Statement, StreamTrait, TableLockMode,
+    TransactionError, TransactionTrait,
+};
+use sea_query::{DynIden, IntoIden};
+use std::{future::Future, pin::Pin, sync::Mutex, time::Duration};
+
+/// A connection recording every statement it runs, with its values, for tests to assert
+/// the SQL built against a real database
+///
+/// Unlike [MockDatabase](crate::MockDatabase), the statements are run on the connection,
+/// so the results come from the database. Statements run within a transaction opened with
+/// [begin](TransactionTrait::begin) or [transaction](TransactionTrait::transaction)
+/// are run on the [DatabaseTransaction] and are not recorded.
+///
+/// ```
+/// # use sea_orm::{error::*, tests_cfg::*, *};
+/// #
+/// # #[smol_potat::main]
+/// # #[cfg(feature = "mock")]
+/// # pub async fn main() -> Result<(), DbErr> {
+/// #
+/// # let db = MockDatabase::new(DbBackend::Postgres)
+/// #     .append_query_results([Vec::<cake::Model>::new()])
+/// #     .into_connection();
+/// #
+/// let recorder = RecordingConnection::new(&db);
+/// cake::Entity::find().all(&recorder).await?;
+///
+/// assert_eq!(
+///     recorder.recorded_statements(),
+///     [Statement::from_sql_and_values(
+///         DbBackend::Postgres,
+///         r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+///         []
+///     )]
+/// );
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct RecordingConnection<'conn, C> {
+    conn: &'conn C,
+    statements: Mutex<Vec<Statement>>,
+}
+
+impl<'conn, C> RecordingConnection<'conn, C> {
+    /// Record the statements run on a connection
+    pub fn new(conn: &'conn C) -> Self {
+        Self {
+            conn,
+            statements: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// The statements run so far, in order
+    ///
+    /// # Panics
+    ///
+    /// Panics if another thread panicked while recording a statement.
+    pub fn recorded_statements(&self) -> Vec<Statement> {
+        self.statements.lock().expect("Fail to lock").clone()
+    }
+
+    /// Take the statements run so far, so that the next ones are recorded afresh
+    ///
+    /// # Panics
+    ///
+    /// Panics if another thread panicked while recording a statement.
+    pub fn take_recorded_statements(&self) -> Vec<Statement> {
+        std::mem::take(&mut *self.statements.lock().expect("Fail to lock"))
+    }
+
+    /// The connection the statements are run on
+    pub fn inner(&self) -> &'conn C {
+        self.conn
+    }
+
+    fn record(&self, stmt: &Statement) {
+        self.statements
+            .lock()
+            .expect("Fail to lock")
+            .push(stmt.clone());
+    }
+}
+
+#[async_trait::async_trait]
+impl<'conn, C> ConnectionTrait for RecordingConnection<'conn, C>
+where
+    C: ConnectionTrait,
+{
+    fn get_database_backend(&self) -> DbBackend {
+        self.conn.get_database_backend()
+    }
+
+    async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
+        self.record(&stmt);
+        self.conn.execute(stmt).await
+    }
+
+    async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
+        self.record(&Statement::from_string(self.get_database_backend(), sql));
+        self.conn.execute_unprepared(sql).await
+    }
+
+    async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+        self.record(&stmt);
+        self.conn.query_one(stmt).await
+    }
+
+    async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+        self.record(&stmt);
+        self.conn.query_all(stmt).await
+    }
+
+    async fn execute_with_timeout(
+        &self,
+        stmt: Statement,
+        timeout: Duration,
+    ) -> Result<ExecResult, DbErr> {
+        self.record(&stmt);
+        self.conn.execute_with_timeout(stmt, timeout).await
+    }
+
+    async fn query_one_with_timeout(
+        &self,
+        stmt: Statement,
+        timeout: Duration,
+    ) -> Result<Option<QueryResult>, DbErr> {
+        self.record(&stmt);
+        self.conn.query_one_with_timeout(stmt, timeout).await
+    }
+
+    async fn query_all_with_timeout(
+        &self,
+        stmt: Statement,
+        timeout: Duration,
+    ) -> Result<Vec<QueryResult>, DbErr> {
+        self.record(&stmt);
+        self.conn.query_all_with_timeout(stmt, timeout).await
+    }
+
+    fn support_returning(&self) -> bool {
+        self.conn.support_returning()
+    }
+
+    fn is_mock_connection(&self) -> bool {
+        self.conn.is_mock_connection()
+    }
+
+    async fn lock_tables<I, T>(&self, tables: I, mode: TableLockMode) -> Result<(), DbErr>
+    where
+        Self: Sized,
+        I: IntoIterator<Item = T> + Send,
+        T: IntoIden + Send,
+    {
+        let tables: Vec<DynIden> = tables.into_iter().map(IntoIden::into_iden).collect();
+        if let Ok(sql) = lock_tables_statement(
+            self.get_database_backend(),
+            tables.iter().map(|table| table.to_string()).collect(),
+            mode,
+        ) {
+            self.record(&Statement::from_string(self.get_database_backend(), sql));
+        }
+        self.conn.lock_tables(tables, mode).await
+    }
+
+    async fn unlock_tables(&self) -> Result<(), DbErr> {
+        if self.get_database_backend() == DbBackend::MySql {
+            self.record(&Statement::from_string(DbBackend::MySql, "UNLOCK TABLES"));
+        }
+        self.conn.unlock_tables().await
+    }
+}
+
+impl<'conn, C> StreamTrait for RecordingConnection<'conn, C>
+where
+    C: StreamTrait,
+{
+    type Stream<'a>
+        = C::Stream<'a>
+    where
+        Self: 'a;
+
+    fn stream<'a>(
+        &'a self,
+        stmt: Statement,
+    ) -> Pin<Box<dyn Future<Output = Result<Self::Stream<'a>, DbErr>> + 'a + Send>> {
+        self.record(&stmt);
+        self.conn.stream(stmt)
+    }
+
+    fn stream_with_fetch_size<'a>(
+        &'a self,
+        stmt: Statement,
+        fetch_size: u64,
+    ) -> Pin<Box<dyn Future<Output = Result<Self::Stream<'a>, DbErr>> + 'a + Send>> {
+        self.record(&stmt);
+        self.conn.stream_with_fetch_size(stmt, fetch_size)
+    }
+}
+
+#[async_trait::async_trait]
+impl<'conn, C> TransactionTrait for RecordingConnection<'conn, C>
+where
+    C: TransactionTrait + Sync,
+{
+    async fn begin(&self) -> Result<DatabaseTransaction, DbErr> {
+        self.conn.begin().await
+    }
+
+    async fn begin_with_config(
+        &self,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<DatabaseTransaction, DbErr> {
+        self.conn
+            .begin_with_config(isolation_level, access_mode)
+            .await
+    }
+
+    async fn transaction<F, T, E>(&self, callback: F) -> Result<T, TransactionError<E>>
+    where
+        F: for<'c> FnOnce(
+                &'c DatabaseTransaction,
+            ) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'c>>
+            + Send,
+        T: Send,
+        E: std::error::Error + Send,
+    {
+        self.conn.transaction(callback).await
+    }
+
+    async fn transaction_with_config<F, T, E>(
+        &self,
+        callback: F,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<T, TransactionError<E>>
+    where
+        F: for<'c> FnOnce(
+                &'c DatabaseTransaction,
+            ) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'c>>
+            + Send,
+        T: Send,
+        E: std::error::Error + Send,
+    {
+        self.conn
+            .transaction_with_config(callback, isolation_level, access_mode)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{entity::*, error::*, tests_cfg::*, DbBackend, MockDatabase, Statement};
+    use pretty_assertions::assert_eq;
+
+    #[smol_potat::test]
+    async fn record_statements() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[cake::Model {
+                id: 1,
+                name: "Cheese Cake".to_owned(),
+            }]])
+            .append_exec_results([crate::MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .into_connection();
+        let recorder = crate::RecordingConnection::new(&db);
+
+        cake::Entity::find_by_id(1).one(&recorder).await?;
+        cake::Entity::delete_by_id(1).exec(&recorder).await?;
+
+        assert_eq!(
+            recorder.take_recorded_statements(),
+            [
+                Statement::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" = $1 LIMIT $2"#,
+                    [1i32.into(), 1u64.into()]
+                ),
+                Statement::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"DELETE FROM "cake" WHERE "cake"."id" = $1"#,
+                    [1i32.into()]
+                ),
+            ]
+        );
+        assert_eq!(recorder.recorded_statements(), []);
+        assert_eq!(db.into_transaction_log().len(), 2);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,439 @@
+use crate::{
+    AccessMode, ConnectionTrait, DatabaseConnection, DatabaseTransaction, DbBackend, DbErr,
+    ExecResult, IsolationLevel, QueryResult, QueryStream, Statement, StreamTrait, TransactionError,
+    TransactionTrait,
+};
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+use tracing::instrument;
+
+/// A connection to a primary database and its read replicas.
+///
+/// Statements that only read data, the [read_only](Statement::read_only()) ones, are sent to the
+/// readers in turn: the `SELECT`s built by the query builder that do not lock rows, and the raw
+/// statements marked as such. Every other statement, and every transaction, is sent to the
+/// writer. When there is no reader, all statements are sent to the writer.
+///
+/// Replicas may lag behind the primary: use [on_writer](Self::on_writer) to read the rows
+/// just written.
+///
+/// ```
+/// # use sea_orm::{error::*, tests_cfg::*, *};
+/// #
+/// # #[smol_potat::main]
+/// # #[cfg(feature = "mock")]
+/// # pub async fn main() -> Result<(), DbErr> {
+/// #
+/// # let writer = MockDatabase::new(DbBackend::Postgres)
+/// #     .append_query_results([[cake::Model {
+/// #         id: 1,
+/// #         name: "New York Cheese".to_owned(),
+/// #     }]])
+/// #     .into_connection();
+/// # let reader = MockDatabase::new(DbBackend::Postgres)
+/// #     .append_query_results([[cake::Model {
+/// #         id: 1,
+/// #         name: "New York Cheese".to_owned(),
+/// #     }]])
+/// #     .into_connection();
+/// #
+/// use sea_orm::{entity::*, query::*, tests_cfg::cake};
+///
+/// let db = ReplicatedDatabaseConnection::new(writer, [reader]);
+///
+/// // Sent to the reader
+/// let cakes: Vec<cake::Model> = cake::Entity::find().all(&db).await?;
+///
+/// // Sent to the writer
+/// let cake: Option<cake::Model> = cake::Entity::find_by_id(1).one(db.on_writer()).await?;
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ReplicatedDatabaseConnection {
+    writer: DatabaseConnection,
+    readers: Vec<DatabaseConnection>,
+    next_reader: AtomicUsize,
+}
+
+impl ReplicatedDatabaseConnection {
+    /// Combine the connection to the primary database with the connections to its replicas.
+    ///
+    /// All connections are expected to use the same database backend.
+    pub fn new<I>(writer: DatabaseConnection, readers: I) -> Self
+    where
+        I: IntoIterator<Item = DatabaseConnection>,
+    {
+        Self {
+            writer,
+            readers: readers.into_iter().collect(),
+            next_reader: AtomicUsize::new(0),
+        }
+    }
+
+    /// The connection to the primary database, which sees its own writes immediately
+    pub fn on_writer(&self) -> &DatabaseConnection {
+        &self.writer
+    }
+
+    /// The connection to the next replica in turn, or to the primary database if there is none
+    pub fn on_reader(&self) -> &DatabaseConnection {
+        if self.readers.is_empty() {
+            return &self.writer;
+        }
+        let next = self.next_reader.fetch_add(1, Ordering::Relaxed);
+        &self.readers[next % self.readers.len()]
+    }
+
+    /// Split into the connection to the primary database and the connections to its replicas
+    pub fn into_parts(self) -> (DatabaseConnection, Vec<DatabaseConnection>) {
+        (self.writer, self.readers)
+    }
+
+    /// Checks if the connections to the primary database and to its replicas are still valid,
+    /// see [DatabaseConnection::ping]
+    pub async fn ping(&self) -> Result<(), DbErr> {
+        self.writer.ping().await?;
+        for reader in self.readers.iter() {
+            reader.ping().await?;
+        }
+        Ok(())
+    }
+
+    /// Explicitly close the connections to the primary database and to its replicas
+    pub async fn close(self) -> Result<(), DbErr> {
+        self.writer.close().await?;
+        for reader in self.readers {
+            reader.close().await?;
+        }
+        Ok(())
+    }
+
+    fn route(&self, stmt: &Statement) -> &DatabaseConnection {
+        if stmt.read_only {
+            self.on_reader()
+        } else {
+            self.on_writer()
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ConnectionTrait for ReplicatedDatabaseConnection {
+    fn get_database_backend(&self) -> DbBackend {
+        self.writer.get_database_backend()
+    }
+
+    #[instrument(level = "trace")]
+    async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
+        self.writer.execute(stmt).await
+    }
+
+    #[instrument(level = "trace")]
+    async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
+        self.writer.execute_unprepared(sql).await
+    }
+
+    #[instrument(level = "trace")]
+    async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+        self.route(&stmt).query_one(stmt).await
+    }
+
+    #[instrument(level = "trace")]
+    async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+        self.route(&stmt).query_all(stmt).await
+    }
+
+    #[instrument(level = "trace")]
+    async fn execute_with_timeout(
+        &self,
+        stmt: Statement,
+        timeout: Duration,
+    ) -> Result<ExecResult, DbErr> {
+        self.writer.execute_with_timeout(stmt, timeout).await
+    }
+
+    #[instrument(level = "trace")]
+    async fn query_one_with_timeout(
+        &self,
+        stmt: Statement,
+        timeout: Duration,
+    ) -> Result<Option<QueryResult>, DbErr> {
+        self.route(&stmt)
+            .query_one_with_timeout(stmt, timeout)
+            .await
+    }
+
+    #[instrument(level = "trace")]
+    async fn query_all_with_timeout(
+        &self,
+        stmt: Statement,
+        timeout: Duration,
+    ) -> Result<Vec<QueryResult>, DbErr> {
+        self.route(&stmt)
+            .query_all_with_timeout(stmt, timeout)
+            .await
+    }
+
+    fn support_returning(&self) -> bool {
+        self.writer.support_returning()
+    }
+
+    fn is_mock_connection(&self) -> bool {
+        self.writer.is_mock_connection()
+    }
+
+    #[instrument(level = "trace")]
+    async fn begin_unless_in_transaction(&self) -> Result<Option<DatabaseTransaction>, DbErr> {
+        self.writer.begin_unless_in_transaction().await
+    }
+}
+
+impl StreamTrait for ReplicatedDatabaseConnection {
+    type Stream<'a> = QueryStream;
+
+    #[instrument(level = "trace")]
+    fn stream<'a>(
+        &'a self,
+        stmt: Statement,
+    ) -> Pin<Box<dyn Future<Output = Result<Self::Stream<'a>, DbErr>> + 'a + Send>> {
+        self.route(&stmt).stream(stmt)
+    }
+
+    #[instrument(level = "trace")]
+    fn stream_with_fetch_size<'a>(
+        &'a self,
+        stmt: Statement,
+        fetch_size: u64,
+    ) -> Pin<Box<dyn Future<Output = Result<Self::Stream<'a>, DbErr>> + 'a + Send>> {
+        self.route(&stmt).stream_with_fetch_size(stmt, fetch_size)
+    }
+}
+
+#[async_trait::async_trait]
+impl TransactionTrait for ReplicatedDatabaseConnection {
+    #[instrument(level = "trace")]
+    async fn begin(&self) -> Result<DatabaseTransaction, DbErr> {
+        self.writer.begin().await
+    }
+
+    #[instrument(level = "trace")]
+    async fn begin_with_config(
+        &self,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<DatabaseTransaction, DbErr> {
+        self.writer
+            .begin_with_config(isolation_level, access_mode)
+            .await
+    }
+
+    #[instrument(level = "trace", skip(callback))]
+    async fn transaction<F, T, E>(&self, callback: F) -> Result<T, TransactionError<E>>
+    where
+        F: for<'c> FnOnce(
+                &'c DatabaseTransaction,
+            ) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'c>>
+            + Send,
+        T: Send,
+        E: std::error::Error + Send,
+    {
+        self.writer.transaction(callback).await
+    }
+
+    #[instrument(level = "trace", skip(callback))]
+    async fn transaction_with_config<F, T, E>(
+        &self,
+        callback: F,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<T, TransactionError<E>>
+    where
+        F: for<'c> FnOnce(
+                &'c DatabaseTransaction,
+            ) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'c>>
+            + Send,
+        T: Send,
+        E: std::error::Error + Send,
+    {
+        self.writer
+            .transaction_with_config(callback, isolation_level, access_mode)
+            .await
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use crate::{
+        entity::*, error::*, tests_cfg::*, DatabaseConnection, DbBackend, MockDatabase,
+        MockExecResult, QueryFilter, QuerySelect, ReplicatedDatabaseConnection, Statement,
+        Transaction, TransactionTrait,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn cake(id: i32) -> cake::Model {
+        cake::Model {
+            id,
+            name: "Cheese Cake".to_owned(),
+        }
+    }
+
+    fn select_cake(sql: &str) -> Transaction {
+        Transaction::from_sql_and_values(DbBackend::Postgres, sql, [1u64.into()])
+    }
+
+    #[smol_potat::test]
+    async fn route_statements() -> Result<(), DbErr> {
+        let writer = MockDatabase::new(DbBackend::Postgres)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 1,
+                rows_affected: 1,
+            }])
+            .append_query_results([[cake(1)], [cake(1)], [cake(1)]])
+            .into_connection();
+        let readers = (0..2).map(|_| {
+            MockDatabase::new(DbBackend::Postgres)
+                .append_query_results([[cake(1)]])
+                .into_connection()
+        });
+        let db = ReplicatedDatabaseConnection::new(writer, readers);
+
+        // Reads are spread over the readers
+        cake::Entity::find().one(&db).await?;
+        cake::Entity::find()
+            .filter(cake::Column::Id.eq(1))
+            .one(&db)
+            .await?;
+
+        // Writes, locking reads, transactions and explicit reads from the writer are not
+        cake::Entity::delete_by_id(1).exec(&db).await?;
+        cake::Entity::find().lock_exclusive().one(&db).await?;
+        cake::Entity::find().one(db.on_writer()).await?;
+        let txn = db.begin().await?;
+        cake::Entity::find().one(&txn).await?;
+        txn.commit().await?;
+
+        let (writer, readers) = db.into_parts();
+        let mut readers = readers.into_iter();
+        assert_eq!(
+            readers.next().unwrap().into_transaction_log(),
+            [select_cake(
+                r#"SELECT "cake"."id", "cake"."name" FROM "cake" LIMIT $1"#
+            )]
+        );
+        assert_eq!(
+            readers.next().unwrap().into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" = $1 LIMIT $2"#,
+                [1i32.into(), 1u64.into()]
+            )]
+        );
+        assert_eq!(
+            writer.into_transaction_log(),
+            [
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"DELETE FROM "cake" WHERE "cake"."id" = $1"#,
+                    [1i32.into()]
+                ),
+                select_cake(r#"SELECT "cake"."id", "cake"."name" FROM "cake" LIMIT $1 FOR UPDATE"#),
+                select_cake(r#"SELECT "cake"."id", "cake"."name" FROM "cake" LIMIT $1"#),
+                Transaction::many([
+                    Statement::from_string(DbBackend::Postgres, "BEGIN"),
+                    Statement::from_sql_and_values(
+                        DbBackend::Postgres,
+                        r#"SELECT "cake"."id", "cake"."name" FROM "cake" LIMIT $1"#,
+                        [1u64.into()]
+                    ),
+                    Statement::from_string(DbBackend::Postgres, "COMMIT"),
+                ]),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn route_raw_statements() -> Result<(), DbErr> {
+        let writer = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[cake(1)], [cake(1)]])
+            .into_connection();
+        let reader = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[cake(1)]])
+            .into_connection();
+        let db = ReplicatedDatabaseConnection::new(writer, [reader]);
+
+        // Raw statements are sent to the writer, unless marked as read-only
+        let select = r#"SELECT "cake"."id", "cake"."name" FROM "cake""#;
+        let with = r#"WITH "deleted" AS (DELETE FROM "cake" RETURNING *) SELECT * FROM "deleted""#;
+        cake::Entity::find()
+            .from_raw_sql(Statement::from_string(DbBackend::Postgres, with))
+            .one(&db)
+            .await?;
+        cake::Entity::find()
+            .from_raw_sql(Statement::from_string(DbBackend::Postgres, select))
+            .one(&db)
+            .await?;
+        cake::Entity::find()
+            .from_raw_sql(Statement::from_string(DbBackend::Postgres, select).read_only())
+            .one(&db)
+            .await?;
+
+        let (writer, readers) = db.into_parts();
+        assert_eq!(
+            readers.into_iter().next().unwrap().into_transaction_log(),
+            [Transaction::one(Statement::from_string(
+                DbBackend::Postgres,
+                select
+            ))]
+        );
+        assert_eq!(
+            writer.into_transaction_log(),
+            [
+                Transaction::one(Statement::from_string(DbBackend::Postgres, with)),
+                Transaction::one(Statement::from_string(DbBackend::Postgres, select)),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn ping_every_connection() {
+        let writer = MockDatabase::new(DbBackend::Postgres).into_connection();
+        let reader = MockDatabase::new(DbBackend::Postgres).into_connection();
+        let db = ReplicatedDatabaseConnection::new(writer, [reader]);
+        assert_eq!(db.ping().await, Ok(()));
+
+        let writer = MockDatabase::new(DbBackend::Postgres).into_connection();
+        let db = ReplicatedDatabaseConnection::new(writer, [DatabaseConnection::Disconnected]);
+        assert!(db.ping().await.is_err());
+    }
+
+    #[smol_potat::test]
+    async fn route_without_readers() -> Result<(), DbErr> {
+        let writer = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[cake(1)]])
+            .into_connection();
+        let db = ReplicatedDatabaseConnection::new(writer, []);
+
+        cake::Entity::find().one(&db).await?;
+
+        let (writer, _) = db.into_parts();
+        assert_eq!(
+            writer.into_transaction_log(),
+            [select_cake(
+                r#"SELECT "cake"."id", "cake"."name" FROM "cake" LIMIT $1"#
+            )]
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,293 @@
+use crate::{
+    AccessMode, ConnectionTrait, DatabaseTransaction, DbBackend, DbErr, ExecResult, IsolationLevel,
+    QueryResult, Statement, StreamTrait, TableLockMode, TransactionError, TransactionTrait,
+};
+use sea_query::IntoIden;
+use std::{fmt::Write, future::Future, pin::Pin, time::Duration};
+use tracing::{Instrument, Span};
+
+/// A connection running its statements within a span carrying contextual fields,
+/// such as a request id or a tenant, created with
+/// [DatabaseConnection::with_span_fields](crate::DatabaseConnection::with_span_fields)
+///
+/// Every statement is run within a `sea_orm.query` span at the `TRACE` level, whose `fields`
+/// field lists the contextual fields as `key=value` pairs. The spans of the connection
+/// are nested within it.
+///
+/// A transaction run with [transaction](TransactionTrait::transaction) is wholly within the
+/// span, while a transaction opened with [begin](TransactionTrait::begin) is not: use
+/// [DatabaseTransaction::with_span_fields] on it again.
+#[derive(Debug)]
+pub struct SpannedConnection<'conn, C> {
+    conn: &'conn C,
+    fields: String,
+}
+
+impl<'conn, C> SpannedConnection<'conn, C> {
+    pub(crate) fn new<I, K, V>(conn: &'conn C, fields: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let mut formatted = String::new();
+        for (key, value) in fields {
+            if !formatted.is_empty() {
+                formatted.push(' ');
+            }
+            let _ = write!(formatted, "{}={}", key.as_ref(), value.as_ref());
+        }
+        Self {
+            conn,
+            fields: formatted,
+        }
+    }
+
+    /// The contextual fields, as `key=value` pairs separated by spaces
+    pub fn span_fields(&self) -> &str {
+        &self.fields
+    }
+
+    /// The connection the statements are run on
+    pub fn inner(&self) -> &'conn C {
+        self.conn
+    }
+
+    fn span(&self) -> Span {
+        tracing::trace_span!("sea_orm.query", fields = %self.fields)
+    }
+}
+
+#[async_trait::async_trait]
+impl<'conn, C> ConnectionTrait for SpannedConnection<'conn, C>
+where
+    C: ConnectionTrait,
+{
+    fn get_database_backend(&self) -> DbBackend {
+        self.conn.get_database_backend()
+    }
+
+    async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
+        self.conn.execute(stmt).instrument(self.span()).await
+    }
+
+    async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
+        self.conn
+            .execute_unprepared(sql)
+            .instrument(self.span())
+            .await
+    }
+
+    async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+        self.conn.query_one(stmt).instrument(self.span()).await
+    }
+
+    async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+        self.conn.query_all(stmt).instrument(self.span()).await
+    }
+
+    async fn execute_with_timeout(
+        &self,
+        stmt: Statement,
+        timeout: Duration,
+    ) -> Result<ExecResult, DbErr> {
+        self.conn
+            .execute_with_timeout(stmt, timeout)
+            .instrument(self.span())
+            .await
+    }
+
+    async fn query_one_with_timeout(
+        &self,
+        stmt: Statement,
+        timeout: Duration,
+    ) -> Result<Option<QueryResult>, DbErr> {
+        self.conn
+            .query_one_with_timeout(stmt, timeout)
+            .instrument(self.span())
+            .await
+    }
+
+    async fn query_all_with_timeout(
+        &self,
+        stmt: Statement,
+        timeout: Duration,
+    ) -> Result<Vec<QueryResult>, DbErr> {
+        self.conn
+            .query_all_with_timeout(stmt, timeout)
+            .instrument(self.span())
+            .await
+    }
+
+    fn support_returning(&self) -> bool {
+        self.conn.support_returning()
+    }
+
+    fn is_mock_connection(&self) -> bool {
+        self.conn.is_mock_connection()
+    }
+
+    async fn lock_tables<I, T>(&self, tables: I, mode: TableLockMode) -> Result<(), DbErr>
+    where
+        Self: Sized,
+        I: IntoIterator<Item = T> + Send,
+        T: IntoIden + Send,
+    {
+        self.conn
+            .lock_tables(tables, mode)
+            .instrument(self.span())
+            .await
+    }
+
+    async fn unlock_tables(&self) -> Result<(), DbErr> {
+        self.conn.unlock_tables().instrument(self.span()).await
+    }
+}
+
+impl<'conn, C> StreamTrait for SpannedConnection<'conn, C>
+where
+    C: StreamTrait,
+{
+    type Stream<'a>
+        = C::Stream<'a>
+    where
+        Self: 'a;
+
+    fn stream<'a>(
+        &'a self,
+        stmt: Statement,
+    ) -> Pin<Box<dyn Future<Output = Result<Self::Stream<'a>, DbErr>> + 'a + Send>> {
+        Box::pin(self.conn.stream(stmt).instrument(self.span()))
+    }
+
+    fn stream_with_fetch_size<'a>(
+        &'a self,
+        stmt: Statement,
+        fetch_size: u64,
+    ) -> Pin<Box<dyn Future<Output = Result<Self::Stream<'a>, DbErr>> + 'a + Send>> {
+        Box::pin(
+            self.conn
+                .stream_with_fetch_size(stmt, fetch_size)
+                .instrument(self.span()),
+        )
+    }
+}
+
+#[async_trait::async_trait]
+impl<'conn, C> TransactionTrait for SpannedConnection<'conn, C>
+where
+    C: TransactionTrait + Sync,
+{
+    async fn begin(&self) -> Result<DatabaseTransaction, DbErr> {
+        self.conn.begin().instrument(self.span()).await
+    }
+
+    async fn begin_with_config(
+        &self,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<DatabaseTransaction, DbErr> {
+        self.conn
+            .begin_with_config(isolation_level, access_mode)
+            .instrument(self.span())
+            .await
+    }
+
+    async fn transaction<F, T, E>(&self, callback: F) -> Result<T, TransactionError<E>>
+    where
+        F: for<'c> FnOnce(
+                &'c DatabaseTransaction,
+            ) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'c>>
+            + Send,
+        T: Send,
+        E: std::error::Error + Send,
+    {
+        self.conn
+            .transaction(callback)
+            .instrument(self.span())
+            .await
+    }
+
+    async fn transaction_with_config<F, T, E>(
+        &self,
+        callback: F,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<T, TransactionError<E>>
+    where
+        F: for<'c> FnOnce(
+                &'c DatabaseTransaction,
+            ) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'c>>
+            + Send,
+        T: Send,
+        E: std::error::Error + Send,
+    {
+        self.conn
+            .transaction_with_config(callback, isolation_level, access_mode)
+            .instrument(self.span())
+            .await
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use crate::{entity::*, error::*, tests_cfg::*, DbBackend, MockDatabase, Transaction};
+    use pretty_assertions::assert_eq;
+    use std::{
+        io,
+        sync::{Arc, Mutex},
+    };
+
+    #[derive(Clone, Default)]
+    struct Output(Arc<Mutex<Vec<u8>>>);
+
+    impl io::Write for Output {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[smol_potat::test]
+    async fn span_fields() -> Result<(), DbErr> {
+        let output = Output::default();
+        let writer = output.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_max_level(tracing::Level::TRACE)
+            .with_span_events(tracing_subscriber::fmt::format::FmtSpan::NEW)
+            .with_ansi(false)
+            .with_writer(move || writer.clone())
+            .finish();
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[cake::Model {
+                id: 1,
+                name: "Cheese Cake".to_owned(),
+            }]])
+            .into_connection();
+        let traced = db.with_span_fields([("tenant", "acme"), ("request_id", "42")]);
+        assert_eq!(traced.span_fields(), "tenant=acme request_id=42");
+
+        cake::Entity::find().one(&traced).await?;
+
+        let output = String::from_utf8(output.0.lock().unwrap().clone()).unwrap();
+        assert!(output.contains("sea_orm.query{fields=tenant=acme request_id=42}"));
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"SELECT "cake"."id", "cake"."name" FROM "cake" LIMIT $1"#,
+                [1u64.into()]
+            )]
+        );
+
+        Ok(())
+    }
+}
@@ -1,10 +1,10 @@
-use crate::DbBackend;
+use crate::{error::query_err, DbBackend, DbErr};
 use sea_query::{inject_parameters, MysqlQueryBuilder, PostgresQueryBuilder, SqliteQueryBuilder};
 pub use sea_query::{Value, Values};
 use std::fmt;
 
 /// Defines an SQL statement
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone)]
 pub struct Statement {
     /// The SQL query
     pub sql: String,
@@ -13,6 +13,19 @@ pub struct Statement {
     /// The database backend this statement is constructed for.
     /// The SQL dialect and values should be valid for the DbBackend.
     pub db_backend: DbBackend,
+    /// Whether the statement only reads data, so that a
+    /// [ReplicatedDatabaseConnection](crate::ReplicatedDatabaseConnection) can send it to a
+    /// replica. It is set for the `SELECT`s built from a [SelectStatement](sea_query::SelectStatement)
+    /// that do not lock rows; mark a raw statement with [read_only](Self::read_only()).
+    pub read_only: bool,
+}
+
+/// Two statements are equal when their SQL, values and backends are,
+/// whether they are marked as read-only or not
+impl PartialEq for Statement {
+    fn eq(&self, other: &Self) -> bool {
+        self.sql == other.sql && self.values == other.values && self.db_backend == other.db_backend
+    }
 }
 
 /// Any type that can build a [Statement]
@@ -31,6 +44,7 @@ impl Statement {
             sql: stmt.into(),
             values: None,
             db_backend,
+            read_only: false,
         }
     }
 
@@ -52,8 +66,79 @@ impl Statement {
             sql: stmt.0.into(),
             values: Some(stmt.1),
             db_backend,
+            read_only: false,
         }
     }
+
+    /// Mark the statement as only reading data, so that a
+    /// [ReplicatedDatabaseConnection](crate::ReplicatedDatabaseConnection) can send it to a
+    /// replica. Raw statements are sent to the primary database otherwise, even `SELECT`s,
+    /// as they may call functions writing data, or be a `WITH` modifying rows.
+    pub fn read_only(mut self) -> Self {
+        self.read_only = true;
+        self
+    }
+}
+
+impl Statement {
+    /// Check that the placeholders of the SQL are those of the backend, with a value for each:
+    /// `$1`, `$2` and so on for Postgres, `?` for MySQL and SQLite.
+    /// Placeholders within quotes and comments are ignored.
+    pub(crate) fn check_placeholders(&self) -> Result<(), DbErr> {
+        let num_values = self.values.as_ref().map_or(0, |values| values.0.len());
+        let mut num_question_marks = 0;
+        let mut max_dollar_index = 0;
+        let mut chars = self.sql.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '\'' | '"' | '`' => for _ in chars.by_ref().take_while(|d| *d != c) {},
+                '-' if chars.next_if_eq(&'-').is_some() => {
+                    for _ in chars.by_ref().take_while(|d| *d != '\n') {}
+                }
+                '?' => num_question_marks += 1,
+                '$' => {
+                    let mut index = 0;
+                    while let Some(d) = chars.next_if(char::is_ascii_digit) {
+                        index = index * 10 + (d as usize - '0' as usize);
+                    }
+                    max_dollar_index = max_dollar_index.max(index);
+                }
+                _ => {}
+            }
+        }
+
+        let backend = self.db_backend;
+        match backend {
+            DbBackend::Postgres if max_dollar_index == 0 && num_question_marks > 0 => {
+                // `?` is also an operator of jsonb and hstore
+                if num_values > 0 {
+                    return Err(query_err(
+                        "Postgres placeholders are `$1`, `$2` and so on, not `?`",
+                    ));
+                }
+            }
+            DbBackend::Postgres => {
+                if max_dollar_index != num_values {
+                    return Err(query_err(format!(
+                        "Expected {max_dollar_index} values for the placeholders, got {num_values}"
+                    )));
+                }
+            }
+            DbBackend::MySql | DbBackend::Sqlite => {
+                if max_dollar_index > 0 {
+                    return Err(query_err(format!(
+                        "{backend:?} placeholders are `?`, not `$1`, `$2` and so on"
+                    )));
+                }
+                if num_question_marks != num_values {
+                    return Err(query_err(format!(
+                        "Expected {num_question_marks} values for the placeholders, got {num_values}"
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
 impl fmt::Display for Statement {
@@ -105,11 +190,32 @@ macro_rules! build_query_stmt {
 }
 
 build_query_stmt!(sea_query::InsertStatement);
-build_query_stmt!(sea_query::SelectStatement);
 build_query_stmt!(sea_query::UpdateStatement);
 build_query_stmt!(sea_query::DeleteStatement);
 build_query_stmt!(sea_query::WithQuery);
 
+impl StatementBuilder for sea_query::SelectStatement {
+    fn build(&self, db_backend: &DbBackend) -> Statement {
+        let stmt = build_any_stmt!(self, db_backend);
+        let mut stmt = Statement::from_string_values_tuple(*db_backend, stmt);
+        stmt.read_only = !locks_rows(&stmt.sql);
+        stmt
+    }
+}
+
+/// Whether a `SELECT` built by SeaQuery locks the rows it selects; the values are bound
+/// rather than inlined, so the SQL only has keywords and identifiers
+fn locks_rows(sql: &str) -> bool {
+    [
+        " FOR UPDATE",
+        " FOR NO KEY UPDATE",
+        " FOR SHARE",
+        " FOR KEY SHARE",
+    ]
+    .iter()
+    .any(|lock| sql.contains(lock))
+}
+
 macro_rules! build_schema_stmt {
     ($stmt: ty) => {
         impl StatementBuilder for $stmt {
@@ -145,3 +251,54 @@ macro_rules! build_type_stmt {
 build_type_stmt!(sea_query::extension::postgres::TypeAlterStatement);
 build_type_stmt!(sea_query::extension::postgres::TypeCreateStatement);
 build_type_stmt!(sea_query::extension::postgres::TypeDropStatement);
+
+#[cfg(test)]
+mod tests {
+    use crate::{DbBackend, Statement};
+
+    fn check(db_backend: DbBackend, sql: &str, num_values: i32) -> Result<(), String> {
+        Statement::from_sql_and_values(db_backend, sql, (0..num_values).map(Into::into))
+            .check_placeholders()
+            .map_err(|err| err.to_string())
+    }
+
+    #[test]
+    fn check_placeholders() {
+        let sql = r#"SELECT * FROM "cake" WHERE "id" = $1 AND "name" <> $2"#;
+        assert_eq!(check(DbBackend::Postgres, sql, 2), Ok(()));
+        assert_eq!(
+            check(DbBackend::Postgres, sql, 1),
+            Err("Query Error: Expected 2 values for the placeholders, got 1".to_owned())
+        );
+        assert_eq!(
+            check(DbBackend::MySql, sql, 2),
+            Err("Query Error: MySql placeholders are `?`, not `$1`, `$2` and so on".to_owned())
+        );
+
+        let sql = "SELECT * FROM `cake` WHERE `id` = ? AND `name` <> ?";
+        assert_eq!(check(DbBackend::MySql, sql, 2), Ok(()));
+        assert_eq!(check(DbBackend::Sqlite, sql, 2), Ok(()));
+        assert_eq!(
+            check(DbBackend::Sqlite, sql, 3),
+            Err("Query Error: Expected 2 values for the placeholders, got 3".to_owned())
+        );
+        assert_eq!(
+            check(DbBackend::Postgres, sql, 2),
+            Err("Query Error: Postgres placeholders are `$1`, `$2` and so on, not `?`".to_owned())
+        );
+
+        // Quoted and commented out placeholders, and the `?` operator of Postgres
+        assert_eq!(
+            check(
+                DbBackend::Postgres,
+                "SELECT '$2?', \"a?\" FROM t -- $3\nWHERE data ? 'key' AND id = $1",
+                1
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            check(DbBackend::Sqlite, "SELECT '?', \"?\" -- ?\nFROM t", 0),
+            Ok(())
+        );
+    }
+}
@@ -7,6 +7,9 @@ use tracing::instrument;
 #[cfg(feature = "sqlx-dep")]
 use futures::TryStreamExt;
 
+#[cfg(feature = "sqlx-postgres")]
+use futures::future::Either;
+
 #[cfg(feature = "sqlx-dep")]
 use sqlx::Executor;
 
@@ -38,6 +41,7 @@ impl QueryStream {
         stmt: Statement,
         conn: InnerConnection,
         metric_callback: Option<crate::metric::Callback>,
+        fetch_size: Option<u64>,
     ) -> QueryStream {
         QueryStreamBuilder {
             stmt,
@@ -59,10 +63,16 @@ impl QueryStream {
                 InnerConnection::Postgres(c) => {
                     let query = crate::driver::sqlx_postgres::sqlx_query(stmt);
                     let _start = _metric_callback.is_some().then(std::time::SystemTime::now);
-                    let stream = c
-                        .fetch(query)
-                        .map_ok(Into::into)
-                        .map_err(sqlx_error_to_query_err);
+                    let stream = match fetch_size {
+                        Some(fetch_size) => Either::Left(
+                            crate::driver::sqlx_postgres::sqlx_cursor_stream(c, stmt, fetch_size),
+                        ),
+                        None => Either::Right(
+                            c.fetch(query)
+                                .map_ok(Into::into)
+                                .map_err(sqlx_error_to_query_err),
+                        ),
+                    };
                     let elapsed = _start.map(|s| s.elapsed().unwrap_or_default());
                     MetricStream::new(_metric_callback, stmt, elapsed, stream)
                 }
@@ -0,0 +1,107 @@
+use crate::DbErr;
+use futures::Future;
+use std::time::Duration;
+
+/// Run `future` to completion, or fail with [DbErr::Timeout] once `duration` has elapsed.
+///
+/// The deadline is tracked by the timer of the runtime selected with the `runtime-*` features,
+/// and cannot be enforced without one. On expiry the future is dropped: any statement it has
+/// sent keeps running on the server unless the caller cancels it.
+pub(crate) async fn with_timeout<F, T>(duration: Duration, future: F) -> Result<T, DbErr>
+where
+    F: Future<Output = Result<T, DbErr>>,
+{
+    #[cfg(any(feature = "runtime-tokio", feature = "runtime-actix"))]
+    {
+        tokio::time::timeout(duration, future)
+            .await
+            .unwrap_or(Err(DbErr::Timeout(duration)))
+    }
+    #[cfg(all(
+        feature = "runtime-async-std",
+        not(any(feature = "runtime-tokio", feature = "runtime-actix"))
+    ))]
+    {
+        async_std::future::timeout(duration, future)
+            .await
+            .unwrap_or(Err(DbErr::Timeout(duration)))
+    }
+    #[cfg(not(any(
+        feature = "runtime-async-std",
+        feature = "runtime-tokio",
+        feature = "runtime-actix"
+    )))]
+    {
+        drop((duration, future));
+        Err(DbErr::Custom(
+            "A query timeout requires one of the `runtime-*` features".to_owned(),
+        ))
+    }
+}
+
+/// Wait for `duration` on the timer of the runtime selected with the `runtime-*` features.
+///
+/// Without one, it returns at once: only a [MockDatabase](crate::MockDatabase) can be used then,
+/// and the mock has nothing to wait for.
+pub(crate) async fn sleep(duration: Duration) {
+    #[cfg(any(feature = "runtime-tokio", feature = "runtime-actix"))]
+    tokio::time::sleep(duration).await;
+    #[cfg(all(
+        feature = "runtime-async-std",
+        not(any(feature = "runtime-tokio", feature = "runtime-actix"))
+    ))]
+    async_std::task::sleep(duration).await;
+    #[cfg(not(any(
+        feature = "runtime-async-std",
+        feature = "runtime-tokio",
+        feature = "runtime-actix"
+    )))]
+    let _ = duration;
+}
+
+#[cfg(all(
+    test,
+    any(
+        feature = "runtime-async-std",
+        feature = "runtime-tokio",
+        feature = "runtime-actix"
+    )
+))]
+mod tests {
+    use super::*;
+    use futures::future;
+
+    #[cfg_attr(any(feature = "runtime-tokio", feature = "runtime-actix"), tokio::test)]
+    #[cfg_attr(
+        not(any(feature = "runtime-tokio", feature = "runtime-actix")),
+        async_std::test
+    )]
+    async fn with_timeout_completes() {
+        let res = with_timeout(Duration::from_secs(10), async { Ok(1) }).await;
+        assert_eq!(res, Ok(1));
+    }
+
+    #[cfg_attr(any(feature = "runtime-tokio", feature = "runtime-actix"), tokio::test)]
+    #[cfg_attr(
+        not(any(feature = "runtime-tokio", feature = "runtime-actix")),
+        async_std::test
+    )]
+    async fn with_timeout_expires() {
+        let duration = Duration::from_millis(10);
+        let res = with_timeout(duration, future::pending::<Result<(), DbErr>>()).await;
+        assert_eq!(res, Err(DbErr::Timeout(duration)));
+    }
+
+    #[cfg_attr(any(feature = "runtime-tokio", feature = "runtime-actix"), tokio::test)]
+    #[cfg_attr(
+        not(any(feature = "runtime-tokio", feature = "runtime-actix")),
+        async_std::test
+    )]
+    async fn with_timeout_keeps_error() {
+        let res: Result<(), _> = with_timeout(Duration::from_secs(10), async {
+            Err(DbErr::Custom("failed".to_owned()))
+        })
+        .await;
+        assert_eq!(res, Err(DbErr::Custom("failed".to_owned())));
+    }
+}
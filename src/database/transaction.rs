@@ -1,11 +1,12 @@
 use crate::{
-    debug_print, error::*, AccessMode, ConnectionTrait, DbBackend, DbErr, ExecResult,
-    InnerConnection, IsolationLevel, QueryResult, Statement, StreamTrait, TransactionStream,
-    TransactionTrait,
+    debug_print, error::*, lock_tables_statement, AccessMode, ConnectionTrait, DbBackend, DbErr,
+    ExecResult, InnerConnection, IsolationLevel, QueryResult, Statement, StreamTrait,
+    TableLockMode, TransactionStream, TransactionTrait,
 };
 #[cfg(feature = "sqlx-dep")]
 use crate::{sqlx_error_to_exec_err, sqlx_error_to_query_err};
 use futures::lock::Mutex;
+use sea_query::IntoIden;
 #[cfg(feature = "sqlx-dep")]
 use sqlx::TransactionManager;
 use std::{future::Future, pin::Pin, sync::Arc};
@@ -76,7 +77,7 @@ impl DatabaseTransaction {
             }
             #[cfg(feature = "mock")]
             InnerConnection::Mock(ref mut c) => {
-                c.begin();
+                c.begin_with_config(isolation_level, access_mode);
                 Ok(())
             }
             #[allow(unreachable_patterns)]
@@ -108,6 +109,23 @@ impl DatabaseTransaction {
         res
     }
 
+    /// Run the statements within a span carrying contextual fields, such as a request id or a
+    /// tenant, see [SpannedConnection](crate::SpannedConnection)
+    pub fn with_span_fields<I, K, V>(&self, fields: I) -> crate::SpannedConnection<'_, Self>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        crate::SpannedConnection::new(self, fields)
+    }
+
+    /// Get the schema search path of the connection of the transaction (Postgres only),
+    /// see [DatabaseConnection::set_schema_search_path](crate::DatabaseConnection::set_schema_search_path)
+    pub async fn schema_search_path(&self) -> Result<String, DbErr> {
+        super::db_connection::query_schema_search_path(self).await
+    }
+
     /// Commit a transaction atomically
     #[instrument(level = "trace")]
     #[allow(unreachable_code, unused_mut)]
@@ -397,6 +415,32 @@ impl ConnectionTrait for DatabaseTransaction {
             _ => Err(conn_err("Disconnected")),
         }
     }
+
+    async fn lock_tables<I, T>(&self, tables: I, mode: TableLockMode) -> Result<(), DbErr>
+    where
+        Self: Sized,
+        I: IntoIterator<Item = T> + Send,
+        T: IntoIden + Send,
+    {
+        let tables = tables
+            .into_iter()
+            .map(|table| table.into_iden().to_string())
+            .collect();
+        let sql = lock_tables_statement(self.backend, tables, mode)?;
+        self.execute_unprepared(&sql).await?;
+        Ok(())
+    }
+
+    async fn unlock_tables(&self) -> Result<(), DbErr> {
+        match self.backend {
+            DbBackend::MySql => {
+                self.execute_unprepared("UNLOCK TABLES").await?;
+                Ok(())
+            }
+            DbBackend::Postgres => Ok(()),
+            DbBackend::Sqlite => Err(query_err("Table locks are not supported by Sqlite")),
+        }
+    }
 }
 
 impl StreamTrait for DatabaseTransaction {
@@ -413,6 +457,24 @@ impl StreamTrait for DatabaseTransaction {
                 conn,
                 stmt,
                 self.metric_callback.clone(),
+                None,
+            ))
+        })
+    }
+
+    #[instrument(level = "trace")]
+    fn stream_with_fetch_size<'a>(
+        &'a self,
+        stmt: Statement,
+        fetch_size: u64,
+    ) -> Pin<Box<dyn Future<Output = Result<Self::Stream<'a>, DbErr>> + 'a + Send>> {
+        Box::pin(async move {
+            let conn = self.conn.lock().await;
+            Ok(crate::TransactionStream::build(
+                conn,
+                stmt,
+                self.metric_callback.clone(),
+                Some(fetch_size),
             ))
         })
     }
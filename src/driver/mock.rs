@@ -1,6 +1,6 @@
 use crate::{
-    debug_print, error::*, DatabaseConnection, DbBackend, ExecResult, MockDatabase, QueryResult,
-    Statement, Transaction,
+    debug_print, error::*, AccessMode, DatabaseConnection, DbBackend, ExecResult, IsolationLevel,
+    MockDatabase, QueryResult, Statement, Transaction,
 };
 use futures::Stream;
 use std::{
@@ -36,6 +36,15 @@ pub trait MockDatabaseTrait: Send + Debug {
     /// Create a transaction that can be committed atomically
     fn begin(&mut self);
 
+    /// Create a transaction with isolation level and/or access mode
+    fn begin_with_config(
+        &mut self,
+        _isolation_level: Option<IsolationLevel>,
+        _access_mode: Option<AccessMode>,
+    ) {
+        self.begin()
+    }
+
     /// Commit a successful transaction atomically into the [MockDatabase]
     fn commit(&mut self);
 
@@ -184,6 +193,23 @@ impl MockDatabaseConnection {
             .begin()
     }
 
+    /// Create a statement block of SQL statements, with isolation level and/or access mode
+    ///
+    /// # Panics
+    ///
+    /// Panics if the mocker mutex is being held by another thread.
+    #[instrument(level = "trace")]
+    pub fn begin_with_config(
+        &self,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) {
+        self.mocker
+            .lock()
+            .expect("Failed to acquire mocker")
+            .begin_with_config(isolation_level, access_mode)
+    }
+
     /// Commit a transaction atomically to the database
     #[instrument(level = "trace")]
     pub fn commit(&self) {
@@ -222,7 +248,12 @@ impl
             Option<crate::metric::Callback>,
         ),
     ) -> Self {
-        crate::QueryStream::build(stmt, crate::InnerConnection::Mock(conn), metric_callback)
+        crate::QueryStream::build(
+            stmt,
+            crate::InnerConnection::Mock(conn),
+            metric_callback,
+            None,
+        )
     }
 }
 
@@ -230,6 +261,8 @@ impl crate::DatabaseTransaction {
     pub(crate) async fn new_mock(
         inner: Arc<crate::MockDatabaseConnection>,
         metric_callback: Option<crate::metric::Callback>,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
     ) -> Result<crate::DatabaseTransaction, DbErr> {
         use futures::lock::Mutex;
         let backend = inner.get_database_backend();
@@ -237,8 +270,8 @@ impl crate::DatabaseTransaction {
             Arc::new(Mutex::new(crate::InnerConnection::Mock(inner))),
             backend,
             metric_callback,
-            None,
-            None,
+            isolation_level,
+            access_mode,
         )
         .await
     }
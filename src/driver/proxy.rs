@@ -125,7 +125,12 @@ impl
             Option<crate::metric::Callback>,
         ),
     ) -> Self {
-        crate::QueryStream::build(stmt, crate::InnerConnection::Proxy(conn), metric_callback)
+        crate::QueryStream::build(
+            stmt,
+            crate::InnerConnection::Proxy(conn),
+            metric_callback,
+            None,
+        )
     }
 }
 
@@ -26,10 +26,17 @@ pub fn sqlx_map_err_ignore_not_found<T: std::fmt::Debug>(
     }
 }
 
-/// Converts an [sqlx::error] error to a [DbErr]
-pub fn sqlx_conn_acquire_err(sqlx_err: sqlx::Error) -> DbErr {
-    match sqlx_err {
-        sqlx::Error::PoolTimedOut => DbErr::ConnectionAcquire(ConnAcquireErr::Timeout),
+/// Converts an [sqlx::error] error from acquiring a connection of `pool` to a [DbErr],
+/// reporting the usage of the pool on a timeout
+pub fn sqlx_conn_acquire_err<DB>(pool: &sqlx::Pool<DB>) -> impl FnOnce(sqlx::Error) -> DbErr + '_
+where
+    DB: sqlx::Database,
+{
+    move |sqlx_err| match sqlx_err {
+        sqlx::Error::PoolTimedOut => DbErr::ConnAcquireTimeout {
+            max_connections: pool.options().get_max_connections(),
+            in_use: pool.size().saturating_sub(pool.num_idle() as u32),
+        },
         sqlx::Error::PoolClosed => DbErr::ConnectionAcquire(ConnAcquireErr::ConnectionClosed),
         _ => DbErr::Conn(RuntimeErr::SqlxError(sqlx_err)),
     }
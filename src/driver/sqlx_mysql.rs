@@ -1,16 +1,16 @@
 use futures::lock::Mutex;
 use log::LevelFilter;
 use sea_query::Values;
-use std::{future::Future, pin::Pin, sync::Arc};
+use std::{future::Future, pin::Pin, sync::Arc, time::Duration};
 
 use sqlx::{
-    mysql::{MySqlConnectOptions, MySqlQueryResult, MySqlRow},
+    mysql::{MySqlConnectOptions, MySqlConnection, MySqlQueryResult, MySqlRow},
     pool::PoolConnection,
     Connection, Executor, MySql, MySqlPool,
 };
 
 use sea_query_binder::SqlxValues;
-use tracing::instrument;
+use tracing::{instrument, warn};
 
 use crate::{
     debug_print, error::*, executor::*, AccessMode, ConnectOptions, DatabaseConnection,
@@ -28,6 +28,7 @@ pub struct SqlxMySqlConnector;
 pub struct SqlxMySqlPoolConnection {
     pub(crate) pool: MySqlPool,
     metric_callback: Option<crate::metric::Callback>,
+    slow_query: Option<crate::metric::SlowQuery>,
 }
 
 impl std::fmt::Debug for SqlxMySqlPoolConnection {
@@ -49,6 +50,9 @@ impl SqlxMySqlConnector {
             .url
             .parse::<MySqlConnectOptions>()
             .map_err(sqlx_error_to_conn_err)?;
+        if let Some(capacity) = options.statement_cache_capacity {
+            opt = opt.statement_cache_capacity(capacity);
+        }
         use sqlx::ConnectOptions;
         if !options.sqlx_logging {
             opt = opt.disable_statement_logging();
@@ -61,6 +65,7 @@ impl SqlxMySqlConnector {
                 );
             }
         }
+        let slow_query = options.slow_query();
         let pool = if options.connect_lazy {
             options.sqlx_pool_options().connect_lazy_with(opt)
         } else {
@@ -73,7 +78,8 @@ impl SqlxMySqlConnector {
         Ok(DatabaseConnection::SqlxMySqlPoolConnection(
             SqlxMySqlPoolConnection {
                 pool,
-                metric_callback: None,
+                metric_callback: crate::metric::callback(None, slow_query),
+                slow_query,
             },
         ))
     }
@@ -85,6 +91,7 @@ impl SqlxMySqlConnector {
         DatabaseConnection::SqlxMySqlPoolConnection(SqlxMySqlPoolConnection {
             pool,
             metric_callback: None,
+            slow_query: None,
         })
     }
 }
@@ -96,7 +103,11 @@ impl SqlxMySqlPoolConnection {
         debug_print!("{}", stmt);
 
         let query = sqlx_query(&stmt);
-        let mut conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+        let mut conn = self
+            .pool
+            .acquire()
+            .await
+            .map_err(sqlx_conn_acquire_err(&self.pool))?;
         crate::metric::metric!(self.metric_callback, &stmt, {
             match query.execute(&mut *conn).await {
                 Ok(res) => Ok(res.into()),
@@ -110,7 +121,11 @@ impl SqlxMySqlPoolConnection {
     pub async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
         debug_print!("{}", sql);
 
-        let conn = &mut self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+        let conn = &mut self
+            .pool
+            .acquire()
+            .await
+            .map_err(sqlx_conn_acquire_err(&self.pool))?;
         match conn.execute(sql).await {
             Ok(res) => Ok(res.into()),
             Err(err) => Err(sqlx_error_to_exec_err(err)),
@@ -123,7 +138,11 @@ impl SqlxMySqlPoolConnection {
         debug_print!("{}", stmt);
 
         let query = sqlx_query(&stmt);
-        let mut conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+        let mut conn = self
+            .pool
+            .acquire()
+            .await
+            .map_err(sqlx_conn_acquire_err(&self.pool))?;
         crate::metric::metric!(self.metric_callback, &stmt, {
             match query.fetch_one(&mut *conn).await {
                 Ok(row) => Ok(Some(row.into())),
@@ -141,7 +160,11 @@ impl SqlxMySqlPoolConnection {
         debug_print!("{}", stmt);
 
         let query = sqlx_query(&stmt);
-        let mut conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+        let mut conn = self
+            .pool
+            .acquire()
+            .await
+            .map_err(sqlx_conn_acquire_err(&self.pool))?;
         crate::metric::metric!(self.metric_callback, &stmt, {
             match query.fetch_all(&mut *conn).await {
                 Ok(rows) => Ok(rows.into_iter().map(|r| r.into()).collect()),
@@ -150,12 +173,139 @@ impl SqlxMySqlPoolConnection {
         })
     }
 
+    /// Execute a [Statement] on a MySQL backend, cancelling it with `KILL QUERY`
+    /// if it does not complete within `timeout`
+    #[instrument(level = "trace")]
+    pub async fn execute_with_timeout(
+        &self,
+        stmt: Statement,
+        timeout: Duration,
+    ) -> Result<ExecResult, DbErr> {
+        debug_print!("{}", stmt);
+
+        let query = sqlx_query(&stmt);
+        let mut conn = self
+            .pool
+            .acquire()
+            .await
+            .map_err(sqlx_conn_acquire_err(&self.pool))?;
+        let connection_id = connection_id(&mut conn).await?;
+        let res = crate::metric::metric!(self.metric_callback, &stmt, {
+            crate::with_timeout(timeout, async {
+                match query.execute(&mut *conn).await {
+                    Ok(res) => Ok(res.into()),
+                    Err(err) => Err(sqlx_error_to_exec_err(err)),
+                }
+            })
+            .await
+        });
+        self.cancel_on_timeout(conn, connection_id, res).await
+    }
+
+    /// Get one result from a SQL query, cancelling it with `KILL QUERY`
+    /// if it does not complete within `timeout`
+    #[instrument(level = "trace")]
+    pub async fn query_one_with_timeout(
+        &self,
+        stmt: Statement,
+        timeout: Duration,
+    ) -> Result<Option<QueryResult>, DbErr> {
+        debug_print!("{}", stmt);
+
+        let query = sqlx_query(&stmt);
+        let mut conn = self
+            .pool
+            .acquire()
+            .await
+            .map_err(sqlx_conn_acquire_err(&self.pool))?;
+        let connection_id = connection_id(&mut conn).await?;
+        let res = crate::metric::metric!(self.metric_callback, &stmt, {
+            crate::with_timeout(timeout, async {
+                match query.fetch_one(&mut *conn).await {
+                    Ok(row) => Ok(Some(row.into())),
+                    Err(err) => match err {
+                        sqlx::Error::RowNotFound => Ok(None),
+                        _ => Err(sqlx_error_to_query_err(err)),
+                    },
+                }
+            })
+            .await
+        });
+        self.cancel_on_timeout(conn, connection_id, res).await
+    }
+
+    /// Get the results of a query returning them as a Vec<[QueryResult]>, cancelling it
+    /// with `KILL QUERY` if it does not complete within `timeout`
+    #[instrument(level = "trace")]
+    pub async fn query_all_with_timeout(
+        &self,
+        stmt: Statement,
+        timeout: Duration,
+    ) -> Result<Vec<QueryResult>, DbErr> {
+        debug_print!("{}", stmt);
+
+        let query = sqlx_query(&stmt);
+        let mut conn = self
+            .pool
+            .acquire()
+            .await
+            .map_err(sqlx_conn_acquire_err(&self.pool))?;
+        let connection_id = connection_id(&mut conn).await?;
+        let res = crate::metric::metric!(self.metric_callback, &stmt, {
+            crate::with_timeout(timeout, async {
+                match query.fetch_all(&mut *conn).await {
+                    Ok(rows) => Ok(rows.into_iter().map(|r| r.into()).collect()),
+                    Err(err) => Err(sqlx_error_to_query_err(err)),
+                }
+            })
+            .await
+        });
+        self.cancel_on_timeout(conn, connection_id, res).await
+    }
+
+    /// Cancel the statement left running by a timed out query.
+    ///
+    /// The cancellation is sent over a new connection, as the pool may have none to spare,
+    /// and the connection of the timed out query is closed rather than returned to the pool.
+    /// A failure to cancel is logged, the query still fails with [DbErr::Timeout].
+    async fn cancel_on_timeout<T>(
+        &self,
+        mut conn: PoolConnection<MySql>,
+        connection_id: u64,
+        res: Result<T, DbErr>,
+    ) -> Result<T, DbErr> {
+        if let Err(DbErr::Timeout(_)) = res {
+            conn.close_on_drop();
+            if let Err(err) = self.kill_query(connection_id).await {
+                warn!("Failed to cancel the statement of a timed out query: {err}");
+            }
+        }
+        res
+    }
+
+    /// Cancel the statement running on a connection with `KILL QUERY`
+    async fn kill_query(&self, connection_id: u64) -> Result<(), DbErr> {
+        let options = self.pool.connect_options();
+        let mut cancel_conn = MySqlConnection::connect_with(&options)
+            .await
+            .map_err(sqlx_error_to_conn_err)?;
+        cancel_conn
+            .execute(format!("KILL QUERY {connection_id}").as_str())
+            .await
+            .map_err(sqlx_error_to_exec_err)?;
+        cancel_conn.close().await.map_err(sqlx_error_to_conn_err)
+    }
+
     /// Stream the results of executing a SQL query
     #[instrument(level = "trace")]
     pub async fn stream(&self, stmt: Statement) -> Result<QueryStream, DbErr> {
         debug_print!("{}", stmt);
 
-        let conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+        let conn = self
+            .pool
+            .acquire()
+            .await
+            .map_err(sqlx_conn_acquire_err(&self.pool))?;
         Ok(QueryStream::from((
             conn,
             stmt,
@@ -170,7 +320,11 @@ impl SqlxMySqlPoolConnection {
         isolation_level: Option<IsolationLevel>,
         access_mode: Option<AccessMode>,
     ) -> Result<DatabaseTransaction, DbErr> {
-        let conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+        let conn = self
+            .pool
+            .acquire()
+            .await
+            .map_err(sqlx_conn_acquire_err(&self.pool))?;
         DatabaseTransaction::new_mysql(
             conn,
             self.metric_callback.clone(),
@@ -196,7 +350,11 @@ impl SqlxMySqlPoolConnection {
         T: Send,
         E: std::error::Error + Send,
     {
-        let conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+        let conn = self
+            .pool
+            .acquire()
+            .await
+            .map_err(sqlx_conn_acquire_err(&self.pool))?;
         let transaction = DatabaseTransaction::new_mysql(
             conn,
             self.metric_callback.clone(),
@@ -212,12 +370,28 @@ impl SqlxMySqlPoolConnection {
     where
         F: Fn(&crate::metric::Info<'_>) + Send + Sync + 'static,
     {
-        self.metric_callback = Some(Arc::new(callback));
+        self.metric_callback = crate::metric::callback(Some(Arc::new(callback)), self.slow_query);
+    }
+
+    /// Get the statistics of the prepared statement cache of a pooled connection
+    pub async fn statement_cache_stats(&self) -> Result<crate::StatementCacheStats, DbErr> {
+        let conn = self
+            .pool
+            .acquire()
+            .await
+            .map_err(sqlx_conn_acquire_err(&self.pool))?;
+        Ok(crate::StatementCacheStats {
+            cached_statements: conn.cached_statements_size(),
+        })
     }
 
     /// Checks if a connection to the database is still valid.
     pub async fn ping(&self) -> Result<(), DbErr> {
-        let conn = &mut self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+        let conn = &mut self
+            .pool
+            .acquire()
+            .await
+            .map_err(sqlx_conn_acquire_err(&self.pool))?;
         match conn.ping().await {
             Ok(_) => Ok(()),
             Err(err) => Err(sqlx_error_to_conn_err(err)),
@@ -247,6 +421,14 @@ impl From<MySqlQueryResult> for ExecResult {
     }
 }
 
+/// The thread ID of the server connection, to kill its statements with
+async fn connection_id(conn: &mut MySqlConnection) -> Result<u64, DbErr> {
+    sqlx::query_scalar("SELECT CONNECTION_ID()")
+        .fetch_one(conn)
+        .await
+        .map_err(sqlx_error_to_query_err)
+}
+
 pub(crate) fn sqlx_query(stmt: &Statement) -> sqlx::query::Query<'_, MySql, SqlxValues> {
     let values = stmt
         .values
@@ -260,21 +442,8 @@ pub(crate) async fn set_transaction_config(
     isolation_level: Option<IsolationLevel>,
     access_mode: Option<AccessMode>,
 ) -> Result<(), DbErr> {
-    if let Some(isolation_level) = isolation_level {
-        let stmt = Statement {
-            sql: format!("SET TRANSACTION ISOLATION LEVEL {isolation_level}"),
-            values: None,
-            db_backend: DbBackend::MySql,
-        };
-        let query = sqlx_query(&stmt);
-        conn.execute(query).await.map_err(sqlx_error_to_exec_err)?;
-    }
-    if let Some(access_mode) = access_mode {
-        let stmt = Statement {
-            sql: format!("SET TRANSACTION {access_mode}"),
-            values: None,
-            db_backend: DbBackend::MySql,
-        };
+    for stmt in crate::transaction_config_statements(DbBackend::MySql, isolation_level, access_mode)
+    {
         let query = sqlx_query(&stmt);
         conn.execute(query).await.map_err(sqlx_error_to_exec_err)?;
     }
@@ -295,7 +464,12 @@ impl
             Option<crate::metric::Callback>,
         ),
     ) -> Self {
-        crate::QueryStream::build(stmt, crate::InnerConnection::MySql(conn), metric_callback)
+        crate::QueryStream::build(
+            stmt,
+            crate::InnerConnection::MySql(conn),
+            metric_callback,
+            None,
+        )
     }
 }
 
@@ -1,16 +1,24 @@
-use futures::lock::Mutex;
+use futures::{lock::Mutex, Stream, TryStreamExt};
 use log::LevelFilter;
 use sea_query::Values;
-use std::{future::Future, pin::Pin, sync::Arc};
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
 
 use sqlx::{
     pool::PoolConnection,
-    postgres::{PgConnectOptions, PgQueryResult, PgRow},
+    postgres::{PgConnectOptions, PgConnection, PgQueryResult, PgRow},
     Connection, Executor, PgPool, Postgres,
 };
 
 use sea_query_binder::SqlxValues;
-use tracing::instrument;
+use tracing::{instrument, warn};
 
 use crate::{
     debug_print, error::*, executor::*, AccessMode, ConnectOptions, DatabaseConnection,
@@ -28,6 +36,7 @@ pub struct SqlxPostgresConnector;
 pub struct SqlxPostgresPoolConnection {
     pub(crate) pool: PgPool,
     metric_callback: Option<crate::metric::Callback>,
+    slow_query: Option<crate::metric::SlowQuery>,
 }
 
 impl std::fmt::Debug for SqlxPostgresPoolConnection {
@@ -49,6 +58,9 @@ impl SqlxPostgresConnector {
             .url
             .parse::<PgConnectOptions>()
             .map_err(sqlx_error_to_conn_err)?;
+        if let Some(capacity) = options.statement_cache_capacity {
+            opt = opt.statement_cache_capacity(capacity);
+        }
         use sqlx::ConnectOptions;
         if !options.sqlx_logging {
             opt = opt.disable_statement_logging();
@@ -66,6 +78,7 @@ impl SqlxPostgresConnector {
             .as_ref()
             .map(|schema| format!("SET search_path = {schema}"));
         let lazy = options.connect_lazy;
+        let slow_query = options.slow_query();
         let mut pool_options = options.sqlx_pool_options();
         if let Some(sql) = set_search_path_sql {
             pool_options = pool_options.after_connect(move |conn, _| {
@@ -88,7 +101,8 @@ impl SqlxPostgresConnector {
         Ok(DatabaseConnection::SqlxPostgresPoolConnection(
             SqlxPostgresPoolConnection {
                 pool,
-                metric_callback: None,
+                metric_callback: crate::metric::callback(None, slow_query),
+                slow_query,
             },
         ))
     }
@@ -100,6 +114,7 @@ impl SqlxPostgresConnector {
         DatabaseConnection::SqlxPostgresPoolConnection(SqlxPostgresPoolConnection {
             pool,
             metric_callback: None,
+            slow_query: None,
         })
     }
 }
@@ -111,7 +126,11 @@ impl SqlxPostgresPoolConnection {
         debug_print!("{}", stmt);
 
         let query = sqlx_query(&stmt);
-        let mut conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+        let mut conn = self
+            .pool
+            .acquire()
+            .await
+            .map_err(sqlx_conn_acquire_err(&self.pool))?;
         crate::metric::metric!(self.metric_callback, &stmt, {
             match query.execute(&mut *conn).await {
                 Ok(res) => Ok(res.into()),
@@ -125,7 +144,11 @@ impl SqlxPostgresPoolConnection {
     pub async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
         debug_print!("{}", sql);
 
-        let conn = &mut self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+        let conn = &mut self
+            .pool
+            .acquire()
+            .await
+            .map_err(sqlx_conn_acquire_err(&self.pool))?;
         match conn.execute(sql).await {
             Ok(res) => Ok(res.into()),
             Err(err) => Err(sqlx_error_to_exec_err(err)),
@@ -138,7 +161,11 @@ impl SqlxPostgresPoolConnection {
         debug_print!("{}", stmt);
 
         let query = sqlx_query(&stmt);
-        let mut conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+        let mut conn = self
+            .pool
+            .acquire()
+            .await
+            .map_err(sqlx_conn_acquire_err(&self.pool))?;
         crate::metric::metric!(self.metric_callback, &stmt, {
             match query.fetch_one(&mut *conn).await {
                 Ok(row) => Ok(Some(row.into())),
@@ -156,7 +183,11 @@ impl SqlxPostgresPoolConnection {
         debug_print!("{}", stmt);
 
         let query = sqlx_query(&stmt);
-        let mut conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+        let mut conn = self
+            .pool
+            .acquire()
+            .await
+            .map_err(sqlx_conn_acquire_err(&self.pool))?;
         crate::metric::metric!(self.metric_callback, &stmt, {
             match query.fetch_all(&mut *conn).await {
                 Ok(rows) => Ok(rows.into_iter().map(|r| r.into()).collect()),
@@ -165,12 +196,163 @@ impl SqlxPostgresPoolConnection {
         })
     }
 
+    /// Execute a [Statement] on a PostgreSQL backend, cancelling it with `pg_cancel_backend`
+    /// if it does not complete within `timeout`
+    #[instrument(level = "trace")]
+    pub async fn execute_with_timeout(
+        &self,
+        stmt: Statement,
+        timeout: Duration,
+    ) -> Result<ExecResult, DbErr> {
+        debug_print!("{}", stmt);
+
+        let query = sqlx_query(&stmt);
+        let mut conn = self
+            .pool
+            .acquire()
+            .await
+            .map_err(sqlx_conn_acquire_err(&self.pool))?;
+        let res = crate::metric::metric!(self.metric_callback, &stmt, {
+            crate::with_timeout(timeout, async {
+                match query.execute(&mut *conn).await {
+                    Ok(res) => Ok(res.into()),
+                    Err(err) => Err(sqlx_error_to_exec_err(err)),
+                }
+            })
+            .await
+        });
+        self.cancel_on_timeout(conn, &stmt.sql, res).await
+    }
+
+    /// Get one result from a SQL query, cancelling it with `pg_cancel_backend`
+    /// if it does not complete within `timeout`
+    #[instrument(level = "trace")]
+    pub async fn query_one_with_timeout(
+        &self,
+        stmt: Statement,
+        timeout: Duration,
+    ) -> Result<Option<QueryResult>, DbErr> {
+        debug_print!("{}", stmt);
+
+        let query = sqlx_query(&stmt);
+        let mut conn = self
+            .pool
+            .acquire()
+            .await
+            .map_err(sqlx_conn_acquire_err(&self.pool))?;
+        let res = crate::metric::metric!(self.metric_callback, &stmt, {
+            crate::with_timeout(timeout, async {
+                match query.fetch_one(&mut *conn).await {
+                    Ok(row) => Ok(Some(row.into())),
+                    Err(err) => match err {
+                        sqlx::Error::RowNotFound => Ok(None),
+                        _ => Err(sqlx_error_to_query_err(err)),
+                    },
+                }
+            })
+            .await
+        });
+        self.cancel_on_timeout(conn, &stmt.sql, res).await
+    }
+
+    /// Get the results of a query returning them as a Vec<[QueryResult]>, cancelling it
+    /// with `pg_cancel_backend` if it does not complete within `timeout`
+    #[instrument(level = "trace")]
+    pub async fn query_all_with_timeout(
+        &self,
+        stmt: Statement,
+        timeout: Duration,
+    ) -> Result<Vec<QueryResult>, DbErr> {
+        debug_print!("{}", stmt);
+
+        let query = sqlx_query(&stmt);
+        let mut conn = self
+            .pool
+            .acquire()
+            .await
+            .map_err(sqlx_conn_acquire_err(&self.pool))?;
+        let res = crate::metric::metric!(self.metric_callback, &stmt, {
+            crate::with_timeout(timeout, async {
+                match query.fetch_all(&mut *conn).await {
+                    Ok(rows) => Ok(rows.into_iter().map(|r| r.into()).collect()),
+                    Err(err) => Err(sqlx_error_to_query_err(err)),
+                }
+            })
+            .await
+        });
+        self.cancel_on_timeout(conn, &stmt.sql, res).await
+    }
+
+    /// Cancel the statement left running by a timed out query.
+    ///
+    /// The cancellation is sent over a new connection, as the pool may have none to spare,
+    /// and the connection of the timed out query is closed rather than returned to the pool.
+    /// A failure to cancel is logged, the query still fails with [DbErr::Timeout].
+    async fn cancel_on_timeout<T>(
+        &self,
+        mut conn: PoolConnection<Postgres>,
+        sql: &str,
+        res: Result<T, DbErr>,
+    ) -> Result<T, DbErr> {
+        if let Err(DbErr::Timeout(_)) = res {
+            conn.close_on_drop();
+            if let Err(err) = self.cancel_backend(sql).await {
+                warn!("Failed to cancel the statement of a timed out query: {err}");
+            }
+        }
+        res
+    }
+
+    /// Cancel the statement with `pg_cancel_backend`.
+    ///
+    /// Rather than asking every connection for its process ID before running a query in case
+    /// it times out, the backend is looked up in `pg_stat_activity` by the SQL it is running,
+    /// which may be truncated there. It is only cancelled if no other backend of the same user
+    /// runs the same SQL, as it cannot be told which one is blocked on the timed out query.
+    async fn cancel_backend(&self, sql: &str) -> Result<(), DbErr> {
+        let options = self.pool.connect_options();
+        let mut cancel_conn = PgConnection::connect_with(&options)
+            .await
+            .map_err(sqlx_error_to_conn_err)?;
+        let pids: Vec<i32> = sqlx::query_scalar(
+            "SELECT pid FROM pg_stat_activity \
+             WHERE pid <> pg_backend_pid() AND datname = current_database() \
+             AND usename = current_user AND state = 'active' \
+             AND left($1, length(query)) = query",
+        )
+        .bind(sql)
+        .fetch_all(&mut cancel_conn)
+        .await
+        .map_err(sqlx_error_to_query_err)?;
+        let res = match pids.as_slice() {
+            [pid] => sqlx::query("SELECT pg_cancel_backend($1)")
+                .bind(pid)
+                .execute(&mut cancel_conn)
+                .await
+                .map(|_| ())
+                .map_err(sqlx_error_to_exec_err),
+            [] => Err(DbErr::Custom(
+                "The statement is no longer running".to_owned(),
+            )),
+            _ => Err(DbErr::Custom(format!(
+                "The statement cannot be told apart from the same SQL run by {} other backends",
+                pids.len() - 1
+            ))),
+        };
+        cancel_conn.close().await.map_err(sqlx_error_to_conn_err)?;
+        res
+    }
+
     /// Stream the results of executing a SQL query
     #[instrument(level = "trace")]
     pub async fn stream(&self, stmt: Statement) -> Result<QueryStream, DbErr> {
         debug_print!("{}", stmt);
 
-        let conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+        let conn = self
+            .pool
+            .acquire()
+            .await
+            .map_err(sqlx_conn_acquire_err(&self.pool))?;
         Ok(QueryStream::from((
             conn,
             stmt,
@@ -178,6 +360,29 @@ impl SqlxPostgresPoolConnection {
         )))
     }
 
+    /// Stream the results of executing a SQL query through a server-side cursor,
+    /// fetching `fetch_size` rows at a time
+    #[instrument(level = "trace")]
+    pub async fn stream_with_fetch_size(
+        &self,
+        stmt: Statement,
+        fetch_size: u64,
+    ) -> Result<QueryStream, DbErr> {
+        debug_print!("{}", stmt);
+
+        let conn = self
+            .pool
+            .acquire()
+            .await
+            .map_err(sqlx_conn_acquire_err(&self.pool))?;
+        Ok(QueryStream::build(
+            stmt,
+            crate::InnerConnection::Postgres(conn),
+            self.metric_callback.clone(),
+            Some(fetch_size),
+        ))
+    }
+
     /// Bundle a set of SQL statements that execute together.
     #[instrument(level = "trace")]
     pub async fn begin(
@@ -185,7 +390,11 @@ impl SqlxPostgresPoolConnection {
         isolation_level: Option<IsolationLevel>,
         access_mode: Option<AccessMode>,
     ) -> Result<DatabaseTransaction, DbErr> {
-        let conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+        let conn = self
+            .pool
+            .acquire()
+            .await
+            .map_err(sqlx_conn_acquire_err(&self.pool))?;
         DatabaseTransaction::new_postgres(
             conn,
             self.metric_callback.clone(),
@@ -211,7 +420,11 @@ impl SqlxPostgresPoolConnection {
         T: Send,
         E: std::error::Error + Send,
     {
-        let conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+        let conn = self
+            .pool
+            .acquire()
+            .await
+            .map_err(sqlx_conn_acquire_err(&self.pool))?;
         let transaction = DatabaseTransaction::new_postgres(
             conn,
             self.metric_callback.clone(),
@@ -227,18 +440,63 @@ impl SqlxPostgresPoolConnection {
     where
         F: Fn(&crate::metric::Info<'_>) + Send + Sync + 'static,
     {
-        self.metric_callback = Some(Arc::new(callback));
+        self.metric_callback = crate::metric::callback(Some(Arc::new(callback)), self.slow_query);
+    }
+
+    /// Get the statistics of the prepared statement cache of a pooled connection
+    pub async fn statement_cache_stats(&self) -> Result<crate::StatementCacheStats, DbErr> {
+        let conn = self
+            .pool
+            .acquire()
+            .await
+            .map_err(sqlx_conn_acquire_err(&self.pool))?;
+        Ok(crate::StatementCacheStats {
+            cached_statements: conn.cached_statements_size(),
+        })
     }
 
     /// Checks if a connection to the database is still valid.
     pub async fn ping(&self) -> Result<(), DbErr> {
-        let conn = &mut self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+        let conn = &mut self
+            .pool
+            .acquire()
+            .await
+            .map_err(sqlx_conn_acquire_err(&self.pool))?;
         match conn.ping().await {
             Ok(_) => Ok(()),
             Err(err) => Err(sqlx_error_to_conn_err(err)),
         }
     }
 
+    /// Open a pool of a single connection, with the options of this pool, on which the search
+    /// path is set once with `SET search_path` after connecting
+    pub(crate) async fn with_search_path(
+        &self,
+        search_path: &str,
+    ) -> Result<DatabaseConnection, DbErr> {
+        let sql = format!("SET search_path = {search_path}");
+        let pool = sqlx::pool::PoolOptions::<Postgres>::new()
+            .max_connections(1)
+            .acquire_timeout(self.pool.options().get_acquire_timeout())
+            .test_before_acquire(self.pool.options().get_test_before_acquire())
+            .after_connect(move |conn, _| {
+                let sql = sql.clone();
+                Box::pin(async move {
+                    sqlx::Executor::execute(conn, sql.as_str())
+                        .await
+                        .map(|_| ())
+                })
+            })
+            .connect_with(self.pool.connect_options().as_ref().clone())
+            .await
+            .map_err(sqlx_error_to_conn_err)?;
+        Ok(DatabaseConnection::SqlxPostgresPoolConnection(Self {
+            pool,
+            metric_callback: self.metric_callback.clone(),
+            slow_query: self.slow_query,
+        }))
+    }
+
     /// Explicitly close the Postgres connection
     pub async fn close(self) -> Result<(), DbErr> {
         self.pool.close().await;
@@ -270,26 +528,81 @@ pub(crate) fn sqlx_query(stmt: &Statement) -> sqlx::query::Query<'_, Postgres, S
     sqlx::query_with(&stmt.sql, SqlxValues(values))
 }
 
+enum CursorState<'a> {
+    Idle(&'a mut PgConnection),
+    Open(Box<sqlx::Transaction<'a, Postgres>>),
+}
+
+/// Stream the rows of a query through a server-side cursor, fetching `fetch_size` rows at a time,
+/// so that no more than `fetch_size` rows are held in memory. The cursor is declared within
+/// a transaction, or a savepoint if a transaction is already open, which is rolled back
+/// if the stream is dropped before it is exhausted.
+pub(crate) fn sqlx_cursor_stream<'a>(
+    conn: &'a mut PgConnection,
+    stmt: &'a Statement,
+    fetch_size: u64,
+) -> impl Stream<Item = Result<QueryResult, DbErr>> + 'a + Send {
+    static CURSOR_ID: AtomicU64 = AtomicU64::new(0);
+    let cursor = format!(
+        "sea_orm_cursor_{}",
+        CURSOR_ID.fetch_add(1, Ordering::Relaxed)
+    );
+    // `FETCH 0` fetches the current row over and over again
+    let fetch_size = fetch_size.max(1);
+
+    futures::stream::try_unfold(CursorState::Idle(conn), move |state| {
+        let cursor = cursor.clone();
+        async move {
+            let mut transaction = match state {
+                CursorState::Idle(conn) => {
+                    let mut transaction = conn.begin().await.map_err(sqlx_error_to_query_err)?;
+                    let declare = Statement {
+                        sql: format!("DECLARE {cursor} NO SCROLL CURSOR FOR {}", stmt.sql),
+                        values: stmt.values.clone(),
+                        db_backend: DbBackend::Postgres,
+                        read_only: false,
+                    };
+                    sqlx_query(&declare)
+                        .execute(&mut *transaction)
+                        .await
+                        .map_err(sqlx_error_to_query_err)?;
+                    Box::new(transaction)
+                }
+                CursorState::Open(transaction) => transaction,
+            };
+
+            let fetch = format!("FETCH {fetch_size} FROM {cursor}");
+            let rows = sqlx::query(&fetch)
+                .fetch_all(&mut **transaction)
+                .await
+                .map_err(sqlx_error_to_query_err)?;
+            if rows.is_empty() {
+                let close = format!("CLOSE {cursor}");
+                sqlx::query(&close)
+                    .execute(&mut **transaction)
+                    .await
+                    .map_err(sqlx_error_to_query_err)?;
+                transaction
+                    .commit()
+                    .await
+                    .map_err(sqlx_error_to_query_err)?;
+                return Ok::<_, DbErr>(None);
+            }
+            Ok(Some((rows, CursorState::Open(transaction))))
+        }
+    })
+    .map_ok(|rows| futures::stream::iter(rows.into_iter().map(|row| Ok(row.into()))))
+    .try_flatten()
+}
+
 pub(crate) async fn set_transaction_config(
     conn: &mut PoolConnection<Postgres>,
     isolation_level: Option<IsolationLevel>,
     access_mode: Option<AccessMode>,
 ) -> Result<(), DbErr> {
-    if let Some(isolation_level) = isolation_level {
-        let stmt = Statement {
-            sql: format!("SET TRANSACTION ISOLATION LEVEL {isolation_level}"),
-            values: None,
-            db_backend: DbBackend::Postgres,
-        };
-        let query = sqlx_query(&stmt);
-        conn.execute(query).await.map_err(sqlx_error_to_exec_err)?;
-    }
-    if let Some(access_mode) = access_mode {
-        let stmt = Statement {
-            sql: format!("SET TRANSACTION {access_mode}"),
-            values: None,
-            db_backend: DbBackend::Postgres,
-        };
+    for stmt in
+        crate::transaction_config_statements(DbBackend::Postgres, isolation_level, access_mode)
+    {
         let query = sqlx_query(&stmt);
         conn.execute(query).await.map_err(sqlx_error_to_exec_err)?;
     }
@@ -314,6 +627,7 @@ impl
             stmt,
             crate::InnerConnection::Postgres(conn),
             metric_callback,
+            None,
         )
     }
 }
@@ -556,6 +870,14 @@ pub(crate) fn from_sqlx_postgres_row_to_proxy_row(row: &sqlx::postgres::PgRow) -
                             )),
                         ),
 
+                        "INTERVAL" => Value::String(Some(Box::new(
+                            crate::PgInterval::from(
+                                row.try_get::<sqlx::postgres::types::PgInterval, _>(c.ordinal())
+                                    .expect("Failed to get interval"),
+                            )
+                            .to_string(),
+                        ))),
+
                         #[cfg(feature = "with-ipnetwork")]
                         "INET" | "CIDR" => Value::IpNetwork(Some(Box::new(
                             row.try_get(c.ordinal()).expect("Failed to get ip address"),
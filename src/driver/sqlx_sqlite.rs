@@ -29,6 +29,7 @@ pub struct SqlxSqliteConnector;
 pub struct SqlxSqlitePoolConnection {
     pub(crate) pool: SqlitePool,
     metric_callback: Option<crate::metric::Callback>,
+    slow_query: Option<crate::metric::SlowQuery>,
 }
 
 impl std::fmt::Debug for SqlxSqlitePoolConnection {
@@ -54,6 +55,9 @@ impl SqlxSqliteConnector {
         if let Some(sqlcipher_key) = &options.sqlcipher_key {
             opt = opt.pragma("key", sqlcipher_key.clone());
         }
+        if let Some(capacity) = options.statement_cache_capacity {
+            opt = opt.statement_cache_capacity(capacity);
+        }
         use sqlx::ConnectOptions;
         if !options.sqlx_logging {
             opt = opt.disable_statement_logging();
@@ -71,6 +75,7 @@ impl SqlxSqliteConnector {
             options.max_connections(1);
         }
 
+        let slow_query = options.slow_query();
         let pool = if options.connect_lazy {
             options.sqlx_pool_options().connect_lazy_with(opt)
         } else {
@@ -83,7 +88,8 @@ impl SqlxSqliteConnector {
 
         let pool = SqlxSqlitePoolConnection {
             pool,
-            metric_callback: None,
+            metric_callback: crate::metric::callback(None, slow_query),
+            slow_query,
         };
 
         #[cfg(feature = "sqlite-use-returning-for-3_35")]
@@ -102,6 +108,7 @@ impl SqlxSqliteConnector {
         DatabaseConnection::SqlxSqlitePoolConnection(SqlxSqlitePoolConnection {
             pool,
             metric_callback: None,
+            slow_query: None,
         })
     }
 }
@@ -113,7 +120,11 @@ impl SqlxSqlitePoolConnection {
         debug_print!("{}", stmt);
 
         let query = sqlx_query(&stmt);
-        let mut conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+        let mut conn = self
+            .pool
+            .acquire()
+            .await
+            .map_err(sqlx_conn_acquire_err(&self.pool))?;
         crate::metric::metric!(self.metric_callback, &stmt, {
             match query.execute(&mut *conn).await {
                 Ok(res) => Ok(res.into()),
@@ -127,7 +138,11 @@ impl SqlxSqlitePoolConnection {
     pub async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
         debug_print!("{}", sql);
 
-        let conn = &mut self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+        let conn = &mut self
+            .pool
+            .acquire()
+            .await
+            .map_err(sqlx_conn_acquire_err(&self.pool))?;
         match conn.execute(sql).await {
             Ok(res) => Ok(res.into()),
             Err(err) => Err(sqlx_error_to_exec_err(err)),
@@ -140,7 +155,11 @@ impl SqlxSqlitePoolConnection {
         debug_print!("{}", stmt);
 
         let query = sqlx_query(&stmt);
-        let mut conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+        let mut conn = self
+            .pool
+            .acquire()
+            .await
+            .map_err(sqlx_conn_acquire_err(&self.pool))?;
         crate::metric::metric!(self.metric_callback, &stmt, {
             match query.fetch_one(&mut *conn).await {
                 Ok(row) => Ok(Some(row.into())),
@@ -158,7 +177,11 @@ impl SqlxSqlitePoolConnection {
         debug_print!("{}", stmt);
 
         let query = sqlx_query(&stmt);
-        let mut conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+        let mut conn = self
+            .pool
+            .acquire()
+            .await
+            .map_err(sqlx_conn_acquire_err(&self.pool))?;
         crate::metric::metric!(self.metric_callback, &stmt, {
             match query.fetch_all(&mut *conn).await {
                 Ok(rows) => Ok(rows.into_iter().map(|r| r.into()).collect()),
@@ -172,7 +195,11 @@ impl SqlxSqlitePoolConnection {
     pub async fn stream(&self, stmt: Statement) -> Result<QueryStream, DbErr> {
         debug_print!("{}", stmt);
 
-        let conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+        let conn = self
+            .pool
+            .acquire()
+            .await
+            .map_err(sqlx_conn_acquire_err(&self.pool))?;
         Ok(QueryStream::from((
             conn,
             stmt,
@@ -187,7 +214,11 @@ impl SqlxSqlitePoolConnection {
         isolation_level: Option<IsolationLevel>,
         access_mode: Option<AccessMode>,
     ) -> Result<DatabaseTransaction, DbErr> {
-        let conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+        let conn = self
+            .pool
+            .acquire()
+            .await
+            .map_err(sqlx_conn_acquire_err(&self.pool))?;
         DatabaseTransaction::new_sqlite(
             conn,
             self.metric_callback.clone(),
@@ -213,7 +244,11 @@ impl SqlxSqlitePoolConnection {
         T: Send,
         E: std::error::Error + Send,
     {
-        let conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+        let conn = self
+            .pool
+            .acquire()
+            .await
+            .map_err(sqlx_conn_acquire_err(&self.pool))?;
         let transaction = DatabaseTransaction::new_sqlite(
             conn,
             self.metric_callback.clone(),
@@ -229,12 +264,28 @@ impl SqlxSqlitePoolConnection {
     where
         F: Fn(&crate::metric::Info<'_>) + Send + Sync + 'static,
     {
-        self.metric_callback = Some(Arc::new(callback));
+        self.metric_callback = crate::metric::callback(Some(Arc::new(callback)), self.slow_query);
+    }
+
+    /// Get the statistics of the prepared statement cache of a pooled connection
+    pub async fn statement_cache_stats(&self) -> Result<crate::StatementCacheStats, DbErr> {
+        let conn = self
+            .pool
+            .acquire()
+            .await
+            .map_err(sqlx_conn_acquire_err(&self.pool))?;
+        Ok(crate::StatementCacheStats {
+            cached_statements: conn.cached_statements_size(),
+        })
     }
 
     /// Checks if a connection to the database is still valid.
     pub async fn ping(&self) -> Result<(), DbErr> {
-        let conn = &mut self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+        let conn = &mut self
+            .pool
+            .acquire()
+            .await
+            .map_err(sqlx_conn_acquire_err(&self.pool))?;
         match conn.ping().await {
             Ok(_) => Ok(()),
             Err(err) => Err(sqlx_error_to_conn_err(err)),
@@ -292,6 +343,7 @@ async fn get_version(conn: &SqlxSqlitePoolConnection) -> Result<String, DbErr> {
         sql: "SELECT sqlite_version()".to_string(),
         values: None,
         db_backend: crate::DbBackend::Sqlite,
+        read_only: true,
     };
     conn.query_one(stmt)
         .await?
@@ -347,7 +399,12 @@ impl
             Option<crate::metric::Callback>,
         ),
     ) -> Self {
-        crate::QueryStream::build(stmt, crate::InnerConnection::Sqlite(conn), metric_callback)
+        crate::QueryStream::build(
+            stmt,
+            crate::InnerConnection::Sqlite(conn),
+            metric_callback,
+            None,
+        )
     }
 }
 
@@ -1,6 +1,6 @@
 use crate::{
-    error::*, ConnectionTrait, DeleteResult, EntityTrait, Iterable, PrimaryKeyArity,
-    PrimaryKeyToColumn, PrimaryKeyTrait, Value,
+    error::*, ConnectionTrait, DeleteResult, EntityTrait, EntityWritable, Iterable,
+    PrimaryKeyArity, PrimaryKeyToColumn, PrimaryKeyTrait, Value,
 };
 use async_trait::async_trait;
 use sea_query::{Nullable, ValueTuple};
@@ -94,9 +94,22 @@ pub trait ActiveModelTrait: Clone + Debug {
     /// Get a immutable [ActiveValue] from an ActiveModel
     fn get(&self, c: <Self::Entity as EntityTrait>::Column) -> ActiveValue<Value>;
 
-    /// Set the Value into an ActiveModel
+    /// Set the Value into an ActiveModel.
+    /// An [ActiveValue::Unchanged] attribute given the value it holds stays unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value can not be converted into the attribute, see [try_set](Self::try_set).
     fn set(&mut self, c: <Self::Entity as EntityTrait>::Column, v: Value);
 
+    /// Set the Value into an ActiveModel, like [set](Self::set), or fail with [DbErr::Type] if the
+    /// value can not be converted into the attribute, for instance by its
+    /// [ValueConverter](crate::ValueConverter)
+    fn try_set(&mut self, c: <Self::Entity as EntityTrait>::Column, v: Value) -> Result<(), DbErr> {
+        self.set(c, v);
+        Ok(())
+    }
+
     /// Set the state of an [ActiveValue] to the not set state
     fn not_set(&mut self, c: <Self::Entity as EntityTrait>::Column);
 
@@ -275,6 +288,7 @@ pub trait ActiveModelTrait: Clone + Debug {
     /// ```
     async fn insert<'a, C>(self, db: &'a C) -> Result<<Self::Entity as EntityTrait>::Model, DbErr>
     where
+        Self::Entity: EntityWritable,
         <Self::Entity as EntityTrait>::Model: IntoActiveModel<Self>,
         Self: ActiveModelBehavior + 'a,
         C: ConnectionTrait,
@@ -397,6 +411,7 @@ pub trait ActiveModelTrait: Clone + Debug {
     /// ```
     async fn update<'a, C>(self, db: &'a C) -> Result<<Self::Entity as EntityTrait>::Model, DbErr>
     where
+        Self::Entity: EntityWritable,
         <Self::Entity as EntityTrait>::Model: IntoActiveModel<Self>,
         Self: ActiveModelBehavior + 'a,
         C: ConnectionTrait,
@@ -410,6 +425,7 @@ pub trait ActiveModelTrait: Clone + Debug {
     /// Only works if the entity has auto increment primary key.
     async fn save<'a, C>(self, db: &'a C) -> Result<Self, DbErr>
     where
+        Self::Entity: EntityWritable,
         <Self::Entity as EntityTrait>::Model: IntoActiveModel<Self>,
         Self: ActiveModelBehavior + 'a,
         C: ConnectionTrait,
@@ -475,6 +491,7 @@ pub trait ActiveModelTrait: Clone + Debug {
     /// ```
     async fn delete<'a, C>(self, db: &'a C) -> Result<DeleteResult, DbErr>
     where
+        Self::Entity: EntityWritable,
         Self: ActiveModelBehavior + 'a,
         C: ConnectionTrait,
     {
@@ -509,7 +526,7 @@ pub trait ActiveModelTrait: Clone + Debug {
         // Restore primary key values
         for (col, active_value) in primary_key_values {
             match active_value {
-                ActiveValue::Unchanged(v) | ActiveValue::Set(v) => self.set(col, v),
+                ActiveValue::Unchanged(v) | ActiveValue::Set(v) => self.try_set(col, v)?,
                 NotSet => self.not_set(col),
             }
         }
@@ -525,12 +542,12 @@ pub trait ActiveModelTrait: Clone + Debug {
         for<'de> <<Self as ActiveModelTrait>::Entity as EntityTrait>::Model:
             serde::de::Deserialize<'de>,
     {
-        use crate::{Iden, Iterable};
+        use crate::{ColumnTrait, Iterable};
 
         // Mark down which attribute exists in the JSON object
         let json_keys: Vec<(<Self::Entity as EntityTrait>::Column, bool)> =
             <<Self::Entity as EntityTrait>::Column>::iter()
-                .map(|col| (col, json.get(col.to_string()).is_some()))
+                .map(|col| (col, json.get(col.json_key()).is_some()))
                 .collect();
 
         // Convert JSON object into ActiveModel via Model
@@ -541,8 +558,8 @@ pub trait ActiveModelTrait: Clone + Debug {
         // Transform attribute that exists in JSON object into ActiveValue::Set, otherwise ActiveValue::NotSet
         for (col, json_key_exists) in json_keys {
             match (json_key_exists, am.get(col)) {
-                (true, ActiveValue::Set(value) | ActiveValue::Unchanged(value)) => {
-                    am.set(col, value);
+                (true, ActiveValue::Set(_) | ActiveValue::Unchanged(_)) => {
+                    am.reset(col);
                 }
                 _ => {
                     am.not_set(col);
@@ -558,6 +575,49 @@ pub trait ActiveModelTrait: Clone + Debug {
         <Self::Entity as EntityTrait>::Column::iter()
             .any(|col| self.get(col).is_set() && !self.get(col).is_unchanged())
     }
+
+    /// The columns of the attributes of `ActiveModel` which are `Set`, in the order of the Entity,
+    /// that is the columns written to the database by `update`.
+    ///
+    /// An attribute given the value it already holds, with [set](Self::set) or with
+    /// [ActiveValue::set_if_not_equals], stays [ActiveValue::Unchanged] and is left out.
+    /// Assigning an [ActiveValue::Set] to the field directly marks it as changed in any case.
+    ///
+    /// Only the new values are kept by the `ActiveModel`: for a diff, the old values can be taken
+    /// from the Model it was converted from, with [ModelTrait::get](crate::ModelTrait::get).
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, tests_cfg::cake};
+    ///
+    /// let model = cake::Model {
+    ///     id: 1,
+    ///     name: "Chocolate Cake".to_owned(),
+    /// };
+    /// let mut cake = model.clone().into_active_model();
+    /// assert!(cake.changed_columns().is_empty());
+    ///
+    /// cake.name = Set("Cheese Cake".to_owned());
+    /// assert_eq!(cake.changed_columns().len(), 1);
+    ///
+    /// let diff: Vec<_> = cake
+    ///     .changed_columns()
+    ///     .into_iter()
+    ///     .map(|col| (col.as_str().to_owned(), model.get(col), cake.get(col).unwrap()))
+    ///     .collect();
+    /// assert_eq!(
+    ///     diff,
+    ///     [(
+    ///         "name".to_owned(),
+    ///         "Chocolate Cake".into(),
+    ///         "Cheese Cake".into()
+    ///     )]
+    /// );
+    /// ```
+    fn changed_columns(&self) -> Vec<<Self::Entity as EntityTrait>::Column> {
+        <Self::Entity as EntityTrait>::Column::iter()
+            .filter(|col| self.get(*col).is_set())
+            .collect()
+    }
 }
 
 /// A Trait for overriding the ActiveModel behavior
@@ -596,6 +656,87 @@ pub trait ActiveModelBehavior: ActiveModelTrait {
     }
 
     /// Will be called before `ActiveModel::insert`, `ActiveModel::update`, and `ActiveModel::save`
+    ///
+    /// It is called before the `INSERT` or `UPDATE` statement is built, and `insert` tells which
+    /// one it is. The returned ActiveModel is the one written, so it can set attributes like a
+    /// slug; the auto-managed timestamps and version are applied after it. Returning an error
+    /// aborts the write, and no statement is executed. The hooks are not called by
+    /// `Entity::insert`, `Entity::insert_many` or `Entity::update_many`.
+    ///
+    /// ```
+    /// # #[cfg(feature = "mock")]
+    /// # use sea_orm::{error::*, DbBackend, MockDatabase, Statement, Transaction};
+    /// use sea_orm::entity::prelude::*;
+    /// use sea_orm::Set;
+    ///
+    /// #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    /// #[sea_orm(table_name = "post")]
+    /// pub struct Model {
+    ///     #[sea_orm(primary_key)]
+    ///     pub id: i32,
+    ///     pub title: String,
+    ///     pub slug: String,
+    /// }
+    ///
+    /// # #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    /// # pub enum Relation {}
+    /// #
+    /// #[async_trait::async_trait]
+    /// impl ActiveModelBehavior for ActiveModel {
+    ///     async fn before_save<C>(mut self, _db: &C, insert: bool) -> Result<Self, DbErr>
+    ///     where
+    ///         C: ConnectionTrait,
+    ///     {
+    ///         let title = self.title.as_ref().trim().to_owned();
+    ///         if title.is_empty() {
+    ///             return Err(DbErr::Custom("The title is empty".to_owned()));
+    ///         }
+    ///         if insert {
+    ///             self.slug = Set(title.to_lowercase().replace(' ', "-"));
+    ///         }
+    ///         Ok(self)
+    ///     }
+    /// }
+    ///
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results([[Model {
+    /// #         id: 1,
+    /// #         title: "Hello World".to_owned(),
+    /// #         slug: "hello-world".to_owned(),
+    /// #     }]])
+    /// #     .into_connection();
+    /// #
+    /// let post = ActiveModel {
+    ///     title: Set("Hello World".to_owned()),
+    ///     ..Default::default()
+    /// }
+    /// .insert(&db)
+    /// .await?;
+    /// assert_eq!(post.slug, "hello-world");
+    ///
+    /// let res = ActiveModel {
+    ///     title: Set(" ".to_owned()),
+    ///     ..Default::default()
+    /// }
+    /// .insert(&db)
+    /// .await;
+    /// assert_eq!(res, Err(DbErr::Custom("The title is empty".to_owned())));
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     [Transaction::from_sql_and_values(
+    ///         DbBackend::Postgres,
+    ///         r#"INSERT INTO "post" ("title", "slug") VALUES ($1, $2) RETURNING "id", "title", "slug""#,
+    ///         ["Hello World".into(), "hello-world".into()]
+    ///     )]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
     async fn before_save<C>(self, db: &C, insert: bool) -> Result<Self, DbErr>
     where
         C: ConnectionTrait,
@@ -604,6 +745,10 @@ pub trait ActiveModelBehavior: ActiveModelTrait {
     }
 
     /// Will be called after `ActiveModel::insert`, `ActiveModel::update`, and `ActiveModel::save`
+    ///
+    /// It is called once the statement has been executed, with the Model as returned by
+    /// the database. Returning an error does not undo the write: the ActiveModel has to be
+    /// saved within a transaction for it to be rolled back.
     async fn after_save<C>(
         model: <Self::Entity as EntityTrait>::Model,
         db: &C,
@@ -1291,6 +1436,61 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    #[cfg(all(feature = "macros", feature = "with-json"))]
+    fn test_active_model_from_json_serde_rename() -> Result<(), DbErr> {
+        mod api_user {
+            use crate as sea_orm;
+            use crate::entity::prelude::*;
+            use serde::{Deserialize, Serialize};
+
+            #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+            #[sea_orm(table_name = "user", serde_rename_all = "camelCase")]
+            #[serde(rename_all = "camelCase")]
+            pub struct Model {
+                #[sea_orm(primary_key)]
+                pub id: i32,
+                #[sea_orm(column_name = "full_name")]
+                pub display_name: String,
+                #[sea_orm(serde_rename = "mail")]
+                #[serde(rename = "mail", default)]
+                pub email: Option<String>,
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+            pub enum Relation {}
+
+            impl ActiveModelBehavior for ActiveModel {}
+        }
+
+        assert_eq!(api_user::Column::DisplayName.json_key(), "displayName");
+        assert_eq!(api_user::Column::Email.json_key(), "mail");
+
+        let model = api_user::Model {
+            id: 1,
+            display_name: "Jane".to_owned(),
+            email: None,
+        };
+        assert_eq!(
+            serde_json::to_value(&model).unwrap(),
+            json!({ "id": 1, "displayName": "Jane", "mail": null })
+        );
+
+        assert_eq!(
+            api_user::ActiveModel::from_json(json!({
+                "id": 1,
+                "displayName": "Jane",
+            }))?,
+            api_user::ActiveModel {
+                id: ActiveValue::Set(1),
+                display_name: ActiveValue::Set("Jane".to_owned()),
+                email: ActiveValue::NotSet,
+            }
+        );
+
+        Ok(())
+    }
+
     #[smol_potat::test]
     #[cfg(feature = "with-json")]
     async fn test_active_model_set_from_json_3() -> Result<(), DbErr> {
@@ -1366,6 +1566,45 @@ mod tests {
         assert!(fruit.is_changed());
     }
 
+    #[test]
+    fn test_active_model_changed_columns() {
+        let mut fruit = fruit::Model {
+            id: 1,
+            name: "Apple".into(),
+            cake_id: None,
+        }
+        .into_active_model();
+        assert!(fruit.changed_columns().is_empty());
+
+        fruit.set(fruit::Column::CakeId, 2i32.into());
+        fruit.not_set(fruit::Column::Id);
+        fruit.set(fruit::Column::Name, "Orange".into());
+        let names = |fruit: &fruit::ActiveModel| -> Vec<String> {
+            fruit
+                .changed_columns()
+                .into_iter()
+                .map(|col| col.as_str().to_owned())
+                .collect()
+        };
+        assert_eq!(names(&fruit), ["name", "cake_id"]);
+
+        // Setting an attribute to its existing value does not change it
+        let mut fruit = fruit::Model {
+            id: 1,
+            name: "Apple".into(),
+            cake_id: Some(2),
+        }
+        .into_active_model();
+        fruit.set(fruit::Column::Name, "Apple".into());
+        fruit.cake_id.set_if_not_equals(Some(2));
+        assert!(fruit.changed_columns().is_empty());
+        assert_eq!(fruit.name, Unchanged("Apple".to_owned()));
+
+        fruit.set(fruit::Column::Name, "Orange".into());
+        fruit.cake_id.set_if_not_equals(None);
+        assert_eq!(names(&fruit), ["name", "cake_id"]);
+    }
+
     #[test]
     fn test_reset_1() {
         assert_eq!(
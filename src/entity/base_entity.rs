@@ -1,9 +1,12 @@
 use crate::{
-    ActiveModelBehavior, ActiveModelTrait, ColumnTrait, Delete, DeleteMany, DeleteOne,
-    FromQueryResult, Insert, ModelTrait, PrimaryKeyToColumn, PrimaryKeyTrait, QueryFilter, Related,
-    RelationBuilder, RelationTrait, RelationType, Select, Update, UpdateMany, UpdateOne,
+    ActiveModelBehavior, ActiveModelTrait, ColumnTrait, DbBackend, DbErr, Delete, DeleteByIds,
+    DeleteMany, DeleteOne, FromQueryResult, Insert, ModelTrait, PrimaryKeyToColumn,
+    PrimaryKeyTrait, QueryFilter, Related, RelationBuilder, RelationTrait, RelationType, Select,
+    SelectModel, SelectorRaw, Statement, Update, UpdateMany, UpdateOne,
+};
+use sea_query::{
+    Alias, Iden, IntoIden, IntoTableRef, IntoValueTuple, SelectStatement, TableRef, Value,
 };
-use sea_query::{Alias, Iden, IntoIden, IntoTableRef, IntoValueTuple, TableRef};
 use std::fmt::Debug;
 pub use strum::IntoEnumIterator as Iterable;
 
@@ -173,10 +176,136 @@ pub trait EntityTrait: EntityName {
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// Rows deleted through a [soft-delete](crate::ColumnDef::soft_delete) column are skipped.
     fn find() -> Select<Self> {
+        let mut select = Select::new();
+        for col in Self::Column::iter() {
+            if col.def().is_soft_delete() {
+                select = select.filter(col.is_null());
+            }
+        }
+        select
+    }
+
+    /// Find all models, including the ones deleted through a
+    /// [soft-delete](crate::ColumnDef::soft_delete) column
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::with_deleted()
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "cake"."id", "cake"."name" FROM "cake""#
+    /// );
+    /// ```
+    fn with_deleted() -> Select<Self> {
         Select::new()
     }
 
+    /// Find models from a subquery, a derived table aliased as the table of the Entity.
+    /// It has to select the columns of the Entity, as it is read into its Model.
+    ///
+    /// The rows of the subquery are taken as they are: rows deleted through a
+    /// [soft-delete](crate::ColumnDef::soft_delete) column are skipped when the subquery skips them,
+    /// like one built with [find](EntityTrait::find).
+    ///
+    /// The latest fruit of every cake, ordered by name:
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::fruit, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     fruit::Entity::find_from_subquery(
+    ///         fruit::Entity::find()
+    ///             .distinct_on([fruit::Column::CakeId])
+    ///             .order_by_asc(fruit::Column::CakeId)
+    ///             .order_by_desc(fruit::Column::Id)
+    ///     )
+    ///     .order_by_asc(fruit::Column::Name)
+    ///     .build(DbBackend::Postgres)
+    ///     .to_string(),
+    ///     [
+    ///         r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id""#,
+    ///         r#"FROM (SELECT DISTINCT ON ("cake_id") "fruit"."id", "fruit"."name", "fruit"."cake_id""#,
+    ///         r#"FROM "fruit" ORDER BY "fruit"."cake_id" ASC, "fruit"."id" DESC) AS "fruit""#,
+    ///         r#"ORDER BY "fruit"."name" ASC"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    fn find_from_subquery<S>(subquery: S) -> Select<Self>
+    where
+        S: Into<SelectStatement>,
+    {
+        Select::from_subquery(subquery.into())
+    }
+
+    /// Find models with a raw SQL query, binding the values to its placeholders, for queries
+    /// which can't be built otherwise. It has to select the columns of the Entity,
+    /// as it is read into its Model.
+    ///
+    /// The placeholders have to be those of the backend: `$1`, `$2` and so on for Postgres,
+    /// `?` for MySQL and SQLite, with as many values. Otherwise it's a [DbErr::Query].
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results([[cake::Model {
+    /// #         id: 1,
+    /// #         name: "New York Cheese".to_owned(),
+    /// #     }]])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, tests_cfg::cake, DbBackend};
+    ///
+    /// let cakes = cake::Entity::find_by_sql(
+    ///     DbBackend::Postgres,
+    ///     r#"SELECT "id", "name" FROM "cake" WHERE "name" ILIKE $1"#,
+    ///     ["%cheese%".into()],
+    /// )?
+    /// .all(&db)
+    /// .await?;
+    ///
+    /// assert_eq!(
+    ///     cakes,
+    ///     [cake::Model {
+    ///         id: 1,
+    ///         name: "New York Cheese".to_owned(),
+    ///     }]
+    /// );
+    ///
+    /// assert!(cake::Entity::find_by_sql(
+    ///     DbBackend::Postgres,
+    ///     r#"SELECT "id", "name" FROM "cake" WHERE "name" = ?"#,
+    ///     ["Cheese".into()],
+    /// )
+    /// .is_err());
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn find_by_sql<S, I>(
+        db_backend: DbBackend,
+        sql: S,
+        values: I,
+    ) -> Result<SelectorRaw<SelectModel<Self::Model>>, DbErr>
+    where
+        S: Into<String>,
+        I: IntoIterator<Item = Value>,
+    {
+        let stmt = Statement::from_sql_and_values(db_backend, sql, values);
+        stmt.check_placeholders()?;
+        Ok(Self::find().from_raw_sql(stmt))
+    }
+
     /// Find a model by primary key
     ///
     /// # Example
@@ -853,8 +982,98 @@ pub trait EntityTrait: EntityName {
         }
         delete
     }
+
+    /// Delete the models based on their primary keys, in a single statement with an `IN`
+    /// condition, unless there are so many keys that they are split across several statements
+    /// to bind no more parameters per statement than the backend allows.
+    /// The statements are not run in a transaction of their own.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_exec_results([
+    /// #         MockExecResult {
+    /// #             last_insert_id: 0,
+    /// #             rows_affected: 2,
+    /// #         },
+    /// #         MockExecResult {
+    /// #             last_insert_id: 0,
+    /// #             rows_affected: 1,
+    /// #         },
+    /// #     ])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::{cake_filling, fruit}};
+    ///
+    /// let delete_result = fruit::Entity::delete_by_ids([1, 2, 3]).exec(&db).await?;
+    ///
+    /// assert_eq!(delete_result.rows_affected, 2);
+    ///
+    /// let delete_result = cake_filling::Entity::delete_by_ids([(2, 3), (2, 4)])
+    ///     .exec(&db)
+    ///     .await?;
+    ///
+    /// assert_eq!(delete_result.rows_affected, 1);
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     [
+    ///         Transaction::from_sql_and_values(
+    ///             DbBackend::Postgres,
+    ///             r#"DELETE FROM "fruit" WHERE "fruit"."id" IN ($1, $2, $3)"#,
+    ///             [1i32.into(), 2i32.into(), 3i32.into()]
+    ///         ),
+    ///         Transaction::from_sql_and_values(
+    ///             DbBackend::Postgres,
+    ///             r#"DELETE FROM "cake_filling" WHERE ("cake_filling"."cake_id" = $1 AND "cake_filling"."filling_id" = $2) OR ("cake_filling"."cake_id" = $3 AND "cake_filling"."filling_id" = $4)"#,
+    ///             [2i32.into(), 3i32.into(), 2i32.into(), 4i32.into()]
+    ///         ),
+    ///     ]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if arity of input values don't match arity of primary key
+    fn delete_by_ids<I, T>(ids: I) -> DeleteByIds<Self>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<<Self::PrimaryKey as PrimaryKeyTrait>::ValueType>,
+    {
+        let arity = Self::PrimaryKey::iter().count();
+        let ids = ids
+            .into_iter()
+            .map(|id| {
+                let id = id.into().into_value_tuple();
+                if id.clone().into_iter().count() != arity {
+                    panic!("primary key arity mismatch");
+                }
+                id
+            })
+            .collect();
+        DeleteByIds {
+            delete: Self::delete_many(),
+            ids,
+        }
+    }
 }
 
+/// An Entity whose rows can be written to, i.e. inserted, updated and deleted
+///
+/// It is implemented by [DeriveEntity](sea_orm_macros::DeriveEntity), and so by
+/// [DeriveEntityModel](sea_orm_macros::DeriveEntityModel), unless the Entity maps a read-only
+/// view, marked with `#[sea_orm(view)]`. Executing a write on an Entity without it
+/// is a compile error.
+pub trait EntityWritable: EntityTrait {}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -972,4 +1191,31 @@ mod tests {
         delete_by_id("UUID");
         delete_by_id(Cow::from("UUID"));
     }
+
+    #[test]
+    fn test_find_from_subquery() {
+        use crate::tests_cfg::{cake, fruit};
+        use crate::{entity::*, query::*, DbBackend};
+
+        assert_eq!(
+            fruit::Entity::find_from_subquery(
+                fruit::Entity::find()
+                    .filter(fruit::Column::Name.contains("apple"))
+                    .limit(10)
+            )
+            .find_also_related(cake::Entity)
+            .filter(cake::Column::Name.contains("cheese"))
+            .build(DbBackend::MySql)
+            .to_string(),
+            [
+                "SELECT `fruit`.`id` AS `A_id`, `fruit`.`name` AS `A_name`, `fruit`.`cake_id` AS `A_cake_id`,",
+                "`cake`.`id` AS `B_id`, `cake`.`name` AS `B_name`",
+                "FROM (SELECT `fruit`.`id`, `fruit`.`name`, `fruit`.`cake_id` FROM `fruit`",
+                "WHERE `fruit`.`name` LIKE '%apple%' LIMIT 10) AS `fruit`",
+                "LEFT JOIN `cake` ON `fruit`.`cake_id` = `cake`.`id`",
+                "WHERE `cake`.`name` LIKE '%cheese%'",
+            ]
+            .join(" ")
+        );
+    }
 }
@@ -1,6 +1,8 @@
 use crate::{DbBackend, EntityName, Iden, IdenStatic, IntoSimpleExpr, Iterable};
 use sea_query::{
-    Alias, BinOper, DynIden, Expr, IntoIden, SeaRc, SelectStatement, SimpleExpr, Value,
+    extension::{postgres::PgBinOper, sqlite::SqliteBinOper},
+    Alias, BinOper, DynIden, Expr, Func, FunctionCall, IntoIden, SeaRc, SelectStatement,
+    SimpleExpr, Value,
 };
 use std::str::FromStr;
 
@@ -17,6 +19,34 @@ pub struct ColumnDef {
     pub(crate) indexed: bool,
     pub(crate) default: Option<SimpleExpr>,
     pub(crate) comment: Option<String>,
+    pub(crate) version: bool,
+    pub(crate) soft_delete: bool,
+    pub(crate) created_timestamp: Option<TimestampSource>,
+    pub(crate) updated_timestamp: Option<TimestampSource>,
+    pub(crate) generated: Option<(SimpleExpr, bool)>,
+    pub(crate) check: Option<SimpleExpr>,
+    pub(crate) collation: Option<String>,
+}
+
+/// Where the value of an auto-managed timestamp column comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimestampSource {
+    /// The database clock, using `CURRENT_TIMESTAMP`
+    Database,
+    /// The application clock, using [`chrono::Utc::now`]
+    #[cfg(feature = "with-chrono")]
+    Rust,
+}
+
+impl TimestampSource {
+    /// The expression for the current timestamp
+    pub fn now(&self) -> SimpleExpr {
+        match self {
+            Self::Database => Expr::current_timestamp().into(),
+            #[cfg(feature = "with-chrono")]
+            Self::Rust => Expr::val(chrono::Utc::now()).into(),
+        }
+    }
 }
 
 macro_rules! bind_oper {
@@ -60,8 +90,11 @@ macro_rules! bind_subquery_func {
     ( $func: ident ) => {
         #[allow(clippy::wrong_self_convention)]
         #[allow(missing_docs)]
-        fn $func(&self, s: SelectStatement) -> SimpleExpr {
-            Expr::col((self.entity_name(), *self)).$func(s)
+        fn $func<S>(&self, s: S) -> SimpleExpr
+        where
+            S: Into<SelectStatement>,
+        {
+            Expr::col((self.entity_name(), *self)).$func(s.into())
         }
     };
 }
@@ -225,6 +258,26 @@ pub trait ColumnTrait: IdenStatic + Iterable + FromStr {
     bind_func_no_params!(min);
     bind_func_no_params!(sum);
     bind_func_no_params!(count);
+
+    /// The average of the column, see also SeaQuery's [Func::avg]
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .select_only()
+    ///         .column_as(cake::Column::Id.avg(), "avg_id")
+    ///         .group_by(cake::Column::Name)
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT AVG("cake"."id") AS "avg_id" FROM "cake" GROUP BY "cake"."name""#
+    /// );
+    /// ```
+    fn avg(&self) -> SimpleExpr {
+        Func::avg(Expr::col((self.entity_name(), *self))).into()
+    }
+
     bind_func_no_params!(is_null);
     bind_func_no_params!(is_not_null);
 
@@ -242,6 +295,200 @@ pub trait ColumnTrait: IdenStatic + Iterable + FromStr {
     bind_subquery_func!(in_subquery);
     bind_subquery_func!(not_in_subquery);
 
+    /// Extract the value found at a JSON path of a JSON column, like `$.address.city`,
+    /// so it can be compared with other values.
+    ///
+    /// The value is extracted as text on Postgres, with `jsonb_extract_path_text`
+    /// (or `json_extract_path_text` for `json` columns), and on MySQL, with
+    /// `JSON_UNQUOTE(JSON_EXTRACT(..))`. SQLite has no JSON column type: the column is
+    /// expected to hold JSON text, from which `json_extract` gets a value of the matching SQL type.
+    ///
+    /// Only member accesses (`.key`) and array indices (`[0]`) are supported in the path.
+    fn json_path(&self, db_backend: DbBackend, path: &str) -> Expr {
+        let col = Expr::col((self.entity_name(), *self));
+        let func = match db_backend {
+            DbBackend::Postgres => {
+                let func = match self.def().get_column_type() {
+                    ColumnType::Json => "json_extract_path_text",
+                    _ => "jsonb_extract_path_text",
+                };
+                Func::cust(Alias::new(func)).args(
+                    std::iter::once(col.into())
+                        .chain(json_path_keys(path).into_iter().map(SimpleExpr::from)),
+                )
+            }
+            DbBackend::MySql => Func::cust(Alias::new("JSON_UNQUOTE")).arg(
+                Func::cust(Alias::new("JSON_EXTRACT"))
+                    .arg(col)
+                    .arg(path.to_owned()),
+            ),
+            DbBackend::Sqlite => Func::cust(Alias::new("json_extract"))
+                .arg(col)
+                .arg(path.to_owned()),
+        };
+        Expr::expr(func)
+    }
+
+    /// Check that a JSON column contains the given JSON value, with the `@>` operator.
+    /// Postgres only; `json` columns are cast as `jsonb`.
+    fn json_contains<V>(&self, v: V) -> SimpleExpr
+    where
+        V: Into<Value>,
+    {
+        let col = match self.def().get_column_type() {
+            ColumnType::Json => {
+                Expr::expr(Expr::col((self.entity_name(), *self)).cast_as(Alias::new("jsonb")))
+            }
+            _ => Expr::col((self.entity_name(), *self)),
+        };
+        col.binary(PgBinOper::Contains, Expr::val(v))
+    }
+
+    /// Check that a range column contains the given value, with the `@>` operator.
+    /// Postgres only.
+    ///
+    /// The value is either an element of the range, like an `i32` for an `int4range` column,
+    /// or a [PgRange](crate::PgRange), which has to be wholly contained.
+    fn range_contains<V>(&self, v: V) -> SimpleExpr
+    where
+        V: Into<Value>,
+    {
+        let v = match v.into() {
+            // A range is sent in its text form and cast to the range type of the column
+            v @ Value::String(_) => self.save_as(Expr::val(v)),
+            v => Expr::val(v).into(),
+        };
+        Expr::col((self.entity_name(), *self)).binary(PgBinOper::Contains, v)
+    }
+
+    /// Check that an `hstore` column has the given key, with the `?` operator. Postgres only.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .filter(cake::Column::Name.hstore_contains_key("color"))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."name" ? 'color'"#
+    /// );
+    /// ```
+    fn hstore_contains_key(&self, key: &str) -> SimpleExpr {
+        Expr::col((self.entity_name(), *self)).binary(BinOper::Custom("?"), Expr::val(key))
+    }
+
+    /// Look up the value of the given key in an `hstore` column, with the `->` operator,
+    /// so it can be compared with other values. It is `NULL` if the key is missing.
+    /// Postgres only.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .filter(cake::Column::Name.hstore_get("color").eq("red"))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE ("cake"."name" -> 'color') = 'red'"#
+    /// );
+    /// ```
+    fn hstore_get(&self, key: &str) -> Expr {
+        Expr::expr(
+            Expr::col((self.entity_name(), *self)).binary(BinOper::Custom("->"), Expr::val(key)),
+        )
+    }
+
+    /// Check that a PostGIS `geometry` or `geography` column is within the given distance
+    /// of the point, with the `ST_DWithin` function. The distance is in the units of the
+    /// spatial reference system, or in meters for `geography` columns.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend, PgPoint};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .filter(cake::Column::Name.st_dwithin(PgPoint::new(1.0, 2.0), 0.5))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE ST_DWithin("cake"."name", 'POINT(1 2)', 0.5)"#
+    /// );
+    /// ```
+    #[cfg(feature = "postgis")]
+    fn st_dwithin(&self, point: crate::PgPoint, distance: f64) -> SimpleExpr {
+        Func::cust(Alias::new("ST_DWithin"))
+            .arg(Expr::col((self.entity_name(), *self)))
+            .arg(self.save_as(Expr::val(point)))
+            .arg(distance)
+            .into()
+    }
+
+    /// Check that an `inet` or `cidr` column is contained within the given network,
+    /// or equal to it, with the `<<=` operator. Postgres only.
+    ///
+    /// ```
+    /// # #[cfg(feature = "with-ipnetwork")]
+    /// # {
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    /// use sea_orm::prelude::IpNetwork;
+    ///
+    /// let network: IpNetwork = "10.0.0.0/8".parse().unwrap();
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .filter(cake::Column::Name.within_network(network))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."name" <<= '10.0.0.0/8'"#
+    /// );
+    /// # }
+    /// ```
+    fn within_network<V>(&self, v: V) -> SimpleExpr
+    where
+        V: Into<Value>,
+    {
+        Expr::col((self.entity_name(), *self)).binary(BinOper::Custom("<<="), Expr::val(v))
+    }
+
+    /// Full-text search of the column, with the native operator of the database backend:
+    /// `to_tsvector(..) @@ plainto_tsquery(..)` on Postgres, `MATCH (..) AGAINST (..)` on MySQL
+    /// and `MATCH` on SQLite.
+    ///
+    /// The search terms are plain words, not a query in the syntax of the backend.
+    /// MySQL requires a `FULLTEXT` index on the column, and SQLite requires the table
+    /// of the entity to be an FTS5 virtual table.
+    fn matches(&self, db_backend: DbBackend, terms: &str) -> SimpleExpr {
+        let col = Expr::col((self.entity_name(), *self));
+        match db_backend {
+            DbBackend::Postgres => {
+                Expr::expr(to_tsvector(col)).binary(PgBinOper::Matches, plainto_tsquery(terms))
+            }
+            DbBackend::MySql => match_against(col, terms),
+            DbBackend::Sqlite => col.binary(SqliteBinOper::Match, Expr::val(terms)),
+        }
+    }
+
+    /// The relevance of the column to a full-text search, as a floating point number
+    /// which is higher for more relevant rows, see [`ColumnTrait::matches`].
+    ///
+    /// It is computed with `ts_rank` on Postgres, `MATCH (..) AGAINST (..)` on MySQL
+    /// and the opposite of `bm25` on SQLite.
+    fn match_rank(&self, db_backend: DbBackend, terms: &str) -> SimpleExpr {
+        let col = Expr::col((self.entity_name(), *self));
+        match db_backend {
+            DbBackend::Postgres => Expr::expr(
+                Func::cust(Alias::new("ts_rank"))
+                    .arg(to_tsvector(col))
+                    .arg(plainto_tsquery(terms)),
+            )
+            .cast_as(Alias::new("double precision")),
+            DbBackend::MySql => match_against(col, terms),
+            DbBackend::Sqlite => {
+                Expr::expr(Func::cust(Alias::new("bm25")).arg(Expr::col(self.entity_name())))
+                    .mul(-1)
+            }
+        }
+    }
+
     /// Construct a [`SimpleExpr::Column`] wrapped in [`Expr`].
     fn into_expr(self) -> Expr {
         Expr::expr(self.into_simple_expr())
@@ -279,8 +526,12 @@ pub trait ColumnTrait: IdenStatic + Iterable + FromStr {
     }
 
     /// Cast value of an enum column as enum type; do nothing if `self` is not an enum.
-    /// Will also transform `Array(Vec<Json>)` into `Json(Vec<Json>)` if the column type is `Json`.
+    /// Will also transform `Array(Vec<Json>)` into `Json(Vec<Json>)` if the column type is `Json`,
+    /// and cast the text form of a [PgInterval](crate::PgInterval) as `interval`.
     fn save_enum_as(&self, val: Expr) -> SimpleExpr {
+        if let ColumnType::Interval(..) = self.def().get_column_type() {
+            return val.as_enum(Alias::new("interval"));
+        }
         cast_enum_as(val, self, |col, enum_name, col_type| {
             let type_name = match col_type {
                 ColumnType::Array(_) => {
@@ -291,6 +542,14 @@ pub trait ColumnTrait: IdenStatic + Iterable + FromStr {
             col.as_enum(type_name)
         })
     }
+
+    /// The key of the column in the JSON form of the Model, used by
+    /// [ActiveModelTrait::from_json](crate::ActiveModelTrait::from_json).
+    /// It is the name of the field, or the name given with `#[sea_orm(serde_rename = "...")]`
+    /// or `#[sea_orm(serde_rename_all = "...")]` on a Model derived with `DeriveEntityModel`.
+    fn json_key(&self) -> &str {
+        self.as_str()
+    }
 }
 
 /// SeaORM's utility methods that act on [ColumnType]
@@ -311,6 +570,13 @@ impl ColumnTypeTrait for ColumnType {
             indexed: false,
             default: None,
             comment: None,
+            version: false,
+            soft_delete: false,
+            created_timestamp: None,
+            updated_timestamp: None,
+            generated: None,
+            check: None,
+            collation: None,
         }
     }
 
@@ -349,6 +615,22 @@ impl ColumnDef {
         self
     }
 
+    /// Set the collation of a text column, rendered as `COLLATE <name>` when creating the table,
+    /// like `NOCASE` on SQLite or `utf8mb4_unicode_ci` on MySQL.
+    ///
+    /// Collations are named differently by each backend, so the name is passed through as is;
+    /// it is only quoted on Postgres, where collations like `"C"` and `"en-US-x-icu"` are
+    /// identifiers. With `DeriveEntityModel`, the field is marked with `#[sea_orm(collation = "..")]`.
+    pub fn collation(mut self, name: &str) -> Self {
+        self.collation = Some(name.to_owned());
+        self
+    }
+
+    /// Get the collation of the column, if any
+    pub fn get_collation(&self) -> Option<&str> {
+        self.collation.as_deref()
+    }
+
     /// Mark the column as nullable
     pub fn null(self) -> Self {
         self.nullable()
@@ -376,6 +658,41 @@ impl ColumnDef {
     }
 
     /// Set the default value or expression of a column
+    ///
+    /// An expression, like `Expr::current_timestamp()` or `Expr::cust("gen_random_uuid()")`,
+    /// is rendered as is in the `CREATE TABLE` statement, and evaluated by the database for
+    /// each inserted row. With `DeriveEntityModel`, the field is marked with
+    /// `#[sea_orm(default_expr = "..")]`, and a literal with `#[sea_orm(default_value = ..)]`.
+    ///
+    /// ```
+    /// use sea_orm::entity::prelude::*;
+    /// use sea_orm::sea_query::{Expr, PostgresQueryBuilder};
+    /// use sea_orm::{DbBackend, Schema};
+    ///
+    /// #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    /// #[sea_orm(table_name = "session")]
+    /// pub struct Model {
+    ///     #[sea_orm(
+    ///         primary_key,
+    ///         auto_increment = false,
+    ///         default_expr = "Expr::cust(\"gen_random_uuid()\")"
+    ///     )]
+    ///     pub id: Uuid,
+    ///     #[sea_orm(default_expr = "Expr::current_timestamp()")]
+    ///     pub created_at: DateTimeWithTimeZone,
+    /// }
+    ///
+    /// # #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    /// # pub enum Relation {}
+    /// #
+    /// # impl ActiveModelBehavior for ActiveModel {}
+    /// #
+    /// let stmt = Schema::new(DbBackend::Postgres)
+    ///     .create_table_from_entity(Entity)
+    ///     .to_string(PostgresQueryBuilder);
+    /// assert!(stmt.contains(r#""id" uuid NOT NULL DEFAULT gen_random_uuid() PRIMARY KEY"#));
+    /// assert!(stmt.contains(r#""created_at" timestamp with time zone NOT NULL DEFAULT CURRENT_TIMESTAMP"#));
+    /// ```
     pub fn default<T>(mut self, default: T) -> Self
     where
         T: Into<SimpleExpr>,
@@ -393,6 +710,164 @@ impl ColumnDef {
     pub fn is_null(&self) -> bool {
         self.null
     }
+
+    /// Mark the column as the version of the row, for optimistic locking
+    ///
+    /// Updating an ActiveModel then only matches the row if its version is unchanged,
+    /// and increments it.
+    pub fn version(mut self) -> Self {
+        self.version = true;
+        self
+    }
+
+    /// Returns true if the column is the version of the row
+    pub fn is_version(&self) -> bool {
+        self.version
+    }
+
+    /// Mark the column as the deletion time of the row, for soft deletes
+    ///
+    /// Deleting then sets the column to the current timestamp instead of removing the row,
+    /// and [`EntityTrait::find`](crate::EntityTrait::find) skips the rows where it is not null.
+    /// Use [`EntityTrait::with_deleted`](crate::EntityTrait::with_deleted) to select them anyway,
+    /// and [`really_delete`](crate::DeleteMany::really_delete) to remove them for good.
+    ///
+    /// Soft-deleted rows still take part in unique constraints, so a deleted row blocks
+    /// inserting another one with the same unique values. On Postgres and SQLite, create the
+    /// unique index as a partial index `WHERE deleted_at IS NULL` instead. On MySQL, add the
+    /// deletion time to the unique key; as `NULL`s are distinct, this allows any number of
+    /// deleted rows, but only one live row.
+    pub fn soft_delete(mut self) -> Self {
+        self.soft_delete = true;
+        self
+    }
+
+    /// Returns true if the column is the deletion time of the row
+    pub fn is_soft_delete(&self) -> bool {
+        self.soft_delete
+    }
+
+    /// Set the column to the current timestamp when inserting an ActiveModel,
+    /// unless it is given a value
+    pub fn created_timestamp(mut self, source: TimestampSource) -> Self {
+        self.created_timestamp = Some(source);
+        self
+    }
+
+    /// Set the column to the current timestamp when inserting or updating an ActiveModel,
+    /// unless it is given a value
+    pub fn updated_timestamp(mut self, source: TimestampSource) -> Self {
+        self.updated_timestamp = Some(source);
+        self
+    }
+
+    /// Get the source of the timestamp set on insert, if any
+    pub fn get_created_timestamp(&self) -> Option<TimestampSource> {
+        self.created_timestamp
+    }
+
+    /// Get the source of the timestamp set on insert and update, if any
+    pub fn get_updated_timestamp(&self) -> Option<TimestampSource> {
+        self.updated_timestamp
+    }
+
+    /// Mark the column as generated, computed by the database from the other columns of the row,
+    /// like `GENERATED ALWAYS AS (expr) STORED`. A stored column is computed when the row is
+    /// written, a virtual one when it is read; Postgres only supports stored columns.
+    ///
+    /// The column is read into the Model like any other, but it is never written to:
+    /// its values are left out when inserting or updating an ActiveModel, even if set.
+    /// With `DeriveEntityModel`, the field is marked with `#[sea_orm(generated_expr = "..")]`,
+    /// and `stored` for a stored column.
+    ///
+    /// In a migration, the column is defined with the `generated` method of the `ColumnDef`
+    /// of SeaQuery, taking the same arguments:
+    ///
+    /// ```
+    /// use sea_orm::sea_query::{ColumnDef, Expr, PostgresQueryBuilder, Table};
+    /// use sea_orm::DeriveIden;
+    ///
+    /// #[derive(DeriveIden)]
+    /// enum Person {
+    ///     Table,
+    ///     FirstName,
+    ///     LastName,
+    ///     FullName,
+    /// }
+    ///
+    /// let stmt = Table::create()
+    ///     .table(Person::Table)
+    ///     .col(ColumnDef::new(Person::FirstName).string().not_null())
+    ///     .col(ColumnDef::new(Person::LastName).string().not_null())
+    ///     .col(
+    ///         ColumnDef::new(Person::FullName)
+    ///             .string()
+    ///             .generated(Expr::cust("first_name || ' ' || last_name"), true),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert!(stmt
+    ///     .to_string(PostgresQueryBuilder)
+    ///     .contains("GENERATED ALWAYS AS (first_name || ' ' || last_name) STORED"));
+    /// ```
+    pub fn generated<T>(mut self, expr: T, stored: bool) -> Self
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.generated = Some((expr.into(), stored));
+        self
+    }
+
+    /// Returns true if the column is generated by the database
+    pub fn is_generated(&self) -> bool {
+        self.generated.is_some()
+    }
+
+    /// Add a `CHECK (expr)` constraint to the column, rejecting the rows for which the expression
+    /// is false. Column-level checks are supported by every backend, SQLite included; MySQL
+    /// enforces them since 8.0.16, and ignores them before.
+    ///
+    /// With `DeriveEntityModel`, the field is marked with `#[sea_orm(check_expr = "..")]`.
+    /// In a migration, the `check` method of the `ColumnDef` of SeaQuery takes the same argument,
+    /// and a named constraint of an existing table is added and dropped with `add_check`
+    /// and `drop_check` of the `SchemaManager`.
+    ///
+    /// ```
+    /// use sea_orm::entity::prelude::*;
+    /// use sea_orm::sea_query::{Expr, PostgresQueryBuilder};
+    /// use sea_orm::{DbBackend, Schema};
+    ///
+    /// #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    /// #[sea_orm(table_name = "product")]
+    /// pub struct Model {
+    ///     #[sea_orm(primary_key)]
+    ///     pub id: i32,
+    ///     #[sea_orm(check_expr = "Expr::col(Column::Price).gte(0)")]
+    ///     pub price: i32,
+    /// }
+    ///
+    /// # #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    /// # pub enum Relation {}
+    /// #
+    /// # impl ActiveModelBehavior for ActiveModel {}
+    /// #
+    /// let stmt = Schema::new(DbBackend::Postgres).create_table_from_entity(Entity);
+    /// assert!(stmt
+    ///     .to_string(PostgresQueryBuilder)
+    ///     .contains(r#""price" integer NOT NULL CHECK ("price" >= 0)"#));
+    /// ```
+    pub fn check<T>(mut self, expr: T) -> Self
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.check = Some(expr.into());
+        self
+    }
+
+    /// Get the `CHECK` constraint of the column, if any
+    pub fn get_check(&self) -> Option<&SimpleExpr> {
+        self.check.as_ref()
+    }
 }
 
 struct Text;
@@ -410,6 +885,27 @@ impl Iden for TextArray {
     }
 }
 
+fn to_tsvector(col: Expr) -> FunctionCall {
+    Func::cust(Alias::new("to_tsvector")).arg(col)
+}
+
+fn plainto_tsquery(terms: &str) -> FunctionCall {
+    Func::cust(Alias::new("plainto_tsquery")).arg(terms)
+}
+
+fn match_against(col: Expr, terms: &str) -> SimpleExpr {
+    Expr::cust_with_exprs("MATCH (?) AGAINST (?)", [col.into(), terms.into()])
+}
+
+/// Split a JSON path like `$.tags[0].name` into its keys: `tags`, `0` and `name`
+fn json_path_keys(path: &str) -> Vec<String> {
+    let path = path.strip_prefix('$').unwrap_or(path);
+    path.split(['.', '[', ']'])
+        .filter(|key| !key.is_empty())
+        .map(ToOwned::to_owned)
+        .collect()
+}
+
 fn cast_enum_as<C, F>(expr: Expr, col: &C, f: F) -> SimpleExpr
 where
     C: ColumnTrait,
@@ -448,6 +944,18 @@ where
                 _ => expr,
             }
         }
+        // Ranges and hstores are sent and read in their text form, just like enums
+        ColumnType::Custom(name)
+            if crate::is_pg_range_type(&name.to_string())
+                || crate::is_pg_hstore_type(&name.to_string()) =>
+        {
+            f(expr, SeaRc::clone(name), col_type)
+        }
+        // So are PostGIS geometries
+        #[cfg(feature = "postgis")]
+        ColumnType::Custom(name) if crate::is_postgis_type(&name.to_string()) => {
+            f(expr, SeaRc::clone(name), col_type)
+        }
         _ => match col_type.get_enum_name() {
             Some(enum_name) => f(expr, SeaRc::clone(enum_name), col_type),
             None => expr.into(),
@@ -458,7 +966,8 @@ where
 #[cfg(test)]
 mod tests {
     use crate::{
-        tests_cfg::*, ColumnTrait, Condition, DbBackend, EntityTrait, QueryFilter, QueryTrait,
+        tests_cfg::*, ColumnTrait, Condition, DbBackend, EntityTrait, QueryFilter, QuerySelect,
+        QueryTrait,
     };
     use sea_query::Query;
 
@@ -510,6 +1019,261 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_in_subquery_select() {
+        assert_eq!(
+            cake::Entity::find()
+                .filter(
+                    cake::Column::Id.in_subquery(
+                        cake_filling::Entity::find()
+                            .select_only()
+                            .column(cake_filling::Column::CakeId)
+                    )
+                )
+                .filter(
+                    cake::Column::Id.not_in_subquery(
+                        fruit::Entity::find()
+                            .select_only()
+                            .column(fruit::Column::CakeId)
+                            .filter(fruit::Column::Name.contains("rotten"))
+                    )
+                )
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "SELECT `cake`.`id`, `cake`.`name` FROM `cake`",
+                "WHERE `cake`.`id` IN (SELECT `cake_filling`.`cake_id` FROM `cake_filling`)",
+                "AND `cake`.`id` NOT IN (SELECT `fruit`.`cake_id` FROM `fruit` WHERE `fruit`.`name` LIKE '%rotten%')",
+            ]
+            .join(" ")
+        );
+    }
+
+    #[smol_potat::test]
+    #[cfg(feature = "mock")]
+    async fn test_in_subquery_round_trip() -> Result<(), crate::DbErr> {
+        use crate::{MockDatabase, Transaction};
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[cake::Model {
+                id: 1,
+                name: "Cheese Cake".to_owned(),
+            }]])
+            .into_connection();
+
+        cake::Entity::find()
+            .filter(
+                cake::Column::Id.in_subquery(
+                    cake_filling::Entity::find()
+                        .select_only()
+                        .column(cake_filling::Column::CakeId),
+                ),
+            )
+            .all(&db)
+            .await?;
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+                    r#"WHERE "cake"."id" IN (SELECT "cake_filling"."cake_id" FROM "cake_filling")"#,
+                ]
+                .join(" "),
+                []
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(all(feature = "macros", feature = "with-json"))]
+    fn json_path_1() {
+        use serde_json::json;
+
+        mod document {
+            use crate as sea_orm;
+            use crate::entity::prelude::*;
+
+            #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+            #[sea_orm(table_name = "document")]
+            pub struct Model {
+                #[sea_orm(primary_key)]
+                pub id: i32,
+                #[sea_orm(column_type = "JsonBinary")]
+                pub data: Json,
+                pub meta: Json,
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+            pub enum Relation {}
+
+            impl ActiveModelBehavior for ActiveModel {}
+        }
+        use document::*;
+
+        let find_city = |db_backend| {
+            Entity::find()
+                .filter(
+                    Column::Data
+                        .json_path(db_backend, "$.address.city")
+                        .eq("NYC"),
+                )
+                .build(db_backend)
+                .to_string()
+        };
+        assert_eq!(
+            find_city(DbBackend::Postgres),
+            [
+                r#"SELECT "document"."id", "document"."data", "document"."meta" FROM "document""#,
+                r#"WHERE jsonb_extract_path_text("document"."data", 'address', 'city') = 'NYC'"#,
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            find_city(DbBackend::MySql),
+            [
+                "SELECT `document`.`id`, `document`.`data`, `document`.`meta` FROM `document`",
+                "WHERE JSON_UNQUOTE(JSON_EXTRACT(`document`.`data`, '$.address.city')) = 'NYC'",
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            find_city(DbBackend::Sqlite),
+            [
+                r#"SELECT "document"."id", "document"."data", "document"."meta" FROM "document""#,
+                r#"WHERE json_extract("document"."data", '$.address.city') = 'NYC'"#,
+            ]
+            .join(" ")
+        );
+
+        assert_eq!(
+            Entity::find()
+                .filter(
+                    Column::Meta
+                        .json_path(DbBackend::Postgres, "$.tags[1]")
+                        .is_not_null()
+                )
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT "document"."id", "document"."data", "document"."meta" FROM "document""#,
+                r#"WHERE json_extract_path_text("document"."meta", 'tags', '1') IS NOT NULL"#,
+            ]
+            .join(" ")
+        );
+
+        assert_eq!(
+            Entity::find()
+                .filter(Column::Data.json_contains(json!({ "tags": ["new"] })))
+                .filter(Column::Meta.json_contains(json!({ "draft": false })))
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT "document"."id", "document"."data", "document"."meta" FROM "document""#,
+                r#"WHERE "document"."data" @> E'{\"tags\":[\"new\"]}'"#,
+                r#"AND CAST("document"."meta" AS jsonb) @> E'{\"draft\":false}'"#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "macros")]
+    fn range_contains_1() {
+        use crate::{ActiveModelTrait, ColumnType, IntoActiveModel, PgRange, Update};
+        use sea_query::{Alias, IntoIden};
+
+        mod availability {
+            use crate as sea_orm;
+            use crate::entity::prelude::*;
+
+            #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+            #[sea_orm(table_name = "availability")]
+            pub struct Model {
+                #[sea_orm(primary_key)]
+                pub id: i32,
+                pub slot: PgRange<i32>,
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+            pub enum Relation {}
+
+            impl ActiveModelBehavior for ActiveModel {}
+        }
+        use availability::*;
+
+        assert_eq!(
+            Column::Slot.def().get_column_type(),
+            &ColumnType::Custom(Alias::new("int4range").into_iden())
+        );
+
+        assert_eq!(
+            Entity::find()
+                .filter(Column::Slot.range_contains(5))
+                .filter(Column::Slot.range_contains(PgRange::from(3..4)))
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT "availability"."id", CAST("availability"."slot" AS text) FROM "availability""#,
+                r#"WHERE "availability"."slot" @> 5"#,
+                r#"AND "availability"."slot" @> (CAST('[3,4)' AS int4range))"#,
+            ]
+            .join(" ")
+        );
+
+        let mut model = Model {
+            id: 1,
+            slot: (1..10).into(),
+        }
+        .into_active_model();
+        model.reset(Column::Slot);
+        assert_eq!(
+            Update::one(model).build(DbBackend::Postgres).to_string(),
+            r#"UPDATE "availability" SET "slot" = CAST('[1,10)' AS int4range) WHERE "availability"."id" = 1"#,
+        );
+    }
+
+    #[test]
+    fn matches_1() {
+        use crate::QuerySelect;
+
+        let search = |db_backend| {
+            cake::Entity::find()
+                .column_as(cake::Column::Name.match_rank(db_backend, "cheese"), "rank")
+                .filter(cake::Column::Name.matches(db_backend, "cheese"))
+                .build(db_backend)
+                .to_string()
+        };
+        assert_eq!(
+            search(DbBackend::Postgres),
+            [
+                r#"SELECT "cake"."id", "cake"."name","#,
+                r#"CAST(ts_rank(to_tsvector("cake"."name"), plainto_tsquery('cheese')) AS double precision) AS "rank""#,
+                r#"FROM "cake" WHERE to_tsvector("cake"."name") @@ plainto_tsquery('cheese')"#,
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            search(DbBackend::MySql),
+            [
+                "SELECT `cake`.`id`, `cake`.`name`, MATCH (`cake`.`name`) AGAINST ('cheese') AS `rank`",
+                "FROM `cake` WHERE MATCH (`cake`.`name`) AGAINST ('cheese')",
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            search(DbBackend::Sqlite),
+            [
+                r#"SELECT "cake"."id", "cake"."name", bm25("cake") * -1 AS "rank""#,
+                r#"FROM "cake" WHERE "cake"."name" MATCH 'cheese'"#,
+            ]
+            .join(" ")
+        );
+    }
+
     #[test]
     fn test_col_from_str() {
         use std::str::FromStr;
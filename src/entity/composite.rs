@@ -0,0 +1,246 @@
+use crate::{error::type_err, DbErr};
+
+/// A Rust struct mapped to a Postgres composite type, created with `CREATE TYPE .. AS (..)`.
+/// It should be derived with [DeriveCompositeType](crate::DeriveCompositeType).
+///
+/// The value is sent to, and read from the database in its text form, like `(1,"Main Street")`.
+/// The column has to be cast accordingly, with the `select_as = "text"` and
+/// `save_as = "<type name>"` attributes on the field of the Model.
+pub trait PgComposite: Sized {
+    /// The name of the composite type
+    fn composite_type() -> &'static str;
+
+    /// The text form of the fields, in the order of the composite type; `None` is `NULL`
+    fn to_composite_fields(&self) -> Vec<Option<String>>;
+
+    /// Build a value from the text form of its fields
+    fn from_composite_fields(fields: Vec<Option<String>>) -> Result<Self, DbErr>;
+
+    /// The text form of the value, like `(1,"Main Street")`
+    fn to_pg_composite(&self) -> String {
+        format_pg_composite(self.to_composite_fields())
+    }
+
+    /// Parse a value from its text form
+    fn from_pg_composite(s: &str) -> Result<Self, DbErr> {
+        Self::from_composite_fields(parse_pg_composite(s)?)
+    }
+}
+
+/// A type which values can be the fields of a [PgComposite]
+pub trait PgCompositeField: Sized {
+    /// The text form of the field; `None` is `NULL`
+    fn to_composite_field(&self) -> Option<String>;
+
+    /// Parse the field from its text form
+    fn from_composite_field(field: Option<&str>) -> Result<Self, DbErr>;
+}
+
+/// Format the fields of a composite value, quoting them where needed
+pub fn format_pg_composite(fields: Vec<Option<String>>) -> String {
+    let mut s = String::from("(");
+    for (i, field) in fields.into_iter().enumerate() {
+        if i > 0 {
+            s.push(',');
+        }
+        let field = match field {
+            Some(field) => field,
+            None => continue,
+        };
+        let quote = field.is_empty()
+            || field
+                .chars()
+                .any(|c| matches!(c, '"' | '\\' | '(' | ')' | ',') || c.is_whitespace());
+        if !quote {
+            s.push_str(&field);
+            continue;
+        }
+        s.push('"');
+        for c in field.chars() {
+            if matches!(c, '"' | '\\') {
+                s.push(c);
+            }
+            s.push(c);
+        }
+        s.push('"');
+    }
+    s.push(')');
+    s
+}
+
+/// Parse the fields of a composite value; an empty unquoted field is `NULL`
+pub fn parse_pg_composite(s: &str) -> Result<Vec<Option<String>>, DbErr> {
+    let err = || type_err(format!("Invalid composite value: {s}"));
+    let inner = s
+        .trim()
+        .strip_prefix('(')
+        .and_then(|s| s.strip_suffix(')'))
+        .ok_or_else(err)?;
+
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut in_quotes = false;
+    let mut chars = inner.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => field.push(chars.next().ok_or_else(err)?),
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            '"' => {
+                quoted = true;
+                in_quotes = !in_quotes;
+            }
+            ',' if !in_quotes => {
+                let field = std::mem::take(&mut field);
+                fields.push((quoted || !field.is_empty()).then_some(field));
+                quoted = false;
+            }
+            c => field.push(c),
+        }
+    }
+    if in_quotes {
+        return Err(err());
+    }
+    fields.push((quoted || !field.is_empty()).then_some(field));
+
+    Ok(fields)
+}
+
+fn parse_field<T>(field: Option<&str>) -> Result<T, DbErr>
+where
+    T: std::str::FromStr,
+{
+    let field = field.ok_or_else(|| {
+        type_err(format!(
+            "A null value was encountered while decoding a composite field of {}",
+            std::any::type_name::<T>()
+        ))
+    })?;
+    field.parse().map_err(|_| {
+        type_err(format!(
+            "Invalid composite field of {}: {field}",
+            std::any::type_name::<T>()
+        ))
+    })
+}
+
+impl<T> PgCompositeField for Option<T>
+where
+    T: PgCompositeField,
+{
+    fn to_composite_field(&self) -> Option<String> {
+        self.as_ref().and_then(T::to_composite_field)
+    }
+
+    fn from_composite_field(field: Option<&str>) -> Result<Self, DbErr> {
+        field
+            .map(|field| T::from_composite_field(Some(field)))
+            .transpose()
+    }
+}
+
+impl PgCompositeField for bool {
+    fn to_composite_field(&self) -> Option<String> {
+        Some(if *self { "t" } else { "f" }.to_owned())
+    }
+
+    fn from_composite_field(field: Option<&str>) -> Result<Self, DbErr> {
+        match field {
+            Some("t" | "true") => Ok(true),
+            Some("f" | "false") => Ok(false),
+            field => parse_field(field),
+        }
+    }
+}
+
+macro_rules! impl_pg_composite_field {
+    ( $type: ty ) => {
+        impl PgCompositeField for $type {
+            fn to_composite_field(&self) -> Option<String> {
+                Some(self.to_string())
+            }
+
+            fn from_composite_field(field: Option<&str>) -> Result<Self, DbErr> {
+                parse_field(field)
+            }
+        }
+    };
+}
+
+impl_pg_composite_field!(String);
+impl_pg_composite_field!(i16);
+impl_pg_composite_field!(i32);
+impl_pg_composite_field!(i64);
+impl_pg_composite_field!(f32);
+impl_pg_composite_field!(f64);
+
+#[cfg(feature = "with-rust_decimal")]
+impl_pg_composite_field!(rust_decimal::Decimal);
+
+#[cfg(feature = "with-uuid")]
+impl_pg_composite_field!(uuid::Uuid);
+
+#[cfg(feature = "with-chrono")]
+impl_pg_composite_field!(chrono::NaiveDate);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn format_composite() {
+        assert_eq!(
+            format_pg_composite(vec![Some("1".to_owned()), Some("Main".to_owned())]),
+            "(1,Main)"
+        );
+        assert_eq!(
+            format_pg_composite(vec![
+                Some("Main Street".to_owned()),
+                None,
+                Some(String::new()),
+                Some(r#"say "hi", \o/"#.to_owned()),
+            ]),
+            r#"("Main Street",,"","say ""hi"", \\o/")"#
+        );
+        assert_eq!(format_pg_composite(vec![None]), "()");
+    }
+
+    #[test]
+    fn parse_composite() -> Result<(), DbErr> {
+        assert_eq!(
+            parse_pg_composite("(1,Main)")?,
+            [Some("1".to_owned()), Some("Main".to_owned())]
+        );
+        assert_eq!(
+            parse_pg_composite(r#"("Main Street",,"","say ""hi"", \\o/")"#)?,
+            [
+                Some("Main Street".to_owned()),
+                None,
+                Some(String::new()),
+                Some(r#"say "hi", \o/"#.to_owned()),
+            ]
+        );
+        assert_eq!(parse_pg_composite("()")?, [None]);
+        assert!(parse_pg_composite("1,2").is_err());
+        assert!(parse_pg_composite(r#"("open)"#).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn composite_field() -> Result<(), DbErr> {
+        assert_eq!(true.to_composite_field(), Some("t".to_owned()));
+        assert!(bool::from_composite_field(Some("t"))?);
+        assert_eq!(i32::from_composite_field(Some("42"))?, 42);
+        assert!(i32::from_composite_field(None).is_err());
+        assert!(i32::from_composite_field(Some("x")).is_err());
+        assert_eq!(Option::<i32>::from_composite_field(None)?, None);
+        assert_eq!(None::<String>.to_composite_field(), None);
+
+        Ok(())
+    }
+}
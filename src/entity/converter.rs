@@ -0,0 +1,78 @@
+use crate::{DbErr, TryGetable};
+use sea_query::{Value, ValueType};
+
+/// Converts the value of a Model field to and from the value stored in the database,
+/// like text encrypted at rest or a compressed blob.
+///
+/// It is set on the field with `#[sea_orm(with = "path::to::Converter")]`, and the derived
+/// Model and ActiveModel then read, get and set the column through it: the Model and
+/// ActiveModel hold the values of [ModelValue](ValueConverter::ModelValue), while
+/// [ModelTrait::get](crate::ModelTrait::get), [ActiveModelTrait::get](crate::ActiveModelTrait::get)
+/// and what is sent to the database are the values of [DbValue](ValueConverter::DbValue).
+/// Unless `column_type` is given, the type of the column is that of `DbValue`.
+///
+/// The values in conditions, like `Column::Secret.eq(..)`, are compared as they are stored,
+/// so they have to be converted with [to_db](ValueConverter::to_db) first.
+///
+/// ```
+/// use sea_orm::entity::prelude::*;
+///
+/// /// Stores the text with its letters rotated by 13 places
+/// pub struct Rot13;
+///
+/// fn rot13(s: String) -> String {
+///     s.chars()
+///         .map(|c| match c {
+///             'a'..='m' | 'A'..='M' => (c as u8 + 13) as char,
+///             'n'..='z' | 'N'..='Z' => (c as u8 - 13) as char,
+///             c => c,
+///         })
+///         .collect()
+/// }
+///
+/// impl ValueConverter for Rot13 {
+///     type ModelValue = String;
+///     type DbValue = String;
+///
+///     fn to_db(value: String) -> String {
+///         rot13(value)
+///     }
+///
+///     fn from_db(value: String) -> Result<String, DbErr> {
+///         Ok(rot13(value))
+///     }
+/// }
+///
+/// #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+/// #[sea_orm(table_name = "account")]
+/// pub struct Model {
+///     #[sea_orm(primary_key)]
+///     pub id: i32,
+///     #[sea_orm(with = "Rot13")]
+///     pub password_hint: String,
+/// }
+///
+/// # #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// # pub enum Relation {}
+/// #
+/// # impl ActiveModelBehavior for ActiveModel {}
+/// #
+/// let model = Model {
+///     id: 1,
+///     password_hint: "Hello".to_owned(),
+/// };
+/// assert_eq!(model.get(Column::PasswordHint), "Uryyb".into());
+/// ```
+pub trait ValueConverter {
+    /// The type of the Model field
+    type ModelValue: Into<Value>;
+
+    /// The type of the value stored in the database
+    type DbValue: TryGetable + ValueType + Into<Value>;
+
+    /// Convert the value of the field into the value to store
+    fn to_db(value: Self::ModelValue) -> Self::DbValue;
+
+    /// Convert the stored value into the value of the field
+    fn from_db(value: Self::DbValue) -> Result<Self::ModelValue, DbErr>;
+}
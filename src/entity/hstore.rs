@@ -0,0 +1,228 @@
+use crate::{error::type_err, ColIdx, ColumnType, DbErr, QueryResult, TryGetError, TryGetable};
+use sea_query::{Alias, ArrayType, IntoIden, Nullable, Value, ValueType, ValueTypeErr};
+use std::{collections::HashMap, fmt, iter::Peekable, str::Chars, str::FromStr};
+
+/// A value of the Postgres `hstore` type, a map of string keys to string values, which may be `NULL`
+///
+/// The column type is `ColumnType::Custom`, named `hstore`, and the `hstore` extension has to be
+/// created in the database. The map is sent to, and read from the database in its text form,
+/// like `"a"=>"1", "b"=>NULL`: values are cast to `hstore` and columns are selected as `text`.
+///
+/// Keys can be checked with [ColumnTrait::hstore_contains_key](crate::ColumnTrait::hstore_contains_key),
+/// and values looked up with [ColumnTrait::hstore_get](crate::ColumnTrait::hstore_get).
+///
+/// ```
+/// use sea_orm::PgHstore;
+///
+/// let hstore = PgHstore::from_iter([("a", Some("1")), ("b", None)]);
+/// assert_eq!(hstore.to_string(), r#""a"=>"1", "b"=>NULL"#);
+/// assert_eq!(r#""a"=>"1", "b"=>NULL"#.parse::<PgHstore>().unwrap(), hstore);
+/// assert_eq!(hstore.0["b"], None);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PgHstore(pub HashMap<String, Option<String>>);
+
+impl From<HashMap<String, Option<String>>> for PgHstore {
+    fn from(map: HashMap<String, Option<String>>) -> Self {
+        Self(map)
+    }
+}
+
+impl From<PgHstore> for HashMap<String, Option<String>> {
+    fn from(hstore: PgHstore) -> Self {
+        hstore.0
+    }
+}
+
+impl<K, V> FromIterator<(K, Option<V>)> for PgHstore
+where
+    K: Into<String>,
+    V: Into<String>,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (K, Option<V>)>,
+    {
+        Self(
+            iter.into_iter()
+                .map(|(key, value)| (key.into(), value.map(Into::into)))
+                .collect(),
+        )
+    }
+}
+
+impl fmt::Display for PgHstore {
+    /// The text form of the map, with the keys in order
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut entries: Vec<_> = self.0.iter().collect();
+        entries.sort();
+        for (i, (key, value)) in entries.into_iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write_quoted(f, key)?;
+            write!(f, "=>")?;
+            match value {
+                Some(value) => write_quoted(f, value)?,
+                None => write!(f, "NULL")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+fn write_quoted(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    write!(f, "\"")?;
+    for c in s.chars() {
+        if matches!(c, '"' | '\\') {
+            write!(f, "\\")?;
+        }
+        write!(f, "{c}")?;
+    }
+    write!(f, "\"")
+}
+
+impl FromStr for PgHstore {
+    type Err = DbErr;
+
+    /// Parse a map from its text form, with quoted or unquoted keys and values
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || type_err(format!("Invalid hstore: {s}"));
+        let mut map = HashMap::new();
+        let mut chars = s.chars().peekable();
+        loop {
+            skip_whitespace(&mut chars);
+            if chars.peek().is_none() {
+                break;
+            }
+            let (key, _) = parse_token(&mut chars).ok_or_else(err)?;
+            skip_whitespace(&mut chars);
+            if (chars.next(), chars.next()) != (Some('='), Some('>')) {
+                return Err(err());
+            }
+            skip_whitespace(&mut chars);
+            let value = match parse_token(&mut chars).ok_or_else(err)? {
+                (value, false) if value.eq_ignore_ascii_case("NULL") => None,
+                (value, _) => Some(value),
+            };
+            map.insert(key, value);
+            skip_whitespace(&mut chars);
+            match chars.next() {
+                Some(',') => continue,
+                None => break,
+                Some(_) => return Err(err()),
+            }
+        }
+        Ok(Self(map))
+    }
+}
+
+fn skip_whitespace(chars: &mut Peekable<Chars>) {
+    while chars.next_if(|c| c.is_whitespace()).is_some() {}
+}
+
+/// Parse a key or a value, and whether it was quoted
+fn parse_token(chars: &mut Peekable<Chars>) -> Option<(String, bool)> {
+    let mut token = String::new();
+    if chars.next_if_eq(&'"').is_some() {
+        loop {
+            match chars.next()? {
+                '"' => return Some((token, true)),
+                '\\' => token.push(chars.next()?),
+                c => token.push(c),
+            }
+        }
+    }
+    while let Some(c) = chars.next_if(|c| !c.is_whitespace() && !matches!(c, '=' | ',' | '"')) {
+        if c == '\\' {
+            token.push(chars.next()?);
+        } else {
+            token.push(c);
+        }
+    }
+    (!token.is_empty()).then_some((token, false))
+}
+
+impl From<PgHstore> for Value {
+    fn from(hstore: PgHstore) -> Self {
+        Value::String(Some(Box::new(hstore.to_string())))
+    }
+}
+
+impl Nullable for PgHstore {
+    fn null() -> Value {
+        Value::String(None)
+    }
+}
+
+impl ValueType for PgHstore {
+    fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+        match v {
+            Value::String(Some(s)) => s.parse().map_err(|_| ValueTypeErr),
+            _ => Err(ValueTypeErr),
+        }
+    }
+
+    fn type_name() -> String {
+        stringify!(PgHstore).to_owned()
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::String
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::Custom(Alias::new("hstore").into_iden())
+    }
+}
+
+impl TryGetable for PgHstore {
+    fn try_get_by<I: ColIdx>(res: &QueryResult, index: I) -> Result<Self, TryGetError> {
+        let s = String::try_get_by(res, index)?;
+        s.parse().map_err(TryGetError::DbErr)
+    }
+}
+
+pub(crate) fn is_pg_hstore_type(name: &str) -> bool {
+    name == "hstore"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn format_hstore() {
+        assert_eq!(PgHstore::default().to_string(), "");
+        assert_eq!(
+            PgHstore::from_iter([("b", None), ("a", Some("1"))]).to_string(),
+            r#""a"=>"1", "b"=>NULL"#
+        );
+        assert_eq!(
+            PgHstore::from_iter([(r#"say "hi""#, Some(r"C:\temp"))]).to_string(),
+            r#""say \"hi\""=>"C:\\temp""#
+        );
+    }
+
+    #[test]
+    fn parse_hstore() {
+        assert_eq!("".parse::<PgHstore>().unwrap(), PgHstore::default());
+        assert_eq!(
+            r#""a"=>"1", "b"=>NULL, "c"=>"NULL""#.parse::<PgHstore>().unwrap(),
+            PgHstore::from_iter([("a", Some("1")), ("b", None), ("c", Some("NULL"))])
+        );
+        assert_eq!(
+            "a=>1,b => null".parse::<PgHstore>().unwrap(),
+            PgHstore::from_iter([("a", Some("1")), ("b", None::<&str>)])
+        );
+        assert_eq!(
+            r#""say \"hi\""=>"C:\\temp""#.parse::<PgHstore>().unwrap(),
+            PgHstore::from_iter([(r#"say "hi""#, Some(r"C:\temp"))])
+        );
+        assert!("a".parse::<PgHstore>().is_err());
+        assert!("a=>".parse::<PgHstore>().is_err());
+        assert!(r#""a"=>"1"#.parse::<PgHstore>().is_err());
+        assert!("a=>1 b=>2".parse::<PgHstore>().is_err());
+    }
+}
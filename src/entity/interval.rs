@@ -0,0 +1,256 @@
+use crate::{error::type_err, ColumnType, DbErr};
+use sea_query::{ArrayType, Nullable, Value, ValueType, ValueTypeErr};
+use std::{fmt, str::FromStr, time::Duration};
+
+const MICROSECONDS_PER_SECOND: i64 = 1_000_000;
+
+/// A value of the Postgres `interval` type
+///
+/// Like Postgres, it keeps months, days and microseconds apart, as a month has no fixed number
+/// of days and a day has no fixed number of seconds across daylight saving time changes:
+/// `1 mon` and `30 days` are different intervals.
+///
+/// It can be converted from and into a [Duration], as long as it has no months and
+/// is not negative. Days count as 24 hours.
+///
+/// The interval is sent to the database in its text form, like `1 months 2 days 3 microseconds`.
+/// The value of a column of type [ColumnType::Interval] is cast to `interval` by
+/// [ColumnTrait::save_as](crate::ColumnTrait::save_as), when it is inserted, updated or compared.
+///
+/// ```
+/// use sea_orm::PgInterval;
+/// use std::time::Duration;
+///
+/// let interval = PgInterval::try_from(Duration::from_secs(90)).unwrap();
+/// assert_eq!(interval.microseconds, 90_000_000);
+/// assert_eq!(Duration::try_from(interval).unwrap(), Duration::from_secs(90));
+///
+/// let interval = PgInterval::new(1, 0, 0);
+/// assert!(Duration::try_from(interval).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PgInterval {
+    /// The number of months
+    pub months: i32,
+    /// The number of days
+    pub days: i32,
+    /// The number of microseconds
+    pub microseconds: i64,
+}
+
+impl PgInterval {
+    /// Create an interval from its components
+    pub fn new(months: i32, days: i32, microseconds: i64) -> Self {
+        Self {
+            months,
+            days,
+            microseconds,
+        }
+    }
+}
+
+impl TryFrom<Duration> for PgInterval {
+    type Error = DbErr;
+
+    fn try_from(duration: Duration) -> Result<Self, Self::Error> {
+        if duration.subsec_nanos() % 1_000 != 0 {
+            return Err(type_err(format!(
+                "{duration:?} is more precise than the microseconds of an interval"
+            )));
+        }
+        let microseconds = <i64 as TryFrom<_>>::try_from(duration.as_micros())
+            .map_err(|_| type_err(format!("{duration:?} is too long for an interval")))?;
+        Ok(Self::new(0, 0, microseconds))
+    }
+}
+
+impl TryFrom<PgInterval> for Duration {
+    type Error = DbErr;
+
+    fn try_from(interval: PgInterval) -> Result<Self, Self::Error> {
+        if interval.months != 0 {
+            return Err(type_err(format!(
+                "{interval} has months, which have no fixed length"
+            )));
+        }
+        let microseconds = i64::from(interval.days)
+            .checked_mul(24 * 60 * 60 * MICROSECONDS_PER_SECOND)
+            .and_then(|days| days.checked_add(interval.microseconds))
+            .ok_or_else(|| type_err(format!("{interval} is too long for a duration")))?;
+        let microseconds = <u64 as TryFrom<_>>::try_from(microseconds)
+            .map_err(|_| type_err(format!("{interval} is negative")))?;
+        Ok(Duration::from_micros(microseconds))
+    }
+}
+
+impl fmt::Display for PgInterval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} months {} days {} microseconds",
+            self.months, self.days, self.microseconds
+        )
+    }
+}
+
+impl FromStr for PgInterval {
+    type Err = DbErr;
+
+    /// Parse an interval from quantities and units, like `1 months 2 days 3 microseconds`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || type_err(format!("Invalid interval: {s}"));
+        let mut interval = Self::default();
+        let mut words = s.split_whitespace();
+        while let Some(quantity) = words.next() {
+            let unit = words.next().ok_or_else(err)?;
+            match unit {
+                "month" | "months" => interval.months = quantity.parse().map_err(|_| err())?,
+                "day" | "days" => interval.days = quantity.parse().map_err(|_| err())?,
+                "microsecond" | "microseconds" => {
+                    interval.microseconds = quantity.parse().map_err(|_| err())?
+                }
+                _ => return Err(err()),
+            }
+        }
+        Ok(interval)
+    }
+}
+
+#[cfg(feature = "sqlx-postgres")]
+impl From<sqlx::postgres::types::PgInterval> for PgInterval {
+    fn from(interval: sqlx::postgres::types::PgInterval) -> Self {
+        Self::new(interval.months, interval.days, interval.microseconds)
+    }
+}
+
+#[cfg(feature = "sqlx-postgres")]
+impl From<PgInterval> for sqlx::postgres::types::PgInterval {
+    fn from(interval: PgInterval) -> Self {
+        Self {
+            months: interval.months,
+            days: interval.days,
+            microseconds: interval.microseconds,
+        }
+    }
+}
+
+impl From<PgInterval> for Value {
+    fn from(interval: PgInterval) -> Self {
+        Value::String(Some(Box::new(interval.to_string())))
+    }
+}
+
+impl Nullable for PgInterval {
+    fn null() -> Value {
+        Value::String(None)
+    }
+}
+
+impl ValueType for PgInterval {
+    fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+        match v {
+            Value::String(Some(s)) => s.parse().map_err(|_| ValueTypeErr),
+            _ => Err(ValueTypeErr),
+        }
+    }
+
+    fn type_name() -> String {
+        stringify!(PgInterval).to_owned()
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::String
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::Interval(None, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn interval_text() {
+        let interval = PgInterval::new(14, -3, 4_500_000);
+        assert_eq!(
+            interval.to_string(),
+            "14 months -3 days 4500000 microseconds"
+        );
+        assert_eq!(
+            interval.to_string().parse::<PgInterval>().unwrap(),
+            interval
+        );
+        assert_eq!(
+            "1 day".parse::<PgInterval>().unwrap(),
+            PgInterval::new(0, 1, 0)
+        );
+        assert!("1 year".parse::<PgInterval>().is_err());
+        assert!("1".parse::<PgInterval>().is_err());
+    }
+
+    #[test]
+    fn interval_duration() {
+        let interval: Result<PgInterval, _> = Duration::from_millis(1500).try_into();
+        assert_eq!(interval.unwrap(), PgInterval::new(0, 0, 1_500_000));
+        let interval: Result<PgInterval, _> = Duration::from_nanos(1).try_into();
+        assert!(interval.is_err());
+        let interval: Result<PgInterval, _> = Duration::from_secs(u64::MAX).try_into();
+        assert!(interval.is_err());
+
+        assert_eq!(
+            Duration::try_from(PgInterval::new(0, 2, 30_000_000)).unwrap(),
+            Duration::from_secs(2 * 24 * 60 * 60 + 30)
+        );
+        assert!(Duration::try_from(PgInterval::new(1, 0, 0)).is_err());
+        assert!(Duration::try_from(PgInterval::new(0, -1, 0)).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "macros")]
+    fn interval_cast() {
+        use crate::{entity::*, query::*, DbBackend};
+
+        mod reminder {
+            use crate as sea_orm;
+            use crate::entity::prelude::*;
+
+            #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+            #[sea_orm(table_name = "reminder")]
+            pub struct Model {
+                #[sea_orm(primary_key)]
+                pub id: i32,
+                pub every: PgInterval,
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+            pub enum Relation {}
+
+            impl ActiveModelBehavior for ActiveModel {}
+        }
+
+        let every = PgInterval::new(0, 7, 0);
+        assert_eq!(
+            reminder::Entity::insert(reminder::ActiveModel {
+                id: ActiveValue::set(1),
+                every: ActiveValue::set(every),
+            })
+            .build(DbBackend::Postgres)
+            .to_string(),
+            r#"INSERT INTO "reminder" ("id", "every") VALUES (1, CAST('0 months 7 days 0 microseconds' AS interval))"#,
+        );
+        assert_eq!(
+            reminder::Entity::find()
+                .filter(reminder::Column::Every.eq(every))
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT "reminder"."id", "reminder"."every" FROM "reminder""#,
+                r#"WHERE "reminder"."every" = CAST('0 months 7 days 0 microseconds' AS interval)"#,
+            ]
+            .join(" ")
+        );
+    }
+}
@@ -1,7 +1,9 @@
 use crate::{
-    join_tbl_on_condition, unpack_table_ref, EntityTrait, QuerySelect, RelationDef, Select,
+    join_tbl_on_condition, unpack_table_ref, EntityTrait, QuerySelect, RelationDef, RelationTrait,
+    Select,
 };
 use sea_query::{Alias, Condition, IntoIden, JoinType, SeaRc};
+use std::marker::PhantomData;
 
 /// Same as [RelationDef]
 pub type LinkDef = RelationDef;
@@ -19,7 +21,7 @@ pub trait Linked {
 
     /// Find all the Entities that are linked to the Entity
     fn find_linked(&self) -> Select<Self::ToEntity> {
-        let mut select = Select::new();
+        let mut select = Self::ToEntity::find();
         for (i, mut rel) in self.link().into_iter().rev().enumerate() {
             let from_tbl = Alias::new(format!("r{i}")).into_iden();
             let to_tbl = if i > 0 {
@@ -46,3 +48,84 @@ pub trait Linked {
         select
     }
 }
+
+/// A link of an Entity to itself, through a self-referencing relation,
+/// so that there is no need to implement [Linked] by hand
+///
+/// `SelfReferencing::new(Relation::Parent)` follows the relation from a child to its parent,
+/// while its reverse, `SelfReferencing::new(Relation::Parent).rev()`, follows it from a parent
+/// to its children. The joined table is aliased, like any other link, so the columns of
+/// both sides of the self-join do not clash.
+///
+/// ```ignore
+/// #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+/// #[sea_orm(table_name = "category")]
+/// pub struct Model {
+///     #[sea_orm(primary_key)]
+///     pub id: i32,
+///     pub parent_id: Option<i32>,
+///     pub name: String,
+/// }
+///
+/// #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// pub enum Relation {
+///     #[sea_orm(belongs_to = "Entity", from = "Column::ParentId", to = "Column::Id")]
+///     Parent,
+/// }
+///
+/// // The parent of a category
+/// let parent: Option<Model> = category
+///     .find_linked(SelfReferencing::new(Relation::Parent))
+///     .one(db)
+///     .await?;
+///
+/// // The categories, each with its immediate children
+/// let categories: Vec<(Model, Vec<Model>)> = Entity::find()
+///     .find_with_linked(SelfReferencing::new(Relation::Parent).rev())
+///     .all(db)
+///     .await?;
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct SelfReferencing<E, R> {
+    rel: R,
+    reverse: bool,
+    entity: PhantomData<E>,
+}
+
+impl<E, R> SelfReferencing<E, R>
+where
+    E: EntityTrait<Relation = R>,
+    R: RelationTrait,
+{
+    /// Link an Entity to itself through a self-referencing relation
+    pub fn new(rel: R) -> Self {
+        Self {
+            rel,
+            reverse: false,
+            entity: PhantomData,
+        }
+    }
+
+    /// Follow the relation the other way around
+    pub fn rev(self) -> Self {
+        Self {
+            reverse: !self.reverse,
+            ..self
+        }
+    }
+}
+
+impl<E, R> Linked for SelfReferencing<E, R>
+where
+    E: EntityTrait<Relation = R>,
+    R: RelationTrait,
+{
+    type FromEntity = E;
+
+    type ToEntity = E;
+
+    fn link(&self) -> Vec<LinkDef> {
+        let rel = self.rel.def();
+        vec![if self.reverse { rel.rev() } else { rel }]
+    }
+}
@@ -100,23 +100,37 @@ mod active_enum;
 mod active_model;
 mod base_entity;
 mod column;
+mod composite;
+mod converter;
+mod hstore;
 mod identity;
+mod interval;
 mod link;
 mod model;
 mod partial_model;
+#[cfg(feature = "postgis")]
+mod postgis;
 /// Re-export common types from the entity
 pub mod prelude;
 mod primary_key;
+mod range;
 mod relation;
 
 pub use active_enum::*;
 pub use active_model::*;
 pub use base_entity::*;
 pub use column::*;
+pub use composite::*;
+pub use converter::*;
+pub use hstore::*;
 pub use identity::*;
+pub use interval::*;
 pub use link::*;
 pub use model::*;
 pub use partial_model::*;
+#[cfg(feature = "postgis")]
+pub use postgis::*;
 // pub use prelude::*;
 pub use primary_key::*;
+pub use range::*;
 pub use relation::*;
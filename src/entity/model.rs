@@ -1,7 +1,7 @@
 use crate::{
     ActiveModelBehavior, ActiveModelTrait, ConnectionTrait, DbErr, DeleteResult, EntityTrait,
-    IntoActiveModel, Linked, QueryFilter, QueryResult, Related, Select, SelectModel, SelectorRaw,
-    Statement,
+    EntityWritable, IntoActiveModel, Linked, QueryFilter, QueryResult, Related, Select,
+    SelectModel, SelectorRaw, Statement,
 };
 use async_trait::async_trait;
 pub use sea_query::Value;
@@ -17,8 +17,19 @@ pub trait ModelTrait: Clone + Send + Debug {
     fn get(&self, c: <Self::Entity as EntityTrait>::Column) -> Value;
 
     /// Set the [Value] of a column in an Entity
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value can not be converted into the field, see [try_set](Self::try_set).
     fn set(&mut self, c: <Self::Entity as EntityTrait>::Column, v: Value);
 
+    /// Set the [Value] of a column in an Entity, or fail with [DbErr::Type] if the value can not
+    /// be converted into the field, for instance by its [ValueConverter](crate::ValueConverter)
+    fn try_set(&mut self, c: <Self::Entity as EntityTrait>::Column, v: Value) -> Result<(), DbErr> {
+        self.set(c, v);
+        Ok(())
+    }
+
     /// Find related Models
     fn find_related<R>(&self, _: R) -> Select<R>
     where
@@ -41,6 +52,7 @@ pub trait ModelTrait: Clone + Send + Debug {
     async fn delete<'a, A, C>(self, db: &'a C) -> Result<DeleteResult, DbErr>
     where
         Self: IntoActiveModel<A>,
+        Self::Entity: EntityWritable,
         C: ConnectionTrait,
         A: ActiveModelTrait<Entity = Self::Entity> + ActiveModelBehavior + Send + 'a,
     {
@@ -0,0 +1,265 @@
+use crate::{error::type_err, ColIdx, ColumnType, DbErr, QueryResult, TryGetError, TryGetable};
+use sea_query::{Alias, ArrayType, IntoIden, Nullable, Value, ValueType, ValueTypeErr};
+use std::{fmt, str::FromStr};
+
+/// The flag of the geometry type in EWKB, telling that an SRID follows
+const EWKB_SRID_FLAG: u32 = 0x2000_0000;
+
+/// The geometry type of a point in WKB
+const WKB_POINT: u32 = 1;
+
+/// A point of a PostGIS `geometry` column, with an optional spatial reference system
+///
+/// The column type is `ColumnType::Custom`, named `geometry`; `geography` columns, and
+/// columns with a type modifier like `geometry(Point, 4326)`, are supported as well
+/// with an explicit `column_type`. The PostGIS extension has to be created in the database.
+///
+/// The point is sent to the database in its EWKT form, like `SRID=4326;POINT(2.35 48.85)`,
+/// and cast to the type of the column. The column is selected as `text`, that is in
+/// the hexadecimal form of its EWKB. Points with a Z or M coordinate are not supported.
+///
+/// Points within a distance can be found with [ColumnTrait::st_dwithin](crate::ColumnTrait::st_dwithin).
+///
+/// ```
+/// use sea_orm::PgPoint;
+///
+/// let point = PgPoint::new(2.35, 48.85).with_srid(4326);
+/// assert_eq!(point.to_string(), "SRID=4326;POINT(2.35 48.85)");
+/// assert_eq!("SRID=4326;POINT(2.35 48.85)".parse::<PgPoint>().unwrap(), point);
+/// assert_eq!(
+///     "0101000020E6100000CDCCCCCCCCCC0240CDCCCCCCCC6C4840"
+///         .parse::<PgPoint>()
+///         .unwrap(),
+///     point
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PgPoint {
+    /// The X coordinate, or the longitude
+    pub x: f64,
+    /// The Y coordinate, or the latitude
+    pub y: f64,
+    /// The identifier of the spatial reference system, like `4326` for WGS 84
+    pub srid: Option<i32>,
+}
+
+impl PgPoint {
+    /// Create a point from its coordinates, without a spatial reference system
+    pub fn new(x: f64, y: f64) -> Self {
+        Self { x, y, srid: None }
+    }
+
+    /// Set the spatial reference system of the point
+    pub fn with_srid(mut self, srid: i32) -> Self {
+        self.srid = Some(srid);
+        self
+    }
+}
+
+impl fmt::Display for PgPoint {
+    /// The EWKT form of the point, or its WKT form if it has no spatial reference system
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(srid) = self.srid {
+            write!(f, "SRID={srid};")?;
+        }
+        write!(f, "POINT({} {})", self.x, self.y)
+    }
+}
+
+impl FromStr for PgPoint {
+    type Err = DbErr;
+
+    /// Parse a point from its EWKT or WKT form, or the hexadecimal form of its EWKB or WKB
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let point = if s.bytes().all(|b| b.is_ascii_hexdigit()) {
+            parse_ewkb_hex(s)
+        } else {
+            parse_ewkt(s)
+        };
+        point.ok_or_else(|| type_err(format!("Invalid point: {s}")))
+    }
+}
+
+fn parse_ewkt(s: &str) -> Option<PgPoint> {
+    let (srid, wkt) = match s.split_once(';') {
+        Some((srid, wkt)) => (Some(srid.trim().strip_prefix("SRID=")?.parse().ok()?), wkt),
+        None => (None, s),
+    };
+    let wkt = wkt.trim();
+    if !wkt.get(..5)?.eq_ignore_ascii_case("POINT") {
+        return None;
+    }
+    let coordinates = wkt[5..].trim().strip_prefix('(')?.strip_suffix(')')?;
+    let mut coordinates = coordinates.split_whitespace();
+    let x = coordinates.next()?.parse().ok()?;
+    let y = coordinates.next()?.parse().ok()?;
+    if coordinates.next().is_some() {
+        return None;
+    }
+    Some(PgPoint { x, y, srid })
+}
+
+fn parse_ewkb_hex(s: &str) -> Option<PgPoint> {
+    if s.len() % 2 != 0 {
+        return None;
+    }
+    let bytes: Vec<u8> = (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).ok())
+        .collect::<Option<_>>()?;
+
+    let little_endian = match bytes.first()? {
+        0 => false,
+        1 => true,
+        _ => return None,
+    };
+    let mut rest = &bytes[1..];
+    let u32_of = |b: &[u8]| {
+        let b = b.try_into().ok()?;
+        Some(if little_endian {
+            u32::from_le_bytes(b)
+        } else {
+            u32::from_be_bytes(b)
+        })
+    };
+    let f64_of = |b: &[u8]| {
+        let b = b.try_into().ok()?;
+        Some(if little_endian {
+            f64::from_le_bytes(b)
+        } else {
+            f64::from_be_bytes(b)
+        })
+    };
+
+    let geometry_type = u32_of(take(&mut rest, 4)?)?;
+    // Any flag other than the SRID one, like those of the Z and M coordinates, is unsupported
+    if geometry_type & !EWKB_SRID_FLAG != WKB_POINT {
+        return None;
+    }
+    let srid = if geometry_type & EWKB_SRID_FLAG != 0 {
+        Some(u32_of(take(&mut rest, 4)?)? as i32)
+    } else {
+        None
+    };
+    let x = f64_of(take(&mut rest, 8)?)?;
+    let y = f64_of(take(&mut rest, 8)?)?;
+    if !rest.is_empty() {
+        return None;
+    }
+    Some(PgPoint { x, y, srid })
+}
+
+/// Split the first `n` bytes off
+fn take<'a>(rest: &mut &'a [u8], n: usize) -> Option<&'a [u8]> {
+    if rest.len() < n {
+        return None;
+    }
+    let (head, tail) = rest.split_at(n);
+    *rest = tail;
+    Some(head)
+}
+
+impl From<PgPoint> for Value {
+    fn from(point: PgPoint) -> Self {
+        Value::String(Some(Box::new(point.to_string())))
+    }
+}
+
+impl Nullable for PgPoint {
+    fn null() -> Value {
+        Value::String(None)
+    }
+}
+
+impl ValueType for PgPoint {
+    fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+        match v {
+            Value::String(Some(s)) => s.parse().map_err(|_| ValueTypeErr),
+            _ => Err(ValueTypeErr),
+        }
+    }
+
+    fn type_name() -> String {
+        stringify!(PgPoint).to_owned()
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::String
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::Custom(Alias::new("geometry").into_iden())
+    }
+}
+
+impl TryGetable for PgPoint {
+    fn try_get_by<I: ColIdx>(res: &QueryResult, index: I) -> Result<Self, TryGetError> {
+        let s = String::try_get_by(res, index)?;
+        s.parse().map_err(TryGetError::DbErr)
+    }
+}
+
+/// Whether the type is a PostGIS `geometry` or `geography`, with or without a type modifier
+pub(crate) fn is_postgis_type(name: &str) -> bool {
+    ["geometry", "geography"].iter().any(|ty| {
+        name.strip_prefix(ty)
+            .map_or(false, |rest| rest.is_empty() || rest.starts_with('('))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn format_point() {
+        assert_eq!(PgPoint::new(1.0, -2.5).to_string(), "POINT(1 -2.5)");
+        assert_eq!(
+            PgPoint::new(2.35, 48.85).with_srid(4326).to_string(),
+            "SRID=4326;POINT(2.35 48.85)"
+        );
+    }
+
+    #[test]
+    fn parse_point() {
+        assert_eq!("POINT(1 -2.5)".parse(), Ok(PgPoint::new(1.0, -2.5)));
+        assert_eq!(
+            "SRID=3857; point ( 1 2 )".parse(),
+            Ok(PgPoint::new(1.0, 2.0).with_srid(3857))
+        );
+        // WKB in big endian, without an SRID
+        assert_eq!(
+            "00000000013FF00000000000004000000000000000".parse(),
+            Ok(PgPoint::new(1.0, 2.0))
+        );
+        // EWKB in little endian, with an SRID
+        assert_eq!(
+            "0101000020E6100000000000000000F03F0000000000000040".parse(),
+            Ok(PgPoint::new(1.0, 2.0).with_srid(4326))
+        );
+        assert!("POINT(1)".parse::<PgPoint>().is_err());
+        assert!("POINT(1 2 3)".parse::<PgPoint>().is_err());
+        assert!("LINESTRING(0 0, 1 1)".parse::<PgPoint>().is_err());
+        assert!("SRID=x;POINT(1 2)".parse::<PgPoint>().is_err());
+        // A point with a Z coordinate
+        assert!(
+            "01010000A0E6100000000000000000F03F00000000000000400000000000000840"
+                .parse::<PgPoint>()
+                .is_err()
+        );
+        // A truncated EWKB
+        assert!("0101000020E6100000000000000000F03F"
+            .parse::<PgPoint>()
+            .is_err());
+    }
+
+    #[test]
+    fn postgis_type() {
+        assert!(is_postgis_type("geometry"));
+        assert!(is_postgis_type("geography(Point, 4326)"));
+        assert!(!is_postgis_type("geometry_columns"));
+        assert!(!is_postgis_type("text"));
+    }
+}
@@ -3,17 +3,18 @@ pub use crate::{
     sea_query::{DynIden, Expr, RcOrArc, SeaRc, StringLen},
     ActiveEnum, ActiveModelBehavior, ActiveModelTrait, ColumnDef, ColumnTrait, ColumnType,
     ColumnTypeTrait, ConnectionTrait, CursorTrait, DatabaseConnection, DbConn, EntityName,
-    EntityTrait, EnumIter, ForeignKeyAction, Iden, IdenStatic, Linked, LoaderTrait, ModelTrait,
-    PaginatorTrait, PrimaryKeyArity, PrimaryKeyToColumn, PrimaryKeyTrait, QueryFilter, QueryResult,
-    Related, RelationDef, RelationTrait, Select, Value,
+    EntityTrait, EntityWritable, EnumIter, ForeignKeyAction, Iden, IdenStatic, Linked, LoaderTrait, ModelTrait,
+    PaginatorTrait, PgHstore, PgInterval, PgRange, PrimaryKeyArity, PrimaryKeyToColumn,
+    PrimaryKeyTrait, QueryFilter, QueryResult, Related, RelationDef, RelationTrait, Select,
+    SelfReferencing, Value, ValueConverter,
 };
 
 #[cfg(feature = "macros")]
 pub use crate::{
     DeriveActiveEnum, DeriveActiveModel, DeriveActiveModelBehavior, DeriveColumn,
-    DeriveCustomColumn, DeriveDisplay, DeriveEntity, DeriveEntityModel, DeriveIden,
-    DeriveIntoActiveModel, DeriveModel, DerivePartialModel, DerivePrimaryKey, DeriveRelatedEntity,
-    DeriveRelation, DeriveValueType,
+    DeriveCompositeType, DeriveCustomColumn, DeriveDisplay, DeriveEntity, DeriveEntityModel,
+    DeriveIden, DeriveIntoActiveModel, DeriveModel, DerivePartialModel, DerivePrimaryKey,
+    DeriveRelatedEntity, DeriveRelation, DeriveValueType,
 };
 
 pub use async_trait;
@@ -83,3 +84,12 @@ pub use bigdecimal::BigDecimal;
 
 #[cfg(feature = "with-uuid")]
 pub use uuid::Uuid;
+
+#[cfg(feature = "with-ipnetwork")]
+pub use ipnetwork::IpNetwork;
+
+#[cfg(feature = "with-mac_address")]
+pub use mac_address::MacAddress;
+
+#[cfg(feature = "postgis")]
+pub use crate::PgPoint;
@@ -0,0 +1,354 @@
+use crate::{error::type_err, ColIdx, ColumnType, QueryResult, TryGetError, TryGetable};
+use sea_query::{Alias, ArrayType, IntoIden, Nullable, Value, ValueType, ValueTypeErr};
+use std::{fmt, ops::Bound, str::FromStr};
+
+/// The Postgres range types, like `int4range` or `tsrange`
+const PG_RANGE_TYPES: [&str; 6] = [
+    "int4range",
+    "int8range",
+    "numrange",
+    "tsrange",
+    "tstzrange",
+    "daterange",
+];
+
+/// A value of a Postgres range type, with its lower and upper bounds
+///
+/// It is the type of a Model field for a range column: `PgRange<i32>` for `int4range`,
+/// `PgRange<i64>` for `int8range`, and with the `with-chrono` feature, `PgRange<Date>`
+/// for `daterange`, `PgRange<DateTime>` for `tsrange` and `PgRange<DateTimeWithTimeZone>`
+/// for `tstzrange`. The column type is `ColumnType::Custom`, named after the range type.
+///
+/// The range is sent to, and read from the database in its text form, like `[1,10)`:
+/// values are cast to the range type and columns are selected as `text`.
+/// Postgres stores discrete ranges in their canonical form, so `[1,10]` is read back as `[1,11)`.
+/// Empty ranges have no bounds and can not be represented.
+///
+/// ```
+/// use sea_orm::PgRange;
+/// use std::ops::Bound;
+///
+/// let range = PgRange::from(1..10);
+/// assert_eq!(range.start, Bound::Included(1));
+/// assert_eq!(range.end, Bound::Excluded(10));
+/// assert_eq!(range.to_string(), "[1,10)");
+/// assert_eq!("[1,10)".parse::<PgRange<i32>>(), Ok(range));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PgRange<T> {
+    /// The lower bound
+    pub start: Bound<T>,
+    /// The upper bound
+    pub end: Bound<T>,
+}
+
+/// A type which values can be the bounds of a [PgRange]
+pub trait PgRangeElement: Sized {
+    /// The name of the range type, like `int4range`
+    fn range_type() -> &'static str;
+
+    /// Parse a bound from its text form, with the quotes removed
+    fn parse_bound(s: &str) -> Option<Self>;
+
+    /// The text form of the bound
+    fn format_bound(&self) -> String;
+}
+
+/// The text form of a range could not be parsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsePgRangeError(String);
+
+impl fmt::Display for ParsePgRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid range: {}", self.0)
+    }
+}
+
+impl std::error::Error for ParsePgRangeError {}
+
+impl<T> PgRange<T> {
+    /// Create a range from its bounds
+    pub fn new(start: Bound<T>, end: Bound<T>) -> Self {
+        Self { start, end }
+    }
+}
+
+impl<T> From<(Bound<T>, Bound<T>)> for PgRange<T> {
+    fn from((start, end): (Bound<T>, Bound<T>)) -> Self {
+        Self { start, end }
+    }
+}
+
+impl<T> From<PgRange<T>> for (Bound<T>, Bound<T>) {
+    fn from(range: PgRange<T>) -> Self {
+        (range.start, range.end)
+    }
+}
+
+impl<T> From<std::ops::Range<T>> for PgRange<T> {
+    fn from(range: std::ops::Range<T>) -> Self {
+        Self {
+            start: Bound::Included(range.start),
+            end: Bound::Excluded(range.end),
+        }
+    }
+}
+
+impl<T> From<std::ops::RangeInclusive<T>> for PgRange<T> {
+    fn from(range: std::ops::RangeInclusive<T>) -> Self {
+        let (start, end) = range.into_inner();
+        Self {
+            start: Bound::Included(start),
+            end: Bound::Included(end),
+        }
+    }
+}
+
+impl<T> fmt::Display for PgRange<T>
+where
+    T: PgRangeElement,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.start {
+            Bound::Included(v) => write!(f, "[{}", v.format_bound())?,
+            Bound::Excluded(v) => write!(f, "({}", v.format_bound())?,
+            Bound::Unbounded => write!(f, "(")?,
+        }
+        write!(f, ",")?;
+        match &self.end {
+            Bound::Included(v) => write!(f, "{}]", v.format_bound()),
+            Bound::Excluded(v) => write!(f, "{})", v.format_bound()),
+            Bound::Unbounded => write!(f, ")"),
+        }
+    }
+}
+
+impl<T> FromStr for PgRange<T>
+where
+    T: PgRangeElement,
+{
+    type Err = ParsePgRangeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParsePgRangeError(s.to_owned());
+        let parse = |bound: &str| {
+            let bound = bound.trim();
+            let bound = bound
+                .strip_prefix('"')
+                .and_then(|bound| bound.strip_suffix('"'))
+                .unwrap_or(bound);
+            T::parse_bound(bound).ok_or_else(err)
+        };
+
+        let range = s.trim();
+        let mut chars = range.chars();
+        let (lower, upper) = (chars.next(), chars.next_back());
+        let (start, end) = chars.as_str().split_once(',').ok_or_else(err)?;
+
+        let start = match (lower, start.trim()) {
+            (Some('[' | '('), "") => Bound::Unbounded,
+            (Some('['), start) => Bound::Included(parse(start)?),
+            (Some('('), start) => Bound::Excluded(parse(start)?),
+            _ => return Err(err()),
+        };
+        let end = match (upper, end.trim()) {
+            (Some(']' | ')'), "") => Bound::Unbounded,
+            (Some(']'), end) => Bound::Included(parse(end)?),
+            (Some(')'), end) => Bound::Excluded(parse(end)?),
+            _ => return Err(err()),
+        };
+
+        Ok(Self { start, end })
+    }
+}
+
+impl<T> From<PgRange<T>> for Value
+where
+    T: PgRangeElement,
+{
+    fn from(range: PgRange<T>) -> Self {
+        Value::String(Some(Box::new(range.to_string())))
+    }
+}
+
+impl<T> Nullable for PgRange<T>
+where
+    T: PgRangeElement,
+{
+    fn null() -> Value {
+        Value::String(None)
+    }
+}
+
+impl<T> ValueType for PgRange<T>
+where
+    T: PgRangeElement,
+{
+    fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+        match v {
+            Value::String(Some(s)) => s.parse().map_err(|_| ValueTypeErr),
+            _ => Err(ValueTypeErr),
+        }
+    }
+
+    fn type_name() -> String {
+        format!("PgRange<{}>", std::any::type_name::<T>())
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::String
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::Custom(Alias::new(T::range_type()).into_iden())
+    }
+}
+
+impl<T> TryGetable for PgRange<T>
+where
+    T: PgRangeElement,
+{
+    fn try_get_by<I: ColIdx>(res: &QueryResult, index: I) -> Result<Self, TryGetError> {
+        let s = String::try_get_by(res, index)?;
+        s.parse()
+            .map_err(|e: ParsePgRangeError| type_err(e.to_string()).into())
+    }
+}
+
+pub(crate) fn is_pg_range_type(name: &str) -> bool {
+    PG_RANGE_TYPES.contains(&name)
+}
+
+macro_rules! impl_pg_range_element {
+    ($ty: ty, $range_type: expr) => {
+        impl PgRangeElement for $ty {
+            fn range_type() -> &'static str {
+                $range_type
+            }
+
+            fn parse_bound(s: &str) -> Option<Self> {
+                s.parse().ok()
+            }
+
+            fn format_bound(&self) -> String {
+                self.to_string()
+            }
+        }
+    };
+}
+
+impl_pg_range_element!(i32, "int4range");
+impl_pg_range_element!(i64, "int8range");
+
+#[cfg(feature = "with-rust_decimal")]
+impl_pg_range_element!(rust_decimal::Decimal, "numrange");
+
+#[cfg(feature = "with-chrono")]
+impl PgRangeElement for chrono::NaiveDate {
+    fn range_type() -> &'static str {
+        "daterange"
+    }
+
+    fn parse_bound(s: &str) -> Option<Self> {
+        chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d").ok()
+    }
+
+    fn format_bound(&self) -> String {
+        self.format("%Y-%m-%d").to_string()
+    }
+}
+
+#[cfg(feature = "with-chrono")]
+impl PgRangeElement for chrono::NaiveDateTime {
+    fn range_type() -> &'static str {
+        "tsrange"
+    }
+
+    fn parse_bound(s: &str) -> Option<Self> {
+        chrono::NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S%.f").ok()
+    }
+
+    fn format_bound(&self) -> String {
+        self.format("%Y-%m-%d %H:%M:%S%.f").to_string()
+    }
+}
+
+#[cfg(feature = "with-chrono")]
+impl PgRangeElement for chrono::DateTime<chrono::FixedOffset> {
+    fn range_type() -> &'static str {
+        "tstzrange"
+    }
+
+    fn parse_bound(s: &str) -> Option<Self> {
+        chrono::DateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S%.f%#z").ok()
+    }
+
+    fn format_bound(&self) -> String {
+        self.format("%Y-%m-%d %H:%M:%S%.f%:z").to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn format_range() {
+        assert_eq!(PgRange::from(1..10).to_string(), "[1,10)");
+        assert_eq!(PgRange::from(1..=10).to_string(), "[1,10]");
+        assert_eq!(
+            PgRange::new(Bound::Excluded(1i64), Bound::Unbounded).to_string(),
+            "(1,)"
+        );
+        assert_eq!(
+            PgRange::<i32>::new(Bound::Unbounded, Bound::Unbounded).to_string(),
+            "(,)"
+        );
+    }
+
+    #[test]
+    fn parse_range() {
+        assert_eq!("[1,10)".parse(), Ok(PgRange::from(1..10)));
+        assert_eq!(r#"["1","10"]"#.parse(), Ok(PgRange::from(1..=10)));
+        assert_eq!(
+            "(,5)".parse(),
+            Ok(PgRange::new(Bound::Unbounded, Bound::Excluded(5)))
+        );
+        assert_eq!(
+            "[-3,)".parse(),
+            Ok(PgRange::new(Bound::Included(-3), Bound::Unbounded))
+        );
+        assert!("empty".parse::<PgRange<i32>>().is_err());
+        assert!("[1;10)".parse::<PgRange<i32>>().is_err());
+        assert!("[a,10)".parse::<PgRange<i32>>().is_err());
+        assert!("{1,10}".parse::<PgRange<i32>>().is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "with-chrono")]
+    fn parse_datetime_range() {
+        use chrono::NaiveDate;
+
+        let start = NaiveDate::from_ymd_opt(2024, 1, 1)
+            .unwrap()
+            .and_hms_opt(9, 0, 0)
+            .unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 1, 1)
+            .unwrap()
+            .and_hms_opt(17, 30, 0)
+            .unwrap();
+        let range = PgRange::from(start..end);
+        assert_eq!(
+            r#"["2024-01-01 09:00:00","2024-01-01 17:30:00")"#.parse(),
+            Ok(range.clone())
+        );
+        assert_eq!(range.to_string().parse(), Ok(range));
+
+        let range: PgRange<chrono::DateTime<chrono::FixedOffset>> =
+            r#"["2024-01-01 09:00:00+00","2024-01-01 17:30:00.5+02")"#
+                .parse()
+                .unwrap();
+        assert_eq!(range.to_string().parse(), Ok(range));
+    }
+}
@@ -40,7 +40,7 @@ where
 
     /// Find related Entities
     fn find_related() -> Select<R> {
-        Select::<R>::new().join_join_rev(JoinType::InnerJoin, Self::to(), Self::via())
+        R::find().join_join_rev(JoinType::InnerJoin, Self::to(), Self::via())
     }
 }
 
@@ -18,6 +18,14 @@ pub enum DbErr {
     /// This error can happen when the connection pool is fully-utilized
     #[error("Failed to acquire connection from pool: {0}")]
     ConnectionAcquire(#[source] ConnAcquireErr),
+    /// The connection pool timed out, most likely because all of its connections are in use
+    #[error("Connection pool timed out: {in_use} of {max_connections} connections in use")]
+    ConnAcquireTimeout {
+        /// The maximum number of connections of the pool
+        max_connections: u32,
+        /// The number of connections checked out of the pool when the timeout occurred
+        in_use: u32,
+    },
     /// Runtime type conversion error
     #[error("Error converting `{from}` into `{into}`: {source}")]
     TryIntoErr {
@@ -70,9 +78,13 @@ pub enum DbErr {
     #[error("None of the records are inserted")]
     RecordNotInserted,
     /// None of the records are updated, that means a WHERE condition has no matches.
-    /// May be the table is empty or the record does not exist
+    /// May be the table is empty, the record does not exist,
+    /// or its [version](crate::ColumnDef::version) has changed since it was read
     #[error("None of the records are updated")]
     RecordNotUpdated,
+    /// The statement did not complete within its [query timeout](crate::Selector::query_timeout)
+    #[error("Query timed out after {0:?}")]
+    Timeout(std::time::Duration),
 }
 
 /// Connection Acquire error
@@ -111,7 +123,6 @@ impl Eq for DbErr {}
 #[error("Failed to match \"{0}\" as Column")]
 pub struct ColumnFromStrErr(pub String);
 
-#[allow(dead_code)]
 pub(crate) fn conn_err<T>(s: T) -> DbErr
 where
     T: ToString,
@@ -119,7 +130,7 @@ where
     DbErr::Conn(RuntimeErr::Internal(s.to_string()))
 }
 
-#[allow(dead_code)]
+#[cfg(feature = "mock")]
 pub(crate) fn exec_err<T>(s: T) -> DbErr
 where
     T: ToString,
@@ -127,7 +138,6 @@ where
     DbErr::Exec(RuntimeErr::Internal(s.to_string()))
 }
 
-#[allow(dead_code)]
 pub(crate) fn query_err<T>(s: T) -> DbErr
 where
     T: ToString,
@@ -135,7 +145,6 @@ where
     DbErr::Query(RuntimeErr::Internal(s.to_string()))
 }
 
-#[allow(dead_code)]
 pub(crate) fn type_err<T>(s: T) -> DbErr
 where
     T: ToString,
@@ -143,7 +152,7 @@ where
     DbErr::Type(s.to_string())
 }
 
-#[allow(dead_code)]
+#[cfg(feature = "with-json")]
 pub(crate) fn json_err<T>(s: T) -> DbErr
 where
     T: ToString,
@@ -161,9 +170,20 @@ pub enum SqlErr {
     /// Error for Foreign key constraint
     #[error("Foreign Key Constraint Violated: {0}")]
     ForeignKeyConstraintViolation(String),
+    /// Error for a transaction which could not be serialized with concurrent transactions,
+    /// and which may succeed if retried
+    #[error("Serialization Failure: {0}")]
+    SerializationFailure(String),
+    /// Error for a transaction which was rolled back to break a deadlock with concurrent
+    /// transactions, and which may succeed if retried
+    #[error("Deadlock Detected: {0}")]
+    Deadlock(String),
+    /// Error for a row lock which could not be acquired, right away with `NOWAIT`,
+    /// or before the lock wait timeout
+    #[error("Lock Not Available: {0}")]
+    LockNotAvailable(String),
 }
 
-#[allow(dead_code)]
 impl DbErr {
     /// Convert generic DbErr by sqlx to SqlErr, return none if the error is not any type of SqlErr
     pub fn sql_err(&self) -> Option<SqlErr> {
@@ -204,6 +224,11 @@ impl DbErr {
                         1216 | 1217 | 1451 | 1452 | 1557 | 1761 | 1762 => {
                             return Some(SqlErr::ForeignKeyConstraintViolation(e.message().into()))
                         }
+                        // 1213 Deadlock found when trying to get lock; try restarting transaction
+                        1213 => return Some(SqlErr::Deadlock(e.message().into())),
+                        // 1205 Lock wait timeout exceeded; try restarting transaction
+                        // 3572 Statement aborted because lock(s) could not be acquired immediately and NOWAIT is set
+                        1205 | 3572 => return Some(SqlErr::LockNotAvailable(e.message().into())),
                         _ => return None,
                     }
                 }
@@ -218,6 +243,9 @@ impl DbErr {
                         "23503" => {
                             return Some(SqlErr::ForeignKeyConstraintViolation(e.message().into()))
                         }
+                        "40001" => return Some(SqlErr::SerializationFailure(e.message().into())),
+                        "40P01" => return Some(SqlErr::Deadlock(e.message().into())),
+                        "55P03" => return Some(SqlErr::LockNotAvailable(e.message().into())),
                         _ => return None,
                     }
                 }
@@ -239,4 +267,154 @@ impl DbErr {
         }
         None
     }
+
+    /// The name of the constraint violated by the statement, like a unique index or a
+    /// foreign key, to tell apart the errors of [`SqlErr::UniqueConstraintViolation`]
+    /// and [`SqlErr::ForeignKeyConstraintViolation`]. Returns none for any other error.
+    ///
+    /// Postgres reports the name of the constraint with the error. MySQL only reports it
+    /// in the message, which is parsed: the name of the index for duplicate entries, like
+    /// `PRIMARY` or `idx-user-email`, and the name of the foreign key if the message has it.
+    /// SQLite does not name the violated constraint, so it's always none there.
+    pub fn constraint_name(&self) -> Option<String> {
+        #[cfg(feature = "sqlx-dep")]
+        {
+            let e = self.sqlx_database_error()?;
+            if let Some(constraint) = e.constraint() {
+                return Some(constraint.to_owned());
+            }
+            #[cfg(feature = "sqlx-mysql")]
+            if let Some(e) = e.try_downcast_ref::<sqlx::mysql::MySqlDatabaseError>() {
+                return mysql_constraint_name(e.number(), e.message());
+            }
+        }
+        None
+    }
+
+    /// The name of the table of the violated constraint, like the referencing table of
+    /// a foreign key, for both the child row inserted and the parent row deleted.
+    /// Returns none for any other error.
+    ///
+    /// Postgres reports the table with the error. MySQL only reports it in the message of
+    /// foreign key violations, which is parsed. SQLite does not report it, so it's always none there.
+    pub fn constraint_table(&self) -> Option<String> {
+        #[cfg(feature = "sqlx-dep")]
+        {
+            let e = self.sqlx_database_error()?;
+            if let Some(table) = e.table() {
+                return Some(table.to_owned());
+            }
+            #[cfg(feature = "sqlx-mysql")]
+            if let Some(e) = e.try_downcast_ref::<sqlx::mysql::MySqlDatabaseError>() {
+                return mysql_constraint_table(e.number(), e.message());
+            }
+        }
+        None
+    }
+
+    #[cfg(feature = "sqlx-dep")]
+    fn sqlx_database_error(&self) -> Option<&(dyn sqlx::error::DatabaseError + 'static)> {
+        match self {
+            DbErr::Exec(RuntimeErr::SqlxError(sqlx::Error::Database(e)))
+            | DbErr::Query(RuntimeErr::SqlxError(sqlx::Error::Database(e))) => Some(e.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+/// Parse the name of the violated constraint out of the message of a MySQL error
+#[cfg(feature = "sqlx-mysql")]
+fn mysql_constraint_name(error_number: u16, message: &str) -> Option<String> {
+    match error_number {
+        // Duplicate entry 'a@b.c' for key 'user.idx-user-email', where the index
+        // is prefixed with the table name since MySQL 8.0.19
+        1062 | 1586 => {
+            let key = message.rsplit_once(" for key '")?.1.strip_suffix('\'')?;
+            Some(key.rsplit_once('.').map_or(key, |(_, key)| key).to_owned())
+        }
+        // a foreign key constraint fails (`db`.`cake`, CONSTRAINT `fk-cake-bakery_id` FOREIGN KEY ..)
+        1216 | 1217 | 1451 | 1452 => {
+            let constraint = message.split_once("CONSTRAINT `")?.1;
+            Some(constraint.split_once('`')?.0.to_owned())
+        }
+        _ => None,
+    }
+}
+
+/// Parse the table of the violated constraint out of the message of a MySQL error
+#[cfg(feature = "sqlx-mysql")]
+fn mysql_constraint_table(error_number: u16, message: &str) -> Option<String> {
+    match error_number {
+        // a foreign key constraint fails (`db`.`cake`, CONSTRAINT ..)
+        1216 | 1217 | 1451 | 1452 => {
+            let (table, _) = message.split_once("`, CONSTRAINT `")?;
+            Some(table.rsplit_once('`')?.1.to_owned())
+        }
+        _ => None,
+    }
+}
+
+#[cfg(all(test, feature = "sqlx-mysql"))]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn mysql_constraint_name_from_message() {
+        assert_eq!(
+            mysql_constraint_name(1062, "Duplicate entry '1' for key 'PRIMARY'").as_deref(),
+            Some("PRIMARY")
+        );
+        assert_eq!(
+            mysql_constraint_name(
+                1062,
+                "Duplicate entry 'a@b.c' for key 'user.idx-user-email'"
+            )
+            .as_deref(),
+            Some("idx-user-email")
+        );
+        assert_eq!(
+            mysql_constraint_name(
+                1452,
+                "Cannot add or update a child row: a foreign key constraint fails \
+                (`db`.`cake`, CONSTRAINT `fk-cake-bakery_id` FOREIGN KEY (`bakery_id`) \
+                REFERENCES `bakery` (`id`) ON DELETE SET NULL ON UPDATE CASCADE)"
+            )
+            .as_deref(),
+            Some("fk-cake-bakery_id")
+        );
+        assert_eq!(
+            mysql_constraint_name(
+                1216,
+                "Cannot add or update a child row: a foreign key constraint fails"
+            ),
+            None
+        );
+        assert_eq!(mysql_constraint_name(1213, "Deadlock found"), None);
+    }
+
+    #[test]
+    fn mysql_constraint_table_from_message() {
+        assert_eq!(
+            mysql_constraint_table(
+                1451,
+                "Cannot delete or update a parent row: a foreign key constraint fails \
+                (`db`.`cakes_bakers`, CONSTRAINT `fk-cakes_bakers-baker_id` FOREIGN KEY \
+                (`baker_id`) REFERENCES `baker` (`id`))"
+            )
+            .as_deref(),
+            Some("cakes_bakers")
+        );
+        assert_eq!(
+            mysql_constraint_table(
+                1217,
+                "Cannot delete or update a parent row: a foreign key constraint fails"
+            ),
+            None
+        );
+        assert_eq!(
+            mysql_constraint_table(1062, "Duplicate entry '1' for key 'PRIMARY'"),
+            None
+        );
+    }
 }
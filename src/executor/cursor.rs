@@ -1,13 +1,15 @@
 use crate::{
-    ConnectionTrait, DbErr, EntityTrait, FromQueryResult, Identity, IdentityOf, IntoIdentity,
-    PartialModelTrait, PrimaryKeyToColumn, QueryOrder, QuerySelect, Select, SelectModel, SelectTwo,
-    SelectTwoModel, SelectorTrait,
+    ConnectionTrait, DbBackend, DbErr, EntityTrait, FromQueryResult, Identity, IdentityOf,
+    IntoIdentity, ModelTrait, PartialModelTrait, PinBoxStream, PrimaryKeyToColumn, QueryOrder,
+    QuerySelect, RuntimeErr, Select, SelectChecks, SelectModel, SelectTwo, SelectTwoModel,
+    SelectorTrait,
 };
+use async_stream::stream;
 use sea_query::{
     Condition, DynIden, Expr, IntoValueTuple, Order, SeaRc, SelectStatement, SimpleExpr, Value,
     ValueTuple,
 };
-use std::marker::PhantomData;
+use std::{marker::PhantomData, num::NonZeroU64};
 use strum::IntoEnumIterator as Iterable;
 
 #[cfg(feature = "with-json")]
@@ -20,6 +22,7 @@ where
     S: SelectorTrait,
 {
     query: SelectStatement,
+    checks: SelectChecks,
     table: DynIden,
     order_columns: Identity,
     secondary_order_by: Vec<(DynIden, Identity)>,
@@ -43,6 +46,7 @@ where
     {
         Self {
             query,
+            checks: SelectChecks::default(),
             table,
             order_columns: order_columns.into_identity(),
             last: None,
@@ -74,39 +78,64 @@ where
         self
     }
 
-    fn apply_filters(&mut self) -> &mut Self {
+    fn apply_filters(&mut self, backend: DbBackend) -> Result<&mut Self, DbErr> {
         if let Some(values) = self.after.clone() {
-            let condition = self.apply_filter(values, |c, v| {
-                let exp = Expr::col((SeaRc::clone(&self.table), SeaRc::clone(c)));
+            let condition = self.apply_filter(backend, values, |lhs, rhs| {
                 if self.sort_asc {
-                    exp.gt(v)
+                    lhs.gt(rhs)
                 } else {
-                    exp.lt(v)
+                    lhs.lt(rhs)
                 }
-            });
+            })?;
             self.query.cond_where(condition);
         }
 
         if let Some(values) = self.before.clone() {
-            let condition = self.apply_filter(values, |c, v| {
-                let exp = Expr::col((SeaRc::clone(&self.table), SeaRc::clone(c)));
+            let condition = self.apply_filter(backend, values, |lhs, rhs| {
                 if self.sort_asc {
-                    exp.lt(v)
+                    lhs.lt(rhs)
                 } else {
-                    exp.gt(v)
+                    lhs.gt(rhs)
                 }
-            });
+            })?;
             self.query.cond_where(condition);
         }
 
-        self
+        Ok(self)
     }
 
-    fn apply_filter<F>(&self, values: ValueTuple, f: F) -> Condition
+    fn apply_filter<F>(
+        &self,
+        backend: DbBackend,
+        values: ValueTuple,
+        cmp: F,
+    ) -> Result<Condition, DbErr>
     where
-        F: Fn(&DynIden, Value) -> SimpleExpr,
+        F: Fn(Expr, SimpleExpr) -> SimpleExpr,
     {
-        match (&self.order_columns, values) {
+        let col = |c: &DynIden| Expr::col((SeaRc::clone(&self.table), SeaRc::clone(c)));
+        let f = |c: &DynIden, v: Value| cmp(col(c), v.into());
+
+        // Compare composite keys with a row value, i.e.
+        //   WHERE (col_1, col_2, ..., col_n) > (val_1, val_2, ..., val_n)
+        // SQLite lacks row-value comparisons, so it falls back to the expansion below.
+        if backend != DbBackend::Sqlite && !matches!(self.order_columns, Identity::Unary(_)) {
+            let columns: Vec<DynIden> = self.order_columns.clone().into_iter().collect();
+            let values = match values {
+                ValueTuple::One(v1) => vec![v1],
+                ValueTuple::Two(v1, v2) => vec![v1, v2],
+                ValueTuple::Three(v1, v2, v3) => vec![v1, v2, v3],
+                ValueTuple::Many(vec) => vec,
+            };
+            if columns.len() != values.len() {
+                return Err(arity_mismatch(columns.len(), values.len()));
+            }
+            let lhs = Expr::tuple(columns.iter().map(|c| SimpleExpr::from(col(c))));
+            let rhs = Expr::tuple(values.into_iter().map(SimpleExpr::Value));
+            return Ok(Condition::all().add(cmp(lhs, rhs.into())));
+        }
+
+        let condition = match (&self.order_columns, values) {
             (Identity::Unary(c1), ValueTuple::One(v1)) => Condition::all().add(f(c1, v1)),
             (Identity::Binary(c1, c2), ValueTuple::Two(v1, v2)) => Condition::any()
                 .add(
@@ -186,8 +215,14 @@ where
                         cond_any.add(inner_cond_all)
                     })
             }
-            _ => panic!("column arity mismatch"),
-        }
+            (columns, values) => {
+                return Err(arity_mismatch(
+                    columns.clone().into_iter().count(),
+                    values.into_iter().count(),
+                ))
+            }
+        };
+        Ok(condition)
     }
 
     /// Use ascending sort order
@@ -281,8 +316,9 @@ where
     {
         self.apply_limit();
         self.apply_order_by();
-        self.apply_filters();
+        self.apply_filters(db.get_database_backend())?;
 
+        self.checks.ensure_supported(db.get_database_backend())?;
         let stmt = db.get_database_backend().build(&self.query);
         let rows = db.query_all(stmt).await?;
         let mut buffer = Vec::with_capacity(rows.len());
@@ -302,6 +338,7 @@ where
     {
         Cursor {
             query: self.query,
+            checks: self.checks,
             table: self.table,
             order_columns: self.order_columns,
             last: self.last,
@@ -328,6 +365,7 @@ where
     pub fn into_json(self) -> Cursor<SelectModel<JsonValue>> {
         Cursor {
             query: self.query,
+            checks: self.checks,
             table: self.table,
             order_columns: self.order_columns,
             last: self.last,
@@ -357,6 +395,14 @@ where
     fn query(&mut self) -> &mut SelectStatement {
         &mut self.query
     }
+
+    fn mark_row_lock(&mut self) {
+        self.checks.row_lock = true;
+    }
+
+    fn mark_distinct_on(&mut self) {
+        self.checks.distinct_on = true;
+    }
 }
 
 impl<S> QueryOrder for Cursor<S>
@@ -394,10 +440,146 @@ where
     where
         C: IntoIdentity,
     {
-        Cursor::new(self.query, SeaRc::new(E::default()), order_columns)
+        let mut cursor = Cursor::new(self.query, SeaRc::new(E::default()), order_columns);
+        cursor.checks = self.checks;
+        cursor
     }
 }
 
+impl<E> Select<E>
+where
+    E: EntityTrait,
+{
+    /// Stream all the models, fetched with keyset pagination in chunks of `chunk_size` rows
+    /// ordered by the primary key.
+    ///
+    /// Every chunk is a separate query resuming after the primary key of the last model
+    /// of the previous chunk, so no server-side cursor or transaction is held open between
+    /// chunks and at most one chunk is kept in memory. This suits exporting a large table.
+    /// Rows inserted or deleted while streaming may or may not be seen, but no row is
+    /// returned twice.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results([
+    /// #         vec![
+    /// #             cake::Model { id: 1, name: "New York Cheese".to_owned() },
+    /// #             cake::Model { id: 2, name: "Chocolate Forest".to_owned() },
+    /// #         ],
+    /// #         vec![cake::Model { id: 3, name: "Lemon Tart".to_owned() }],
+    /// #     ])
+    /// #     .into_connection();
+    /// #
+    /// use futures::TryStreamExt;
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake};
+    /// use std::num::NonZeroU64;
+    ///
+    /// let chunk_size = NonZeroU64::new(2).unwrap();
+    /// let mut stream = cake::Entity::find().stream_keyset(&db, chunk_size);
+    /// let mut ids = Vec::new();
+    /// while let Some(cake) = stream.try_next().await? {
+    ///     ids.push(cake.id);
+    /// }
+    /// assert_eq!(ids, [1, 2, 3]);
+    /// # drop(stream);
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     [
+    ///         Transaction::from_sql_and_values(
+    ///             DbBackend::Postgres,
+    ///             r#"SELECT "cake"."id", "cake"."name" FROM "cake" ORDER BY "cake"."id" ASC LIMIT $1"#,
+    ///             [2u64.into()]
+    ///         ),
+    ///         Transaction::from_sql_and_values(
+    ///             DbBackend::Postgres,
+    ///             r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" > $1 ORDER BY "cake"."id" ASC LIMIT $2"#,
+    ///             [2i32.into(), 2u64.into()]
+    ///         ),
+    ///     ]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn stream_keyset<'db, C>(
+        self,
+        db: &'db C,
+        chunk_size: NonZeroU64,
+    ) -> PinBoxStream<'db, Result<E::Model, DbErr>>
+    where
+        C: ConnectionTrait,
+        E: 'db,
+        E::Model: 'db,
+    {
+        let chunk_size = chunk_size.get();
+        let columns: Vec<E::Column> = <E::PrimaryKey as Iterable>::iter()
+            .map(|pk| pk.into_column())
+            .collect();
+        let order_columns = columns_identity(
+            columns
+                .iter()
+                .map(|col| SeaRc::new(*col) as DynIden)
+                .collect(),
+        );
+        Box::pin(stream! {
+            let mut after = None;
+            loop {
+                let mut cursor = Cursor::<SelectModel<E::Model>>::new(
+                    self.query.clone(),
+                    SeaRc::new(E::default()),
+                    order_columns.clone(),
+                );
+                if let Some(values) = after.take() {
+                    cursor.after(values);
+                }
+                let models = cursor.first(chunk_size).all(db).await?;
+                let is_last_chunk = (models.len() as u64) < chunk_size;
+                let last = models
+                    .last()
+                    .map(|model| values_tuple(columns.iter().map(|col| model.get(*col)).collect()));
+                for model in models {
+                    yield Ok(model);
+                }
+                match last {
+                    Some(values) if !is_last_chunk => after = Some(values),
+                    _ => break,
+                }
+            }
+        })
+    }
+}
+
+fn columns_identity(mut columns: Vec<DynIden>) -> Identity {
+    match columns.len() {
+        1 => Identity::Unary(columns.remove(0)),
+        2 => Identity::Binary(columns.remove(0), columns.remove(0)),
+        3 => Identity::Ternary(columns.remove(0), columns.remove(0), columns.remove(0)),
+        _ => Identity::Many(columns),
+    }
+}
+
+fn values_tuple(mut values: Vec<Value>) -> ValueTuple {
+    match values.len() {
+        1 => ValueTuple::One(values.remove(0)),
+        2 => ValueTuple::Two(values.remove(0), values.remove(0)),
+        3 => ValueTuple::Three(values.remove(0), values.remove(0), values.remove(0)),
+        _ => ValueTuple::Many(values),
+    }
+}
+
+fn arity_mismatch(columns: usize, values: usize) -> DbErr {
+    DbErr::Query(RuntimeErr::Internal(format!(
+        "Column arity mismatch: the cursor is ordered by {columns} columns, but {values} values were given"
+    )))
+}
+
 impl<E, F, M, N> CursorTrait for SelectTwo<E, F>
 where
     E: EntityTrait<Model = M>,
@@ -434,6 +616,7 @@ where
             order_columns.identity_of(),
         );
         cursor.set_secondary_order_by(primary_keys);
+        cursor.checks = self.checks;
         cursor
     }
 
@@ -456,6 +639,7 @@ where
             order_columns.identity_of(),
         );
         cursor.set_secondary_order_by(primary_keys);
+        cursor.checks = self.checks;
         cursor
     }
 }
@@ -1402,19 +1586,13 @@ mod tests {
                 [
                     r#"SELECT "example"."id", "example"."category""#,
                     r#"FROM "example""#,
-                    r#"WHERE ("example"."category" = $1 AND "example"."id" > $2)"#,
-                    r#"OR "example"."category" > $3"#,
+                    r#"WHERE ("example"."category", "example"."id") > ($1, $2)"#,
                     r#"ORDER BY "example"."category" ASC, "example"."id" ASC"#,
-                    r#"LIMIT $4"#,
+                    r#"LIMIT $3"#,
                 ]
                 .join(" ")
                 .as_str(),
-                [
-                    "A".to_string().into(),
-                    2i32.into(),
-                    "A".to_string().into(),
-                    3_u64.into(),
-                ]
+                ["A".to_string().into(), 2i32.into(), 3_u64.into()]
             )])]
         );
 
@@ -1448,19 +1626,13 @@ mod tests {
                 [
                     r#"SELECT "example"."id", "example"."category""#,
                     r#"FROM "example""#,
-                    r#"WHERE ("example"."category" = $1 AND "example"."id" > $2)"#,
-                    r#"OR "example"."category" > $3"#,
+                    r#"WHERE ("example"."category", "example"."id") > ($1, $2)"#,
                     r#"ORDER BY "example"."category" ASC, "example"."id" ASC"#,
-                    r#"LIMIT $4"#,
+                    r#"LIMIT $3"#,
                 ]
                 .join(" ")
                 .as_str(),
-                [
-                    "A".to_string().into(),
-                    2i32.into(),
-                    "A".to_string().into(),
-                    3_u64.into(),
-                ]
+                ["A".to_string().into(), 2i32.into(), 3_u64.into()]
             )])]
         );
 
@@ -1493,19 +1665,13 @@ mod tests {
                 [
                     r#"SELECT "example"."id", "example"."category""#,
                     r#"FROM "example""#,
-                    r#"WHERE ("example"."category" = $1 AND "example"."id" < $2)"#,
-                    r#"OR "example"."category" < $3"#,
+                    r#"WHERE ("example"."category", "example"."id") < ($1, $2)"#,
                     r#"ORDER BY "example"."category" DESC, "example"."id" DESC"#,
-                    r#"LIMIT $4"#,
+                    r#"LIMIT $3"#,
                 ]
                 .join(" ")
                 .as_str(),
-                [
-                    "A".to_string().into(),
-                    2i32.into(),
-                    "A".to_string().into(),
-                    3_u64.into(),
-                ]
+                ["A".to_string().into(), 2i32.into(), 3_u64.into()]
             )])]
         );
 
@@ -1539,19 +1705,13 @@ mod tests {
                 [
                     r#"SELECT "example"."id", "example"."category""#,
                     r#"FROM "example""#,
-                    r#"WHERE ("example"."category" = $1 AND "example"."id" < $2)"#,
-                    r#"OR "example"."category" < $3"#,
+                    r#"WHERE ("example"."category", "example"."id") < ($1, $2)"#,
                     r#"ORDER BY "example"."category" DESC, "example"."id" DESC"#,
-                    r#"LIMIT $4"#,
+                    r#"LIMIT $3"#,
                 ]
                 .join(" ")
                 .as_str(),
-                [
-                    "A".to_string().into(),
-                    2i32.into(),
-                    "A".to_string().into(),
-                    3_u64.into(),
-                ]
+                ["A".to_string().into(), 2i32.into(), 3_u64.into()]
             )])]
         );
 
@@ -1662,11 +1822,9 @@ mod tests {
                 [
                     r#"SELECT "m"."x", "m"."y", "m"."z""#,
                     r#"FROM "m""#,
-                    r#"WHERE ("m"."x" = $1 AND "m"."y" = $2 AND "m"."z" > $3)"#,
-                    r#"OR ("m"."x" = $4 AND "m"."y" > $5)"#,
-                    r#"OR "m"."x" > $6"#,
+                    r#"WHERE ("m"."x", "m"."y", "m"."z") > ($1, $2, $3)"#,
                     r#"ORDER BY "m"."x" ASC, "m"."y" ASC, "m"."z" ASC"#,
-                    r#"LIMIT $7"#,
+                    r#"LIMIT $4"#,
                 ]
                 .join(" ")
                 .as_str(),
@@ -1674,9 +1832,6 @@ mod tests {
                     ('x' as i32).into(),
                     "y".into(),
                     ('z' as i64).into(),
-                    ('x' as i32).into(),
-                    "y".into(),
-                    ('x' as i32).into(),
                     4_u64.into(),
                 ]
             ),])]
@@ -1713,11 +1868,9 @@ mod tests {
                 [
                     r#"SELECT "m"."x", "m"."y", "m"."z""#,
                     r#"FROM "m""#,
-                    r#"WHERE ("m"."x" = $1 AND "m"."y" = $2 AND "m"."z" > $3)"#,
-                    r#"OR ("m"."x" = $4 AND "m"."y" > $5)"#,
-                    r#"OR "m"."x" > $6"#,
+                    r#"WHERE ("m"."x", "m"."y", "m"."z") > ($1, $2, $3)"#,
                     r#"ORDER BY "m"."x" ASC, "m"."y" ASC, "m"."z" ASC"#,
-                    r#"LIMIT $7"#,
+                    r#"LIMIT $4"#,
                 ]
                 .join(" ")
                 .as_str(),
@@ -1725,9 +1878,6 @@ mod tests {
                     ('x' as i32).into(),
                     "y".into(),
                     ('z' as i64).into(),
-                    ('x' as i32).into(),
-                    "y".into(),
-                    ('x' as i32).into(),
                     4_u64.into(),
                 ]
             ),])]
@@ -1784,11 +1934,91 @@ mod tests {
             r#"FROM "t" WHERE"#,
         ].join(" ");
 
+        for backend in [DbBackend::MySql, DbBackend::Postgres] {
+            let mut cursor =
+                Entity::find().cursor_by((Column::Col1, Column::Col2, Column::Col3, Column::Col4));
+            cursor.after(("val_1", "val_2", "val_3", "val_4"));
+            cursor
+                .apply_limit()
+                .apply_order_by()
+                .apply_filters(backend)?;
+
+            let expected = format!("{base_sql} {}", [
+                r#"("t"."col_1", "t"."col_2", "t"."col_3", "t"."col_4") > ('val_1', 'val_2', 'val_3', 'val_4')"#,
+                r#"ORDER BY "t"."col_1" ASC, "t"."col_2" ASC, "t"."col_3" ASC, "t"."col_4" ASC"#,
+            ].join(" "));
+            let expected = match backend {
+                DbBackend::MySql => expected.replace('"', "`"),
+                _ => expected,
+            };
+            assert_eq!(backend.build(&cursor.query).to_string(), expected);
+        }
+
+        assert_eq!(
+            DbBackend::Postgres
+                .build(
+                    &Entity::find()
+                        .cursor_by((Column::Col1, Column::Col2, Column::Col3))
+                        .after(("val_1", "val_2", "val_3"))
+                        .before(("val_4", "val_5", "val_6"))
+                        .desc()
+                        .apply_limit()
+                        .apply_order_by()
+                        .apply_filters(DbBackend::Postgres)?
+                        .query
+                )
+                .to_string(),
+            format!(
+                "{base_sql} {}",
+                [
+                    r#"("t"."col_1", "t"."col_2", "t"."col_3") < ('val_1', 'val_2', 'val_3')"#,
+                    r#"AND ("t"."col_1", "t"."col_2", "t"."col_3") > ('val_4', 'val_5', 'val_6')"#,
+                    r#"ORDER BY "t"."col_1" DESC, "t"."col_2" DESC, "t"."col_3" DESC"#,
+                ]
+                .join(" ")
+            )
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn cursor_arity_mismatch() -> Result<(), DbErr> {
+        use composite_entity::*;
+
+        for backend in [DbBackend::MySql, DbBackend::Postgres, DbBackend::Sqlite] {
+            let db = MockDatabase::new(backend).into_connection();
+            assert_eq!(
+                Entity::find()
+                    .cursor_by((Column::Col1, Column::Col2))
+                    .after(("val_1", "val_2", "val_3"))
+                    .all(&db)
+                    .await,
+                Err(DbErr::Query(RuntimeErr::Internal(
+                    "Column arity mismatch: the cursor is ordered by 2 columns, but 3 values were given"
+                        .to_owned()
+                )))
+            );
+            assert!(db.into_transaction_log().is_empty());
+        }
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn cursor_by_many_sqlite() -> Result<(), DbErr> {
+        use composite_entity::*;
+
+        let base_sql = [
+            r#"SELECT "t"."col_1", "t"."col_2", "t"."col_3", "t"."col_4", "t"."col_5", "t"."col_6", "t"."col_7", "t"."col_8", "t"."col_9", "t"."col_10", "t"."col_11", "t"."col_12""#,
+            r#"FROM "t" WHERE"#,
+        ].join(" ");
+
         assert_eq!(
-            DbBackend::Postgres.build(&
+            DbBackend::Sqlite.build(&
                 Entity::find()
                 .cursor_by((Column::Col1, Column::Col2, Column::Col3, Column::Col4))
-                .after(("val_1", "val_2", "val_3", "val_4")).apply_limit().apply_order_by().apply_filters().query
+                .after(("val_1", "val_2", "val_3", "val_4")).apply_limit().apply_order_by().apply_filters(DbBackend::Sqlite)?.query
             ).to_string(),
             format!("{base_sql} {}", [
                 r#"("t"."col_1" = 'val_1' AND "t"."col_2" = 'val_2' AND "t"."col_3" = 'val_3' AND "t"."col_4" > 'val_4')"#,
@@ -1800,10 +2030,10 @@ mod tests {
         );
 
         assert_eq!(
-            DbBackend::Postgres.build(&
+            DbBackend::Sqlite.build(&
                 Entity::find()
                 .cursor_by((Column::Col1, Column::Col2, Column::Col3, Column::Col4, Column::Col5))
-                .after(("val_1", "val_2", "val_3", "val_4", "val_5")).apply_limit().apply_order_by().apply_filters()
+                .after(("val_1", "val_2", "val_3", "val_4", "val_5")).apply_limit().apply_order_by().apply_filters(DbBackend::Sqlite)?
                 .query
             ).to_string(),
             format!("{base_sql} {}", [
@@ -1817,10 +2047,10 @@ mod tests {
         );
 
         assert_eq!(
-            DbBackend::Postgres.build(&
+            DbBackend::Sqlite.build(&
                 Entity::find()
                 .cursor_by((Column::Col1, Column::Col2, Column::Col3, Column::Col4, Column::Col5, Column::Col6))
-                .after(("val_1", "val_2", "val_3", "val_4", "val_5", "val_6")).apply_limit().apply_order_by().apply_filters()
+                .after(("val_1", "val_2", "val_3", "val_4", "val_5", "val_6")).apply_limit().apply_order_by().apply_filters(DbBackend::Sqlite)?
                 .query
             ).to_string(),
             format!("{base_sql} {}", [
@@ -1835,10 +2065,10 @@ mod tests {
         );
 
         assert_eq!(
-            DbBackend::Postgres.build(&
+            DbBackend::Sqlite.build(&
                 Entity::find()
                 .cursor_by((Column::Col1, Column::Col2, Column::Col3, Column::Col4, Column::Col5, Column::Col6, Column::Col7))
-                .before(("val_1", "val_2", "val_3", "val_4", "val_5", "val_6", "val_7")).apply_limit().apply_order_by().apply_filters()
+                .before(("val_1", "val_2", "val_3", "val_4", "val_5", "val_6", "val_7")).apply_limit().apply_order_by().apply_filters(DbBackend::Sqlite)?
                 .query
             ).to_string(),
             format!("{base_sql} {}", [
@@ -1854,10 +2084,10 @@ mod tests {
         );
 
         assert_eq!(
-            DbBackend::Postgres.build(&
+            DbBackend::Sqlite.build(&
                 Entity::find()
                 .cursor_by((Column::Col1, Column::Col2, Column::Col3, Column::Col4, Column::Col5, Column::Col6, Column::Col7, Column::Col8))
-                .before(("val_1", "val_2", "val_3", "val_4", "val_5", "val_6", "val_7", "val_8")).apply_limit().apply_order_by().apply_filters()
+                .before(("val_1", "val_2", "val_3", "val_4", "val_5", "val_6", "val_7", "val_8")).apply_limit().apply_order_by().apply_filters(DbBackend::Sqlite)?
                 .query
             ).to_string(),
             format!("{base_sql} {}", [
@@ -1874,10 +2104,10 @@ mod tests {
         );
 
         assert_eq!(
-            DbBackend::Postgres.build(&
+            DbBackend::Sqlite.build(&
                 Entity::find()
                 .cursor_by((Column::Col1, Column::Col2, Column::Col3, Column::Col4, Column::Col5, Column::Col6, Column::Col7, Column::Col8, Column::Col9))
-                .before(("val_1", "val_2", "val_3", "val_4", "val_5", "val_6", "val_7", "val_8", "val_9")).apply_limit().apply_order_by().apply_filters()
+                .before(("val_1", "val_2", "val_3", "val_4", "val_5", "val_6", "val_7", "val_8", "val_9")).apply_limit().apply_order_by().apply_filters(DbBackend::Sqlite)?
                 .query
             ).to_string(),
             format!("{base_sql} {}", [
@@ -1898,7 +2128,7 @@ mod tests {
     }
 
     #[smol_potat::test]
-    async fn cursor_by_many_desc() -> Result<(), DbErr> {
+    async fn cursor_by_many_sqlite_desc() -> Result<(), DbErr> {
         use composite_entity::*;
 
         let base_sql = [
@@ -1907,10 +2137,10 @@ mod tests {
         ].join(" ");
 
         assert_eq!(
-            DbBackend::Postgres.build(&
+            DbBackend::Sqlite.build(&
                 Entity::find()
                 .cursor_by((Column::Col1, Column::Col2, Column::Col3, Column::Col4))
-                .before(("val_1", "val_2", "val_3", "val_4")).desc().apply_limit().apply_order_by().apply_filters().query
+                .before(("val_1", "val_2", "val_3", "val_4")).desc().apply_limit().apply_order_by().apply_filters(DbBackend::Sqlite)?.query
             ).to_string(),
             format!("{base_sql} {}", [
                 r#"("t"."col_1" = 'val_1' AND "t"."col_2" = 'val_2' AND "t"."col_3" = 'val_3' AND "t"."col_4" > 'val_4')"#,
@@ -1922,10 +2152,10 @@ mod tests {
         );
 
         assert_eq!(
-            DbBackend::Postgres.build(&
+            DbBackend::Sqlite.build(&
                 Entity::find()
                 .cursor_by((Column::Col1, Column::Col2, Column::Col3, Column::Col4, Column::Col5))
-                .before(("val_1", "val_2", "val_3", "val_4", "val_5")).desc().apply_limit().apply_order_by().apply_filters()
+                .before(("val_1", "val_2", "val_3", "val_4", "val_5")).desc().apply_limit().apply_order_by().apply_filters(DbBackend::Sqlite)?
                 .query
             ).to_string(),
             format!("{base_sql} {}", [
@@ -1939,10 +2169,10 @@ mod tests {
         );
 
         assert_eq!(
-            DbBackend::Postgres.build(&
+            DbBackend::Sqlite.build(&
                 Entity::find()
                 .cursor_by((Column::Col1, Column::Col2, Column::Col3, Column::Col4, Column::Col5, Column::Col6))
-                .before(("val_1", "val_2", "val_3", "val_4", "val_5", "val_6")).desc().apply_limit().apply_order_by().apply_filters()
+                .before(("val_1", "val_2", "val_3", "val_4", "val_5", "val_6")).desc().apply_limit().apply_order_by().apply_filters(DbBackend::Sqlite)?
                 .query
             ).to_string(),
             format!("{base_sql} {}", [
@@ -1957,10 +2187,10 @@ mod tests {
         );
 
         assert_eq!(
-            DbBackend::Postgres.build(&
+            DbBackend::Sqlite.build(&
                 Entity::find()
                 .cursor_by((Column::Col1, Column::Col2, Column::Col3, Column::Col4, Column::Col5, Column::Col6, Column::Col7))
-                .after(("val_1", "val_2", "val_3", "val_4", "val_5", "val_6", "val_7")).desc().apply_limit().apply_order_by().apply_filters()
+                .after(("val_1", "val_2", "val_3", "val_4", "val_5", "val_6", "val_7")).desc().apply_limit().apply_order_by().apply_filters(DbBackend::Sqlite)?
                 .query
             ).to_string(),
             format!("{base_sql} {}", [
@@ -1976,10 +2206,10 @@ mod tests {
         );
 
         assert_eq!(
-            DbBackend::Postgres.build(&
+            DbBackend::Sqlite.build(&
                 Entity::find()
                 .cursor_by((Column::Col1, Column::Col2, Column::Col3, Column::Col4, Column::Col5, Column::Col6, Column::Col7, Column::Col8))
-                .after(("val_1", "val_2", "val_3", "val_4", "val_5", "val_6", "val_7", "val_8")).desc().apply_limit().apply_order_by().apply_filters()
+                .after(("val_1", "val_2", "val_3", "val_4", "val_5", "val_6", "val_7", "val_8")).desc().apply_limit().apply_order_by().apply_filters(DbBackend::Sqlite)?
                 .query
             ).to_string(),
             format!("{base_sql} {}", [
@@ -1996,10 +2226,10 @@ mod tests {
         );
 
         assert_eq!(
-            DbBackend::Postgres.build(&
+            DbBackend::Sqlite.build(&
                 Entity::find()
                 .cursor_by((Column::Col1, Column::Col2, Column::Col3, Column::Col4, Column::Col5, Column::Col6, Column::Col7, Column::Col8, Column::Col9))
-                .after(("val_1", "val_2", "val_3", "val_4", "val_5", "val_6", "val_7", "val_8", "val_9")).desc().apply_limit().apply_order_by().apply_filters()
+                .after(("val_1", "val_2", "val_3", "val_4", "val_5", "val_6", "val_7", "val_8", "val_9")).desc().apply_limit().apply_order_by().apply_filters(DbBackend::Sqlite)?
                 .query
             ).to_string(),
             format!("{base_sql} {}", [
@@ -2204,17 +2434,12 @@ mod tests {
                     r#""related"."id" AS "B_id", "related"."name" AS "B_name", "related"."test_id" AS "B_test_id""#,
                     r#"FROM "base""#,
                     r#"LEFT JOIN "related" ON "base"."id" = "related"."test_id""#,
-                    r#"WHERE ("base"."id" = $1 AND "base"."name" > $2) OR "base"."id" > $3"#,
-                    r#"ORDER BY "base"."id" ASC, "base"."name" ASC, "related"."id" ASC, "related"."name" ASC LIMIT $4"#,
+                    r#"WHERE ("base"."id", "base"."name") > ($1, $2)"#,
+                    r#"ORDER BY "base"."id" ASC, "base"."name" ASC, "related"."id" ASC, "related"."name" ASC LIMIT $3"#,
                 ]
                 .join(" ")
                 .as_str(),
-                [
-                    1_i32.into(),
-                    "C".into(),
-                    1_i32.into(),
-                    2_u64.into(),
-                ]
+                [1_i32.into(), "C".into(), 2_u64.into()]
             ),])]
         );
 
@@ -2256,17 +2481,12 @@ mod tests {
                     r#""related"."id" AS "B_id", "related"."name" AS "B_name", "related"."test_id" AS "B_test_id""#,
                     r#"FROM "base""#,
                     r#"LEFT JOIN "related" ON "base"."id" = "related"."test_id""#,
-                    r#"WHERE ("base"."id" = $1 AND "base"."name" > $2) OR "base"."id" > $3"#,
-                    r#"ORDER BY "base"."id" ASC, "base"."name" ASC, "related"."id" ASC, "related"."name" ASC LIMIT $4"#,
+                    r#"WHERE ("base"."id", "base"."name") > ($1, $2)"#,
+                    r#"ORDER BY "base"."id" ASC, "base"."name" ASC, "related"."id" ASC, "related"."name" ASC LIMIT $3"#,
                 ]
                 .join(" ")
                 .as_str(),
-                [
-                    1_i32.into(),
-                    "C".into(),
-                    1_i32.into(),
-                    2_u64.into(),
-                ]
+                [1_i32.into(), "C".into(), 2_u64.into()]
             ),])]
         );
 
@@ -2310,17 +2530,12 @@ mod tests {
                     r#""related"."id" AS "B_id", "related"."name" AS "B_name", "related"."test_id" AS "B_test_id""#,
                     r#"FROM "base""#,
                     r#"LEFT JOIN "related" ON "base"."id" = "related"."test_id""#,
-                    r#"WHERE ("related"."id" = $1 AND "related"."name" > $2) OR "related"."id" > $3"#,
-                    r#"ORDER BY "related"."id" ASC, "related"."name" ASC, "base"."id" ASC, "base"."name" ASC LIMIT $4"#,
+                    r#"WHERE ("related"."id", "related"."name") > ($1, $2)"#,
+                    r#"ORDER BY "related"."id" ASC, "related"."name" ASC, "base"."id" ASC, "base"."name" ASC LIMIT $3"#,
                 ]
                 .join(" ")
                 .as_str(),
-                [
-                    1_i32.into(),
-                    "CAT".into(),
-                    1_i32.into(),
-                    2_u64.into(),
-                ]
+                [1_i32.into(), "CAT".into(), 2_u64.into()]
             ),])]
         );
 
@@ -2365,20 +2580,73 @@ mod tests {
                     r#""related"."id" AS "B_id", "related"."name" AS "B_name", "related"."test_id" AS "B_test_id""#,
                     r#"FROM "base""#,
                     r#"LEFT JOIN "related" ON "base"."id" = "related"."test_id""#,
-                    r#"WHERE ("related"."id" = $1 AND "related"."name" > $2) OR "related"."id" > $3"#,
-                    r#"ORDER BY "related"."id" ASC, "related"."name" ASC, "base"."id" ASC, "base"."name" ASC LIMIT $4"#,
+                    r#"WHERE ("related"."id", "related"."name") > ($1, $2)"#,
+                    r#"ORDER BY "related"."id" ASC, "related"."name" ASC, "base"."id" ASC, "base"."name" ASC LIMIT $3"#,
                 ]
                 .join(" ")
                 .as_str(),
-                [
-                    1_i32.into(),
-                    "CAT".into(),
-                    1_i32.into(),
-                    2_u64.into(),
-                ]
+                [1_i32.into(), "CAT".into(), 2_u64.into()]
             ),])]
         );
 
         Ok(())
     }
+
+    #[smol_potat::test]
+    async fn stream_keyset_composite() -> Result<(), DbErr> {
+        use cake_filling::*;
+        use futures::TryStreamExt;
+        use std::num::NonZeroU64;
+
+        let model = |cake_id, filling_id| Model {
+            cake_id,
+            filling_id,
+        };
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([
+                vec![model(1, 1), model(1, 2)],
+                vec![model(2, 1), model(3, 1)],
+                vec![],
+            ])
+            .into_connection();
+
+        let models: Vec<Model> = Entity::find()
+            .stream_keyset(&db, NonZeroU64::new(2).unwrap())
+            .try_collect()
+            .await?;
+        assert_eq!(models, [model(1, 1), model(1, 2), model(2, 1), model(3, 1)]);
+
+        let select = [
+            r#"SELECT "cake_filling"."cake_id", "cake_filling"."filling_id""#,
+            r#"FROM "cake_filling""#,
+        ]
+        .join(" ");
+        let order_by = r#"ORDER BY "cake_filling"."cake_id" ASC, "cake_filling"."filling_id" ASC"#;
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    format!("{select} {order_by} LIMIT $1"),
+                    [2_u64.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    format!(
+                        r#"{select} WHERE ("cake_filling"."cake_id", "cake_filling"."filling_id") > ($1, $2) {order_by} LIMIT $3"#
+                    ),
+                    [1_i32.into(), 2_i32.into(), 2_u64.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    format!(
+                        r#"{select} WHERE ("cake_filling"."cake_id", "cake_filling"."filling_id") > ($1, $2) {order_by} LIMIT $3"#
+                    ),
+                    [3_i32.into(), 1_i32.into(), 2_u64.into()]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
 }
@@ -1,5 +1,9 @@
-use crate::{error::*, ActiveModelTrait, ConnectionTrait, DeleteMany, DeleteOne, EntityTrait};
-use sea_query::DeleteStatement;
+use crate::{
+    error::*, executor::max_bind_params, returning_unsupported, ActiveModelTrait, ColumnTrait,
+    ConnectionTrait, DeleteByIds, DeleteMany, DeleteOne, EntityTrait, EntityWritable, Iterable,
+    QueryTrait, SelectModel, SelectorRaw, Updater,
+};
+use sea_query::{DeleteStatement, Query, UpdateStatement};
 use std::future::Future;
 
 /// Handles DELETE operations in a ActiveModel using [DeleteStatement]
@@ -18,28 +22,93 @@ pub struct DeleteResult {
 impl<'a, A: 'a> DeleteOne<A>
 where
     A: ActiveModelTrait,
+    A::Entity: EntityWritable,
 {
     /// Execute a DELETE operation on one ActiveModel
+    ///
+    /// If the entity has a soft-delete column, the row is marked as deleted by an UPDATE instead.
     pub fn exec<C>(self, db: &'a C) -> impl Future<Output = Result<DeleteResult, DbErr>> + '_
     where
         C: ConnectionTrait,
     {
         // so that self is dropped before entering await
-        exec_delete_only(self.query, db)
+        exec_delete_only(self.query, self.soft_delete, db)
     }
 }
 
 impl<'a, E> DeleteMany<E>
 where
-    E: EntityTrait,
+    E: EntityWritable,
 {
     /// Execute a DELETE operation on many ActiveModels
+    ///
+    /// If the entity has a soft-delete column, the rows are marked as deleted by an UPDATE instead.
     pub fn exec<C>(self, db: &'a C) -> impl Future<Output = Result<DeleteResult, DbErr>> + '_
     where
         C: ConnectionTrait,
     {
         // so that self is dropped before entering await
-        exec_delete_only(self.query, db)
+        exec_delete_only(self.query, self.soft_delete, db)
+    }
+
+    /// Execute a DELETE operation and return the deleted models, using `RETURNING` syntax
+    ///
+    /// If the entity has a soft-delete column, the rows marked as deleted are returned.
+    ///
+    /// Returns an error if the database backend does not support `DELETE RETURNING`, like MySQL.
+    /// The rows to be deleted have to be selected beforehand in this case.
+    pub async fn exec_with_returning<C>(self, db: &'a C) -> Result<Vec<E::Model>, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        match self.soft_delete {
+            Some(soft_delete) => {
+                Updater::new(soft_delete)
+                    .exec_update_with_returning::<E, _>(db)
+                    .await
+            }
+            None => {
+                Deleter::new(self.query)
+                    .exec_delete_with_returning::<E, _>(db)
+                    .await
+            }
+        }
+    }
+}
+
+impl<E> DeleteByIds<E>
+where
+    E: EntityWritable,
+{
+    /// Execute a DELETE operation on the models with any of the given primary keys,
+    /// returning the number of rows affected by all the statements
+    ///
+    /// If the entity has a soft-delete column, the rows are marked as deleted by an UPDATE instead.
+    pub async fn exec<C>(self, db: &C) -> Result<DeleteResult, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        if self.ids.is_empty() {
+            return Ok(DeleteResult { rows_affected: 0 });
+        }
+
+        let db_backend = db.get_database_backend();
+        let stmt_params = match &self.delete.soft_delete {
+            Some(soft_delete) => db_backend.build(soft_delete).values,
+            None => self.delete.build(db_backend).values,
+        }
+        .map_or(0, |values| values.0.len());
+        let chunk_size = (max_bind_params(db_backend).saturating_sub(stmt_params)
+            / E::PrimaryKey::iter().count())
+        .max(1);
+
+        let mut rows_affected = 0;
+        for ids in self.ids.chunks(chunk_size) {
+            let delete = self.filter_by_ids(ids);
+            let result = exec_delete_only(delete.query, delete.soft_delete, db).await?;
+            rows_affected += result.rows_affected;
+        }
+        Ok(DeleteResult { rows_affected })
     }
 }
 
@@ -56,13 +125,42 @@ impl Deleter {
     {
         exec_delete(self.query, db)
     }
+
+    async fn exec_delete_with_returning<E, C>(mut self, db: &C) -> Result<Vec<E::Model>, DbErr>
+    where
+        E: EntityTrait,
+        C: ConnectionTrait,
+    {
+        let db_backend = db.get_database_backend();
+        if !db.support_returning() {
+            return Err(returning_unsupported(db_backend));
+        }
+        let returning = Query::returning()
+            .exprs(E::Column::iter().map(|c| c.select_as(c.into_returning_expr(db_backend))));
+        self.query.returning(returning);
+        SelectorRaw::<SelectModel<E::Model>>::from_statement(db_backend.build(&self.query))
+            .all(db)
+            .await
+    }
 }
 
-async fn exec_delete_only<C>(query: DeleteStatement, db: &C) -> Result<DeleteResult, DbErr>
+async fn exec_delete_only<C>(
+    query: DeleteStatement,
+    soft_delete: Option<UpdateStatement>,
+    db: &C,
+) -> Result<DeleteResult, DbErr>
 where
     C: ConnectionTrait,
 {
-    Deleter::new(query).exec(db).await
+    match soft_delete {
+        Some(soft_delete) => {
+            let result = Updater::new(soft_delete).exec(db).await?;
+            Ok(DeleteResult {
+                rows_affected: result.rows_affected,
+            })
+        }
+        None => Deleter::new(query).exec(db).await,
+    }
 }
 
 async fn exec_delete<C>(query: DeleteStatement, db: &C) -> Result<DeleteResult, DbErr>
@@ -77,3 +175,60 @@ where
         rows_affected: result.rows_affected(),
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{entity::prelude::*, tests_cfg::*, *};
+    use pretty_assertions::assert_eq;
+
+    #[smol_potat::test]
+    async fn delete_by_ids_chunked() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Sqlite)
+            .append_exec_results([
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 499,
+                },
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 1,
+                },
+            ])
+            .into_connection();
+
+        // 999 parameters at most on SQLite, that is 499 composite keys per statement
+        let ids: Vec<(i32, i32)> = (0..500).map(|i| (i, i + 1)).collect();
+        assert_eq!(
+            cake_filling::Entity::delete_by_ids(ids).exec(&db).await?,
+            DeleteResult { rows_affected: 500 }
+        );
+
+        let log = db.into_transaction_log();
+        assert_eq!(log.len(), 2);
+        assert_eq!(
+            log[1],
+            Transaction::from_sql_and_values(
+                DbBackend::Sqlite,
+                r#"DELETE FROM "cake_filling" WHERE "cake_filling"."cake_id" = ? AND "cake_filling"."filling_id" = ?"#,
+                [499i32.into(), 500i32.into()]
+            )
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn delete_by_ids_empty() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres).into_connection();
+
+        assert_eq!(
+            fruit::Entity::delete_by_ids(Vec::<i32>::new())
+                .exec(&db)
+                .await?,
+            DeleteResult { rows_affected: 0 }
+        );
+        assert!(db.into_transaction_log().is_empty());
+
+        Ok(())
+    }
+}
@@ -1,9 +1,12 @@
 use crate::{
-    error::*, ActiveModelTrait, ColumnTrait, ConnectionTrait, DbBackend, EntityTrait, Insert,
-    IntoActiveModel, Iterable, PrimaryKeyToColumn, PrimaryKeyTrait, SelectModel, SelectorRaw,
-    TryFromU64, TryInsert,
+    error::*, ActiveModelTrait, ChunkedInsert, ColumnTrait, ConnectionTrait, DbBackend, EntityName,
+    EntityTrait, EntityWritable, Insert, IntoActiveModel, Iterable, PrimaryKeyToColumn,
+    PrimaryKeyTrait, QueryFilter, SelectModel, SelectorRaw, TransactionTrait, TryFromU64,
+    TryInsert,
+};
+use sea_query::{
+    Condition, FromValueTuple, Iden, InsertStatement, OnConflict, Query, SimpleExpr, ValueTuple,
 };
-use sea_query::{FromValueTuple, Iden, InsertStatement, Query, ValueTuple};
 use std::{future::Future, marker::PhantomData};
 
 /// Defines a structure to perform INSERT operations in an ActiveModel
@@ -14,6 +17,7 @@ where
 {
     primary_key: Option<ValueTuple>,
     query: InsertStatement,
+    conflict_key: Option<Condition>,
     model: PhantomData<A>,
 }
 
@@ -23,11 +27,37 @@ pub struct InsertResult<A>
 where
     A: ActiveModelTrait,
 {
-    /// The id performed when AUTOINCREMENT was performed on the PrimaryKey
+    /// The primary key of the inserted row, see [InsertResult::key]
     pub last_insert_id: <<<A as ActiveModelTrait>::Entity as EntityTrait>::PrimaryKey as PrimaryKeyTrait>::ValueType,
 }
 
-/// The types of results for an INSERT operation
+impl<A> InsertResult<A>
+where
+    A: ActiveModelTrait,
+{
+    /// The primary key of the inserted row, whichever way it was generated:
+    ///
+    /// - A key which is not auto increment, like a UUID generated by the client or a composite
+    ///   key, is the one `Set` on the ActiveModel, echoed back
+    /// - Otherwise, it is the key returned by the database with `RETURNING` where supported,
+    ///   or its last insert id, which only exists for auto increment keys. A key generated by
+    ///   the database without `RETURNING`, like a UUID default on MySQL, cannot be known,
+    ///   so executing the insert fails instead, with [DbErr::UnpackInsertId] or
+    ///   [DbErr::RecordNotInserted] when the last insert id is `0`.
+    pub fn key(
+        &self,
+    ) -> &<<<A as ActiveModelTrait>::Entity as EntityTrait>::PrimaryKey as PrimaryKeyTrait>::ValueType
+    {
+        &self.last_insert_id
+    }
+}
+
+/// The types of results for an INSERT operation, see [TryInsert]
+///
+/// With `ON CONFLICT DO NOTHING`, an insert is `Conflicted` when no row is inserted. Postgres and
+/// SQLite tell it by the absence of a `RETURNING` row, or by the number of affected rows when
+/// executed without returning. MySQL only has the number of affected rows, which is also zero for
+/// an `ON DUPLICATE KEY UPDATE` setting the existing row to its current values.
 #[derive(Debug)]
 pub enum TryInsertResult<T> {
     /// The INSERT statement did not have any value to insert
@@ -41,6 +71,7 @@ pub enum TryInsertResult<T> {
 impl<A> TryInsert<A>
 where
     A: ActiveModelTrait,
+    A::Entity: EntityWritable,
 {
     /// Execute an insert operation
     #[allow(unused_mut)]
@@ -76,8 +107,8 @@ where
         }
         let res = self.insert_struct.exec_without_returning(db).await;
         match res {
+            Ok(0) | Err(DbErr::RecordNotInserted) => Ok(TryInsertResult::Conflicted),
             Ok(res) => Ok(TryInsertResult::Inserted(res)),
-            Err(DbErr::RecordNotInserted) => Ok(TryInsertResult::Conflicted),
             Err(err) => Err(err),
         }
     }
@@ -95,7 +126,19 @@ where
         if self.insert_struct.columns.is_empty() {
             return Ok(TryInsertResult::Empty);
         }
-        let res = self.insert_struct.exec_with_returning(db).await;
+        let insert = self.insert_struct;
+        let conflict_key = match insert.on_conflict {
+            Some(_) => insert.conflict_key,
+            None => None,
+        };
+        let res = exec_insert_with_returning::<A, _>(
+            insert.primary_key,
+            insert.query,
+            conflict_key,
+            true,
+            db,
+        )
+        .await;
         match res {
             Ok(res) => Ok(TryInsertResult::Inserted(res)),
             Err(DbErr::RecordNotInserted) => Ok(TryInsertResult::Conflicted),
@@ -107,6 +150,7 @@ where
 impl<A> Insert<A>
 where
     A: ActiveModelTrait,
+    A::Entity: EntityWritable,
 {
     /// Execute an insert operation
     #[allow(unused_mut)]
@@ -144,6 +188,23 @@ where
     }
 
     /// Execute an insert operation and return the inserted model (use `RETURNING` syntax if supported)
+    ///
+    /// The model is the row as stored, with every column filled in, including the primary key
+    /// and the defaults computed by the database, like `DEFAULT now()`. Postgres and SQLite
+    /// return it with `RETURNING` in the same statement. MySQL lacks `RETURNING`, so the row
+    /// is selected by its primary key after the insert, taken from the model or from
+    /// `LAST_INSERT_ID()`: that is an extra round-trip to the database. Pass a transaction
+    /// as `db` for the select to see the row exactly as inserted.
+    ///
+    /// With [`on_conflict`](Insert::on_conflict), the returned model is the row as it stands
+    /// after the upsert, whether it was inserted or updated:
+    ///
+    /// - Postgres and SQLite return the row with `RETURNING` in the same statement.
+    ///   A conflict resolved with `DO NOTHING` returns no row, which is a [`DbErr::RecordNotFound`].
+    /// - MySQL lacks `RETURNING`, so the row is selected by a follow-up query keyed on the
+    ///   conflict target. `ON DUPLICATE KEY UPDATE` conflicts on any unique key, so the row is
+    ///   matched by the primary key or any unique column set on the (first) inserted model.
+    ///   Pass a transaction as `db` to run both statements atomically.
     pub fn exec_with_returning<'a, C>(
         self,
         db: &'a C,
@@ -153,7 +214,164 @@ where
         C: ConnectionTrait,
         A: 'a,
     {
-        Inserter::<A>::new(self.primary_key, self.query).exec_with_returning(db)
+        let mut inserter = Inserter::<A>::new(self.primary_key, self.query);
+        if self.on_conflict.is_some() {
+            inserter.conflict_key = self.conflict_key;
+        }
+        inserter.exec_with_returning(db)
+    }
+
+    /// Execute an insert operation and return the primary keys of all inserted rows,
+    /// in the order the rows were added
+    ///
+    /// Backends supporting `RETURNING` return the keys from the insert statement itself.
+    /// Otherwise (i.e. MySQL) the rows are inserted one by one within a transaction,
+    /// taking the generated key of each one.
+    pub async fn exec_with_returning_keys<C>(
+        self,
+        db: &C,
+    ) -> Result<Vec<<<A::Entity as EntityTrait>::PrimaryKey as PrimaryKeyTrait>::ValueType>, DbErr>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        type PrimaryKey<A> = <<A as ActiveModelTrait>::Entity as EntityTrait>::PrimaryKey;
+
+        let db_backend = db.get_database_backend();
+        let Insert {
+            mut query,
+            columns,
+            rows,
+            row_keys,
+            on_conflict,
+            ..
+        } = self;
+        if rows.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        if db.support_returning() {
+            let returning = Query::returning().exprs(PrimaryKey::<A>::iter().map(|c| {
+                c.into_column()
+                    .select_as(c.into_column().into_returning_expr(db_backend))
+            }));
+            query.returning(returning);
+            let cols = PrimaryKey::<A>::iter()
+                .map(|col| col.to_string())
+                .collect::<Vec<_>>();
+            return db
+                .query_all(db_backend.build(&query))
+                .await?
+                .into_iter()
+                .map(|row| {
+                    row.try_get_many("", cols.as_ref())
+                        .map_err(|_| DbErr::UnpackInsertId)
+                })
+                .collect();
+        }
+
+        let columns = set_columns::<A>(columns);
+        let txn = db.begin().await?;
+        let mut keys = Vec::with_capacity(rows.len());
+        for (row, primary_key) in rows.into_iter().zip(row_keys) {
+            let statement = rows_statement::<A>(&columns, [row], &on_conflict);
+            keys.push(
+                exec_insert::<A, _>(primary_key, statement, &txn)
+                    .await?
+                    .last_insert_id,
+            );
+        }
+        txn.commit().await?;
+
+        Ok(keys)
+    }
+}
+
+impl<A> ChunkedInsert<A>
+where
+    A: ActiveModelTrait,
+    A::Entity: EntityWritable,
+{
+    /// Execute the insert chunk by chunk within a transaction,
+    /// returning the total number of rows affected by the statements
+    pub async fn exec<C>(self, db: &C) -> Result<u64, DbErr>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        let db_backend = db.get_database_backend();
+        let Insert {
+            query,
+            columns,
+            rows,
+            on_conflict,
+            ..
+        } = self.insert;
+        if rows.is_empty() {
+            return Ok(0);
+        }
+        let columns = set_columns::<A>(columns);
+        if columns.is_empty() {
+            // Rows of default values take no parameters
+            return Ok(db.execute(db_backend.build(&query)).await?.rows_affected());
+        }
+        let chunk_size = self
+            .chunk_size
+            .unwrap_or_else(|| max_bind_params(db_backend) / columns.len())
+            .max(1);
+
+        let txn = db.begin().await?;
+        let mut rows_affected = 0;
+        let mut rows = rows.into_iter().peekable();
+        while rows.peek().is_some() {
+            let chunk = rows_statement::<A>(&columns, rows.by_ref().take(chunk_size), &on_conflict);
+            rows_affected += txn.execute(db_backend.build(&chunk)).await?.rows_affected();
+        }
+        txn.commit().await?;
+
+        Ok(rows_affected)
+    }
+}
+
+/// The columns having a value in the inserted rows
+fn set_columns<A>(columns: Vec<bool>) -> Vec<<A::Entity as EntityTrait>::Column>
+where
+    A: ActiveModelTrait,
+{
+    <A::Entity as EntityTrait>::Column::iter()
+        .zip(columns)
+        .filter_map(|(col, has_val)| has_val.then_some(col))
+        .collect()
+}
+
+/// Build a statement inserting the given rows, in the same way as the original insert
+fn rows_statement<A>(
+    columns: &[<A::Entity as EntityTrait>::Column],
+    rows: impl IntoIterator<Item = Vec<SimpleExpr>>,
+    on_conflict: &Option<OnConflict>,
+) -> InsertStatement
+where
+    A: ActiveModelTrait,
+{
+    let mut statement = Query::insert();
+    statement.into_table(A::Entity::default().table_ref());
+    if columns.is_empty() {
+        statement.or_default_values();
+    } else {
+        statement.columns(columns.to_vec());
+        for row in rows {
+            statement.values_panic(row);
+        }
+    }
+    if let Some(on_conflict) = on_conflict {
+        statement.on_conflict(on_conflict.clone());
+    }
+    statement
+}
+
+/// The maximum number of bind parameters in a single statement
+pub(crate) fn max_bind_params(db_backend: DbBackend) -> usize {
+    match db_backend {
+        DbBackend::MySql | DbBackend::Postgres => 65535,
+        DbBackend::Sqlite => 999,
     }
 }
 
@@ -166,6 +384,7 @@ where
         Self {
             primary_key,
             query,
+            conflict_key: None,
             model: PhantomData,
         }
     }
@@ -192,6 +411,9 @@ where
     }
 
     /// Execute an insert operation and return the inserted model (use `RETURNING` syntax if supported)
+    ///
+    /// Without `RETURNING`, an upsert is followed by a `SELECT` of the row by its unique keys,
+    /// within a transaction begun for them unless `db` is one already.
     pub fn exec_with_returning<'a, C>(
         self,
         db: &'a C,
@@ -201,7 +423,13 @@ where
         C: ConnectionTrait,
         A: 'a,
     {
-        exec_insert_with_returning::<A, _>(self.primary_key, self.query, db)
+        exec_insert_with_returning::<A, _>(
+            self.primary_key,
+            self.query,
+            self.conflict_key,
+            false,
+            db,
+        )
     }
 }
 
@@ -277,6 +505,8 @@ where
 async fn exec_insert_with_returning<A, C>(
     primary_key: Option<ValueTuple>,
     mut insert_statement: InsertStatement,
+    conflict_key: Option<Condition>,
+    try_insert: bool,
     db: &C,
 ) -> Result<<A::Entity as EntityTrait>::Model, DbErr>
 where
@@ -299,17 +529,183 @@ where
             .one(db)
             .await?
         }
-        false => {
-            let insert_res = exec_insert::<A, _>(primary_key, insert_statement, db).await?;
-            <A::Entity as EntityTrait>::find_by_id(insert_res.last_insert_id)
-                .one(db)
-                .await?
-        }
+        false => match conflict_key {
+            // The row is found within the same transaction as the upsert,
+            // so that it cannot be changed in between
+            Some(conflict_key) => {
+                let txn = db.begin_unless_in_transaction().await?;
+                let found = match &txn {
+                    Some(txn) => {
+                        exec_upsert_then_find::<A, _>(
+                            insert_statement,
+                            conflict_key,
+                            try_insert,
+                            txn,
+                        )
+                        .await?
+                    }
+                    None => {
+                        exec_upsert_then_find::<A, _>(
+                            insert_statement,
+                            conflict_key,
+                            try_insert,
+                            db,
+                        )
+                        .await?
+                    }
+                };
+                if let Some(txn) = txn {
+                    txn.commit().await?;
+                }
+                found
+            }
+            None => {
+                let insert_res = exec_insert::<A, _>(primary_key, insert_statement, db).await?;
+                <A::Entity as EntityTrait>::find_by_id(insert_res.last_insert_id)
+                    .one(db)
+                    .await?
+            }
+        },
     };
     match found {
         Some(model) => Ok(model),
+        None if try_insert => Err(DbErr::RecordNotInserted),
         None => Err(DbErr::RecordNotFound(
             "Failed to find inserted item".to_owned(),
         )),
     }
 }
+
+/// Execute an upsert, then find the row by its conflict key, as the row may have been updated
+/// rather than inserted, in which case `last_insert_id` does not identify it
+async fn exec_upsert_then_find<A, C>(
+    insert_statement: InsertStatement,
+    conflict_key: Condition,
+    try_insert: bool,
+    db: &C,
+) -> Result<Option<<A::Entity as EntityTrait>::Model>, DbErr>
+where
+    C: ConnectionTrait,
+    A: ActiveModelTrait,
+{
+    let insert_statement = db.get_database_backend().build(&insert_statement);
+    let res = db.execute(insert_statement).await?;
+    if try_insert && res.rows_affected() == 0 {
+        return Err(DbErr::RecordNotInserted);
+    }
+    <A::Entity as EntityTrait>::find()
+        .filter(conflict_key)
+        .one(db)
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{entity::prelude::*, tests_cfg::*, *};
+    use pretty_assertions::assert_eq;
+
+    #[smol_potat::test]
+    async fn insert_key_serial() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 15,
+                rows_affected: 1,
+            }])
+            .into_connection();
+        let res = cake::Entity::insert(cake::ActiveModel {
+            name: Set("Apple Pie".to_owned()),
+            ..Default::default()
+        })
+        .exec(&db)
+        .await?;
+        assert_eq!(res.key(), &15);
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[cake::Model {
+                id: 16,
+                name: "Apple Pie".to_owned(),
+            }]])
+            .into_connection();
+        let res = cake::Entity::insert(cake::ActiveModel {
+            name: Set("Apple Pie".to_owned()),
+            ..Default::default()
+        })
+        .exec(&db)
+        .await?;
+        assert_eq!(res.key(), &16);
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn insert_key_composite() -> Result<(), DbErr> {
+        // The last insert id of a composite key is meaningless, the key is echoed back
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .into_connection();
+        let res = cake_filling::Entity::insert(cake_filling::ActiveModel {
+            cake_id: Set(2),
+            filling_id: Set(3),
+        })
+        .exec(&db)
+        .await?;
+        assert_eq!(res.key(), &(2, 3));
+
+        Ok(())
+    }
+
+    #[cfg(feature = "with-uuid")]
+    #[smol_potat::test]
+    async fn insert_key_uuid() -> Result<(), DbErr> {
+        mod session {
+            use crate as sea_orm;
+            use crate::entity::prelude::*;
+
+            #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+            #[sea_orm(table_name = "session")]
+            pub struct Model {
+                #[sea_orm(primary_key, auto_increment = false)]
+                pub id: Uuid,
+                pub name: String,
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+            pub enum Relation {}
+
+            impl ActiveModelBehavior for ActiveModel {}
+        }
+
+        let id = Uuid::new_v4();
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .into_connection();
+        let res = session::Entity::insert(session::ActiveModel {
+            id: Set(id),
+            name: Set("Admin".to_owned()),
+        })
+        .exec(&db)
+        .await?;
+        assert_eq!(res.key(), &id);
+
+        // Generated by the database, the key cannot be known without `RETURNING`
+        let res = session::Entity::insert(session::ActiveModel {
+            name: Set("Admin".to_owned()),
+            ..Default::default()
+        })
+        .exec(&db)
+        .await;
+        assert_eq!(res.err(), Some(DbErr::RecordNotInserted));
+
+        Ok(())
+    }
+}
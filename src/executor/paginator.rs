@@ -1,6 +1,6 @@
 use crate::{
-    error::*, ConnectionTrait, DbBackend, EntityTrait, FromQueryResult, Select, SelectModel,
-    SelectTwo, SelectTwoModel, Selector, SelectorRaw, SelectorTrait,
+    error::*, ConnectionTrait, DbBackend, EntityTrait, FromQueryResult, Select, SelectChecks,
+    SelectModel, SelectTwo, SelectTwoModel, Selector, SelectorRaw, SelectorTrait,
 };
 use async_stream::stream;
 use futures::Stream;
@@ -18,6 +18,7 @@ where
     S: SelectorTrait + 'db,
 {
     pub(crate) query: SelectStatement,
+    pub(crate) checks: SelectChecks,
     pub(crate) page: u64,
     pub(crate) page_size: u64,
     pub(crate) db: &'db C,
@@ -49,6 +50,7 @@ where
             .offset(self.page_size * page)
             .to_owned();
         let builder = self.db.get_database_backend();
+        self.checks.ensure_supported(builder)?;
         let stmt = builder.build(&query);
         let rows = self.db.query_all(stmt).await?;
         let mut buffer = Vec::with_capacity(rows.len());
@@ -67,6 +69,7 @@ where
     /// Get the total number of items
     pub async fn num_items(&self) -> Result<u64, DbErr> {
         let builder = self.db.get_database_backend();
+        self.checks.ensure_supported(builder)?;
         let stmt = SelectStatement::new()
             .expr(Expr::cust("COUNT(*) AS num_items"))
             .from_subquery(
@@ -238,6 +241,7 @@ where
         assert!(page_size != 0, "page_size should not be zero");
         Paginator {
             query: self.query,
+            checks: self.checks,
             page: 0,
             page_size,
             db,
@@ -264,6 +268,7 @@ where
 
         Paginator {
             query,
+            checks: SelectChecks::default(),
             page: 0,
             page_size,
             db,
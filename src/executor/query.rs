@@ -407,6 +407,48 @@ macro_rules! try_getable_mysql {
     };
 }
 
+#[allow(unused_macros)]
+macro_rules! try_getable_postgres {
+    ( $type: ty ) => {
+        impl TryGetable for $type {
+            #[allow(unused_variables)]
+            fn try_get_by<I: ColIdx>(res: &QueryResult, idx: I) -> Result<Self, TryGetError> {
+                match &res.row {
+                    #[cfg(feature = "sqlx-mysql")]
+                    QueryResultRow::SqlxMySql(_) => Err(type_err(format!(
+                        "{} unsupported by sqlx-mysql",
+                        stringify!($type)
+                    ))
+                    .into()),
+                    #[cfg(feature = "sqlx-postgres")]
+                    QueryResultRow::SqlxPostgres(row) => row
+                        .try_get::<Option<$type>, _>(idx.as_sqlx_postgres_index())
+                        .map_err(|e| sqlx_error_to_query_err(e).into())
+                        .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
+                    #[cfg(feature = "sqlx-sqlite")]
+                    QueryResultRow::SqlxSqlite(_) => Err(type_err(format!(
+                        "{} unsupported by sqlx-sqlite",
+                        stringify!($type)
+                    ))
+                    .into()),
+                    #[cfg(feature = "mock")]
+                    QueryResultRow::Mock(row) => row.try_get(idx).map_err(|e| {
+                        debug_print!("{:#?}", e.to_string());
+                        err_null_idx_col(idx)
+                    }),
+                    #[cfg(feature = "proxy")]
+                    QueryResultRow::Proxy(row) => row.try_get(idx).map_err(|e| {
+                        debug_print!("{:#?}", e.to_string());
+                        err_null_idx_col(idx)
+                    }),
+                    #[allow(unreachable_patterns)]
+                    _ => unreachable!(),
+                }
+            }
+        }
+    };
+}
+
 #[allow(unused_macros)]
 macro_rules! try_getable_date_time {
     ( $type: ty ) => {
@@ -498,6 +540,48 @@ try_getable_all!(time::PrimitiveDateTime);
 #[cfg(feature = "with-time")]
 try_getable_all!(time::OffsetDateTime);
 
+impl TryGetable for crate::PgInterval {
+    #[allow(unused_variables)]
+    fn try_get_by<I: ColIdx>(res: &QueryResult, idx: I) -> Result<Self, TryGetError> {
+        match &res.row {
+            #[cfg(feature = "sqlx-mysql")]
+            QueryResultRow::SqlxMySql(_) => {
+                Err(type_err("PgInterval unsupported by sqlx-mysql").into())
+            }
+            #[cfg(feature = "sqlx-postgres")]
+            QueryResultRow::SqlxPostgres(row) => row
+                .try_get::<Option<sqlx::postgres::types::PgInterval>, _>(
+                    idx.as_sqlx_postgres_index(),
+                )
+                .map_err(|e| sqlx_error_to_query_err(e).into())
+                .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx)))
+                .map(Into::into),
+            #[cfg(feature = "sqlx-sqlite")]
+            QueryResultRow::SqlxSqlite(_) => {
+                Err(type_err("PgInterval unsupported by sqlx-sqlite").into())
+            }
+            #[cfg(feature = "mock")]
+            QueryResultRow::Mock(row) => row.try_get(idx).map_err(|e| {
+                debug_print!("{:#?}", e.to_string());
+                err_null_idx_col(idx)
+            }),
+            #[cfg(feature = "proxy")]
+            QueryResultRow::Proxy(row) => row.try_get(idx).map_err(|e| {
+                debug_print!("{:#?}", e.to_string());
+                err_null_idx_col(idx)
+            }),
+            #[allow(unreachable_patterns)]
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[cfg(feature = "with-ipnetwork")]
+try_getable_postgres!(ipnetwork::IpNetwork);
+
+#[cfg(feature = "with-mac_address")]
+try_getable_postgres!(mac_address::MacAddress);
+
 #[cfg(feature = "with-rust_decimal")]
 use rust_decimal::Decimal;
 
@@ -749,7 +833,13 @@ impl TryGetable for String {
     }
 }
 
-#[allow(dead_code)]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-postgres",
+    feature = "sqlx-sqlite",
+    feature = "mock",
+    feature = "proxy"
+))]
 fn err_null_idx_col<I: ColIdx>(idx: I) -> TryGetError {
     TryGetError::Null(format!("{idx:?}"))
 }
@@ -850,6 +940,12 @@ mod postgres_array {
     #[cfg(feature = "with-bigdecimal")]
     try_getable_postgres_array!(bigdecimal::BigDecimal);
 
+    #[cfg(feature = "with-ipnetwork")]
+    try_getable_postgres_array!(ipnetwork::IpNetwork);
+
+    #[cfg(feature = "with-mac_address")]
+    try_getable_postgres_array!(mac_address::MacAddress);
+
     #[allow(unused_macros)]
     macro_rules! try_getable_postgres_array_uuid {
         ( $type: ty, $conversion_fn: expr ) => {
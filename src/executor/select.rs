@@ -1,13 +1,13 @@
 use crate::{
-    error::*, ConnectionTrait, DbBackend, EntityTrait, FromQueryResult, IdenStatic, Iterable,
-    ModelTrait, PartialModelTrait, PrimaryKeyArity, PrimaryKeyToColumn, PrimaryKeyTrait,
-    QueryResult, QuerySelect, Select, SelectA, SelectB, SelectTwo, SelectTwoMany, Statement,
-    StreamTrait, TryGetableMany,
+    error::*, ConnectionTrait, DbBackend, EntityTrait, FromQueryResult, IdenStatic, IntoSimpleExpr,
+    Iterable, ModelTrait, PartialModelTrait, PrimaryKeyArity, PrimaryKeyToColumn, PrimaryKeyTrait,
+    QueryResult, QuerySelect, Select, SelectA, SelectB, SelectChecks, SelectTwo, SelectTwoMany,
+    Statement, StreamTrait, TryGetableMany,
 };
 use futures::{Stream, TryStreamExt};
-use sea_query::{SelectStatement, Value};
+use sea_query::{Func, SelectStatement, Value};
 use std::collections::HashMap;
-use std::{hash::Hash, marker::PhantomData, pin::Pin};
+use std::{hash::Hash, marker::PhantomData, pin::Pin, time::Duration};
 
 #[cfg(feature = "with-json")]
 use crate::JsonValue;
@@ -19,7 +19,10 @@ where
     S: SelectorTrait,
 {
     pub(crate) query: SelectStatement,
+    pub(crate) checks: SelectChecks,
     selector: S,
+    fetch_size: Option<u64>,
+    timeout: Option<Duration>,
 }
 
 /// Performs a raw `SELECT` operation on a model
@@ -31,6 +34,8 @@ where
     pub(crate) stmt: Statement,
     #[allow(dead_code)]
     selector: S,
+    fetch_size: Option<u64>,
+    timeout: Option<Duration>,
 }
 
 /// A Trait for any type that can perform SELECT queries
@@ -141,6 +146,8 @@ where
         SelectorRaw {
             stmt,
             selector: SelectModel { model: PhantomData },
+            fetch_size: None,
+            timeout: None,
         }
     }
 
@@ -151,7 +158,10 @@ where
     {
         Selector {
             query: self.query,
+            checks: self.checks,
             selector: SelectModel { model: PhantomData },
+            fetch_size: None,
+            timeout: None,
         }
     }
 
@@ -199,7 +209,10 @@ where
     pub fn into_json(self) -> Selector<SelectModel<JsonValue>> {
         Selector {
             query: self.query,
+            checks: self.checks,
             selector: SelectModel { model: PhantomData },
+            fetch_size: None,
+            timeout: None,
         }
     }
 
@@ -310,7 +323,9 @@ where
         T: TryGetableMany,
         C: strum::IntoEnumIterator + sea_query::Iden,
     {
-        Selector::<SelectGetableValue<T, C>>::with_columns(self.query)
+        let mut selector = Selector::<SelectGetableValue<T, C>>::with_columns(self.query);
+        selector.checks = self.checks;
+        selector
     }
 
     /// ```
@@ -408,7 +423,9 @@ where
     where
         T: TryGetableMany,
     {
-        Selector::<SelectGetableTuple<T>>::into_tuple(self.query)
+        let mut selector = Selector::<SelectGetableTuple<T>>::into_tuple(self.query);
+        selector.checks = self.checks;
+        selector
     }
 
     /// Get one Model from the SELECT query
@@ -427,7 +444,126 @@ where
         self.into_model().all(db).await
     }
 
-    /// Stream the results of a SELECT operation on a Model
+    /// Count the distinct values of a column, or of an expression, with `COUNT(DISTINCT ...)`
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results([[maplit::btreemap! {
+    /// #         "num_items" => Into::<Value>::into(2i64),
+    /// #     }]])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::fruit};
+    ///
+    /// let num_cakes = fruit::Entity::find()
+    ///     .count_distinct(fruit::Column::CakeId, &db)
+    ///     .await?;
+    ///
+    /// assert_eq!(num_cakes, 2);
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     [Transaction::from_sql_and_values(
+    ///         DbBackend::Postgres,
+    ///         r#"SELECT COUNT(DISTINCT "fruit"."cake_id") AS "num_items" FROM "fruit" LIMIT $1"#,
+    ///         [1u64.into()]
+    ///     ),]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn count_distinct<C, T>(self, col: T, db: &C) -> Result<u64, DbErr>
+    where
+        C: ConnectionTrait,
+        T: IntoSimpleExpr,
+    {
+        let mut select = self
+            .select_only()
+            .expr_as(Func::count_distinct(col.into_simple_expr()), "num_items");
+        select.query.clear_order_by().reset_limit().reset_offset();
+        let num_items: Option<i64> = select.into_tuple().one(db).await?;
+        Ok(num_items.unwrap_or(0) as u64)
+    }
+
+    /// Fetch at most `fetch_size` rows from the database at a time when streaming,
+    /// see [`Selector::fetch_size`]
+    pub fn fetch_size(self, fetch_size: u64) -> Selector<SelectModel<E::Model>> {
+        self.into_model().fetch_size(fetch_size)
+    }
+
+    /// Fail with [DbErr::Timeout] if the query does not complete within `timeout`,
+    /// see [`Selector::query_timeout`]
+    pub fn query_timeout(self, timeout: Duration) -> Selector<SelectModel<E::Model>> {
+        self.into_model().query_timeout(timeout)
+    }
+
+    /// Get the query plan of the SELECT query, see [`Selector::explain`]
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results([[
+    /// #         maplit::btreemap! {
+    /// #             "QUERY PLAN" => Into::<Value>::into("Seq Scan on cake"),
+    /// #         },
+    /// #         maplit::btreemap! {
+    /// #             "QUERY PLAN" => Into::<Value>::into("  Filter: (name = 'Cheese'::text)"),
+    /// #         },
+    /// #     ]])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake};
+    ///
+    /// let plan = cake::Entity::find()
+    ///     .filter(cake::Column::Name.eq("Cheese"))
+    ///     .explain(&db, true)
+    ///     .await?;
+    ///
+    /// assert_eq!(plan, "Seq Scan on cake\n  Filter: (name = 'Cheese'::text)");
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     [Transaction::from_sql_and_values(
+    ///         DbBackend::Postgres,
+    ///         r#"EXPLAIN (ANALYZE) SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."name" = $1"#,
+    ///         ["Cheese".into()]
+    ///     ),]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn explain<C>(self, db: &C, analyze: bool) -> Result<String, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        self.into_model::<E::Model>().explain(db, analyze).await
+    }
+
+    /// Get the query plan of the SELECT query in JSON, see [`Selector::explain_json`]
+    #[cfg(feature = "with-json")]
+    pub async fn explain_json<C>(self, db: &C, analyze: bool) -> Result<JsonValue, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        self.into_model::<E::Model>()
+            .explain_json(db, analyze)
+            .await
+    }
+
+    /// Stream the results of a SELECT operation on a Model, see [`Selector::stream`]
     pub async fn stream<'a: 'b, 'b, C>(
         self,
         db: &'a C,
@@ -464,7 +600,10 @@ where
     {
         Selector {
             query: self.query,
+            checks: self.checks,
             selector: SelectTwoModel { model: PhantomData },
+            fetch_size: None,
+            timeout: None,
         }
     }
 
@@ -485,7 +624,10 @@ where
     pub fn into_json(self) -> Selector<SelectTwoModel<JsonValue, JsonValue>> {
         Selector {
             query: self.query,
+            checks: self.checks,
             selector: SelectTwoModel { model: PhantomData },
+            fetch_size: None,
+            timeout: None,
         }
     }
 
@@ -543,7 +685,10 @@ where
     {
         Selector {
             query: self.query,
+            checks: self.checks,
             selector: SelectTwoModel { model: PhantomData },
+            fetch_size: None,
+            timeout: None,
         }
     }
 
@@ -564,7 +709,10 @@ where
     pub fn into_json(self) -> Selector<SelectTwoModel<JsonValue, JsonValue>> {
         Selector {
             query: self.query,
+            checks: self.checks,
             selector: SelectTwoModel { model: PhantomData },
+            fetch_size: None,
+            timeout: None,
         }
     }
 
@@ -631,10 +779,13 @@ where
     {
         Selector {
             query,
+            checks: SelectChecks::default(),
             selector: SelectGetableValue {
                 columns: PhantomData,
                 model: PhantomData,
             },
+            fetch_size: None,
+            timeout: None,
         }
     }
 
@@ -645,20 +796,26 @@ where
     {
         Selector {
             query,
+            checks: SelectChecks::default(),
             selector: SelectGetableTuple { model: PhantomData },
+            fetch_size: None,
+            timeout: None,
         }
     }
 
-    fn into_selector_raw<C>(self, db: &C) -> SelectorRaw<S>
+    fn into_selector_raw<C>(self, db: &C) -> Result<SelectorRaw<S>, DbErr>
     where
         C: ConnectionTrait,
     {
         let builder = db.get_database_backend();
+        self.checks.ensure_supported(builder)?;
         let stmt = builder.build(&self.query);
-        SelectorRaw {
+        Ok(SelectorRaw {
             stmt,
             selector: self.selector,
-        }
+            fetch_size: self.fetch_size,
+            timeout: self.timeout,
+        })
     }
 
     /// Get the SQL statement
@@ -672,7 +829,7 @@ where
         C: ConnectionTrait,
     {
         self.query.limit(1);
-        self.into_selector_raw(db).one(db).await
+        self.into_selector_raw(db)?.one(db).await
     }
 
     /// Get all items from the Select query
@@ -680,10 +837,40 @@ where
     where
         C: ConnectionTrait,
     {
-        self.into_selector_raw(db).all(db).await
+        self.into_selector_raw(db)?.all(db).await
     }
 
-    /// Stream the results of the Select operation
+    /// Fetch at most `fetch_size` rows from the database at a time when streaming.
+    ///
+    /// On Postgres, the rows are read through a server-side cursor declared within a
+    /// transaction, or a savepoint if a transaction is already open. Other backends ignore
+    /// the fetch size, as their drivers already read rows one at a time.
+    pub fn fetch_size(mut self, fetch_size: u64) -> Self {
+        self.fetch_size = Some(fetch_size);
+        self
+    }
+
+    /// Fail with [DbErr::Timeout] if the query does not complete within `timeout`.
+    ///
+    /// Queries run on a [DatabaseConnection](crate::DatabaseConnection) to Postgres or MySQL
+    /// are cancelled on the server on expiry. Within a transaction and on SQLite, the query is
+    /// only abandoned and keeps running until the database is done with it: on a transaction,
+    /// the next statement waits for it.
+    /// See [ConnectionTrait::query_all_with_timeout].
+    ///
+    /// The timeout applies to [one](Selector::one) and [all](Selector::all), not to streams.
+    pub fn query_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Stream the results of the Select operation.
+    ///
+    /// The rows are decoded one at a time as the stream is polled, and the full result set is not
+    /// held in memory. Without a [fetch size](Selector::fetch_size) the database may send rows
+    /// ahead of the consumer, bounded by the buffers of the connection: Postgres sends the whole
+    /// result set as fast as the socket accepts it, MySQL likewise, and SQLite steps
+    /// through the rows on a worker thread behind a bounded channel.
     pub async fn stream<'a: 'b, 'b, C>(
         self,
         db: &'a C,
@@ -693,7 +880,84 @@ where
         S: 'b,
         S::Item: Send,
     {
-        self.into_selector_raw(db).stream(db).await
+        self.into_selector_raw(db)?.stream(db).await
+    }
+
+    /// Run the Select query with `EXPLAIN` and get the query plan as text, one line per row
+    /// of the plan. With `analyze`, the query is executed, and the plan has the actual time
+    /// spent and the number of rows of each step. Mind that any side effect of the query,
+    /// like a locking clause, happens too.
+    ///
+    /// - Postgres runs `EXPLAIN` or `EXPLAIN (ANALYZE)`.
+    /// - MySQL runs `EXPLAIN FORMAT=TREE` or `EXPLAIN ANALYZE`, which need MySQL 8.0.18,
+    ///   and which MariaDB does not support.
+    /// - SQLite runs `EXPLAIN QUERY PLAN`, and the plan is made of the `detail` column.
+    ///   It cannot analyze a query, which is an error.
+    pub async fn explain<C>(self, db: &C, analyze: bool) -> Result<String, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let builder = db.get_database_backend();
+        let prefix = match (builder, analyze) {
+            (DbBackend::Postgres, false) => "EXPLAIN",
+            (DbBackend::Postgres, true) => "EXPLAIN (ANALYZE)",
+            (DbBackend::MySql, false) => "EXPLAIN FORMAT=TREE",
+            (DbBackend::MySql, true) => "EXPLAIN ANALYZE",
+            (DbBackend::Sqlite, false) => "EXPLAIN QUERY PLAN",
+            (DbBackend::Sqlite, true) => {
+                return Err(DbErr::Query(RuntimeErr::Internal(
+                    "SQLite does not support `EXPLAIN ANALYZE`".to_owned(),
+                )))
+            }
+        };
+        let rows = db.query_all(self.explain_statement(db, prefix)?).await?;
+        let lines = rows
+            .iter()
+            .map(|row| match builder {
+                DbBackend::Sqlite => row.try_get::<String>("", "detail"),
+                _ => row.try_get_by_index::<String>(0),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(lines.join("\n"))
+    }
+
+    /// Run the Select query with `EXPLAIN (FORMAT JSON)` and get the query plan in JSON.
+    /// With `analyze`, the query is executed, like with [`Selector::explain`].
+    ///
+    /// Only Postgres can format the plan in JSON; it is an error on any other backend.
+    #[cfg(feature = "with-json")]
+    pub async fn explain_json<C>(self, db: &C, analyze: bool) -> Result<JsonValue, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let builder = db.get_database_backend();
+        if builder != DbBackend::Postgres {
+            return Err(DbErr::Query(RuntimeErr::Internal(format!(
+                "{builder:?} does not support `EXPLAIN (FORMAT JSON)`, which is specific to Postgres"
+            ))));
+        }
+        let prefix = match analyze {
+            false => "EXPLAIN (FORMAT JSON)",
+            true => "EXPLAIN (ANALYZE, FORMAT JSON)",
+        };
+        let row = db.query_one(self.explain_statement(db, prefix)?).await?;
+        match row {
+            Some(row) => row.try_get_by_index(0),
+            None => Err(DbErr::RecordNotFound(
+                "Failed to explain the query".to_owned(),
+            )),
+        }
+    }
+
+    fn explain_statement<C>(self, db: &C, prefix: &str) -> Result<Statement, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let stmt = self.into_selector_raw(db)?.stmt;
+        Ok(Statement {
+            sql: format!("{prefix} {}", stmt.sql),
+            ..stmt
+        })
     }
 }
 
@@ -709,6 +973,8 @@ where
         SelectorRaw {
             stmt,
             selector: SelectModel { model: PhantomData },
+            fetch_size: None,
+            timeout: None,
         }
     }
 
@@ -725,6 +991,8 @@ where
                 columns: PhantomData,
                 model: PhantomData,
             },
+            fetch_size: None,
+            timeout: None,
         }
     }
 
@@ -799,6 +1067,8 @@ where
         SelectorRaw {
             stmt: self.stmt,
             selector: SelectModel { model: PhantomData },
+            fetch_size: self.fetch_size,
+            timeout: self.timeout,
         }
     }
 
@@ -863,9 +1133,25 @@ where
         SelectorRaw {
             stmt: self.stmt,
             selector: SelectModel { model: PhantomData },
+            fetch_size: self.fetch_size,
+            timeout: self.timeout,
         }
     }
 
+    /// Fetch at most `fetch_size` rows from the database at a time when streaming,
+    /// see [`Selector::fetch_size`]
+    pub fn fetch_size(mut self, fetch_size: u64) -> Self {
+        self.fetch_size = Some(fetch_size);
+        self
+    }
+
+    /// Fail with [DbErr::Timeout] if the query does not complete within `timeout`,
+    /// see [`Selector::query_timeout`]
+    pub fn query_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
     /// Get the SQL statement
     pub fn into_statement(self) -> Statement {
         self.stmt
@@ -915,7 +1201,10 @@ where
     where
         C: ConnectionTrait,
     {
-        let row = db.query_one(self.stmt).await?;
+        let row = match self.timeout {
+            Some(timeout) => db.query_one_with_timeout(self.stmt, timeout).await?,
+            None => db.query_one(self.stmt).await?,
+        };
         match row {
             Some(row) => Ok(Some(S::from_raw_query_result(row)?)),
             None => Ok(None),
@@ -966,7 +1255,10 @@ where
     where
         C: ConnectionTrait,
     {
-        let rows = db.query_all(self.stmt).await?;
+        let rows = match self.timeout {
+            Some(timeout) => db.query_all_with_timeout(self.stmt, timeout).await?,
+            None => db.query_all(self.stmt).await?,
+        };
         let mut models = Vec::new();
         for row in rows.into_iter() {
             models.push(S::from_raw_query_result(row)?);
@@ -984,7 +1276,10 @@ where
         S: 'b,
         S::Item: Send,
     {
-        let stream = db.stream(self.stmt).await?;
+        let stream = match self.fetch_size {
+            Some(fetch_size) => db.stream_with_fetch_size(self.stmt, fetch_size).await?,
+            None => db.stream(self.stmt).await?,
+        };
         Ok(Box::pin(stream.and_then(|row| {
             futures::future::ready(S::from_raw_query_result(row))
         })))
@@ -1129,6 +1424,33 @@ where
     acc
 }
 
+impl SelectChecks {
+    /// Refuse to execute a query the backend would not run as written.
+    ///
+    /// SQLite has no row-level locking, and its query builder drops the locking clause altogether;
+    /// rather than silently running the query unlocked, it is rejected.
+    /// Likewise, `DISTINCT ON` is only available on Postgres.
+    pub(crate) fn ensure_supported(&self, backend: DbBackend) -> Result<(), DbErr> {
+        if let Some((lhs, rhs)) = self.union_mismatch {
+            return Err(DbErr::Query(RuntimeErr::Internal(format!(
+                "Cannot union queries selecting {lhs} and {rhs} columns"
+            ))));
+        }
+        if backend == DbBackend::Sqlite && self.row_lock {
+            return Err(DbErr::Query(RuntimeErr::Internal(
+                "SQLite does not support row locking: `FOR UPDATE` and `FOR SHARE` are unavailable"
+                    .to_owned(),
+            )));
+        }
+        if backend != DbBackend::Postgres && self.distinct_on {
+            return Err(DbErr::Query(RuntimeErr::Internal(format!(
+                "{backend:?} does not support `DISTINCT ON`, which is specific to Postgres"
+            ))));
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use pretty_assertions::assert_eq;
@@ -1533,6 +1855,56 @@ mod tests {
         Ok(())
     }
 
+    #[smol_potat::test]
+    pub async fn with_related_many_to_many_ordered() -> Result<(), sea_orm::DbErr> {
+        use sea_orm::tests_cfg::*;
+        use sea_orm::{
+            DbBackend, EntityTrait, IntoMockRow, MockDatabase, QueryOrder, Statement, Transaction,
+        };
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[
+                cake_filling_models(2, 2).into_mock_row(),
+                (cake_model(3), None::<filling::Model>).into_mock_row(),
+                cake_filling_models(1, 1).into_mock_row(),
+                cake_filling_models(1, 3).into_mock_row(),
+            ]])
+            .into_connection();
+
+        assert_eq!(
+            Cake::find()
+                .order_by_desc(cake::Column::Name)
+                .find_with_related(Filling)
+                .all(&db)
+                .await?,
+            [
+                (cake_model(2), vec![filling_model(2)]),
+                (cake_model(3), vec![]),
+                (cake_model(1), vec![filling_model(1), filling_model(3)]),
+            ]
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::many([Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT "cake"."id" AS "A_id", "cake"."name" AS "A_name","#,
+                    r#""filling"."id" AS "B_id", "filling"."name" AS "B_name", "filling"."vendor_id" AS "B_vendor_id""#,
+                    r#"FROM "cake""#,
+                    r#"LEFT JOIN "cake_filling" ON "cake"."id" = "cake_filling"."cake_id""#,
+                    r#"LEFT JOIN "filling" ON "cake_filling"."filling_id" = "filling"."id""#,
+                    r#"ORDER BY "cake"."name" DESC, "cake"."id" ASC"#
+                ]
+                .join(" ")
+                .as_str(),
+                []
+            ),])]
+        );
+
+        Ok(())
+    }
+
     #[smol_potat::test]
     pub async fn also_linked_base() -> Result<(), sea_orm::DbErr> {
         use sea_orm::tests_cfg::*;
@@ -1818,4 +2190,115 @@ mod tests {
 
         Ok(())
     }
+
+    #[smol_potat::test]
+    pub async fn lock_unsupported_on_sqlite() -> Result<(), sea_orm::DbErr> {
+        use sea_orm::sea_query::LockBehavior;
+        use sea_orm::tests_cfg::*;
+        use sea_orm::{DbBackend, EntityTrait, MockDatabase, QuerySelect};
+
+        let db = MockDatabase::new(DbBackend::Sqlite).into_connection();
+
+        assert!(Cake::find().lock_exclusive().all(&db).await.is_err());
+        assert!(Cake::find()
+            .lock_exclusive_with_behavior(LockBehavior::SkipLocked)
+            .one(&db)
+            .await
+            .is_err());
+        assert!(Cake::find()
+            .lock_shared()
+            .cursor_by(cake::Column::Id)
+            .first(2)
+            .all(&db)
+            .await
+            .is_err());
+
+        assert!(db.into_transaction_log().is_empty());
+
+        // A quoted identifier is not a locking clause
+        let db = MockDatabase::new(DbBackend::Sqlite)
+            .append_query_results([Vec::<cake::Model>::new()])
+            .into_connection();
+        assert_eq!(
+            Cake::find()
+                .column_as(cake::Column::Id, "FOR UPDATE")
+                .all(&db)
+                .await?,
+            []
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    pub async fn distinct_on_unsupported() -> Result<(), sea_orm::DbErr> {
+        use sea_orm::tests_cfg::*;
+        use sea_orm::{DbBackend, EntityTrait, MockDatabase, QueryOrder, QuerySelect};
+
+        for backend in [DbBackend::MySql, DbBackend::Sqlite] {
+            let db = MockDatabase::new(backend).into_connection();
+            assert!(Cake::find()
+                .distinct_on([cake::Column::Name])
+                .order_by_asc(cake::Column::Name)
+                .all(&db)
+                .await
+                .is_err());
+            assert!(db.into_transaction_log().is_empty());
+        }
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[cake_model(1)]])
+            .into_connection();
+        assert_eq!(
+            Cake::find()
+                .distinct_on([cake::Column::Name])
+                .order_by_asc(cake::Column::Name)
+                .all(&db)
+                .await?,
+            [cake_model(1)]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    #[cfg(feature = "with-json")]
+    pub async fn select_explain() -> Result<(), sea_orm::DbErr> {
+        use sea_orm::tests_cfg::*;
+        use sea_orm::{DbBackend, EntityTrait, MockDatabase, Transaction};
+        use serde_json::json;
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[maplit::btreemap! {
+                "QUERY PLAN" => sea_query::Value::from("Seq Scan on cake"),
+            }]])
+            .append_query_results([[maplit::btreemap! {
+                "QUERY PLAN" => sea_query::Value::from(json!([{ "Plan": { "Node Type": "Seq Scan" } }])),
+            }]])
+            .into_connection();
+
+        assert_eq!(Cake::find().explain(&db, false).await?, "Seq Scan on cake");
+        assert_eq!(
+            Cake::find().explain_json(&db, true).await?,
+            json!([{ "Plan": { "Node Type": "Seq Scan" } }])
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"EXPLAIN SELECT "cake"."id", "cake"."name" FROM "cake""#,
+                    []
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"EXPLAIN (ANALYZE, FORMAT JSON) SELECT "cake"."id", "cake"."name" FROM "cake""#,
+                    []
+                ),
+            ]
+        );
+
+        Ok(())
+    }
 }
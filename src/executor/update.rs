@@ -1,6 +1,7 @@
 use crate::{
-    error::*, ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, IntoActiveModel,
-    Iterable, PrimaryKeyTrait, SelectModel, SelectorRaw, UpdateMany, UpdateOne,
+    error::*, ActiveModelTrait, ColumnTrait, ConnectionTrait, DbBackend, EntityTrait,
+    EntityWritable, IntoActiveModel, Iterable, PrimaryKeyTrait, SelectModel, SelectorRaw,
+    UpdateMany, UpdateOne,
 };
 use sea_query::{FromValueTuple, Query, UpdateStatement};
 
@@ -21,6 +22,7 @@ pub struct UpdateResult {
 impl<'a, A: 'a> UpdateOne<A>
 where
     A: ActiveModelTrait,
+    A::Entity: EntityWritable,
 {
     /// Execute an update operation on an ActiveModel
     pub async fn exec<'b, C>(self, db: &'b C) -> Result<<A::Entity as EntityTrait>::Model, DbErr>
@@ -36,7 +38,7 @@ where
 
 impl<'a, E> UpdateMany<E>
 where
-    E: EntityTrait,
+    E: EntityWritable,
 {
     /// Execute an update operation on multiple ActiveModels
     pub async fn exec<C>(self, db: &'a C) -> Result<UpdateResult, DbErr>
@@ -46,11 +48,10 @@ where
         Updater::new(self.query).exec(db).await
     }
 
-    /// Execute an update operation and return the updated model (use `RETURNING` syntax if supported)
+    /// Execute an update operation and return the updated models, using `RETURNING` syntax
     ///
-    /// # Panics
-    ///
-    /// Panics if the database backend does not support `UPDATE RETURNING`.
+    /// Returns an error if the database backend does not support `UPDATE RETURNING`, like MySQL.
+    /// The rows to be updated have to be selected beforehand in this case.
     pub async fn exec_with_returning<C>(self, db: &'a C) -> Result<Vec<E::Model>, DbErr>
     where
         C: ConnectionTrait,
@@ -138,7 +139,10 @@ impl Updater {
         }
     }
 
-    async fn exec_update_with_returning<E, C>(mut self, db: &C) -> Result<Vec<E::Model>, DbErr>
+    pub(crate) async fn exec_update_with_returning<E, C>(
+        mut self,
+        db: &C,
+    ) -> Result<Vec<E::Model>, DbErr>
     where
         E: EntityTrait,
         C: ConnectionTrait,
@@ -161,7 +165,7 @@ impl Updater {
                 .await?;
                 Ok(models)
             }
-            false => unimplemented!("Database backend doesn't support RETURNING"),
+            false => Err(returning_unsupported(db.get_database_backend())),
         }
     }
 
@@ -170,6 +174,12 @@ impl Updater {
     }
 }
 
+pub(crate) fn returning_unsupported(db_backend: DbBackend) -> DbErr {
+    DbErr::Exec(RuntimeErr::Internal(format!(
+        "Database backend doesn't support RETURNING: {db_backend:?}"
+    )))
+}
+
 async fn find_updated_model_by_id<A, C>(
     model: A,
     db: &C,
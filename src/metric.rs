@@ -16,6 +16,52 @@ pub struct Info<'a> {
     pub failed: bool,
 }
 
+/// Reports the statements taking at least `threshold` to complete,
+/// see [ConnectOptions::on_slow_query](crate::ConnectOptions::on_slow_query)
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-postgres",
+    feature = "sqlx-sqlite"
+))]
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct SlowQuery {
+    pub(crate) threshold: Duration,
+    pub(crate) callback: fn(&str, Duration),
+}
+
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-postgres",
+    feature = "sqlx-sqlite"
+))]
+impl SlowQuery {
+    fn report(&self, info: &Info<'_>) {
+        if info.elapsed >= self.threshold {
+            (self.callback)(&info.statement.sql, info.elapsed);
+        }
+    }
+}
+
+/// Combine the metric callback of a connection with its slow query callback
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-postgres",
+    feature = "sqlx-sqlite"
+))]
+pub(crate) fn callback(
+    metric_callback: Option<Callback>,
+    slow_query: Option<SlowQuery>,
+) -> Option<Callback> {
+    match (metric_callback, slow_query) {
+        (metric_callback, None) => metric_callback,
+        (None, Some(slow_query)) => Some(Arc::new(move |info| slow_query.report(info))),
+        (Some(metric_callback), Some(slow_query)) => Some(Arc::new(move |info| {
+            metric_callback(info);
+            slow_query.report(info);
+        })),
+    }
+}
+
 mod inner {
     #[allow(unused_macros)]
     macro_rules! metric {
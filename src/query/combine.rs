@@ -1,8 +1,11 @@
 use crate::{
-    ColumnTrait, EntityTrait, IdenStatic, Iterable, QueryTrait, Select, SelectTwo, SelectTwoMany,
+    ColumnTrait, EntityTrait, IdenStatic, Iterable, QueryTrait, Select, SelectChecks, SelectTwo,
+    SelectTwoMany,
 };
 use core::marker::PhantomData;
-use sea_query::{Alias, ColumnRef, Iden, Order, SeaRc, SelectExpr, SelectStatement, SimpleExpr};
+use sea_query::{
+    Alias, ColumnRef, Iden, Order, SeaRc, SelectExpr, SelectStatement, SimpleExpr, UnionType,
+};
 
 macro_rules! select_def {
     ( $ident: ident, $str: expr ) => {
@@ -77,7 +80,7 @@ where
         F: EntityTrait,
     {
         self = self.apply_alias(SelectA.as_str());
-        SelectTwo::new(self.into_query())
+        SelectTwo::new(self.query, self.checks)
     }
 
     /// Makes a SELECT operation in conjunction to another relation
@@ -86,22 +89,95 @@ where
         F: EntityTrait,
     {
         self = self.apply_alias(SelectA.as_str());
-        SelectTwoMany::new(self.into_query())
+        SelectTwoMany::new(self.query, self.checks)
+    }
+
+    /// Combine the rows of another SELECT of the same Entity, removing duplicates.
+    /// The combined query is converted into models like any other Select.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .filter(cake::Column::Id.eq(1))
+    ///         .union(cake::Entity::find().filter(cake::Column::Name.contains("cheese")))
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     [
+    ///         "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`id` = 1",
+    ///         "UNION (SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`name` LIKE '%cheese%')",
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    ///
+    /// Executing the query returns an error if both queries
+    /// do not select the same number of columns.
+    pub fn union(self, other: Select<E>) -> Self {
+        self.union_with(UnionType::Distinct, other)
+    }
+
+    /// Combine the rows of another SELECT of the same Entity, keeping duplicates
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .filter(cake::Column::Id.eq(1))
+    ///         .union_all(cake::Entity::find().filter(cake::Column::Id.eq(2)))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     [
+    ///         r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" = 1"#,
+    ///         r#"UNION ALL (SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" = 2)"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    ///
+    /// Executing the query returns an error if both queries
+    /// do not select the same number of columns.
+    pub fn union_all(self, other: Select<E>) -> Self {
+        self.union_with(UnionType::All, other)
+    }
+
+    fn union_with(mut self, union_type: UnionType, mut other: Select<E>) -> Self {
+        let (lhs, rhs) = (
+            column_count(&mut self.query),
+            column_count(&mut other.query),
+        );
+        if lhs != rhs {
+            self.checks.union_mismatch.get_or_insert((lhs, rhs));
+        }
+        self.query.union(union_type, other.query);
+        self.checks.row_lock |= other.checks.row_lock;
+        self.checks.distinct_on |= other.checks.distinct_on;
+        self.checks.union_mismatch = self.checks.union_mismatch.or(other.checks.union_mismatch);
+        self
     }
 }
 
+fn column_count(query: &mut SelectStatement) -> usize {
+    let mut count = 0;
+    query.exprs_mut_for_each(|_| count += 1);
+    count
+}
+
 impl<E, F> SelectTwo<E, F>
 where
     E: EntityTrait,
     F: EntityTrait,
 {
-    pub(crate) fn new(query: SelectStatement) -> Self {
-        Self::new_without_prepare(query).prepare_select()
+    pub(crate) fn new(query: SelectStatement, checks: SelectChecks) -> Self {
+        Self::new_without_prepare(query, checks).prepare_select()
     }
 
-    pub(crate) fn new_without_prepare(query: SelectStatement) -> Self {
+    pub(crate) fn new_without_prepare(query: SelectStatement, checks: SelectChecks) -> Self {
         Self {
             query,
+            checks,
             entity: PhantomData,
         }
     }
@@ -117,15 +193,16 @@ where
     E: EntityTrait,
     F: EntityTrait,
 {
-    pub(crate) fn new(query: SelectStatement) -> Self {
-        Self::new_without_prepare(query)
+    pub(crate) fn new(query: SelectStatement, checks: SelectChecks) -> Self {
+        Self::new_without_prepare(query, checks)
             .prepare_select()
             .prepare_order_by()
     }
 
-    pub(crate) fn new_without_prepare(query: SelectStatement) -> Self {
+    pub(crate) fn new_without_prepare(query: SelectStatement, checks: SelectChecks) -> Self {
         Self {
             query,
+            checks,
             entity: PhantomData,
         }
     }
@@ -246,4 +323,21 @@ mod tests {
             ].join(" ")
         );
     }
+
+    #[smol_potat::test]
+    async fn union_column_mismatch() {
+        use crate::{DbErr, MockDatabase, RuntimeErr};
+
+        let db = MockDatabase::new(DbBackend::Postgres).into_connection();
+        assert_eq!(
+            cake::Entity::find()
+                .union(cake::Entity::find().select_only().column(cake::Column::Id))
+                .all(&db)
+                .await,
+            Err(DbErr::Query(RuntimeErr::Internal(
+                "Cannot union queries selecting 2 and 1 columns".to_owned()
+            )))
+        );
+        assert!(db.into_transaction_log().is_empty());
+    }
 }
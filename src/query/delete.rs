@@ -1,9 +1,9 @@
 use crate::{
-    ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, IntoActiveModel, Iterable,
+    ActiveModelTrait, ActiveValue, ColumnTrait, Condition, EntityTrait, IntoActiveModel, Iterable,
     PrimaryKeyToColumn, QueryFilter, QueryTrait,
 };
 use core::marker::PhantomData;
-use sea_query::DeleteStatement;
+use sea_query::{DeleteStatement, Expr, IntoCondition, UpdateStatement, ValueTuple};
 
 /// Defines the structure for a delete operation
 #[derive(Clone, Debug)]
@@ -16,6 +16,8 @@ where
     A: ActiveModelTrait,
 {
     pub(crate) query: DeleteStatement,
+    /// Marks the row as deleted instead, if the entity has a soft-delete column
+    pub(crate) soft_delete: Option<UpdateStatement>,
     pub(crate) model: A,
 }
 
@@ -26,9 +28,22 @@ where
     E: EntityTrait,
 {
     pub(crate) query: DeleteStatement,
+    /// Marks the rows as deleted instead, if the entity has a soft-delete column
+    pub(crate) soft_delete: Option<UpdateStatement>,
     pub(crate) entity: PhantomData<E>,
 }
 
+/// Perform a delete operation on the models with any of the given primary keys,
+/// split across several statements if there are too many keys to bind at once
+#[derive(Clone, Debug)]
+pub struct DeleteByIds<E>
+where
+    E: EntityTrait,
+{
+    pub(crate) delete: DeleteMany<E>,
+    pub(crate) ids: Vec<ValueTuple>,
+}
+
 impl Delete {
     /// Delete one Model or ActiveModel
     ///
@@ -70,6 +85,7 @@ impl Delete {
             query: DeleteStatement::new()
                 .from_table(A::Entity::default().table_ref())
                 .to_owned(),
+            soft_delete: soft_delete_query(A::Entity::default()),
             model: model.into_active_model(),
         };
         myself.prepare()
@@ -96,6 +112,7 @@ impl Delete {
             query: DeleteStatement::new()
                 .from_table(entity.table_ref())
                 .to_owned(),
+            soft_delete: soft_delete_query(entity),
             entity: PhantomData,
         }
     }
@@ -118,6 +135,90 @@ where
         }
         self
     }
+
+    /// Delete the row for good, even if the entity has a
+    /// [soft-delete](crate::ColumnDef::soft_delete) column
+    pub fn really_delete(mut self) -> Self {
+        self.soft_delete = None;
+        self
+    }
+}
+
+impl<E> DeleteMany<E>
+where
+    E: EntityTrait,
+{
+    /// Delete the rows for good, even if the entity has a
+    /// [soft-delete](crate::ColumnDef::soft_delete) column
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::fruit, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     fruit::Entity::delete_many()
+    ///         .filter(fruit::Column::Name.contains("Apple"))
+    ///         .really_delete()
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"DELETE FROM "fruit" WHERE "fruit"."name" LIKE '%Apple%'"#,
+    /// );
+    /// ```
+    pub fn really_delete(mut self) -> Self {
+        self.soft_delete = None;
+        self
+    }
+}
+
+impl<E> DeleteByIds<E>
+where
+    E: EntityTrait,
+{
+    /// Delete the rows for good, even if the entity has a
+    /// [soft-delete](crate::ColumnDef::soft_delete) column
+    pub fn really_delete(mut self) -> Self {
+        self.delete = self.delete.really_delete();
+        self
+    }
+
+    /// The delete operation on the models with any of the given primary keys
+    pub(crate) fn filter_by_ids(&self, ids: &[ValueTuple]) -> DeleteMany<E> {
+        let condition = match E::PrimaryKey::iter().count() {
+            1 => {
+                let col = E::PrimaryKey::iter()
+                    .next()
+                    .expect("primary key has a column")
+                    .into_column();
+                Condition::all().add(col.is_in(ids.iter().cloned().flatten()))
+            }
+            // `(a, b) IN ((1, 2), ..)` is not supported by every backend
+            _ => ids.iter().fold(Condition::any(), |condition, id| {
+                let id = E::PrimaryKey::iter()
+                    .zip(id.clone())
+                    .fold(Condition::all(), |id, (key, v)| {
+                        id.add(key.into_column().eq(v))
+                    });
+                condition.add(id)
+            }),
+        };
+        self.delete.clone().filter(condition)
+    }
+}
+
+/// Set the soft-delete columns of the entity, if any, to the current timestamp,
+/// skipping the rows already deleted
+fn soft_delete_query<E>(entity: E) -> Option<UpdateStatement>
+where
+    E: EntityTrait,
+{
+    let mut query = UpdateStatement::new().table(entity.table_ref()).to_owned();
+    for col in E::Column::iter() {
+        if col.def().is_soft_delete() {
+            query
+                .value(col, Expr::current_timestamp())
+                .and_where(col.is_null());
+        }
+    }
+    (!query.get_values().is_empty()).then_some(query)
 }
 
 impl<A> QueryFilter for DeleteOne<A>
@@ -129,6 +230,18 @@ where
     fn query(&mut self) -> &mut DeleteStatement {
         &mut self.query
     }
+
+    fn filter<F>(mut self, filter: F) -> Self
+    where
+        F: IntoCondition,
+    {
+        let condition = filter.into_condition();
+        if let Some(soft_delete) = &mut self.soft_delete {
+            soft_delete.cond_where(condition.clone());
+        }
+        self.query.cond_where(condition);
+        self
+    }
 }
 
 impl<E> QueryFilter for DeleteMany<E>
@@ -140,6 +253,18 @@ where
     fn query(&mut self) -> &mut DeleteStatement {
         &mut self.query
     }
+
+    fn filter<F>(mut self, filter: F) -> Self
+    where
+        F: IntoCondition,
+    {
+        let condition = filter.into_condition();
+        if let Some(soft_delete) = &mut self.soft_delete {
+            soft_delete.cond_where(condition.clone());
+        }
+        self.query.cond_where(condition);
+        self
+    }
 }
 
 impl<A> QueryTrait for DeleteOne<A>
@@ -183,7 +308,7 @@ where
 #[cfg(test)]
 mod tests {
     use crate::tests_cfg::{cake, fruit};
-    use crate::{entity::*, query::*, DbBackend};
+    use crate::{entity::*, query::*, DbBackend, DbErr};
 
     #[test]
     fn delete_1() {
@@ -217,4 +342,87 @@ mod tests {
             r#"DELETE FROM "fruit" WHERE "fruit"."name" LIKE '%Cheese%'"#,
         );
     }
+
+    #[smol_potat::test]
+    async fn delete_3() -> Result<(), DbErr> {
+        use crate::{DbBackend, MockDatabase, MockExecResult, Transaction};
+
+        mod note {
+            use crate as sea_orm;
+            use crate::entity::prelude::*;
+
+            #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+            #[sea_orm(table_name = "note")]
+            pub struct Model {
+                #[sea_orm(primary_key)]
+                pub id: i32,
+                pub text: String,
+                #[sea_orm(soft_delete)]
+                pub deleted_at: Option<DateTimeUtc>,
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+            pub enum Relation {}
+
+            impl ActiveModelBehavior for ActiveModel {}
+        }
+
+        assert_eq!(
+            note::Entity::find_by_id(1)
+                .build(DbBackend::Postgres)
+                .to_string(),
+            r#"SELECT "note"."id", "note"."text", "note"."deleted_at" FROM "note" WHERE "note"."deleted_at" IS NULL AND "note"."id" = 1"#,
+        );
+        assert_eq!(
+            note::Entity::with_deleted()
+                .filter(note::Column::Id.eq(1))
+                .build(DbBackend::Postgres)
+                .to_string(),
+            r#"SELECT "note"."id", "note"."text", "note"."deleted_at" FROM "note" WHERE "note"."id" = 1"#,
+        );
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_exec_results([
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 1,
+                },
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 1,
+                },
+            ])
+            .into_connection();
+
+        assert_eq!(
+            note::Entity::delete_by_id(1).exec(&db).await?.rows_affected,
+            1
+        );
+        assert_eq!(
+            note::Entity::delete_by_id(1)
+                .really_delete()
+                .exec(&db)
+                .await?
+                .rows_affected,
+            1
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"UPDATE "note" SET "deleted_at" = CURRENT_TIMESTAMP WHERE "note"."deleted_at" IS NULL AND "note"."id" = $1"#,
+                    [1.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"DELETE FROM "note" WHERE "note"."id" = $1"#,
+                    [1.into()]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
 }
@@ -4,7 +4,7 @@ use crate::{
 };
 use sea_query::{
     Alias, ConditionType, Expr, Iden, IntoCondition, IntoIden, LockBehavior, LockType,
-    NullOrdering, SeaRc, SelectExpr, SelectStatement, SimpleExpr, TableRef,
+    NullOrdering, SeaRc, SelectExpr, SelectStatement, SimpleExpr, TableRef, WindowStatement,
 };
 pub use sea_query::{Condition, ConditionalStatement, DynIden, JoinType, Order, OrderedStatement};
 
@@ -21,6 +21,14 @@ pub trait QuerySelect: Sized {
     /// Add the select SQL statement
     fn query(&mut self) -> &mut SelectStatement;
 
+    /// Record that a locking clause was added, for it to be rejected on SQLite
+    #[doc(hidden)]
+    fn mark_row_lock(&mut self) {}
+
+    /// Record that a `DISTINCT ON` clause was added, for it to be rejected outside Postgres
+    #[doc(hidden)]
+    fn mark_distinct_on(&mut self) {}
+
     /// Clear the selection list
     fn select_only(mut self) -> Self {
         self.query().clear_selects();
@@ -341,7 +349,25 @@ pub trait QuerySelect: Sized {
     }
 
     /// Add a DISTINCT ON expression
-    /// NOTE: this function is only supported by `sqlx-postgres`
+    /// NOTE: this function is only supported by `sqlx-postgres`,
+    /// executing the query on other backends returns an error
+    ///
+    /// Postgres keeps the first row of each group, so the `ORDER BY` clause
+    /// must start with the same columns, followed by the ordering within the group.
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::fruit, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     fruit::Entity::find()
+    ///         .distinct_on([fruit::Column::CakeId])
+    ///         .order_by_asc(fruit::Column::CakeId)
+    ///         .order_by_desc(fruit::Column::Id)
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT DISTINCT ON ("cake_id") "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit" ORDER BY "fruit"."cake_id" ASC, "fruit"."id" DESC"#
+    /// );
+    /// ```
+    ///
     /// ```
     /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
     /// struct Input {
@@ -367,6 +393,7 @@ pub trait QuerySelect: Sized {
         I: IntoIterator<Item = T>,
     {
         self.query().distinct_on(cols);
+        self.mark_distinct_on();
         self
     }
 
@@ -432,18 +459,21 @@ pub trait QuerySelect: Sized {
     /// Select lock
     fn lock(mut self, lock_type: LockType) -> Self {
         self.query().lock(lock_type);
+        self.mark_row_lock();
         self
     }
 
     /// Select lock shared
     fn lock_shared(mut self) -> Self {
         self.query().lock_shared();
+        self.mark_row_lock();
         self
     }
 
     /// Select lock exclusive
     fn lock_exclusive(mut self) -> Self {
         self.query().lock_exclusive();
+        self.mark_row_lock();
         self
     }
 
@@ -452,9 +482,58 @@ pub trait QuerySelect: Sized {
     /// See [`SelectStatement::lock_with_behavior`](https://docs.rs/sea-query/*/sea_query/query/struct.SelectStatement.html#method.lock_with_behavior).
     fn lock_with_behavior(mut self, r#type: LockType, behavior: LockBehavior) -> Self {
         self.query().lock_with_behavior(r#type, behavior);
+        self.mark_row_lock();
         self
     }
 
+    /// Select lock shared, waiting on or skipping locked rows as per the behavior (if supported).
+    ///
+    /// Executing a locking query on SQLite, which has no row-level locks, returns an error.
+    /// ```
+    /// use sea_orm::sea_query::LockBehavior;
+    /// use sea_orm::{entity::*, tests_cfg::cake, DbBackend, QuerySelect, QueryTrait};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .lock_shared_with_behavior(LockBehavior::Nowait)
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "cake"."id", "cake"."name" FROM "cake" FOR SHARE NOWAIT"#
+    /// );
+    /// ```
+    fn lock_shared_with_behavior(self, behavior: LockBehavior) -> Self {
+        self.lock_with_behavior(LockType::Share, behavior)
+    }
+
+    /// Select lock exclusive, waiting on or skipping locked rows as per the behavior (if supported).
+    ///
+    /// Executing a locking query on SQLite, which has no row-level locks, returns an error.
+    /// With [LockBehavior::Nowait], a row locked by another transaction fails the query with
+    /// [SqlErr::LockNotAvailable](crate::error::SqlErr::LockNotAvailable) instead of waiting.
+    /// ```
+    /// use sea_orm::sea_query::LockBehavior;
+    /// use sea_orm::{entity::*, tests_cfg::cake, DbBackend, QuerySelect, QueryTrait};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .lock_exclusive_with_behavior(LockBehavior::SkipLocked)
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "cake"."id", "cake"."name" FROM "cake" FOR UPDATE SKIP LOCKED"#
+    /// );
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .lock_exclusive_with_behavior(LockBehavior::SkipLocked)
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     "SELECT `cake`.`id`, `cake`.`name` FROM `cake` FOR UPDATE SKIP LOCKED"
+    /// );
+    /// ```
+    fn lock_exclusive_with_behavior(self, behavior: LockBehavior) -> Self {
+        self.lock_with_behavior(LockType::Update, behavior)
+    }
+
     /// Add an expression to the select expression list.
     /// ```
     /// use sea_orm::sea_query::Expr;
@@ -556,6 +635,66 @@ pub trait QuerySelect: Sized {
         self
     }
 
+    /// Select a window function, computed over the rows of the given window.
+    ///
+    /// ```
+    /// use sea_orm::sea_query::{Expr, Order, WindowStatement};
+    /// use sea_orm::{entity::*, query::*, tests_cfg::fruit, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     fruit::Entity::find()
+    ///         .expr_window_as(
+    ///             Expr::cust("ROW_NUMBER()"),
+    ///             WindowStatement::partition_by(fruit::Column::CakeId)
+    ///                 .order_by(fruit::Column::Id, Order::Desc)
+    ///                 .to_owned(),
+    ///             "rn"
+    ///         )
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id", ROW_NUMBER() OVER ( PARTITION BY "cake_id" ORDER BY "id" DESC ) AS "rn" FROM "fruit""#
+    /// );
+    /// ```
+    ///
+    /// To filter on the result of the window function, e.g. to keep the first row of each
+    /// partition, select from the query as a subquery.
+    ///
+    /// ```
+    /// use sea_orm::sea_query::{Alias, Asterisk, Expr, Order, Query, WindowStatement};
+    /// use sea_orm::{entity::*, query::*, tests_cfg::fruit, DbBackend};
+    ///
+    /// let ranked = fruit::Entity::find()
+    ///     .expr_window_as(
+    ///         Expr::cust("ROW_NUMBER()"),
+    ///         WindowStatement::partition_by(fruit::Column::CakeId)
+    ///             .order_by(fruit::Column::Id, Order::Desc)
+    ///             .to_owned(),
+    ///         "rn",
+    ///     )
+    ///     .into_query();
+    ///
+    /// assert_eq!(
+    ///     DbBackend::Postgres.build(
+    ///         &Query::select()
+    ///             .column(Asterisk)
+    ///             .from_subquery(ranked, Alias::new("ranked"))
+    ///             .and_where(Expr::col(Alias::new("rn")).eq(1))
+    ///             .to_owned()
+    ///     )
+    ///     .to_string(),
+    ///     r#"SELECT * FROM (SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id", ROW_NUMBER() OVER ( PARTITION BY "cake_id" ORDER BY "id" DESC ) AS "rn" FROM "fruit") AS "ranked" WHERE "rn" = 1"#
+    /// );
+    /// ```
+    fn expr_window_as<T, A>(mut self, expr: T, window: WindowStatement, alias: A) -> Self
+    where
+        T: Into<SimpleExpr>,
+        A: IntoIdentity,
+    {
+        self.query()
+            .expr_window_as(expr, window, alias.into_identity());
+        self
+    }
+
     /// Shorthand of `expr_as(Expr::col((T, C)), A)`.
     ///
     /// ```
@@ -837,6 +976,69 @@ pub trait QueryFilter: Sized {
         self
     }
 
+    /// Add an AND WHERE EXISTS expression, keeping only the rows for which the
+    /// subquery returns at least one row. The subquery is usually correlated,
+    /// referencing the columns of the outer query.
+    /// ```
+    /// use sea_orm::sea_query::{Expr, Query};
+    /// use sea_orm::{entity::*, query::*, tests_cfg::{cake, fruit}, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .filter_exists(
+    ///             Query::select()
+    ///                 .expr(Expr::val(1))
+    ///                 .from(fruit::Entity)
+    ///                 .and_where(
+    ///                     Expr::col((fruit::Entity, fruit::Column::CakeId))
+    ///                         .equals((cake::Entity, cake::Column::Id))
+    ///                 )
+    ///                 .to_owned()
+    ///         )
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     [
+    ///         r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+    ///         r#"WHERE EXISTS(SELECT 1 FROM "fruit" WHERE "fruit"."cake_id" = "cake"."id")"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    fn filter_exists(self, subquery: SelectStatement) -> Self {
+        self.filter(Expr::exists(subquery))
+    }
+
+    /// Add an AND WHERE NOT EXISTS expression, keeping only the rows for which the
+    /// subquery returns no row
+    /// ```
+    /// use sea_orm::sea_query::{Expr, Query};
+    /// use sea_orm::{entity::*, query::*, tests_cfg::{cake, fruit}, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .filter_not_exists(
+    ///             Query::select()
+    ///                 .expr(Expr::val(1))
+    ///                 .from(fruit::Entity)
+    ///                 .and_where(
+    ///                     Expr::col((fruit::Entity, fruit::Column::CakeId))
+    ///                         .equals((cake::Entity, cake::Column::Id))
+    ///                 )
+    ///                 .to_owned()
+    ///         )
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     [
+    ///         "SELECT `cake`.`id`, `cake`.`name` FROM `cake`",
+    ///         "WHERE NOT EXISTS(SELECT 1 FROM `fruit` WHERE `fruit`.`cake_id` = `cake`.`id`)",
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    fn filter_not_exists(self, subquery: SelectStatement) -> Self {
+        self.filter(Expr::exists(subquery).not())
+    }
+
     /// Apply a where condition using the model's primary key
     fn belongs_to<M>(mut self, model: &M) -> Self
     where
@@ -894,6 +1096,26 @@ pub(crate) fn join_condition(mut rel: RelationDef) -> Condition {
     condition
 }
 
+/// The condition skipping the rows of the Entity `E` deleted through a
+/// [soft-delete](crate::ColumnDef::soft_delete) column, on the table or alias `tbl`;
+/// `None` if the Entity has no such column
+pub(crate) fn soft_delete_condition<E>(tbl: DynIden) -> Option<Condition>
+where
+    E: EntityTrait,
+{
+    let mut condition = None;
+    for col in E::Column::iter() {
+        if col.def().is_soft_delete() {
+            condition = Some(
+                condition
+                    .unwrap_or_else(Condition::all)
+                    .add(Expr::col((SeaRc::clone(&tbl), col)).is_null()),
+            );
+        }
+    }
+    condition
+}
+
 pub(crate) fn join_tbl_on_condition(
     from_tbl: SeaRc<dyn Iden>,
     to_tbl: SeaRc<dyn Iden>,
@@ -1,9 +1,9 @@
 use crate::{
     ActiveModelTrait, ActiveValue, ColumnTrait, EntityName, EntityTrait, IntoActiveModel, Iterable,
-    PrimaryKeyTrait, QueryTrait,
+    PrimaryKeyToColumn, PrimaryKeyTrait, QueryTrait,
 };
 use core::marker::PhantomData;
-use sea_query::{Expr, InsertStatement, OnConflict, ValueTuple};
+use sea_query::{Condition, Expr, InsertStatement, OnConflict, SimpleExpr, ValueTuple};
 
 /// Performs INSERT operations on a ActiveModel
 #[derive(Debug)]
@@ -14,9 +14,28 @@ where
     pub(crate) query: InsertStatement,
     pub(crate) columns: Vec<bool>,
     pub(crate) primary_key: Option<ValueTuple>,
+    /// Matches the first row by any of its unique keys, to find the row after an upsert
+    pub(crate) conflict_key: Option<Condition>,
+    pub(crate) on_conflict: Option<OnConflict>,
+    /// The values of each row, kept to split the insert into chunks
+    pub(crate) rows: Vec<Vec<SimpleExpr>>,
+    /// The primary key of each row, if it is not generated by the database
+    pub(crate) row_keys: Vec<Option<ValueTuple>>,
     pub(crate) model: PhantomData<A>,
 }
 
+/// Performs INSERT operations on a ActiveModel, split into chunks of rows
+///
+/// Each chunk is inserted by its own statement, and all of them run within a transaction.
+#[derive(Debug)]
+pub struct ChunkedInsert<A>
+where
+    A: ActiveModelTrait,
+{
+    pub(crate) insert: Insert<A>,
+    pub(crate) chunk_size: Option<usize>,
+}
+
 impl<A> Default for Insert<A>
 where
     A: ActiveModelTrait,
@@ -38,6 +57,10 @@ where
                 .to_owned(),
             columns: Vec::new(),
             primary_key: None,
+            conflict_key: None,
+            on_conflict: None,
+            rows: Vec::new(),
+            row_keys: Vec::new(),
             model: PhantomData,
         }
     }
@@ -110,6 +133,8 @@ where
 
     /// Add a Model to Self
     ///
+    /// The values of [generated](crate::ColumnDef::generated) columns are left out.
+    ///
     /// # Panics
     ///
     /// Panics if the column value has discrepancy across rows
@@ -127,25 +152,64 @@ where
             };
         let mut columns = Vec::new();
         let mut values = Vec::new();
+        let mut primary_key_cond = Some(Condition::all());
+        let mut unique_keys = Vec::new();
         let columns_empty = self.columns.is_empty();
         for (idx, col) in <A::Entity as EntityTrait>::Column::iter().enumerate() {
-            let av = am.take(col);
-            let av_has_val = av.is_set() || av.is_unchanged();
+            let mut av = am.take(col);
+            // Generated columns are computed by the database
+            if col.def().is_generated() {
+                av = ActiveValue::NotSet;
+            }
+            // Auto-managed timestamps are filled in, unless given a value
+            let timestamp = match av {
+                ActiveValue::NotSet => {
+                    let col_def = col.def();
+                    col_def
+                        .get_created_timestamp()
+                        .or(col_def.get_updated_timestamp())
+                }
+                _ => None,
+            };
+            let av_has_val = av.is_set() || av.is_unchanged() || timestamp.is_some();
             if columns_empty {
                 self.columns.push(av_has_val);
             } else if self.columns[idx] != av_has_val {
                 panic!("columns mismatch");
             }
+            let is_primary_key = <A::Entity as EntityTrait>::PrimaryKey::from_column(col).is_some();
             match av {
                 ActiveValue::Set(value) | ActiveValue::Unchanged(value) => {
+                    if is_primary_key {
+                        primary_key_cond =
+                            primary_key_cond.map(|cond| cond.add(col.eq(value.clone())));
+                    } else if col.def().unique {
+                        unique_keys.push(col.eq(value.clone()));
+                    }
                     columns.push(col);
                     values.push(col.save_as(Expr::val(value)));
                 }
-                ActiveValue::NotSet => {}
+                ActiveValue::NotSet => {
+                    if is_primary_key {
+                        primary_key_cond = None;
+                    }
+                    if let Some(source) = timestamp {
+                        columns.push(col);
+                        values.push(source.now());
+                    }
+                }
             }
         }
+        if columns_empty && (primary_key_cond.is_some() || !unique_keys.is_empty()) {
+            let conflict_key = primary_key_cond
+                .into_iter()
+                .fold(Condition::any(), Condition::add);
+            self.conflict_key = Some(unique_keys.into_iter().fold(conflict_key, Condition::add));
+        }
         self.query.columns(columns);
-        self.query.values_panic(values);
+        self.query.values_panic(values.clone());
+        self.rows.push(values);
+        self.row_keys.push(self.primary_key.clone());
         self
     }
 
@@ -205,10 +269,39 @@ where
     /// );
     /// ```
     pub fn on_conflict(mut self, on_conflict: OnConflict) -> Self {
-        self.query.on_conflict(on_conflict);
+        self.query.on_conflict(on_conflict.clone());
+        self.on_conflict = Some(on_conflict);
         self
     }
 
+    /// Split the insert into statements of at most `chunk_size` rows each,
+    /// to stay within the limit of bind parameters per statement.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake};
+    ///
+    /// let cakes = (1..=1000).map(|i| cake::ActiveModel {
+    ///     name: Set(format!("Cake {i}")),
+    ///     ..Default::default()
+    /// });
+    /// let insert = cake::Entity::insert_many(cakes).chunked(300);
+    /// ```
+    pub fn chunked(self, chunk_size: usize) -> ChunkedInsert<A> {
+        ChunkedInsert {
+            insert: self,
+            chunk_size: Some(chunk_size),
+        }
+    }
+
+    /// Split the insert into as few statements as the bind parameter limit of the backend
+    /// allows: 999 on SQLite and 65535 on MySQL and Postgres, divided by the number of columns.
+    pub fn auto_chunked(self) -> ChunkedInsert<A> {
+        ChunkedInsert {
+            insert: self,
+            chunk_size: None,
+        }
+    }
+
     /// Allow insert statement return safely if inserting nothing.
     /// The database will not be affected.
     pub fn do_nothing(self) -> TryInsert<A>
@@ -295,6 +388,9 @@ where
 /// Performs INSERT operations on a ActiveModel, will do nothing if input is empty.
 ///
 /// All functions works the same as if it is Insert<A>. Please refer to Insert<A> page for more information
+///
+/// With [`on_conflict`](Insert::on_conflict), the result tells whether the row was inserted or
+/// skipped, see [TryInsertResult](crate::TryInsertResult).
 #[derive(Debug)]
 pub struct TryInsert<A>
 where
@@ -359,7 +455,7 @@ where
     }
 
     pub fn on_conflict(mut self, on_conflict: OnConflict) -> Self {
-        self.insert_struct.query.on_conflict(on_conflict);
+        self.insert_struct = self.insert_struct.on_conflict(on_conflict);
         self
     }
 
@@ -640,4 +736,365 @@ mod tests {
 
         Ok(())
     }
+
+    #[smol_potat::test]
+    async fn insert_10() -> Result<(), DbErr> {
+        use crate::{
+            DbBackend, MockDatabase, MockExecResult, Statement, Transaction, TransactionTrait,
+        };
+
+        mod inventory {
+            use crate as sea_orm;
+            use crate::entity::prelude::*;
+
+            #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+            #[sea_orm(table_name = "inventory")]
+            pub struct Model {
+                #[sea_orm(primary_key)]
+                pub id: i32,
+                #[sea_orm(unique)]
+                pub sku: String,
+                pub quantity: i32,
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+            pub enum Relation {}
+
+            impl ActiveModelBehavior for ActiveModel {}
+        }
+
+        let model = inventory::Model {
+            id: 1,
+            sku: "apple".into(),
+            quantity: 7,
+        };
+
+        // The existing row is updated, so `last_insert_id` cannot be relied on
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 2,
+            }])
+            .append_query_results([[model.clone()]])
+            .into_connection();
+
+        assert_eq!(
+            inventory::Entity::insert(inventory::ActiveModel {
+                sku: ActiveValue::set("apple".to_owned()),
+                quantity: ActiveValue::set(7),
+                ..Default::default()
+            })
+            .on_conflict(
+                OnConflict::column(inventory::Column::Sku)
+                    .update_column(inventory::Column::Quantity)
+                    .to_owned()
+            )
+            .exec_with_returning(&db)
+            .await?,
+            model
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::many([
+                Statement::from_string(DbBackend::MySql, "BEGIN"),
+                Statement::from_sql_and_values(
+                    DbBackend::MySql,
+                    r#"INSERT INTO `inventory` (`sku`, `quantity`) VALUES (?, ?) ON DUPLICATE KEY UPDATE `quantity` = VALUES(`quantity`)"#,
+                    ["apple".into(), 7.into()]
+                ),
+                Statement::from_sql_and_values(
+                    DbBackend::MySql,
+                    r#"SELECT `inventory`.`id`, `inventory`.`sku`, `inventory`.`quantity` FROM `inventory` WHERE `inventory`.`sku` = ? LIMIT ?"#,
+                    ["apple".into(), 1u64.into()]
+                ),
+                Statement::from_string(DbBackend::MySql, "COMMIT"),
+            ])]
+        );
+
+        // Within a transaction, the statements are run on it
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 2,
+            }])
+            .append_query_results([[model.clone()]])
+            .into_connection();
+
+        let txn = db.begin().await?;
+        assert_eq!(
+            inventory::Entity::insert(inventory::ActiveModel {
+                sku: ActiveValue::set("apple".to_owned()),
+                quantity: ActiveValue::set(7),
+                ..Default::default()
+            })
+            .on_conflict(
+                OnConflict::column(inventory::Column::Sku)
+                    .update_column(inventory::Column::Quantity)
+                    .to_owned()
+            )
+            .exec_with_returning(&txn)
+            .await?,
+            model
+        );
+        txn.commit().await?;
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::many([
+                Statement::from_string(DbBackend::MySql, "BEGIN"),
+                Statement::from_sql_and_values(
+                    DbBackend::MySql,
+                    r#"INSERT INTO `inventory` (`sku`, `quantity`) VALUES (?, ?) ON DUPLICATE KEY UPDATE `quantity` = VALUES(`quantity`)"#,
+                    ["apple".into(), 7.into()]
+                ),
+                Statement::from_sql_and_values(
+                    DbBackend::MySql,
+                    r#"SELECT `inventory`.`id`, `inventory`.`sku`, `inventory`.`quantity` FROM `inventory` WHERE `inventory`.`sku` = ? LIMIT ?"#,
+                    ["apple".into(), 1u64.into()]
+                ),
+                Statement::from_string(DbBackend::MySql, "COMMIT"),
+            ])]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn insert_chunked() -> Result<(), DbErr> {
+        use crate::{DbBackend, MockDatabase, MockExecResult, Statement, Transaction};
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_exec_results([
+                MockExecResult {
+                    last_insert_id: 2,
+                    rows_affected: 2,
+                },
+                MockExecResult {
+                    last_insert_id: 3,
+                    rows_affected: 1,
+                },
+            ])
+            .into_connection();
+
+        let cakes = ["Apple Pie", "Orange Scone", "Lemon Tart"].map(|name| cake::ActiveModel {
+            id: ActiveValue::not_set(),
+            name: ActiveValue::set(name.to_owned()),
+        });
+
+        assert_eq!(
+            cake::Entity::insert_many(cakes)
+                .chunked(2)
+                .exec(&db)
+                .await?,
+            3
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::many([
+                Statement::from_string(DbBackend::Postgres, "BEGIN"),
+                Statement::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"INSERT INTO "cake" ("name") VALUES ($1), ($2)"#,
+                    ["Apple Pie".into(), "Orange Scone".into()]
+                ),
+                Statement::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"INSERT INTO "cake" ("name") VALUES ($1)"#,
+                    ["Lemon Tart".into()]
+                ),
+                Statement::from_string(DbBackend::Postgres, "COMMIT"),
+            ])]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn insert_returning_keys() -> Result<(), DbErr> {
+        use crate::{DbBackend, MockDatabase, Statement, Transaction};
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[
+                cake::Model {
+                    id: 1,
+                    name: "Apple Pie".to_owned(),
+                },
+                cake::Model {
+                    id: 2,
+                    name: "Orange Scone".to_owned(),
+                },
+            ]])
+            .into_connection();
+
+        let cakes = ["Apple Pie", "Orange Scone"].map(|name| cake::ActiveModel {
+            id: ActiveValue::not_set(),
+            name: ActiveValue::set(name.to_owned()),
+        });
+
+        assert_eq!(
+            cake::Entity::insert_many(cakes)
+                .exec_with_returning_keys(&db)
+                .await?,
+            [1, 2]
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::many([Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"INSERT INTO "cake" ("name") VALUES ($1), ($2) RETURNING "id""#,
+                ["Apple Pie".into(), "Orange Scone".into()]
+            )])]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn insert_returning_keys_mysql() -> Result<(), DbErr> {
+        use crate::{DbBackend, MockDatabase, MockExecResult, Statement, Transaction};
+
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results([
+                MockExecResult {
+                    last_insert_id: 1,
+                    rows_affected: 1,
+                },
+                MockExecResult {
+                    last_insert_id: 2,
+                    rows_affected: 1,
+                },
+            ])
+            .into_connection();
+
+        let cakes = ["Apple Pie", "Orange Scone"].map(|name| cake::ActiveModel {
+            id: ActiveValue::not_set(),
+            name: ActiveValue::set(name.to_owned()),
+        });
+
+        assert_eq!(
+            cake::Entity::insert_many(cakes)
+                .exec_with_returning_keys(&db)
+                .await?,
+            [1, 2]
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::many([
+                Statement::from_string(DbBackend::MySql, "BEGIN"),
+                Statement::from_sql_and_values(
+                    DbBackend::MySql,
+                    r#"INSERT INTO `cake` (`name`) VALUES (?)"#,
+                    ["Apple Pie".into()]
+                ),
+                Statement::from_sql_and_values(
+                    DbBackend::MySql,
+                    r#"INSERT INTO `cake` (`name`) VALUES (?)"#,
+                    ["Orange Scone".into()]
+                ),
+                Statement::from_string(DbBackend::MySql, "COMMIT"),
+            ])]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn insert_timestamps() {
+        mod post {
+            use crate as sea_orm;
+            use crate::entity::prelude::*;
+
+            #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+            #[sea_orm(table_name = "post")]
+            pub struct Model {
+                #[sea_orm(primary_key)]
+                pub id: i32,
+                pub title: String,
+                #[sea_orm(created_timestamp)]
+                pub created_at: DateTimeUtc,
+                #[sea_orm(updated_timestamp = "rust")]
+                pub updated_at: DateTimeUtc,
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+            pub enum Relation {}
+
+            impl ActiveModelBehavior for ActiveModel {}
+        }
+
+        let statement = post::Entity::insert(post::ActiveModel {
+            title: ActiveValue::set("Hello".to_owned()),
+            ..Default::default()
+        })
+        .build(DbBackend::Postgres);
+
+        assert_eq!(
+            statement.sql,
+            r#"INSERT INTO "post" ("title", "created_at", "updated_at") VALUES ($1, CURRENT_TIMESTAMP, $2)"#
+        );
+        let values = statement.values.unwrap().0;
+        assert_eq!(values[0], "Hello".into());
+        assert!(matches!(
+            values[1],
+            sea_query::Value::ChronoDateTimeUtc(Some(_))
+        ));
+    }
+
+    mod rectangle {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+        #[sea_orm(table_name = "rectangle")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            pub width: i32,
+            pub height: i32,
+            #[sea_orm(generated_expr = "Expr::col(Column::Width).mul(Expr::col(Column::Height))")]
+            pub area: i32,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    #[test]
+    fn insert_generated() {
+        assert_eq!(
+            rectangle::Entity::insert(
+                rectangle::Model {
+                    id: 1,
+                    width: 3,
+                    height: 4,
+                    area: 12,
+                }
+                .into_active_model()
+            )
+            .build(DbBackend::Postgres)
+            .to_string(),
+            r#"INSERT INTO "rectangle" ("id", "width", "height") VALUES (1, 3, 4)"#,
+        );
+    }
+
+    #[test]
+    fn insert_generated_set() {
+        assert_eq!(
+            rectangle::Entity::insert(rectangle::ActiveModel {
+                width: ActiveValue::set(3),
+                height: ActiveValue::set(4),
+                area: ActiveValue::set(12),
+                ..Default::default()
+            })
+            .build(DbBackend::Postgres)
+            .to_string(),
+            r#"INSERT INTO "rectangle" ("width", "height") VALUES (3, 4)"#,
+        );
+    }
 }
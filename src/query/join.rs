@@ -1,21 +1,25 @@
 use crate::{
-    join_tbl_on_condition, unpack_table_ref, ColumnTrait, EntityTrait, IdenStatic, Iterable,
-    Linked, QuerySelect, Related, Select, SelectA, SelectB, SelectTwo, SelectTwoMany,
+    join_condition, join_tbl_on_condition, soft_delete_condition, unpack_table_alias,
+    unpack_table_ref, ColumnTrait, EntityTrait, IdenStatic, Iterable, Linked, QuerySelect, Related,
+    RelationDef, Select, SelectA, SelectB, SelectTwo, SelectTwoMany,
 };
 pub use sea_query::JoinType;
-use sea_query::{Alias, Condition, Expr, IntoIden, SeaRc, SelectExpr};
+use sea_query::{Alias, Condition, ConditionType, Expr, IntoIden, SeaRc, SelectExpr, TableRef};
 
 impl<E> Select<E>
 where
     E: EntityTrait,
 {
     /// Left Join with a Related Entity.
+    ///
+    /// Its rows deleted through a [soft-delete](crate::ColumnDef::soft_delete) column are
+    /// not joined.
     pub fn left_join<R>(self, _: R) -> Self
     where
         R: EntityTrait,
         E: Related<R>,
     {
-        self.join_join(JoinType::LeftJoin, E::to(), E::via())
+        self.join_related::<R>(JoinType::LeftJoin)
     }
 
     /// Right Join with a Related Entity.
@@ -28,23 +32,74 @@ where
     }
 
     /// Inner Join with a Related Entity.
+    ///
+    /// Its rows deleted through a [soft-delete](crate::ColumnDef::soft_delete) column are
+    /// not joined.
     pub fn inner_join<R>(self, _: R) -> Self
     where
         R: EntityTrait,
         E: Related<R>,
     {
-        self.join_join(JoinType::InnerJoin, E::to(), E::via())
+        self.join_related::<R>(JoinType::InnerJoin)
     }
 
     /// Join with an Entity Related to me.
+    ///
+    /// Its rows deleted through a [soft-delete](crate::ColumnDef::soft_delete) column are
+    /// not joined.
     pub fn reverse_join<R>(self, _: R) -> Self
     where
         R: EntityTrait + Related<E>,
     {
-        self.join_rev(JoinType::InnerJoin, R::to())
+        let rel = R::to();
+        self.join_skipping_soft_deleted::<R>(JoinType::InnerJoin, rel.from_tbl.clone(), rel)
+    }
+
+    fn join_related<R>(mut self, join: JoinType) -> Self
+    where
+        R: EntityTrait,
+        E: Related<R>,
+    {
+        if let Some(via) = E::via() {
+            self = self.join(join, via);
+        }
+        let rel = E::to();
+        self.join_skipping_soft_deleted::<R>(join, rel.to_tbl.clone(), rel)
+    }
+
+    /// Join the table of the Entity `R`, one side of `rel`, without its soft-deleted rows
+    fn join_skipping_soft_deleted<R>(
+        mut self,
+        join: JoinType,
+        table_ref: TableRef,
+        rel: RelationDef,
+    ) -> Self
+    where
+        R: EntityTrait,
+    {
+        let tbl = unpack_table_alias(&table_ref).unwrap_or_else(|| unpack_table_ref(&table_ref));
+        let any = matches!(rel.condition_type, ConditionType::Any);
+        let mut condition = join_condition(rel);
+        if let Some(soft_delete) = soft_delete_condition::<R>(tbl) {
+            condition = if any {
+                Condition::all().add(condition).add(soft_delete)
+            } else {
+                condition.add(soft_delete)
+            };
+        }
+        self.query().join(join, table_ref, condition);
+        self
     }
 
     /// Left Join with a Related Entity and select both Entity.
+    ///
+    /// The rows are not grouped: there is one tuple per row of the join, so a model related
+    /// to many others is returned once for each of them, and once with `None` if none is
+    /// related. To get at most one related model per model, like the latest of many, the
+    /// related rows have to be filtered in the join condition, with
+    /// [RelationDef::on_condition](crate::RelationDef::on_condition) and
+    /// [QuerySelect::join](crate::QuerySelect::join) followed by [Select::select_also]:
+    /// filtering them with `filter` would also drop the models related to none.
     pub fn find_also_related<R>(self, r: R) -> SelectTwo<E, R>
     where
         R: EntityTrait,
@@ -54,6 +109,10 @@ where
     }
 
     /// Left Join with a Related Entity and select the related Entity as a `Vec`
+    ///
+    /// A many-to-many relation is joined through the junction table, in a single query.
+    /// The rows are grouped by the primary key of the Entity, which keeps the order of the query;
+    /// an Entity without related Entity comes with an empty `Vec`.
     pub fn find_with_related<R>(self, r: R) -> SelectTwoMany<E, R>
     where
         R: EntityTrait,
@@ -69,7 +128,9 @@ where
         T: EntityTrait,
     {
         let mut slf = self;
-        for (i, mut rel) in l.link().into_iter().enumerate() {
+        let link = l.link();
+        let last = link.len() - 1;
+        for (i, mut rel) in link.into_iter().enumerate() {
             let to_tbl = Alias::new(format!("r{i}")).into_iden();
             let from_tbl = if i > 0 {
                 Alias::new(format!("r{}", i - 1)).into_iden()
@@ -87,12 +148,17 @@ where
             if let Some(f) = rel.on_condition.take() {
                 condition = condition.add(f(SeaRc::clone(&from_tbl), SeaRc::clone(&to_tbl)));
             }
+            if i == last {
+                if let Some(soft_delete) = soft_delete_condition::<T>(SeaRc::clone(&to_tbl)) {
+                    condition = condition.add(soft_delete);
+                }
+            }
 
             slf.query()
                 .join_as(JoinType::LeftJoin, table_ref, to_tbl, condition);
         }
         slf = slf.apply_alias(SelectA.as_str());
-        let mut select_two = SelectTwo::new_without_prepare(slf.query);
+        let mut select_two = SelectTwo::new_without_prepare(slf.query, slf.checks);
         for col in <T::Column as Iterable>::iter() {
             let alias = format!("{}{}", SelectB.as_str(), col.as_str());
             let expr = Expr::col((
@@ -115,7 +181,9 @@ where
         T: EntityTrait,
     {
         let mut slf = self;
-        for (i, mut rel) in l.link().into_iter().enumerate() {
+        let link = l.link();
+        let last = link.len() - 1;
+        for (i, mut rel) in link.into_iter().enumerate() {
             let to_tbl = Alias::new(format!("r{i}")).into_iden();
             let from_tbl = if i > 0 {
                 Alias::new(format!("r{}", i - 1)).into_iden()
@@ -133,12 +201,17 @@ where
             if let Some(f) = rel.on_condition.take() {
                 condition = condition.add(f(SeaRc::clone(&from_tbl), SeaRc::clone(&to_tbl)));
             }
+            if i == last {
+                if let Some(soft_delete) = soft_delete_condition::<T>(SeaRc::clone(&to_tbl)) {
+                    condition = condition.add(soft_delete);
+                }
+            }
 
             slf.query()
                 .join_as(JoinType::LeftJoin, table_ref, to_tbl, condition);
         }
         slf = slf.apply_alias(SelectA.as_str());
-        let mut select_two_many = SelectTwoMany::new_without_prepare(slf.query);
+        let mut select_two_many = SelectTwoMany::new_without_prepare(slf.query, slf.checks);
         for col in <T::Column as Iterable>::iter() {
             let alias = format!("{}{}", SelectB.as_str(), col.as_str());
             let expr = Expr::col((
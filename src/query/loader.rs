@@ -1,10 +1,13 @@
 use crate::{
-    error::*, Condition, ConnectionTrait, DbErr, EntityTrait, Identity, ModelTrait, QueryFilter,
-    Related, RelationType, Select,
+    error::*, executor::max_bind_params, Condition, ConnectionTrait, DbErr, EntityTrait, Identity,
+    ModelTrait, QueryFilter, QueryTrait, Related, RelationDef, RelationType, Select,
 };
 use async_trait::async_trait;
 use sea_query::{ColumnRef, DynIden, Expr, IntoColumnRef, SimpleExpr, TableRef, ValueTuple};
-use std::{collections::HashMap, str::FromStr};
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
 
 /// Entity, or a Select<Entity>; to be used as parameters in [`LoaderTrait`]
 pub trait EntityOrSelect<E: EntityTrait>: Send {
@@ -12,6 +15,43 @@ pub trait EntityOrSelect<E: EntityTrait>: Send {
     fn select(self) -> Select<E>;
 }
 
+/// An Entity, or a Select<Entity>, related to the Entity `E`; to be used in [`RelatedSelects`].
+/// It loads a has_many relation, unless wrapped in [`One`] to load a has_one relation.
+#[async_trait]
+pub trait RelatedSelect<E: EntityTrait>: Send {
+    /// The related Entity
+    type Entity: EntityTrait;
+
+    /// The related models of all models
+    type Models: Send;
+
+    /// If self is Entity, use Entity::find()
+    fn into_select(self) -> Select<Self::Entity>;
+
+    /// Load the related models of each model
+    async fn load<M, C>(self, models: &[M], db: &C) -> Result<Self::Models, DbErr>
+    where
+        M: ModelTrait<Entity = E> + Sync,
+        C: ConnectionTrait;
+}
+
+/// Wraps a [`RelatedSelect`] to load a has_one relation, with at most one model for each model
+#[derive(Clone, Debug)]
+pub struct One<S>(pub S);
+
+/// A tuple of up to 6 [`RelatedSelect`]; to be used as parameters in [`LoaderTrait::load_related`]
+#[async_trait]
+pub trait RelatedSelects<E: EntityTrait>: Send {
+    /// The related models of each relation, in the order of the tuple
+    type Models: Send;
+
+    /// Load the related models of each relation, with one query per relation
+    async fn load<M, C>(self, models: &[M], db: &C) -> Result<Self::Models, DbErr>
+    where
+        M: ModelTrait<Entity = E> + Sync,
+        C: ConnectionTrait;
+}
+
 /// This trait implements the Data Loader API
 #[async_trait]
 pub trait LoaderTrait {
@@ -51,6 +91,49 @@ pub trait LoaderTrait {
         V: EntityTrait,
         V::Model: Send + Sync,
         <<Self as LoaderTrait>::Model as ModelTrait>::Entity: Related<R>;
+
+    /// Used to eager load several has_one and has_many relations at once, with one query per relation.
+    /// The related models of each relation come in the order of the given tuple.
+    /// A has_one relation is given wrapped in [`One`], and has at most one model for each model.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(all(feature = "mock", feature = "macros"))]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results([[fruit::Model {
+    /// #         id: 1,
+    /// #         name: "Apple".to_owned(),
+    /// #         cake_id: Some(1),
+    /// #     }]])
+    /// #     .append_query_results([[cake::Model {
+    /// #         id: 1,
+    /// #         name: "Apple Pie".to_owned(),
+    /// #     }]])
+    /// #     .into_connection();
+    /// #
+    /// let cakes = vec![cake::Model {
+    ///     id: 1,
+    ///     name: "Apple Pie".to_owned(),
+    /// }];
+    /// let (fruits,) = cakes
+    ///     .load_related((Fruit::find().filter(fruit::Column::Name.contains("A")),), &db)
+    ///     .await?;
+    /// assert_eq!(fruits[0][0].name, "Apple");
+    ///
+    /// let (cakes,) = fruits[0].load_related((One(Cake),), &db).await?;
+    /// assert_eq!(cakes[0].as_ref().map(|cake| cake.id), Some(1));
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn load_related<T, C>(&self, selects: T, db: &C) -> Result<T::Models, DbErr>
+    where
+        C: ConnectionTrait,
+        T: RelatedSelects<<<Self as LoaderTrait>::Model as ModelTrait>::Entity>;
 }
 
 impl<E> EntityOrSelect<E> for E
@@ -71,6 +154,103 @@ where
     }
 }
 
+#[async_trait]
+impl<E, R> RelatedSelect<E> for R
+where
+    E: EntityTrait + Related<R>,
+    R: EntityTrait,
+    R::Model: Send + Sync,
+{
+    type Entity = R;
+    type Models = Vec<Vec<R::Model>>;
+
+    fn into_select(self) -> Select<R> {
+        R::find()
+    }
+
+    async fn load<M, C>(self, models: &[M], db: &C) -> Result<Self::Models, DbErr>
+    where
+        M: ModelTrait<Entity = E> + Sync,
+        C: ConnectionTrait,
+    {
+        load_related_many(models, R::find(), db).await
+    }
+}
+
+#[async_trait]
+impl<E, R> RelatedSelect<E> for Select<R>
+where
+    E: EntityTrait + Related<R>,
+    R: EntityTrait,
+    R::Model: Send + Sync,
+{
+    type Entity = R;
+    type Models = Vec<Vec<R::Model>>;
+
+    fn into_select(self) -> Select<R> {
+        self
+    }
+
+    async fn load<M, C>(self, models: &[M], db: &C) -> Result<Self::Models, DbErr>
+    where
+        M: ModelTrait<Entity = E> + Sync,
+        C: ConnectionTrait,
+    {
+        load_related_many(models, self, db).await
+    }
+}
+
+#[async_trait]
+impl<E, S> RelatedSelect<E> for One<S>
+where
+    E: EntityTrait + Related<S::Entity>,
+    S: RelatedSelect<E>,
+    <S::Entity as EntityTrait>::Model: Send + Sync,
+{
+    type Entity = S::Entity;
+    type Models = Vec<Option<<S::Entity as EntityTrait>::Model>>;
+
+    fn into_select(self) -> Select<S::Entity> {
+        self.0.into_select()
+    }
+
+    async fn load<M, C>(self, models: &[M], db: &C) -> Result<Self::Models, DbErr>
+    where
+        M: ModelTrait<Entity = E> + Sync,
+        C: ConnectionTrait,
+    {
+        load_related_one(models, self.into_select(), db).await
+    }
+}
+
+macro_rules! impl_related_selects {
+    ( $($T: ident : $i: tt),+ ) => {
+        #[async_trait]
+        impl<E, $($T),+> RelatedSelects<E> for ($($T,)+)
+        where
+            E: EntityTrait,
+            $($T: RelatedSelect<E>,)+
+        {
+            type Models = ($(<$T as RelatedSelect<E>>::Models,)+);
+
+            async fn load<M, Conn>(self, models: &[M], db: &Conn) -> Result<Self::Models, DbErr>
+            where
+                M: ModelTrait<Entity = E> + Sync,
+                Conn: ConnectionTrait,
+            {
+                Ok(($(self.$i.load(models, db).await?,)+))
+            }
+        }
+    };
+}
+
+impl_related_selects!(A: 0);
+impl_related_selects!(A: 0, B: 1);
+impl_related_selects!(A: 0, B: 1, C: 2);
+impl_related_selects!(A: 0, B: 1, C: 2, D: 3);
+impl_related_selects!(A: 0, B: 1, C: 2, D: 3, F: 4);
+impl_related_selects!(A: 0, B: 1, C: 2, D: 3, F: 4, G: 5);
+
 #[async_trait]
 impl<M> LoaderTrait for Vec<M>
 where
@@ -117,6 +297,14 @@ where
     {
         self.as_slice().load_many_to_many(stmt, via, db).await
     }
+
+    async fn load_related<T, C>(&self, selects: T, db: &C) -> Result<T::Models, DbErr>
+    where
+        C: ConnectionTrait,
+        T: RelatedSelects<<<Self as LoaderTrait>::Model as ModelTrait>::Entity>,
+    {
+        self.as_slice().load_related(selects, db).await
+    }
 }
 
 #[async_trait]
@@ -147,33 +335,7 @@ where
             return Ok(Vec::new());
         }
 
-        let keys: Vec<ValueTuple> = self
-            .iter()
-            .map(|model: &M| extract_key(&rel_def.from_col, model))
-            .collect();
-
-        let condition = prepare_condition(&rel_def.to_tbl, &rel_def.to_col, &keys);
-
-        let stmt = <Select<R> as QueryFilter>::filter(stmt.select(), condition);
-
-        let data = stmt.all(db).await?;
-
-        let hashmap: HashMap<ValueTuple, <R as EntityTrait>::Model> = data.into_iter().fold(
-            HashMap::new(),
-            |mut acc, value: <R as EntityTrait>::Model| {
-                {
-                    let key = extract_key(&rel_def.to_col, &value);
-                    acc.insert(key, value);
-                }
-
-                acc
-            },
-        );
-
-        let result: Vec<Option<<R as EntityTrait>::Model>> =
-            keys.iter().map(|key| hashmap.get(key).cloned()).collect();
-
-        Ok(result)
+        load_one_by(self, &rel_def, stmt.select(), db).await
     }
 
     async fn load_many<R, S, C>(&self, stmt: S, db: &C) -> Result<Vec<Vec<R::Model>>, DbErr>
@@ -198,41 +360,7 @@ where
             return Ok(Vec::new());
         }
 
-        let keys: Vec<ValueTuple> = self
-            .iter()
-            .map(|model: &M| extract_key(&rel_def.from_col, model))
-            .collect();
-
-        let condition = prepare_condition(&rel_def.to_tbl, &rel_def.to_col, &keys);
-
-        let stmt = <Select<R> as QueryFilter>::filter(stmt.select(), condition);
-
-        let data = stmt.all(db).await?;
-
-        let mut hashmap: HashMap<ValueTuple, Vec<<R as EntityTrait>::Model>> =
-            keys.iter()
-                .fold(HashMap::new(), |mut acc, key: &ValueTuple| {
-                    acc.insert(key.clone(), Vec::new());
-                    acc
-                });
-
-        data.into_iter()
-            .for_each(|value: <R as EntityTrait>::Model| {
-                let key = extract_key(&rel_def.to_col, &value);
-
-                let vec = hashmap
-                    .get_mut(&key)
-                    .expect("Failed at finding key on hashmap");
-
-                vec.push(value);
-            });
-
-        let result: Vec<Vec<R::Model>> = keys
-            .iter()
-            .map(|key: &ValueTuple| hashmap.get(key).cloned().unwrap_or_default())
-            .collect();
-
-        Ok(result)
+        load_many_by(self, &rel_def, stmt.select(), db).await
     }
 
     async fn load_many_to_many<R, S, V, C>(
@@ -280,9 +408,8 @@ where
             let mut keymap: HashMap<ValueTuple, Vec<ValueTuple>> = Default::default();
 
             let keys: Vec<ValueTuple> = {
-                let condition = prepare_condition(&via_rel.to_tbl, &via_rel.to_col, &pkeys);
-                let stmt = V::find().filter(condition);
-                let data = stmt.all(db).await?;
+                let data =
+                    find_by_keys(V::find(), &via_rel.to_tbl, &via_rel.to_col, &pkeys, db).await?;
                 data.into_iter().for_each(|model| {
                     let pk = extract_key(&via_rel.to_col, &model);
                     let entry = keymap.entry(pk).or_default();
@@ -294,11 +421,8 @@ where
                 keymap.values().flatten().cloned().collect()
             };
 
-            let condition = prepare_condition(&rel_def.to_tbl, &rel_def.to_col, &keys);
-
-            let stmt = <Select<R> as QueryFilter>::filter(stmt.select(), condition);
-
-            let data = stmt.all(db).await?;
+            let data =
+                find_by_keys(stmt.select(), &rel_def.to_tbl, &rel_def.to_col, &keys, db).await?;
 
             // Map of R::PK -> R::Model
             let data: HashMap<ValueTuple, <R as EntityTrait>::Model> = data
@@ -328,6 +452,198 @@ where
             return Err(query_err("Relation is not ManyToMany"));
         }
     }
+
+    async fn load_related<T, C>(&self, selects: T, db: &C) -> Result<T::Models, DbErr>
+    where
+        C: ConnectionTrait,
+        T: RelatedSelects<<<Self as LoaderTrait>::Model as ModelTrait>::Entity>,
+    {
+        selects.load(*self, db).await
+    }
+}
+
+async fn load_related_one<M, R, C>(
+    models: &[M],
+    stmt: Select<R>,
+    db: &C,
+) -> Result<Vec<Option<R::Model>>, DbErr>
+where
+    C: ConnectionTrait,
+    R: EntityTrait,
+    R::Model: Send + Sync,
+    M: ModelTrait + Sync,
+    M::Entity: Related<R>,
+{
+    if <M::Entity as Related<R>>::via().is_some() {
+        return Err(query_err(
+            "Relation is ManyToMany, which should be loaded with load_many_to_many",
+        ));
+    }
+    let rel_def = <M::Entity as Related<R>>::to();
+    if rel_def.rel_type == RelationType::HasMany {
+        return Err(query_err("Relation is HasMany instead of HasOne"));
+    }
+
+    if models.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    load_one_by(models, &rel_def, stmt, db).await
+}
+
+async fn load_related_many<M, R, C>(
+    models: &[M],
+    stmt: Select<R>,
+    db: &C,
+) -> Result<Vec<Vec<R::Model>>, DbErr>
+where
+    C: ConnectionTrait,
+    R: EntityTrait,
+    R::Model: Send + Sync,
+    M: ModelTrait + Sync,
+    M::Entity: Related<R>,
+{
+    if <M::Entity as Related<R>>::via().is_some() {
+        return Err(query_err(
+            "Relation is ManyToMany, which should be loaded with load_many_to_many",
+        ));
+    }
+    let rel_def = <M::Entity as Related<R>>::to();
+    if rel_def.rel_type == RelationType::HasOne {
+        return Err(query_err(
+            "Relation is HasOne, which should be wrapped in One to be loaded",
+        ));
+    }
+
+    if models.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    load_many_by(models, &rel_def, stmt, db).await
+}
+
+async fn load_one_by<M, R, C>(
+    models: &[M],
+    rel_def: &RelationDef,
+    stmt: Select<R>,
+    db: &C,
+) -> Result<Vec<Option<R::Model>>, DbErr>
+where
+    C: ConnectionTrait,
+    R: EntityTrait,
+    R::Model: Send + Sync,
+    M: ModelTrait + Sync,
+{
+    let keys: Vec<ValueTuple> = models
+        .iter()
+        .map(|model: &M| extract_key(&rel_def.from_col, model))
+        .collect();
+
+    let data = find_by_keys(stmt, &rel_def.to_tbl, &rel_def.to_col, &keys, db).await?;
+
+    let hashmap: HashMap<ValueTuple, <R as EntityTrait>::Model> = data.into_iter().fold(
+        HashMap::new(),
+        |mut acc, value: <R as EntityTrait>::Model| {
+            {
+                let key = extract_key(&rel_def.to_col, &value);
+                acc.insert(key, value);
+            }
+
+            acc
+        },
+    );
+
+    let result: Vec<Option<<R as EntityTrait>::Model>> =
+        keys.iter().map(|key| hashmap.get(key).cloned()).collect();
+
+    Ok(result)
+}
+
+async fn load_many_by<M, R, C>(
+    models: &[M],
+    rel_def: &RelationDef,
+    stmt: Select<R>,
+    db: &C,
+) -> Result<Vec<Vec<R::Model>>, DbErr>
+where
+    C: ConnectionTrait,
+    R: EntityTrait,
+    R::Model: Send + Sync,
+    M: ModelTrait + Sync,
+{
+    let keys: Vec<ValueTuple> = models
+        .iter()
+        .map(|model: &M| extract_key(&rel_def.from_col, model))
+        .collect();
+
+    let data = find_by_keys(stmt, &rel_def.to_tbl, &rel_def.to_col, &keys, db).await?;
+
+    let mut hashmap: HashMap<ValueTuple, Vec<<R as EntityTrait>::Model>> =
+        keys.iter()
+            .fold(HashMap::new(), |mut acc, key: &ValueTuple| {
+                acc.insert(key.clone(), Vec::new());
+                acc
+            });
+
+    data.into_iter()
+        .for_each(|value: <R as EntityTrait>::Model| {
+            let key = extract_key(&rel_def.to_col, &value);
+
+            let vec = hashmap
+                .get_mut(&key)
+                .expect("Failed at finding key on hashmap");
+
+            vec.push(value);
+        });
+
+    let result: Vec<Vec<R::Model>> = keys
+        .iter()
+        .map(|key: &ValueTuple| hashmap.get(key).cloned().unwrap_or_default())
+        .collect();
+
+    Ok(result)
+}
+
+/// Select the models matching any of the keys, in a single query with an `IN` condition,
+/// unless there are so many distinct keys that they are split across several queries
+/// to bind no more parameters per statement than the backend allows
+async fn find_by_keys<R, C>(
+    stmt: Select<R>,
+    table: &TableRef,
+    col: &Identity,
+    keys: &[ValueTuple],
+    db: &C,
+) -> Result<Vec<R::Model>, DbErr>
+where
+    C: ConnectionTrait,
+    R: EntityTrait,
+{
+    let mut seen = HashSet::new();
+    let keys: Vec<ValueTuple> = keys
+        .iter()
+        .filter(|key| seen.insert(*key))
+        .cloned()
+        .collect();
+    if keys.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let db_backend = db.get_database_backend();
+    let stmt_params = stmt
+        .build(db_backend)
+        .values
+        .map_or(0, |values| values.0.len());
+    let chunk_size = (max_bind_params(db_backend).saturating_sub(stmt_params)
+        / col.clone().into_iter().count())
+    .max(1);
+
+    let mut models = Vec::new();
+    for keys in keys.chunks(chunk_size) {
+        let condition = prepare_condition(table, col, keys);
+        let stmt = <Select<R> as QueryFilter>::filter(stmt.clone(), condition);
+        models.extend(stmt.all(db).await?);
+    }
+    Ok(models)
 }
 
 fn cmp_table_ref(left: &TableRef, right: &TableRef) -> bool {
@@ -399,7 +715,6 @@ where
 }
 
 fn prepare_condition(table: &TableRef, col: &Identity, keys: &[ValueTuple]) -> Condition {
-    // TODO when value is hashable, retain only unique values
     let keys = keys.to_owned();
     match col {
         Identity::Unary(column_a) => {
@@ -605,6 +920,185 @@ mod tests {
         assert_eq!(fruits, empty_vec);
     }
 
+    #[tokio::test]
+    async fn test_load_one_single_query() {
+        use sea_orm::{
+            entity::prelude::*, tests_cfg::*, DbBackend, LoaderTrait, MockDatabase, Transaction,
+        };
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[cake_model(1), cake_model(2)]])
+            .into_connection();
+
+        // The key shared by several models is bound once
+        let fruits = vec![
+            fruit_model(1, Some(1)),
+            fruit_model(2, Some(2)),
+            fruit_model(3, Some(1)),
+        ];
+
+        let cakes = fruits
+            .load_one(cake::Entity::find(), &db)
+            .await
+            .expect("Should return something");
+
+        assert_eq!(
+            cakes,
+            [
+                Some(cake_model(1)),
+                Some(cake_model(2)),
+                Some(cake_model(1))
+            ]
+        );
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" IN ($1, $2)"#,
+                [1i32.into(), 2i32.into()]
+            )]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_load_many_chunked() {
+        use sea_orm::{
+            entity::prelude::*, tests_cfg::*, DbBackend, LoaderTrait, MockDatabase, Transaction,
+            Value,
+        };
+
+        // SQLite binds at most 999 parameters, one of which is taken by the filter
+        let db = MockDatabase::new(DbBackend::Sqlite)
+            .append_query_results([[fruit_model(1, Some(1))], [fruit_model(2, Some(1200))]])
+            .into_connection();
+
+        let cakes: Vec<cake::Model> = (1..=1200).map(cake_model).collect();
+
+        let fruits = cakes
+            .load_many(
+                fruit::Entity::find().filter(fruit::Column::Name.ne("grape")),
+                &db,
+            )
+            .await
+            .expect("Should return something");
+
+        assert_eq!(fruits.len(), 1200);
+        assert_eq!(fruits[0], [fruit_model(1, Some(1))]);
+        assert_eq!(fruits[1199], [fruit_model(2, Some(1200))]);
+        assert!(fruits[1..1199].iter().all(Vec::is_empty));
+
+        let chunk = |ids: std::ops::RangeInclusive<i32>| {
+            let params = vec!["?"; ids.clone().count()].join(", ");
+            let values = std::iter::once(Value::from("grape")).chain(ids.map(Value::from));
+            Transaction::from_sql_and_values(
+                DbBackend::Sqlite,
+                format!(
+                    r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit" WHERE "fruit"."name" <> ? AND "fruit"."cake_id" IN ({params})"#
+                ),
+                values,
+            )
+        };
+        assert_eq!(
+            db.into_transaction_log(),
+            [chunk(1..=998), chunk(999..=1200)]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_load_related() {
+        use sea_orm::{
+            entity::prelude::*, tests_cfg::*, DbBackend, LoaderTrait, MockDatabase, One,
+        };
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[fruit_model(1, Some(1)), fruit_model(2, Some(1))]])
+            .append_query_results([[cake_model(1)]])
+            .into_connection();
+
+        let cakes = vec![cake_model(1), cake_model(2)];
+
+        let (fruits,) = cakes
+            .load_related((fruit::Entity,), &db)
+            .await
+            .expect("Should return something");
+
+        assert_eq!(
+            fruits,
+            [
+                vec![fruit_model(1, Some(1)), fruit_model(2, Some(1))],
+                vec![]
+            ]
+        );
+
+        let fruits = vec![fruit_model(1, Some(1)), fruit_model(3, None)];
+
+        let (cakes,) = fruits
+            .load_related((One(cake::Entity::find()),), &db)
+            .await
+            .expect("Should return something");
+
+        assert_eq!(cakes, [Some(cake_model(1)), None]);
+
+        assert_eq!(
+            fruits.load_related((cake::Entity,), &db).await,
+            Err(DbErr::Query(sea_orm::RuntimeErr::Internal(
+                "Relation is HasOne, which should be wrapped in One to be loaded".to_owned()
+            )))
+        );
+
+        assert_eq!(
+            vec![cake_model(1)]
+                .load_related((One(fruit::Entity),), &db)
+                .await,
+            Err(DbErr::Query(sea_orm::RuntimeErr::Internal(
+                "Relation is HasMany instead of HasOne".to_owned()
+            )))
+        );
+
+        assert_eq!(
+            vec![cake_model(1)]
+                .load_related((filling::Entity, fruit::Entity), &db)
+                .await,
+            Err(DbErr::Query(sea_orm::RuntimeErr::Internal(
+                "Relation is ManyToMany, which should be loaded with load_many_to_many".to_owned()
+            )))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_load_related_six() {
+        use sea_orm::{
+            entity::prelude::*, tests_cfg::*, DbBackend, LoaderTrait, MockDatabase, One,
+        };
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results((1..=6).map(|id| [cake_model(id)]))
+            .into_connection();
+
+        let fruits = vec![fruit_model(1, Some(1))];
+
+        let (a, b, c, d, e, f) = fruits
+            .load_related(
+                (
+                    One(cake::Entity),
+                    One(cake::Entity::find()),
+                    One(cake::Entity),
+                    One(cake::Entity),
+                    One(cake::Entity),
+                    One(cake::Entity),
+                ),
+                &db,
+            )
+            .await
+            .expect("Should return something");
+
+        assert_eq!(a, [Some(cake_model(1))]);
+        // the other queries return cakes unrelated to the fruit
+        for cakes in [b, c, d, e, f] {
+            assert_eq!(cakes, [None]);
+        }
+    }
+
     #[tokio::test]
     async fn test_load_many_to_many_base() {
         use sea_orm::{tests_cfg::*, DbBackend, IntoMockRow, LoaderTrait, MockDatabase};
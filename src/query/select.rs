@@ -1,7 +1,9 @@
 use crate::{ColumnTrait, EntityTrait, Iterable, QueryFilter, QueryOrder, QuerySelect, QueryTrait};
 use core::fmt::Debug;
 use core::marker::PhantomData;
-use sea_query::{Expr, IntoColumnRef, SelectStatement, SimpleExpr};
+use sea_query::{
+    CommonTableExpression, Expr, IntoColumnRef, IntoIden, SelectStatement, SimpleExpr, WithClause,
+};
 
 /// Defines a structure to perform select operations
 #[derive(Clone, Debug)]
@@ -10,6 +12,9 @@ where
     E: EntityTrait,
 {
     pub(crate) query: SelectStatement,
+    /// The common table expressions added so far, which are also set on the query
+    pub(crate) with: Option<WithClause>,
+    pub(crate) checks: SelectChecks,
     pub(crate) entity: PhantomData<E>,
 }
 
@@ -21,6 +26,7 @@ where
     F: EntityTrait,
 {
     pub(crate) query: SelectStatement,
+    pub(crate) checks: SelectChecks,
     pub(crate) entity: PhantomData<(E, F)>,
 }
 
@@ -32,9 +38,21 @@ where
     F: EntityTrait,
 {
     pub(crate) query: SelectStatement,
+    pub(crate) checks: SelectChecks,
     pub(crate) entity: PhantomData<(E, F)>,
 }
 
+/// The clauses of a query which not every backend can execute, or which make it invalid,
+/// recorded as they are added since the statement cannot be inspected, and checked
+/// when the query is executed
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct SelectChecks {
+    pub(crate) row_lock: bool,
+    pub(crate) distinct_on: bool,
+    /// The numbers of columns of two queries combined with `UNION` which do not match
+    pub(crate) union_mismatch: Option<(usize, usize)>,
+}
+
 /// Performs a conversion to [SimpleExpr]
 pub trait IntoSimpleExpr {
     /// Method to perform the conversion
@@ -42,7 +60,7 @@ pub trait IntoSimpleExpr {
 }
 
 macro_rules! impl_trait {
-    ( $trait: ident ) => {
+    ( $trait: ident $(, $($item: item)+ )? ) => {
         impl<E> $trait for Select<E>
         where
             E: EntityTrait,
@@ -52,6 +70,7 @@ macro_rules! impl_trait {
             fn query(&mut self) -> &mut SelectStatement {
                 &mut self.query
             }
+            $($($item)+)?
         }
 
         impl<E, F> $trait for SelectTwo<E, F>
@@ -64,6 +83,7 @@ macro_rules! impl_trait {
             fn query(&mut self) -> &mut SelectStatement {
                 &mut self.query
             }
+            $($($item)+)?
         }
 
         impl<E, F> $trait for SelectTwoMany<E, F>
@@ -76,11 +96,20 @@ macro_rules! impl_trait {
             fn query(&mut self) -> &mut SelectStatement {
                 &mut self.query
             }
+            $($($item)+)?
         }
     };
 }
 
-impl_trait!(QuerySelect);
+impl_trait! {
+    QuerySelect,
+    fn mark_row_lock(&mut self) {
+        self.checks.row_lock = true;
+    }
+    fn mark_distinct_on(&mut self) {
+        self.checks.distinct_on = true;
+    }
+}
 impl_trait!(QueryFilter);
 impl_trait!(QueryOrder);
 
@@ -112,12 +141,26 @@ where
     pub(crate) fn new() -> Self {
         Self {
             query: SelectStatement::new(),
+            with: None,
+            checks: SelectChecks::default(),
             entity: PhantomData,
         }
         .prepare_select()
         .prepare_from()
     }
 
+    pub(crate) fn from_subquery(subquery: SelectStatement) -> Self {
+        let mut select = Self {
+            query: SelectStatement::new(),
+            with: None,
+            checks: SelectChecks::default(),
+            entity: PhantomData,
+        }
+        .prepare_select();
+        select.query.from_subquery(subquery, E::default());
+        select
+    }
+
     fn prepare_select(mut self) -> Self {
         self.query.exprs(self.column_list());
         self
@@ -133,6 +176,69 @@ where
         self.query.from(E::default().table_ref());
         self
     }
+
+    /// Prepend a common table expression to the query, in a `WITH` clause,
+    /// so it can be referenced by name like a table
+    ///
+    /// ```
+    /// use sea_orm::sea_query::{Alias, Expr, Query};
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .with(
+    ///             Alias::new("cheese"),
+    ///             Query::select()
+    ///                 .column(cake::Column::Id)
+    ///                 .from(cake::Entity)
+    ///                 .and_where(cake::Column::Name.contains("cheese"))
+    ///                 .to_owned()
+    ///         )
+    ///         .filter(cake::Column::Id.in_subquery(
+    ///             Query::select()
+    ///                 .column(cake::Column::Id)
+    ///                 .from(Alias::new("cheese"))
+    ///                 .to_owned()
+    ///         ))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     [
+    ///         r#"WITH "cheese" AS (SELECT "id" FROM "cake" WHERE "cake"."name" LIKE '%cheese%')"#,
+    ///         r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" IN (SELECT "id" FROM "cheese")"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    ///
+    /// The `WITH` clause is part of the statement, so it is kept when paginating the query,
+    /// joining related entities, or using it as a subquery.
+    pub fn with<N>(mut self, cte_name: N, cte_query: SelectStatement) -> Self
+    where
+        N: IntoIden,
+    {
+        let cte = CommonTableExpression::new()
+            .query(cte_query)
+            .table_name(cte_name)
+            .to_owned();
+        let with = self.with.get_or_insert_with(WithClause::new).cte(cte);
+        self.query.with_cte(with.clone());
+        self
+    }
+
+    /// Prepend a recursive common table expression to the query, in a `WITH RECURSIVE` clause
+    ///
+    /// The query of the expression is usually the `UNION` of a base query and of a query
+    /// referencing the expression itself, which is repeated until it returns no new rows.
+    pub fn with_recursive<N>(self, cte_name: N, cte_query: SelectStatement) -> Self
+    where
+        N: IntoIden,
+    {
+        let mut select = self.with(cte_name, cte_query);
+        if let Some(with) = &mut select.with {
+            select.query.with_cte(with.recursive(true).clone());
+        }
+        select
+    }
 }
 
 impl<E> QueryTrait for Select<E>
@@ -151,6 +257,16 @@ where
     }
 }
 
+/// A Select used as a subquery, like in [ColumnTrait::in_subquery](crate::ColumnTrait::in_subquery)
+impl<E> From<Select<E>> for SelectStatement
+where
+    E: EntityTrait,
+{
+    fn from(select: Select<E>) -> Self {
+        select.query
+    }
+}
+
 macro_rules! select_two {
     ( $selector: ident ) => {
         impl<E, F> QueryTrait for $selector<E, F>
@@ -1,9 +1,11 @@
 use crate::{
-    ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, Iterable, PrimaryKeyToColumn,
-    QueryFilter, QueryTrait,
+    ActiveModelTrait, ActiveValue, ColumnTrait, Condition, EntityTrait, Iterable, PrimaryKeyArity,
+    PrimaryKeyToColumn, PrimaryKeyTrait, QueryFilter, QueryTrait,
 };
 use core::marker::PhantomData;
-use sea_query::{Expr, IntoIden, SimpleExpr, UpdateStatement};
+use sea_query::{
+    CaseStatement, Expr, IntoIden, IntoValueTuple, SimpleExpr, UpdateStatement, Value,
+};
 
 /// Defines a structure to perform UPDATE query operations on a ActiveModel
 #[derive(Clone, Debug)]
@@ -45,6 +47,10 @@ impl Update {
     ///     r#"UPDATE "cake" SET "name" = 'Apple Pie' WHERE "cake"."id" = 1"#,
     /// );
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the primary key is not set
     pub fn one<E, A>(model: A) -> UpdateOne<A>
     where
         E: EntityTrait,
@@ -99,12 +105,27 @@ where
                 ActiveValue::NotSet => panic!("PrimaryKey is not set"),
             }
         }
+        // Only match the row if no one else has updated it since the model was read
+        for col in <A::Entity as EntityTrait>::Column::iter() {
+            if !col.def().is_version() {
+                continue;
+            }
+            match self.model.get(col) {
+                ActiveValue::Set(value) | ActiveValue::Unchanged(value) => {
+                    self = self.filter(col.eq(value));
+                }
+                ActiveValue::NotSet => {}
+            }
+        }
         self
     }
 
     fn prepare_values(mut self) -> Self {
         for col in <A::Entity as EntityTrait>::Column::iter() {
-            if <A::Entity as EntityTrait>::PrimaryKey::from_column(col).is_some() {
+            if <A::Entity as EntityTrait>::PrimaryKey::from_column(col).is_some()
+                || col.def().is_version()
+                || col.def().is_generated()
+            {
                 continue;
             }
             match self.model.get(col) {
@@ -115,6 +136,20 @@ where
                 ActiveValue::Unchanged(_) | ActiveValue::NotSet => {}
             }
         }
+        // Bump the version and the update time, unless there is nothing to update
+        if !self.query.get_values().is_empty() {
+            for col in <A::Entity as EntityTrait>::Column::iter() {
+                let col_def = col.def();
+                if col_def.is_version() {
+                    self.query.value(col, Expr::col(col).add(1));
+                }
+                if let Some(source) = col_def.get_updated_timestamp() {
+                    if !self.model.get(col).is_set() {
+                        self.query.value(col, source.now());
+                    }
+                }
+            }
+        }
         self
     }
 }
@@ -184,12 +219,15 @@ where
     E: EntityTrait,
 {
     /// Add the models to update to Self
+    ///
+    /// The values of [generated](crate::ColumnDef::generated) columns are left out.
     pub fn set<A>(mut self, model: A) -> Self
     where
         A: ActiveModelTrait<Entity = E>,
     {
         for col in E::Column::iter() {
             match model.get(col) {
+                ActiveValue::Set(_) if col.def().is_generated() => {}
                 ActiveValue::Set(value) => {
                     let expr = col.save_as(Expr::val(value));
                     self.query.value(col, expr);
@@ -208,6 +246,74 @@ where
         self.query.value(col, expr);
         self
     }
+
+    /// Set a column to a different value for each row, given by its primary key, in a single
+    /// statement. Only the rows of the given primary keys are updated. Call it for several
+    /// columns with the same primary keys.
+    ///
+    /// The values are picked with a `CASE` expression, which is understood by every backend,
+    /// e.g. to save the positions of a reordered list at once.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::update_many()
+    ///         .set_rows(cake::Column::Name, [(1, "Apple Pie"), (2, "Lemon Tart")])
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     [
+    ///         r#"UPDATE "cake" SET "name" = (CASE"#,
+    ///         r#"WHEN ("cake"."id" = 1) THEN 'Apple Pie'"#,
+    ///         r#"WHEN ("cake"."id" = 2) THEN 'Lemon Tart'"#,
+    ///         r#"ELSE "name" END)"#,
+    ///         r#"WHERE "cake"."id" IN (1, 2)"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    pub fn set_rows<I, K, V>(self, col: E::Column, rows: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<<E::PrimaryKey as PrimaryKeyTrait>::ValueType>,
+        V: Into<Value>,
+    {
+        let mut case: Option<CaseStatement> = None;
+        let mut keys = Vec::new();
+        for (key, value) in rows {
+            let key = key.into().into_value_tuple();
+            let mut condition = Condition::all();
+            for (pk, v) in E::PrimaryKey::iter().zip(key.clone()) {
+                condition = condition.add(pk.into_column().eq(v));
+            }
+            let then = col.save_as(Expr::val(value));
+            case = Some(match case {
+                Some(case) => case.case(condition, then),
+                None => Expr::case(condition, then),
+            });
+            keys.push(key);
+        }
+        let case = match case {
+            Some(case) => case.finally(Expr::col(col)),
+            None => return self,
+        };
+
+        let arity = <<E::PrimaryKey as PrimaryKeyTrait>::ValueType as PrimaryKeyArity>::ARITY;
+        let filter = match E::PrimaryKey::iter().next() {
+            Some(pk) if arity == 1 => {
+                Condition::all().add(pk.into_column().is_in(keys.into_iter().flatten()))
+            }
+            _ => keys.into_iter().fold(Condition::any(), |filter, key| {
+                let mut condition = Condition::all();
+                for (pk, v) in E::PrimaryKey::iter().zip(key) {
+                    condition = condition.add(pk.into_column().eq(v));
+                }
+                filter.add(condition)
+            }),
+        };
+
+        self.col_expr(col, case.into()).filter(filter)
+    }
 }
 
 #[cfg(test)]
@@ -328,4 +434,126 @@ mod tests {
             r#"UPDATE "lunch_set" SET "tea" = CAST('EverydayTea' AS tea) WHERE "lunch_set"."id" = 1"#,
         );
     }
+
+    #[test]
+    fn update_9() {
+        mod document {
+            use crate as sea_orm;
+            use crate::entity::prelude::*;
+
+            #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+            #[sea_orm(table_name = "document")]
+            pub struct Model {
+                #[sea_orm(primary_key)]
+                pub id: i32,
+                pub title: String,
+                #[sea_orm(version)]
+                pub version: i32,
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+            pub enum Relation {}
+
+            impl ActiveModelBehavior for ActiveModel {}
+        }
+
+        assert_eq!(
+            Update::one(document::ActiveModel {
+                id: Unchanged(1),
+                title: Set("Draft".to_owned()),
+                version: Unchanged(3),
+            })
+            .build(DbBackend::Postgres)
+            .to_string(),
+            r#"UPDATE "document" SET "title" = 'Draft', "version" = "version" + 1 WHERE "document"."id" = 1 AND "document"."version" = 3"#,
+        );
+
+        // Nothing to update, so the version is left as is
+        assert!(Update::one(document::ActiveModel {
+            id: Unchanged(1),
+            title: Unchanged("Draft".to_owned()),
+            version: Unchanged(3),
+        })
+        .into_query()
+        .get_values()
+        .is_empty());
+    }
+
+    #[test]
+    fn update_10() {
+        mod post {
+            use crate as sea_orm;
+            use crate::entity::prelude::*;
+
+            #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+            #[sea_orm(table_name = "post")]
+            pub struct Model {
+                #[sea_orm(primary_key)]
+                pub id: i32,
+                pub title: String,
+                #[sea_orm(created_timestamp)]
+                pub created_at: DateTimeUtc,
+                #[sea_orm(updated_timestamp)]
+                pub updated_at: DateTimeUtc,
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+            pub enum Relation {}
+
+            impl ActiveModelBehavior for ActiveModel {}
+        }
+
+        assert_eq!(
+            Update::one(post::ActiveModel {
+                id: Unchanged(1),
+                title: Set("Bye".to_owned()),
+                ..Default::default()
+            })
+            .build(DbBackend::Postgres)
+            .to_string(),
+            r#"UPDATE "post" SET "title" = 'Bye', "updated_at" = CURRENT_TIMESTAMP WHERE "post"."id" = 1"#,
+        );
+    }
+
+    #[test]
+    fn update_many_set_rows() {
+        use crate::tests_cfg::cake_filling_price;
+
+        assert_eq!(
+            Update::many(fruit::Entity)
+                .set_rows(fruit::Column::CakeId, [(1, Some(2)), (3, None)])
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "UPDATE `fruit` SET `cake_id` = (CASE",
+                "WHEN (`fruit`.`id` = 1) THEN 2",
+                "WHEN (`fruit`.`id` = 3) THEN NULL",
+                "ELSE `cake_id` END)",
+                "WHERE `fruit`.`id` IN (1, 3)",
+            ]
+            .join(" ")
+        );
+
+        assert_eq!(
+            Update::many(cake_filling_price::Entity)
+                .set_rows(cake_filling_price::Column::Price, [((1, 2), 10), ((1, 3), 20)])
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"UPDATE "public"."cake_filling_price" SET "price" = (CASE"#,
+                r#"WHEN ("cake_filling_price"."cake_id" = 1 AND "cake_filling_price"."filling_id" = 2) THEN 10"#,
+                r#"WHEN ("cake_filling_price"."cake_id" = 1 AND "cake_filling_price"."filling_id" = 3) THEN 20"#,
+                r#"ELSE "price" END)"#,
+                r#"WHERE ("cake_filling_price"."cake_id" = 1 AND "cake_filling_price"."filling_id" = 2)"#,
+                r#"OR ("cake_filling_price"."cake_id" = 1 AND "cake_filling_price"."filling_id" = 3)"#,
+            ]
+            .join(" ")
+        );
+
+        assert!(Update::many(cake::Entity)
+            .set_rows(cake::Column::Name, Vec::<(i32, String)>::new())
+            .into_query()
+            .get_values()
+            .is_empty());
+    }
 }
@@ -0,0 +1,514 @@
+use crate::{ConnectionTrait, DbBackend, DbErr, Schema, Statement};
+use sea_query::{Alias, ColumnType, IntoIden, RcOrArc, StringLen};
+use std::collections::HashMap;
+
+/// Metadata of a table column, as reported by the database
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ColumnInfo {
+    /// Name of the column
+    pub name: String,
+    /// SQL type of the column, as spelled by the database, with its length or precision,
+    /// like `varchar(255)` on MySQL or `character varying(255)` on Postgres
+    pub column_type: String,
+    /// Whether the column accepts `NULL`
+    pub nullable: bool,
+    /// Default expression of the column, if any
+    pub default: Option<String>,
+}
+
+/// Definition of a table index, as reported by the database
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IndexInfo {
+    /// Name of the index
+    pub name: String,
+    /// Indexed columns, in index order
+    pub columns: Vec<String>,
+    /// Whether it is a unique index
+    pub unique: bool,
+}
+
+/// A foreign key of a table, as reported by the database
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ForeignKeyInfo {
+    /// Name of the foreign key; SQLite does not keep them
+    pub name: Option<String>,
+    /// Referencing columns, in key order
+    pub columns: Vec<String>,
+    /// Referenced table
+    pub referenced_table: String,
+    /// Referenced columns, in key order; empty where the primary key is referenced implicitly
+    pub referenced_columns: Vec<String>,
+}
+
+/// A column of a table found by [`Schema::discover`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DiscoveredColumn {
+    /// Name of the column
+    pub name: String,
+    /// Type of the column, or `ColumnType::Custom` of the SQL type where it is not recognized
+    pub column_type: ColumnType,
+    /// SQL type of the column, as spelled by the database, with its length or precision,
+    /// like `varchar(255)` on MySQL or `character varying(255)` on Postgres
+    pub sql_type: String,
+    /// Whether the column accepts `NULL`
+    pub nullable: bool,
+    /// Default expression of the column, if any
+    pub default: Option<String>,
+    /// Whether the column is part of the primary key
+    pub primary_key: bool,
+}
+
+/// A table found by [`Schema::discover`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DiscoveredTable {
+    /// Name of the table
+    pub name: String,
+    /// Columns of the table, in the order they were defined
+    pub columns: Vec<DiscoveredColumn>,
+    /// Columns of the primary key, in key order
+    pub primary_key: Vec<String>,
+    /// Indexes of the table, see [`Schema::get_indexes`]
+    pub indexes: Vec<IndexInfo>,
+    /// Foreign keys of the table, the relations to the other tables
+    pub foreign_keys: Vec<ForeignKeyInfo>,
+}
+
+impl Schema {
+    /// Describe all tables of the current schema, with their columns, primary keys, indexes
+    /// and foreign keys, for tooling which adapts to the database at runtime, like an admin UI.
+    ///
+    /// The SQL types are mapped to a [`ColumnType`] where they are recognized, like `integer`
+    /// to `ColumnType::Integer` or `varchar(255)` to `ColumnType::String(StringLen::N(255))`,
+    /// including the arrays and the enums of Postgres and the enums of MySQL, which are named
+    /// after their column; the other ones, like custom types, are `ColumnType::Custom`.
+    ///
+    /// Views are not described.
+    pub async fn discover<C>(db: &C) -> Result<Vec<DiscoveredTable>, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let enums = get_enums(db).await?;
+        let mut tables = Vec::new();
+        for name in get_tables(db).await? {
+            let primary_key = Self::get_primary_key(db, &name).await?;
+            let columns = Self::get_columns(db, &name)
+                .await?
+                .into_iter()
+                .map(|column| DiscoveredColumn {
+                    column_type: parse_column_type(&column.name, &column.column_type, &enums),
+                    primary_key: primary_key.contains(&column.name),
+                    name: column.name,
+                    sql_type: column.column_type,
+                    nullable: column.nullable,
+                    default: column.default,
+                })
+                .collect();
+            let indexes = Self::get_indexes(db, &name).await?;
+            let foreign_keys = Self::get_foreign_keys(db, &name).await?;
+            tables.push(DiscoveredTable {
+                name,
+                columns,
+                primary_key,
+                indexes,
+                foreign_keys,
+            });
+        }
+        Ok(tables)
+    }
+
+    /// Get metadata of all columns of a table, in the order they were defined
+    pub async fn get_columns<C, T>(db: &C, table: T) -> Result<Vec<ColumnInfo>, DbErr>
+    where
+        C: ConnectionTrait,
+        T: AsRef<str>,
+    {
+        let builder = db.get_database_backend();
+        let stmt = match builder {
+            DbBackend::MySql => Statement::from_sql_and_values(
+                builder,
+                r#"SELECT COLUMN_NAME AS column_name, COLUMN_TYPE AS column_type, IS_NULLABLE = 'YES' AS is_nullable, COLUMN_DEFAULT AS column_default FROM information_schema.COLUMNS WHERE TABLE_SCHEMA = DATABASE() AND TABLE_NAME = ? ORDER BY ORDINAL_POSITION"#,
+                [table.as_ref().into()],
+            ),
+            DbBackend::Postgres => Statement::from_sql_and_values(
+                builder,
+                r#"SELECT a.attname::text AS column_name, format_type(a.atttypid, a.atttypmod) AS column_type, NOT a.attnotnull AS is_nullable, pg_get_expr(d.adbin, d.adrelid) AS column_default FROM pg_attribute a JOIN pg_class t ON t.oid = a.attrelid JOIN pg_namespace n ON n.oid = t.relnamespace LEFT JOIN pg_attrdef d ON d.adrelid = a.attrelid AND d.adnum = a.attnum WHERE a.attnum > 0 AND NOT a.attisdropped AND n.nspname = CURRENT_SCHEMA() AND t.relname = $1 ORDER BY a.attnum"#,
+                [table.as_ref().into()],
+            ),
+            DbBackend::Sqlite => Statement::from_sql_and_values(
+                builder,
+                r#"SELECT "name" AS "column_name", "type" AS "column_type", "notnull" = 0 AS "is_nullable", "dflt_value" AS "column_default" FROM pragma_table_info(?) ORDER BY "cid""#,
+                [table.as_ref().into()],
+            ),
+        };
+
+        db.query_all(stmt)
+            .await?
+            .into_iter()
+            .map(|row| {
+                Ok(ColumnInfo {
+                    name: row.try_get("", "column_name")?,
+                    column_type: row.try_get("", "column_type")?,
+                    nullable: row.try_get("", "is_nullable")?,
+                    default: row.try_get("", "column_default")?,
+                })
+            })
+            .collect()
+    }
+
+    /// Get the columns of the primary key of a table, in key order
+    pub async fn get_primary_key<C, T>(db: &C, table: T) -> Result<Vec<String>, DbErr>
+    where
+        C: ConnectionTrait,
+        T: AsRef<str>,
+    {
+        let builder = db.get_database_backend();
+        let sql = match builder {
+            DbBackend::MySql => {
+                r#"SELECT COLUMN_NAME AS column_name FROM information_schema.KEY_COLUMN_USAGE WHERE TABLE_SCHEMA = DATABASE() AND TABLE_NAME = ? AND CONSTRAINT_NAME = 'PRIMARY' ORDER BY ORDINAL_POSITION"#
+            }
+            DbBackend::Postgres => {
+                r#"SELECT a.attname::text AS column_name FROM pg_index ix JOIN pg_class t ON t.oid = ix.indrelid JOIN pg_namespace n ON n.oid = t.relnamespace CROSS JOIN LATERAL unnest(ix.indkey) WITH ORDINALITY AS k(attnum, ord) JOIN pg_attribute a ON a.attrelid = t.oid AND a.attnum = k.attnum WHERE ix.indisprimary AND n.nspname = CURRENT_SCHEMA() AND t.relname = $1 ORDER BY k.ord"#
+            }
+            DbBackend::Sqlite => {
+                r#"SELECT "name" AS column_name FROM pragma_table_info(?) WHERE "pk" > 0 ORDER BY "pk""#
+            }
+        };
+        let stmt = Statement::from_sql_and_values(builder, sql, [table.as_ref().into()]);
+
+        db.query_all(stmt)
+            .await?
+            .into_iter()
+            .map(|row| row.try_get("", "column_name"))
+            .collect()
+    }
+
+    /// Get definitions of all indexes of a table, including the primary key index where
+    /// the database reports one. Expression index parts are not listed in `columns`.
+    pub async fn get_indexes<C, T>(db: &C, table: T) -> Result<Vec<IndexInfo>, DbErr>
+    where
+        C: ConnectionTrait,
+        T: AsRef<str>,
+    {
+        let builder = db.get_database_backend();
+        let sql = match builder {
+            DbBackend::MySql => {
+                r#"SELECT INDEX_NAME AS index_name, COLUMN_NAME AS column_name, NON_UNIQUE = 0 AS is_unique FROM information_schema.STATISTICS WHERE TABLE_SCHEMA = DATABASE() AND TABLE_NAME = ? ORDER BY INDEX_NAME, SEQ_IN_INDEX"#
+            }
+            DbBackend::Postgres => {
+                r#"SELECT i.relname::text AS index_name, a.attname::text AS column_name, ix.indisunique AS is_unique FROM pg_index ix JOIN pg_class t ON t.oid = ix.indrelid JOIN pg_class i ON i.oid = ix.indexrelid JOIN pg_namespace n ON n.oid = t.relnamespace CROSS JOIN LATERAL unnest(ix.indkey) WITH ORDINALITY AS k(attnum, ord) LEFT JOIN pg_attribute a ON a.attrelid = t.oid AND a.attnum = k.attnum WHERE n.nspname = CURRENT_SCHEMA() AND t.relname = $1 ORDER BY i.relname, k.ord"#
+            }
+            DbBackend::Sqlite => {
+                r#"SELECT il."name" AS index_name, ii."name" AS column_name, il."unique" AS is_unique FROM pragma_index_list(?) AS il, pragma_index_info(il."name") AS ii ORDER BY il."name", ii."seqno""#
+            }
+        };
+        let stmt = Statement::from_sql_and_values(builder, sql, [table.as_ref().into()]);
+
+        let mut indexes: Vec<IndexInfo> = Vec::new();
+        for row in db.query_all(stmt).await? {
+            let name: String = row.try_get("", "index_name")?;
+            let column: Option<String> = row.try_get("", "column_name")?;
+            let unique: bool = row.try_get("", "is_unique")?;
+            match indexes.last_mut() {
+                Some(index) if index.name == name => index.columns.extend(column),
+                _ => indexes.push(IndexInfo {
+                    name,
+                    columns: column.into_iter().collect(),
+                    unique,
+                }),
+            }
+        }
+
+        Ok(indexes)
+    }
+
+    /// Get the foreign keys of a table, referencing the other tables
+    pub async fn get_foreign_keys<C, T>(db: &C, table: T) -> Result<Vec<ForeignKeyInfo>, DbErr>
+    where
+        C: ConnectionTrait,
+        T: AsRef<str>,
+    {
+        let builder = db.get_database_backend();
+        let sql = match builder {
+            DbBackend::MySql => {
+                r#"SELECT CONSTRAINT_NAME AS fk_name, COLUMN_NAME AS column_name, REFERENCED_TABLE_NAME AS referenced_table, REFERENCED_COLUMN_NAME AS referenced_column FROM information_schema.KEY_COLUMN_USAGE WHERE TABLE_SCHEMA = DATABASE() AND TABLE_NAME = ? AND REFERENCED_TABLE_NAME IS NOT NULL ORDER BY CONSTRAINT_NAME, ORDINAL_POSITION"#
+            }
+            DbBackend::Postgres => {
+                r#"SELECT c.conname::text AS fk_name, a.attname::text AS column_name, rt.relname::text AS referenced_table, ra.attname::text AS referenced_column FROM pg_constraint c JOIN pg_class t ON t.oid = c.conrelid JOIN pg_namespace n ON n.oid = t.relnamespace JOIN pg_class rt ON rt.oid = c.confrelid CROSS JOIN LATERAL unnest(c.conkey, c.confkey) WITH ORDINALITY AS k(attnum, referenced_attnum, ord) JOIN pg_attribute a ON a.attrelid = c.conrelid AND a.attnum = k.attnum JOIN pg_attribute ra ON ra.attrelid = c.confrelid AND ra.attnum = k.referenced_attnum WHERE c.contype = 'f' AND n.nspname = CURRENT_SCHEMA() AND t.relname = $1 ORDER BY c.conname, k.ord"#
+            }
+            DbBackend::Sqlite => {
+                r#"SELECT CAST("id" AS TEXT) AS fk_id, "from" AS column_name, "table" AS referenced_table, "to" AS referenced_column FROM pragma_foreign_key_list(?) ORDER BY "id", "seq""#
+            }
+        };
+        let stmt = Statement::from_sql_and_values(builder, sql, [table.as_ref().into()]);
+
+        let mut foreign_keys: Vec<(String, ForeignKeyInfo)> = Vec::new();
+        for row in db.query_all(stmt).await? {
+            let (id, name) = match builder {
+                DbBackend::Sqlite => (row.try_get("", "fk_id")?, None),
+                _ => {
+                    let name: String = row.try_get("", "fk_name")?;
+                    (name.clone(), Some(name))
+                }
+            };
+            let column: String = row.try_get("", "column_name")?;
+            let referenced_column: Option<String> = row.try_get("", "referenced_column")?;
+            match foreign_keys.last_mut() {
+                Some((last, foreign_key)) if *last == id => {
+                    foreign_key.columns.push(column);
+                    foreign_key.referenced_columns.extend(referenced_column);
+                }
+                _ => foreign_keys.push((
+                    id,
+                    ForeignKeyInfo {
+                        name,
+                        columns: vec![column],
+                        referenced_table: row.try_get("", "referenced_table")?,
+                        referenced_columns: referenced_column.into_iter().collect(),
+                    },
+                )),
+            }
+        }
+
+        Ok(foreign_keys.into_iter().map(|(_, fk)| fk).collect())
+    }
+}
+
+/// Get names of all tables of the current schema, in alphabetical order
+async fn get_tables<C>(db: &C) -> Result<Vec<String>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let builder = db.get_database_backend();
+    let sql = match builder {
+        DbBackend::MySql => {
+            r#"SELECT TABLE_NAME AS table_name FROM information_schema.TABLES WHERE TABLE_SCHEMA = DATABASE() AND TABLE_TYPE = 'BASE TABLE' ORDER BY TABLE_NAME"#
+        }
+        DbBackend::Postgres => {
+            r#"SELECT table_name::text AS table_name FROM information_schema.tables WHERE table_schema = CURRENT_SCHEMA() AND table_type = 'BASE TABLE' ORDER BY table_name"#
+        }
+        DbBackend::Sqlite => {
+            r#"SELECT "name" AS table_name FROM sqlite_master WHERE "type" = 'table' AND "name" NOT LIKE 'sqlite\_%' ESCAPE '\' ORDER BY "name""#
+        }
+    };
+
+    db.query_all(Statement::from_string(builder, sql))
+        .await?
+        .into_iter()
+        .map(|row| row.try_get("", "table_name"))
+        .collect()
+}
+
+/// Get the variants of the enum types of the current schema of Postgres, by type name
+async fn get_enums<C>(db: &C) -> Result<HashMap<String, Vec<String>>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let builder = db.get_database_backend();
+    if builder != DbBackend::Postgres {
+        return Ok(HashMap::new());
+    }
+    let stmt = Statement::from_string(
+        builder,
+        r#"SELECT t.typname::text AS enum_name, e.enumlabel::text AS enum_label FROM pg_enum e JOIN pg_type t ON t.oid = e.enumtypid JOIN pg_namespace n ON n.oid = t.typnamespace WHERE n.nspname = CURRENT_SCHEMA() ORDER BY t.typname, e.enumsortorder"#,
+    );
+
+    let mut enums: HashMap<String, Vec<String>> = HashMap::new();
+    for row in db.query_all(stmt).await? {
+        enums
+            .entry(row.try_get("", "enum_name")?)
+            .or_default()
+            .push(row.try_get("", "enum_label")?);
+    }
+    Ok(enums)
+}
+
+/// Map the SQL type of a `column`, as reported by any of the backends, to a [`ColumnType`].
+///
+/// The enum types of Postgres are looked up in `enums`, by name, whereas the enums of MySQL,
+/// spelled like `enum('a','b')`, are named after the column.
+fn parse_column_type(
+    column: &str,
+    sql_type: &str,
+    enums: &HashMap<String, Vec<String>>,
+) -> ColumnType {
+    let sql_type = sql_type.trim();
+    if let Some(element) = sql_type.strip_suffix("[]") {
+        return ColumnType::Array(RcOrArc::new(parse_column_type(column, element, enums)));
+    }
+    let enum_name = sql_type.trim_matches('"');
+    if let Some(variants) = enums.get(enum_name) {
+        return ColumnType::Enum {
+            name: Alias::new(enum_name).into_iden(),
+            variants: variants.iter().map(|v| Alias::new(v).into_iden()).collect(),
+        };
+    }
+
+    let lowercase = sql_type.to_lowercase();
+    if lowercase.starts_with("enum(") && lowercase.ends_with(')') {
+        return ColumnType::Enum {
+            name: Alias::new(column).into_iden(),
+            variants: parse_enum_labels(&sql_type["enum(".len()..sql_type.len() - 1])
+                .into_iter()
+                .map(|v| Alias::new(v).into_iden())
+                .collect(),
+        };
+    }
+    let unsigned = lowercase.ends_with(" unsigned");
+    let ty = lowercase.trim_end_matches(" unsigned");
+    // The arguments may be followed by more words, like `timestamp(3) with time zone`
+    let (name, args) = match ty.split_once('(') {
+        Some((name, rest)) => {
+            let (args, suffix) = rest.split_once(')').unwrap_or((rest, ""));
+            (format!("{} {}", name.trim(), suffix.trim()), args)
+        }
+        None => (ty.to_owned(), ""),
+    };
+    let args: Vec<u32> = args
+        .split(',')
+        .filter_map(|arg| arg.trim().parse().ok())
+        .collect();
+    let len = args.first().copied();
+
+    match (name.trim(), unsigned) {
+        ("tinyint", _) if len == Some(1) => ColumnType::Boolean,
+        ("tinyint", false) => ColumnType::TinyInteger,
+        ("tinyint", true) => ColumnType::TinyUnsigned,
+        ("smallint" | "int2", false) => ColumnType::SmallInteger,
+        ("smallint", true) => ColumnType::SmallUnsigned,
+        ("int" | "integer" | "int4" | "mediumint", false) => ColumnType::Integer,
+        ("int" | "integer" | "mediumint", true) => ColumnType::Unsigned,
+        ("bigint" | "int8", false) => ColumnType::BigInteger,
+        ("bigint", true) => ColumnType::BigUnsigned,
+        ("real" | "float" | "float4", _) => ColumnType::Float,
+        ("double" | "double precision" | "float8", _) => ColumnType::Double,
+        ("decimal" | "numeric", _) => ColumnType::Decimal(match args[..] {
+            [precision, scale] => Some((precision, scale)),
+            [precision] => Some((precision, 0)),
+            _ => None,
+        }),
+        ("money", _) => ColumnType::Money(None),
+        ("char" | "character" | "bpchar", _) => ColumnType::Char(len),
+        ("varchar" | "character varying", _) => ColumnType::String(match len {
+            Some(len) => StringLen::N(len),
+            None => StringLen::None,
+        }),
+        ("text" | "tinytext" | "mediumtext" | "longtext", _) => ColumnType::Text,
+        ("bool" | "boolean", _) => ColumnType::Boolean,
+        ("date", _) => ColumnType::Date,
+        ("time" | "time without time zone", _) => ColumnType::Time,
+        ("datetime" | "timestamp without time zone", _) => ColumnType::DateTime,
+        ("timestamp", _) => ColumnType::Timestamp,
+        ("timestamptz" | "timestamp with time zone", _) => ColumnType::TimestampWithTimeZone,
+        ("year", _) => ColumnType::Year,
+        ("interval", _) => ColumnType::Interval(None, None),
+        ("json", _) => ColumnType::Json,
+        ("jsonb", _) => ColumnType::JsonBinary,
+        ("uuid", _) => ColumnType::Uuid,
+        ("blob" | "tinyblob" | "mediumblob" | "longblob" | "bytea", _) => ColumnType::Blob,
+        ("binary", _) => ColumnType::Binary(len.unwrap_or(1)),
+        ("varbinary", _) => ColumnType::VarBinary(match len {
+            Some(len) => StringLen::N(len),
+            None => StringLen::None,
+        }),
+        ("inet", _) => ColumnType::Inet,
+        ("cidr", _) => ColumnType::Cidr,
+        ("macaddr", _) => ColumnType::MacAddr,
+        _ => ColumnType::Custom(Alias::new(sql_type).into_iden()),
+    }
+}
+
+/// Parse the quoted labels of a MySQL enum, like `'a','b'`, where a quote is escaped as `''`
+fn parse_enum_labels(labels: &str) -> Vec<String> {
+    let mut parsed = Vec::new();
+    let mut chars = labels.chars().peekable();
+    while chars.next() == Some('\'') {
+        let mut label = String::new();
+        while let Some(c) = chars.next() {
+            match c {
+                '\'' if chars.peek() == Some(&'\'') => {
+                    chars.next();
+                    label.push(c);
+                }
+                '\'' => break,
+                _ => label.push(c),
+            }
+        }
+        parsed.push(label);
+        // Skip the comma between the labels
+        chars.next();
+    }
+    parsed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parse_column_types() {
+        let no_enums = HashMap::new();
+        let parse = |sql_type| parse_column_type("column", sql_type, &no_enums);
+        assert_eq!(parse("integer"), ColumnType::Integer);
+        assert_eq!(parse("INT UNSIGNED"), ColumnType::Unsigned);
+        assert_eq!(parse("int(11)"), ColumnType::Integer);
+        assert_eq!(parse("tinyint(1)"), ColumnType::Boolean);
+        assert_eq!(parse("varchar(255)"), ColumnType::String(StringLen::N(255)));
+        assert_eq!(
+            parse("character varying(40)"),
+            ColumnType::String(StringLen::N(40))
+        );
+        assert_eq!(
+            parse("character varying"),
+            ColumnType::String(StringLen::None)
+        );
+        assert_eq!(parse("numeric(16,4)"), ColumnType::Decimal(Some((16, 4))));
+        assert_eq!(
+            parse("timestamp with time zone"),
+            ColumnType::TimestampWithTimeZone
+        );
+        assert_eq!(
+            parse("timestamp(3) without time zone"),
+            ColumnType::DateTime
+        );
+        assert_eq!(parse("jsonb"), ColumnType::JsonBinary);
+        assert_eq!(
+            parse("text[]"),
+            ColumnType::Array(RcOrArc::new(ColumnType::Text))
+        );
+        assert_eq!(
+            parse("tsvector"),
+            ColumnType::Custom(Alias::new("tsvector").into_iden())
+        );
+    }
+
+    #[test]
+    fn parse_enum_column_types() {
+        let variants = |variants: &[&str]| -> Vec<_> {
+            variants
+                .iter()
+                .map(|v| Alias::new(*v).into_iden())
+                .collect()
+        };
+        let enums = HashMap::from([(
+            "tea".to_owned(),
+            vec!["EverydayTea".to_owned(), "BreakfastTea".to_owned()],
+        )]);
+        let tea = ColumnType::Enum {
+            name: Alias::new("tea").into_iden(),
+            variants: variants(&["EverydayTea", "BreakfastTea"]),
+        };
+        assert_eq!(parse_column_type("kind", "tea", &enums), tea);
+        assert_eq!(
+            parse_column_type("kinds", "tea[]", &enums),
+            ColumnType::Array(RcOrArc::new(tea))
+        );
+        assert_eq!(
+            parse_column_type("kind", "enum('EverydayTea','Builder''s Tea')", &enums),
+            ColumnType::Enum {
+                name: Alias::new("kind").into_iden(),
+                variants: variants(&["EverydayTea", "Builder's Tea"]),
+            }
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
     ActiveEnum, ColumnTrait, ColumnType, DbBackend, EntityTrait, Iterable, PrimaryKeyArity,
-    PrimaryKeyToColumn, PrimaryKeyTrait, RelationTrait, Schema,
+    PrimaryKeyToColumn, PrimaryKeyTrait, RelationTrait, Schema, SchemaStatement,
 };
 use sea_query::{
     extension::postgres::{Type, TypeCreateStatement},
@@ -41,6 +41,49 @@ impl Schema {
         create_index_from_entity(entity, self.backend)
     }
 
+    /// Creates the complete schema of an Entity, in the order the statements have to be
+    /// executed: the Postgres enums of its columns, the table with its foreign keys,
+    /// then its indexes. See [Schema::create_enum_from_entity],
+    /// [Schema::create_table_from_entity] and [Schema::create_index_from_entity].
+    ///
+    /// ```
+    /// use sea_orm::{tests_cfg::indexes, DbBackend, Schema, StatementBuilder};
+    ///
+    /// let db_backend = DbBackend::Sqlite;
+    /// let stmts: Vec<String> = Schema::new(db_backend)
+    ///     .create_statements_from_entity(indexes::Entity)
+    ///     .iter()
+    ///     .map(|stmt| stmt.build(&db_backend).to_string())
+    ///     .collect();
+    /// assert_eq!(stmts.len(), 3);
+    /// assert!(stmts[0].starts_with(r#"CREATE TABLE "public"."indexes""#));
+    /// assert_eq!(
+    ///     stmts[1],
+    ///     r#"CREATE INDEX "idx-indexes-index1_attr" ON "indexes" ("index1_attr")"#
+    /// );
+    /// ```
+    ///
+    /// In a migration, each statement is executed with `SchemaManager::exec_stmt`.
+    pub fn create_statements_from_entity<E>(&self, entity: E) -> Vec<SchemaStatement>
+    where
+        E: EntityTrait,
+    {
+        let mut stmts: Vec<SchemaStatement> = create_enum_from_entity(entity, self.backend)
+            .into_iter()
+            .map(SchemaStatement::Type)
+            .collect();
+        stmts.push(SchemaStatement::Table(create_table_from_entity(
+            entity,
+            self.backend,
+        )));
+        stmts.extend(
+            create_index_from_entity(entity, self.backend)
+                .into_iter()
+                .map(SchemaStatement::Index),
+        );
+        stmts
+    }
+
     /// Creates a column definition for example to update a table.
     ///
     /// ```
@@ -211,6 +254,16 @@ where
         _ => orm_column_def.col_type,
     };
     let mut column_def = ColumnDef::new_with_type(column, types);
+    if let Some(collation) = orm_column_def.collation {
+        column_def.extra(match backend {
+            DbBackend::Postgres => format!("COLLATE \"{}\"", collation.replace('"', "\"\"")),
+            DbBackend::MySql | DbBackend::Sqlite => format!("COLLATE {collation}"),
+        });
+    }
+    // MySQL expects the expression of a generated column before its constraints
+    if let Some((expr, stored)) = orm_column_def.generated {
+        column_def.generated(expr, stored);
+    }
     if !orm_column_def.null {
         column_def.not_null();
     }
@@ -223,6 +276,9 @@ where
     if let Some(comment) = orm_column_def.comment {
         column_def.comment(comment);
     }
+    if let Some(check) = orm_column_def.check {
+        column_def.check(check);
+    }
     for primary_key in E::PrimaryKey::iter() {
         if column.to_string() == primary_key.into_column().to_string() {
             if E::PrimaryKey::auto_increment() {
@@ -238,7 +294,9 @@ where
 
 #[cfg(test)]
 mod tests {
-    use crate::{sea_query::*, tests_cfg::*, DbBackend, EntityName, Schema};
+    use crate::{
+        sea_query::*, tests_cfg::*, ColumnTrait, DbBackend, EntityName, Schema, SchemaStatement,
+    };
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -326,6 +384,90 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_create_table_with_collation() {
+        mod user {
+            use crate as sea_orm;
+            use crate::entity::prelude::*;
+
+            #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+            #[sea_orm(table_name = "user")]
+            pub struct Model {
+                #[sea_orm(primary_key)]
+                pub id: i32,
+                #[sea_orm(unique, collation = "NOCASE")]
+                pub name: String,
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+            pub enum Relation {}
+
+            impl ActiveModelBehavior for ActiveModel {}
+        }
+
+        assert_eq!(user::Column::Name.def().get_collation(), Some("NOCASE"));
+        let stmt = Schema::new(DbBackend::Sqlite).create_table_from_entity(user::Entity);
+        assert!(DbBackend::Sqlite
+            .build(&stmt)
+            .to_string()
+            .contains(r#""name" varchar COLLATE NOCASE NOT NULL UNIQUE"#));
+
+        let stmt = Table::alter()
+            .table(user::Entity)
+            .add_column(
+                Schema::new(DbBackend::Postgres).get_column_def::<user::Entity>(user::Column::Name),
+            )
+            .to_owned();
+        assert_eq!(
+            DbBackend::Postgres.build(&stmt).to_string(),
+            r#"ALTER TABLE "user" ADD COLUMN "name" varchar COLLATE "NOCASE" NOT NULL UNIQUE"#
+        );
+    }
+
+    #[test]
+    fn test_create_statements_from_entity() {
+        for builder in [DbBackend::MySql, DbBackend::Postgres, DbBackend::Sqlite] {
+            let schema = Schema::new(builder);
+
+            let stmts = schema.create_statements_from_entity(indexes::Entity);
+            let mut expected =
+                vec![builder.build(&schema.create_table_from_entity(indexes::Entity))];
+            expected.extend(
+                schema
+                    .create_index_from_entity(indexes::Entity)
+                    .iter()
+                    .map(|stmt| builder.build(stmt)),
+            );
+            assert_eq!(
+                stmts
+                    .iter()
+                    .map(|stmt| builder.build(stmt))
+                    .collect::<Vec<_>>(),
+                expected
+            );
+
+            let stmts = schema.create_statements_from_entity(fruit::Entity);
+            assert_eq!(stmts.len(), 1);
+            assert!(builder.build(&stmts[0]).to_string().contains("FOREIGN KEY"));
+
+            let stmts = schema.create_statements_from_entity(lunch_set::Entity);
+            for stmt in stmts.iter() {
+                assert!(!builder.build(stmt).to_string().is_empty());
+            }
+            if builder == DbBackend::Postgres {
+                assert_eq!(stmts.len(), 2);
+                assert!(matches!(stmts[0], SchemaStatement::Type(_)));
+                assert_eq!(
+                    builder.build(&stmts[0]).to_string(),
+                    r#"CREATE TYPE "tea" AS ENUM ('EverydayTea', 'BreakfastTea')"#
+                );
+                assert!(matches!(stmts[1], SchemaStatement::Table(_)));
+            } else {
+                assert_eq!(stmts.len(), 1);
+            }
+        }
+    }
+
     fn get_indexes_stmt() -> TableCreateStatement {
         Table::create()
             .col(
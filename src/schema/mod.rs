@@ -1,7 +1,13 @@
-use crate::DbBackend;
+use crate::{DbBackend, Statement, StatementBuilder};
+use sea_query::{
+    extension::postgres::TypeCreateStatement, IndexCreateStatement, TableCreateStatement,
+};
 
+mod discover;
 mod entity;
 
+pub use discover::*;
+
 /// This is a helper struct to convert [`EntityTrait`](crate::EntityTrait)
 /// into different [`sea_query`](crate::sea_query) statements.
 #[derive(Debug)]
@@ -15,3 +21,25 @@ impl Schema {
         Self { backend }
     }
 }
+
+/// A statement creating part of the schema of an Entity,
+/// see [`Schema::create_statements_from_entity`]
+#[derive(Debug, Clone)]
+pub enum SchemaStatement {
+    /// A Postgres enum used by a column
+    Type(TypeCreateStatement),
+    /// The table, with its primary key and foreign keys
+    Table(TableCreateStatement),
+    /// An index of the table
+    Index(IndexCreateStatement),
+}
+
+impl StatementBuilder for SchemaStatement {
+    fn build(&self, db_backend: &DbBackend) -> Statement {
+        match self {
+            Self::Type(stmt) => db_backend.build(stmt),
+            Self::Table(stmt) => db_backend.build(stmt),
+            Self::Index(stmt) => db_backend.build(stmt),
+        }
+    }
+}
@@ -26,6 +26,7 @@ async fn main() -> Result<(), DbErr> {
 
     find_related_active_enum(&ctx.db).await?;
     find_linked_active_enum(&ctx.db).await?;
+    unknown_num_value(&ctx.db).await?;
 
     ctx.delete().await;
 
@@ -223,6 +224,32 @@ pub async fn insert_active_enum(db: &DatabaseConnection) -> Result<(), DbErr> {
     Ok(())
 }
 
+pub async fn unknown_num_value(db: &DatabaseConnection) -> Result<(), DbErr> {
+    use active_enum::*;
+
+    let model = ActiveModel {
+        color: Set(Some(Color::White)),
+        ..Default::default()
+    }
+    .insert(db)
+    .await?;
+
+    // An integer which is not the `num_value` of any variant can not be read back
+    Entity::update_many()
+        .col_expr(Column::Color, Expr::value(7))
+        .filter(Column::Id.eq(model.id))
+        .exec(db)
+        .await?;
+    assert_eq!(
+        Entity::find_by_id(model.id).one(db).await,
+        Err(DbErr::Type("unexpected value for Color enum: 7".to_owned()))
+    );
+
+    model.delete(db).await?;
+
+    Ok(())
+}
+
 pub async fn insert_active_enum_child(db: &DatabaseConnection) -> Result<(), DbErr> {
     use active_enum_child::*;
 
@@ -0,0 +1,90 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{features::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{entity::prelude::*, entity::*, DatabaseConnection};
+
+#[sea_orm_macros::test]
+#[cfg(all(feature = "sqlx-postgres", feature = "postgres-array"))]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("array_tests").await;
+    create_tables(&ctx.db).await?;
+    insert_bookmark(&ctx.db).await?;
+    update_bookmark(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn insert_bookmark(db: &DatabaseConnection) -> Result<(), DbErr> {
+    use bookmark::*;
+
+    let bookmarks = [
+        Model {
+            id: 1,
+            url: "https://www.sea-ql.org".to_owned(),
+            tags: vec!["rust".to_owned(), "orm".to_owned()],
+            tags_opt: Some(vec!["async".to_owned()]),
+            votes: vec![1, 2, 3],
+        },
+        // Empty arrays are kept apart from NULL
+        Model {
+            id: 2,
+            url: "https://www.rust-lang.org".to_owned(),
+            tags: vec![],
+            tags_opt: Some(vec![]),
+            votes: vec![],
+        },
+        Model {
+            id: 3,
+            url: "https://crates.io".to_owned(),
+            tags: vec!["with space".to_owned(), "\"quoted\"".to_owned()],
+            tags_opt: None,
+            votes: vec![-1],
+        },
+    ];
+
+    for bookmark in bookmarks.iter() {
+        assert_eq!(
+            &bookmark.clone().into_active_model().insert(db).await?,
+            bookmark
+        );
+    }
+    assert_eq!(Entity::find().all(db).await?, bookmarks);
+
+    Ok(())
+}
+
+pub async fn update_bookmark(db: &DatabaseConnection) -> Result<(), DbErr> {
+    use bookmark::*;
+
+    let model = Entity::find_by_id(2).one(db).await?.unwrap();
+    let model = ActiveModel {
+        tags: Set(vec!["lang".to_owned()]),
+        tags_opt: Set(None),
+        ..model.into_active_model()
+    }
+    .update(db)
+    .await?;
+
+    assert_eq!(Entity::find_by_id(2).one(db).await?, Some(model));
+
+    let model = Entity::find_by_id(3).one(db).await?.unwrap();
+    assert_eq!(model.tags_opt, None);
+    let model = ActiveModel {
+        tags_opt: Set(Some(vec![])),
+        ..model.into_active_model()
+    }
+    .update(db)
+    .await?;
+
+    assert_eq!(
+        Entity::find_by_id(3).one(db).await?.unwrap().tags_opt,
+        Some(vec![])
+    );
+    assert_eq!(model.tags_opt, Some(vec![]));
+
+    Ok(())
+}
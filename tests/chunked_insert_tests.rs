@@ -0,0 +1,48 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{bakery_chain::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{prelude::*, Set};
+
+#[sea_orm_macros::test]
+pub async fn chunked_insert_tests() -> Result<(), DbErr> {
+    let ctx = TestContext::new("chunked_insert_tests").await;
+    create_tables(&ctx.db).await?;
+    chunked_insert(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+fn bakeries(range: std::ops::RangeInclusive<i32>) -> impl Iterator<Item = bakery::ActiveModel> {
+    range.map(|i| new_bakery(&format!("Bakery {i}")))
+}
+
+pub async fn chunked_insert(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let inserted = Bakery::insert_many(bakeries(1..=1000))
+        .chunked(300)
+        .exec(db)
+        .await?;
+    assert_eq!(inserted, 1000);
+    assert_eq!(Bakery::find().count(db).await?, 1000);
+
+    // More rows than SQLite accepts bind parameters for in a single statement
+    let inserted = Bakery::insert_many(bakeries(1001..=2500))
+        .auto_chunked()
+        .exec(db)
+        .await?;
+    assert_eq!(inserted, 1500);
+    assert_eq!(Bakery::find().count(db).await?, 2500);
+
+    let names: Vec<String> = Bakery::find()
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|bakery| bakery.name)
+        .collect();
+    assert!(names.contains(&"Bakery 2500".to_owned()));
+
+    Ok(())
+}
@@ -0,0 +1,112 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{
+    entity::prelude::*, ConnectionTrait, DatabaseConnection, DbBackend, Schema, Statement,
+};
+
+// The same table on each backend, with the case-insensitive collation of the backend
+mod mysql_username {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+    #[sea_orm(table_name = "username")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        #[sea_orm(unique, collation = "utf8mb4_general_ci")]
+        pub name: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+mod pg_username {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+    #[sea_orm(table_name = "username")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        #[sea_orm(unique, collation = "case_insensitive")]
+        pub name: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+mod sqlite_username {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+    #[sea_orm(table_name = "username")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        #[sea_orm(unique, collation = "NOCASE")]
+        pub name: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+#[sea_orm_macros::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("collation_tests").await;
+    let db = &ctx.db;
+    let schema = Schema::new(db.get_database_backend());
+    let stmt = match db.get_database_backend() {
+        DbBackend::MySql => schema.create_table_from_entity(mysql_username::Entity),
+        DbBackend::Postgres => {
+            db.execute_unprepared(
+                "CREATE COLLATION IF NOT EXISTS case_insensitive (provider = icu, locale = 'und-u-ks-level2', deterministic = false)",
+            )
+            .await?;
+            schema.create_table_from_entity(pg_username::Entity)
+        }
+        DbBackend::Sqlite => schema.create_table_from_entity(sqlite_username::Entity),
+    };
+    create_table_without_asserts(db, &stmt).await?;
+    case_insensitive_unique(db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn case_insensitive_unique(db: &DatabaseConnection) -> Result<(), DbErr> {
+    db.execute_unprepared("INSERT INTO username (name) VALUES ('Alice')")
+        .await?;
+
+    // The names only differ by case, so they are the same for the unique constraint
+    assert!(db
+        .execute_unprepared("INSERT INTO username (name) VALUES ('ALICE')")
+        .await
+        .is_err());
+    db.execute_unprepared("INSERT INTO username (name) VALUES ('Bob')")
+        .await?;
+
+    let count = db
+        .query_one(Statement::from_string(
+            db.get_database_backend(),
+            "SELECT COUNT(*) AS count FROM username WHERE name = 'alice'",
+        ))
+        .await?
+        .unwrap()
+        .try_get::<i64>("", "count")?;
+    assert_eq!(count, 1);
+
+    Ok(())
+}
@@ -6,6 +6,7 @@ pub mod customer;
 pub mod lineitem;
 pub mod order;
 pub mod schema;
+pub mod seed;
 
 pub use baker::Entity as Baker;
 pub use bakery::Entity as Bakery;
@@ -15,3 +16,4 @@ pub use customer::Entity as Customer;
 pub use lineitem::Entity as Lineitem;
 pub use order::Entity as Order;
 pub use schema::*;
+pub use seed::*;
@@ -0,0 +1,51 @@
+use super::*;
+use crate::common::setup::rust_dec;
+use sea_orm::{entity::prelude::*, Set};
+
+/// A bakery with the given name, to be inserted
+pub fn new_bakery(name: &str) -> bakery::ActiveModel {
+    bakery::ActiveModel {
+        name: Set(name.to_owned()),
+        profit_margin: Set(10.4),
+        ..Default::default()
+    }
+}
+
+/// A cake of the given bakery, with the given name and price, to be inserted
+pub fn new_cake(name: &str, price: f64, bakery_id: Option<i32>) -> cake::ActiveModel {
+    cake::ActiveModel {
+        name: Set(name.to_owned()),
+        price: Set(rust_dec(price)),
+        bakery_id: Set(bakery_id),
+        gluten_free: Set(false),
+        serial: Set(Uuid::new_v4()),
+        ..Default::default()
+    }
+}
+
+/// Insert the bakeries `Bakery 1` to `Bakery {count}`, which get the ids 1 to `count`
+/// in an empty table
+pub async fn seed_bakeries(db: &DatabaseConnection, count: i32) -> Result<(), DbErr> {
+    Bakery::insert_many((1..=count).map(|i| new_bakery(&format!("Bakery {i}"))))
+        .exec(db)
+        .await?;
+
+    Ok(())
+}
+
+/// Insert two bakeries, with cakes at various prices: three for the first bakery,
+/// two for the second one
+pub async fn seed_priced_cakes(db: &DatabaseConnection) -> Result<(), DbErr> {
+    seed_bakeries(db, 2).await?;
+
+    let cakes = [(1, 2.5), (1, 7.5), (1, 5.0), (2, 3.0), (2, 1.0)].map(|(bakery_id, price)| {
+        new_cake(
+            &format!("Cake of bakery {bakery_id} for {price}"),
+            price,
+            Some(bakery_id),
+        )
+    });
+    Cake::insert_many(cakes).exec(db).await?;
+
+    Ok(())
+}
@@ -0,0 +1,15 @@
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "availability")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub seats: PgRange<i32>,
+    pub opening_hours: Option<PgRange<DateTime>>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
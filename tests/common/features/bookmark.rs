@@ -0,0 +1,17 @@
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "bookmark")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub url: String,
+    pub tags: Vec<String>,
+    pub tags_opt: Option<Vec<String>>,
+    pub votes: Vec<i32>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
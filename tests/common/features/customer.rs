@@ -0,0 +1,27 @@
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "customer")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub name: String,
+    #[sea_orm(select_as = "text", save_as = "address")]
+    pub address: Address,
+    #[sea_orm(select_as = "text", save_as = "address")]
+    pub billing_address: Option<Address>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveCompositeType)]
+#[sea_orm(type_name = "address")]
+pub struct Address {
+    pub street: String,
+    pub city: String,
+    pub zip: Option<String>,
+    pub floor: Option<i32>,
+}
@@ -0,0 +1,17 @@
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "host")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub address: IpNetwork,
+    #[sea_orm(column_type = "Cidr")]
+    pub subnet: IpNetwork,
+    pub mac: Option<MacAddress>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -2,22 +2,38 @@ pub mod active_enum;
 pub mod active_enum_child;
 pub mod active_enum_vec;
 pub mod applog;
+pub mod article;
+pub mod availability;
 pub mod binary;
 pub mod bits;
+pub mod bookmark;
 pub mod byte_primary_key;
 pub mod categories;
 pub mod collection;
 pub mod collection_expanded;
 pub mod custom_active_model;
+pub mod customer;
+pub mod document;
 pub mod dyn_table_name_lazy_static;
 pub mod edit_log;
 pub mod event_trigger;
+pub mod host;
 pub mod insert_default;
+pub mod inventory;
 pub mod json_struct;
 pub mod json_vec;
 pub mod json_vec_derive;
+pub mod live_note;
 pub mod metadata;
+pub mod note;
+pub mod notebook;
 pub mod pi;
+#[cfg(feature = "postgis")]
+pub mod place;
+pub mod post;
+pub mod preference;
+pub mod rectangle;
+pub mod reminder;
 pub mod repository;
 pub mod satellite;
 pub mod schema;
@@ -27,27 +43,44 @@ pub mod teas;
 pub mod transaction_log;
 pub mod uuid_fmt;
 pub mod value_type;
+pub mod vault;
 
 pub use active_enum::Entity as ActiveEnum;
 pub use active_enum_child::Entity as ActiveEnumChild;
 pub use active_enum_vec::Entity as ActiveEnumVec;
 pub use applog::Entity as Applog;
+pub use article::Entity as Article;
+pub use availability::Entity as Availability;
 pub use binary::Entity as Binary;
 pub use bits::Entity as Bits;
+pub use bookmark::Entity as Bookmark;
 pub use byte_primary_key::Entity as BytePrimaryKey;
 pub use categories::Entity as Categories;
 pub use collection::Entity as Collection;
 pub use collection_expanded::Entity as CollectionExpanded;
+pub use customer::Entity as Customer;
+pub use document::Entity as Document;
 pub use dyn_table_name_lazy_static::Entity as DynTableNameLazyStatic;
 pub use edit_log::Entity as EditLog;
 pub use event_trigger::Entity as EventTrigger;
+pub use host::Entity as Host;
 pub use insert_default::Entity as InsertDefault;
+pub use inventory::Entity as Inventory;
 pub use json_struct::Entity as JsonStruct;
 pub use json_vec::Entity as JsonVec;
 pub use json_vec_derive::json_string_vec::Entity as JsonStringVec;
 pub use json_vec_derive::json_struct_vec::Entity as JsonStructVec;
+pub use live_note::Entity as LiveNote;
 pub use metadata::Entity as Metadata;
+pub use note::Entity as Note;
+pub use notebook::Entity as Notebook;
 pub use pi::Entity as Pi;
+#[cfg(feature = "postgis")]
+pub use place::Entity as Place;
+pub use post::Entity as Post;
+pub use preference::Entity as Preference;
+pub use rectangle::Entity as Rectangle;
+pub use reminder::Entity as Reminder;
 pub use repository::Entity as Repository;
 pub use satellite::Entity as Satellite;
 pub use schema::*;
@@ -56,3 +89,4 @@ pub use self_join::Entity as SelfJoin;
 pub use teas::Entity as Teas;
 pub use transaction_log::Entity as TransactionLog;
 pub use uuid_fmt::Entity as UuidFmt;
+pub use vault::Entity as Vault;
@@ -0,0 +1,31 @@
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "note")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub notebook_id: Option<i32>,
+    pub text: String,
+    #[sea_orm(soft_delete)]
+    pub deleted_at: Option<DateTimeWithTimeZone>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        fk_name = "fk-note-notebook",
+        belongs_to = "super::notebook::Entity",
+        from = "Column::NotebookId",
+        to = "super::notebook::Column::Id"
+    )]
+    Notebook,
+}
+
+impl Related<super::notebook::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Notebook.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
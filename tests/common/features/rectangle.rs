@@ -0,0 +1,20 @@
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "rectangle")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub width: i32,
+    pub height: i32,
+    #[sea_orm(
+        generated_expr = "Expr::col(Column::Width).mul(Expr::col(Column::Height))",
+        stored
+    )]
+    pub area: i32,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
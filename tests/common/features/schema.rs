@@ -5,8 +5,8 @@ use sea_orm::{
     ExecResult, Schema,
 };
 use sea_query::{
-    extension::postgres::Type, Alias, ColumnDef, ColumnType, ForeignKeyCreateStatement, IntoIden,
-    StringLen,
+    extension::postgres::Type, Alias, ColumnDef, ColumnType, Expr, ForeignKeyCreateStatement,
+    IntoIden, StringLen,
 };
 
 pub async fn create_tables(db: &DatabaseConnection) -> Result<(), DbErr> {
@@ -40,6 +40,12 @@ pub async fn create_tables(db: &DatabaseConnection) -> Result<(), DbErr> {
     create_active_enum_table(db).await?;
     create_active_enum_child_table(db).await?;
     create_insert_default_table(db).await?;
+    create_inventory_table(db).await?;
+    create_document_table(db).await?;
+    create_notebook_table(db).await?;
+    create_note_table(db).await?;
+    create_article_table(db).await?;
+    create_post_table(db).await?;
     create_pi_table(db).await?;
     create_uuid_fmt_table(db).await?;
     create_edit_log_table(db).await?;
@@ -50,6 +56,8 @@ pub async fn create_tables(db: &DatabaseConnection) -> Result<(), DbErr> {
     }
     create_dyn_table_name_lazy_static_table(db).await?;
     create_value_type_table(db).await?;
+    create_vault_table(db).await?;
+    create_rectangle_table(db).await?;
 
     create_json_vec_table(db).await?;
     create_json_struct_table(db).await?;
@@ -59,8 +67,16 @@ pub async fn create_tables(db: &DatabaseConnection) -> Result<(), DbErr> {
     if DbBackend::Postgres == db_backend {
         create_value_type_postgres_table(db).await?;
         create_collection_table(db).await?;
+        create_bookmark_table(db).await?;
+        create_availability_table(db).await?;
+        create_preference_table(db).await?;
+        create_host_table(db).await?;
+        create_reminder_table(db).await?;
+        create_customer_table(db).await?;
         create_event_trigger_table(db).await?;
         create_categories_table(db).await?;
+        #[cfg(feature = "postgis")]
+        create_place_table(db).await?;
     }
 
     Ok(())
@@ -329,6 +345,158 @@ pub async fn create_insert_default_table(db: &DbConn) -> Result<ExecResult, DbEr
     create_table(db, &create_table_stmt, InsertDefault).await
 }
 
+pub async fn create_inventory_table(db: &DbConn) -> Result<ExecResult, DbErr> {
+    let create_table_stmt = sea_query::Table::create()
+        .table(inventory::Entity.table_ref())
+        .col(
+            ColumnDef::new(inventory::Column::Id)
+                .integer()
+                .not_null()
+                .auto_increment()
+                .primary_key(),
+        )
+        .col(
+            ColumnDef::new(inventory::Column::Sku)
+                .string()
+                .not_null()
+                .unique_key(),
+        )
+        .col(
+            ColumnDef::new(inventory::Column::Quantity)
+                .integer()
+                .not_null(),
+        )
+        .to_owned();
+
+    create_table(db, &create_table_stmt, Inventory).await
+}
+
+pub async fn create_document_table(db: &DbConn) -> Result<ExecResult, DbErr> {
+    let create_table_stmt = sea_query::Table::create()
+        .table(document::Entity.table_ref())
+        .col(
+            ColumnDef::new(document::Column::Id)
+                .integer()
+                .not_null()
+                .auto_increment()
+                .primary_key(),
+        )
+        .col(ColumnDef::new(document::Column::Title).string().not_null())
+        .col(
+            ColumnDef::new(document::Column::Version)
+                .integer()
+                .not_null(),
+        )
+        .to_owned();
+
+    create_table(db, &create_table_stmt, Document).await
+}
+
+pub async fn create_notebook_table(db: &DbConn) -> Result<ExecResult, DbErr> {
+    let create_table_stmt = sea_query::Table::create()
+        .table(notebook::Entity.table_ref())
+        .col(
+            ColumnDef::new(notebook::Column::Id)
+                .integer()
+                .not_null()
+                .auto_increment()
+                .primary_key(),
+        )
+        .col(ColumnDef::new(notebook::Column::Name).string().not_null())
+        .to_owned();
+
+    create_table(db, &create_table_stmt, Notebook).await
+}
+
+pub async fn create_note_table(db: &DbConn) -> Result<ExecResult, DbErr> {
+    let create_table_stmt = sea_query::Table::create()
+        .table(note::Entity.table_ref())
+        .col(
+            ColumnDef::new(note::Column::Id)
+                .integer()
+                .not_null()
+                .auto_increment()
+                .primary_key(),
+        )
+        .col(ColumnDef::new(note::Column::NotebookId).integer())
+        .col(ColumnDef::new(note::Column::Text).string().not_null())
+        .col(ColumnDef::new(note::Column::DeletedAt).timestamp_with_time_zone())
+        .foreign_key(
+            ForeignKeyCreateStatement::new()
+                .name("fk-note-notebook")
+                .from_tbl(Note)
+                .from_col(note::Column::NotebookId)
+                .to_tbl(Notebook)
+                .to_col(notebook::Column::Id),
+        )
+        .to_owned();
+
+    create_table(db, &create_table_stmt, Note).await
+}
+
+pub async fn create_article_table(db: &DbConn) -> Result<ExecResult, DbErr> {
+    let create_table_stmt = sea_query::Table::create()
+        .table(article::Entity.table_ref())
+        .col(
+            ColumnDef::new(article::Column::Id)
+                .integer()
+                .not_null()
+                .auto_increment()
+                .primary_key(),
+        )
+        .col(ColumnDef::new(article::Column::Title).string().not_null())
+        .col(
+            ColumnDef::new(article::Column::CreatedAt)
+                .timestamp_with_time_zone()
+                .not_null(),
+        )
+        .col(
+            ColumnDef::new(article::Column::UpdatedAt)
+                .timestamp_with_time_zone()
+                .not_null(),
+        )
+        .to_owned();
+
+    create_table(db, &create_table_stmt, Article).await
+}
+
+pub async fn create_post_table(db: &DbConn) -> Result<ExecResult, DbErr> {
+    // Full-text search on SQLite requires an FTS5 virtual table
+    if db.get_database_backend() == DbBackend::Sqlite {
+        return db
+            .execute_unprepared("CREATE VIRTUAL TABLE post USING fts5(id UNINDEXED, title, body)")
+            .await;
+    }
+
+    let create_table_stmt = sea_query::Table::create()
+        .table(post::Entity.table_ref())
+        .col(
+            ColumnDef::new(post::Column::Id)
+                .integer()
+                .not_null()
+                .auto_increment()
+                .primary_key(),
+        )
+        .col(ColumnDef::new(post::Column::Title).string().not_null())
+        .col(ColumnDef::new(post::Column::Body).text().not_null())
+        .to_owned();
+    let result = create_table(db, &create_table_stmt, Post).await?;
+
+    // Full-text search on MySQL requires a FULLTEXT index
+    if db.get_database_backend() == DbBackend::MySql {
+        let builder = db.get_database_backend();
+        let create_index_stmt = sea_query::Index::create()
+            .name("idx-post-body")
+            .table(post::Entity)
+            .col(post::Column::Body)
+            .full_text()
+            .to_owned();
+        db.execute(builder.build(&create_index_stmt)).await?;
+    }
+
+    Ok(result)
+}
+
 pub async fn create_json_vec_table(db: &DbConn) -> Result<ExecResult, DbErr> {
     let create_table_stmt = sea_query::Table::create()
         .table(json_vec::Entity.table_ref())
@@ -471,6 +639,193 @@ pub async fn create_collection_table(db: &DbConn) -> Result<ExecResult, DbErr> {
     create_table(db, &stmt, Collection).await
 }
 
+pub async fn create_bookmark_table(db: &DbConn) -> Result<ExecResult, DbErr> {
+    let stmt = sea_query::Table::create()
+        .table(bookmark::Entity)
+        .col(
+            ColumnDef::new(bookmark::Column::Id)
+                .integer()
+                .not_null()
+                .auto_increment()
+                .primary_key(),
+        )
+        .col(ColumnDef::new(bookmark::Column::Url).string().not_null())
+        .col(
+            ColumnDef::new(bookmark::Column::Tags)
+                .array(sea_query::ColumnType::String(StringLen::None))
+                .not_null(),
+        )
+        .col(
+            ColumnDef::new(bookmark::Column::TagsOpt)
+                .array(sea_query::ColumnType::String(StringLen::None)),
+        )
+        .col(
+            ColumnDef::new(bookmark::Column::Votes)
+                .array(sea_query::ColumnType::Integer)
+                .not_null(),
+        )
+        .to_owned();
+
+    create_table(db, &stmt, Bookmark).await
+}
+
+pub async fn create_availability_table(db: &DbConn) -> Result<ExecResult, DbErr> {
+    let stmt = sea_query::Table::create()
+        .table(availability::Entity)
+        .col(
+            ColumnDef::new(availability::Column::Id)
+                .integer()
+                .not_null()
+                .auto_increment()
+                .primary_key(),
+        )
+        .col(
+            ColumnDef::new(availability::Column::Seats)
+                .custom(Alias::new("int4range"))
+                .not_null(),
+        )
+        .col(ColumnDef::new(availability::Column::OpeningHours).custom(Alias::new("tsrange")))
+        .to_owned();
+
+    create_table(db, &stmt, Availability).await
+}
+
+pub async fn create_preference_table(db: &DbConn) -> Result<ExecResult, DbErr> {
+    db.execute_unprepared("CREATE EXTENSION IF NOT EXISTS hstore")
+        .await?;
+
+    let stmt = sea_query::Table::create()
+        .table(preference::Entity)
+        .col(
+            ColumnDef::new(preference::Column::Id)
+                .integer()
+                .not_null()
+                .auto_increment()
+                .primary_key(),
+        )
+        .col(
+            ColumnDef::new(preference::Column::Settings)
+                .custom(Alias::new("hstore"))
+                .not_null(),
+        )
+        .col(ColumnDef::new(preference::Column::Overrides).custom(Alias::new("hstore")))
+        .to_owned();
+
+    create_table(db, &stmt, Preference).await
+}
+
+#[cfg(feature = "postgis")]
+pub async fn create_place_table(db: &DbConn) -> Result<ExecResult, DbErr> {
+    db.execute_unprepared("CREATE EXTENSION IF NOT EXISTS postgis")
+        .await?;
+
+    let stmt = sea_query::Table::create()
+        .table(place::Entity)
+        .col(
+            ColumnDef::new(place::Column::Id)
+                .integer()
+                .not_null()
+                .auto_increment()
+                .primary_key(),
+        )
+        .col(ColumnDef::new(place::Column::Name).string().not_null())
+        .col(
+            ColumnDef::new(place::Column::Location)
+                .custom(Alias::new("geometry"))
+                .not_null(),
+        )
+        .col(ColumnDef::new(place::Column::Entrance).custom(Alias::new("geometry")))
+        .to_owned();
+
+    create_table(db, &stmt, Place).await
+}
+
+pub async fn create_host_table(db: &DbConn) -> Result<ExecResult, DbErr> {
+    let stmt = sea_query::Table::create()
+        .table(host::Entity)
+        .col(
+            ColumnDef::new(host::Column::Id)
+                .integer()
+                .not_null()
+                .auto_increment()
+                .primary_key(),
+        )
+        .col(ColumnDef::new(host::Column::Address).inet().not_null())
+        .col(ColumnDef::new(host::Column::Subnet).cidr().not_null())
+        .col(ColumnDef::new(host::Column::Mac).mac_address())
+        .to_owned();
+
+    create_table(db, &stmt, Host).await
+}
+
+pub async fn create_vault_table(db: &DbConn) -> Result<ExecResult, DbErr> {
+    let stmt = sea_query::Table::create()
+        .table(vault::Entity)
+        .col(
+            ColumnDef::new(vault::Column::Id)
+                .integer()
+                .not_null()
+                .auto_increment()
+                .primary_key(),
+        )
+        .col(ColumnDef::new(vault::Column::Name).string().not_null())
+        .col(ColumnDef::new(vault::Column::Secret).string().not_null())
+        .col(ColumnDef::new(vault::Column::Payload).string())
+        .to_owned();
+
+    create_table(db, &stmt, Vault).await
+}
+
+pub async fn create_reminder_table(db: &DbConn) -> Result<ExecResult, DbErr> {
+    let stmt = sea_query::Table::create()
+        .table(reminder::Entity)
+        .col(
+            ColumnDef::new(reminder::Column::Id)
+                .integer()
+                .not_null()
+                .auto_increment()
+                .primary_key(),
+        )
+        .col(
+            ColumnDef::new(reminder::Column::Every)
+                .interval(None, None)
+                .not_null(),
+        )
+        .col(ColumnDef::new(reminder::Column::Snooze).interval(None, None))
+        .to_owned();
+
+    create_table(db, &stmt, Reminder).await
+}
+
+pub async fn create_customer_table(db: &DbConn) -> Result<ExecResult, DbErr> {
+    db.execute_unprepared("DROP TYPE IF EXISTS address CASCADE")
+        .await?;
+    db.execute_unprepared(
+        "CREATE TYPE address AS (street text, city text, zip text, floor integer)",
+    )
+    .await?;
+
+    let stmt = sea_query::Table::create()
+        .table(customer::Entity)
+        .col(
+            ColumnDef::new(customer::Column::Id)
+                .integer()
+                .not_null()
+                .auto_increment()
+                .primary_key(),
+        )
+        .col(ColumnDef::new(customer::Column::Name).string().not_null())
+        .col(
+            ColumnDef::new(customer::Column::Address)
+                .custom(Alias::new("address"))
+                .not_null(),
+        )
+        .col(ColumnDef::new(customer::Column::BillingAddress).custom(Alias::new("address")))
+        .to_owned();
+
+    create_table(db, &stmt, Customer).await
+}
+
 pub async fn create_pi_table(db: &DbConn) -> Result<ExecResult, DbErr> {
     let stmt = sea_query::Table::create()
         .table(pi::Entity)
@@ -745,3 +1100,37 @@ pub async fn create_value_type_postgres_table(db: &DbConn) -> Result<ExecResult,
 
     create_table(db, &postgres_stmt, value_type::value_type_pg::Entity).await
 }
+
+pub async fn create_rectangle_table(db: &DbConn) -> Result<ExecResult, DbErr> {
+    let stmt = sea_query::Table::create()
+        .table(rectangle::Entity)
+        .col(
+            ColumnDef::new(rectangle::Column::Id)
+                .integer()
+                .not_null()
+                .auto_increment()
+                .primary_key(),
+        )
+        .col(
+            ColumnDef::new(rectangle::Column::Width)
+                .integer()
+                .not_null(),
+        )
+        .col(
+            ColumnDef::new(rectangle::Column::Height)
+                .integer()
+                .not_null(),
+        )
+        .col(
+            ColumnDef::new(rectangle::Column::Area)
+                .integer()
+                .generated(
+                    Expr::col(rectangle::Column::Width).mul(Expr::col(rectangle::Column::Height)),
+                    true,
+                )
+                .not_null(),
+        )
+        .to_owned();
+
+    create_table(db, &stmt, Rectangle).await
+}
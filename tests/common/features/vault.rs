@@ -0,0 +1,68 @@
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "vault")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub name: String,
+    #[sea_orm(with = "Rot13")]
+    pub secret: String,
+    #[sea_orm(with = Hex)]
+    pub payload: Option<Vec<u8>>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+/// Stores the text with its letters rotated by 13 places
+pub struct Rot13;
+
+impl ValueConverter for Rot13 {
+    type ModelValue = String;
+    type DbValue = String;
+
+    fn to_db(value: String) -> String {
+        value
+            .chars()
+            .map(|c| match c {
+                'a'..='m' | 'A'..='M' => (c as u8 + 13) as char,
+                'n'..='z' | 'N'..='Z' => (c as u8 - 13) as char,
+                c => c,
+            })
+            .collect()
+    }
+
+    fn from_db(value: String) -> Result<String, DbErr> {
+        Ok(Self::to_db(value))
+    }
+}
+
+/// Stores the bytes as hexadecimal text
+pub struct Hex;
+
+impl ValueConverter for Hex {
+    type ModelValue = Option<Vec<u8>>;
+    type DbValue = Option<String>;
+
+    fn to_db(value: Option<Vec<u8>>) -> Option<String> {
+        value.map(|bytes| bytes.iter().map(|byte| format!("{byte:02x}")).collect())
+    }
+
+    fn from_db(value: Option<String>) -> Result<Option<Vec<u8>>, DbErr> {
+        value
+            .map(|hex| {
+                (0..hex.len())
+                    .step_by(2)
+                    .map(|i| {
+                        hex.get(i..i + 2)
+                            .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+                            .ok_or_else(|| DbErr::Type(format!("Invalid hexadecimal text: {hex}")))
+                    })
+                    .collect()
+            })
+            .transpose()
+    }
+}
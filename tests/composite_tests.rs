@@ -0,0 +1,96 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{features::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{entity::prelude::*, entity::*, DatabaseConnection, QueryOrder};
+
+#[sea_orm_macros::test]
+#[cfg(feature = "sqlx-postgres")]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("composite_tests").await;
+    create_tables(&ctx.db).await?;
+    insert_customer(&ctx.db).await?;
+    filter_customer(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn insert_customer(db: &DatabaseConnection) -> Result<(), DbErr> {
+    use customer::*;
+
+    let customers = [
+        Model {
+            id: 1,
+            name: "Alice".to_owned(),
+            address: Address {
+                street: "1 Main Street".to_owned(),
+                city: "Springfield".to_owned(),
+                zip: Some("12345".to_owned()),
+                floor: Some(3),
+            },
+            billing_address: None,
+        },
+        // Fields which have to be quoted and escaped, and null fields
+        Model {
+            id: 2,
+            name: "Bob".to_owned(),
+            address: Address {
+                street: r#"Flat "B", 2\3 (rear)"#.to_owned(),
+                city: String::new(),
+                zip: None,
+                floor: None,
+            },
+            billing_address: Some(Address {
+                street: "PO Box 7".to_owned(),
+                city: "Shelbyville".to_owned(),
+                zip: None,
+                floor: Some(-1),
+            }),
+        },
+    ];
+
+    for customer in customers.iter() {
+        assert_eq!(
+            &customer.clone().into_active_model().insert(db).await?,
+            customer
+        );
+    }
+    assert_eq!(
+        Entity::find().order_by_asc(Column::Id).all(db).await?,
+        customers
+    );
+
+    let mut customer = customers[0].clone().into_active_model();
+    customer.billing_address = Set(Some(Address {
+        street: "2 Side Street".to_owned(),
+        city: "Springfield".to_owned(),
+        zip: None,
+        floor: None,
+    }));
+    let customer = customer.update(db).await?;
+    assert_eq!(Entity::find_by_id(1).one(db).await?, Some(customer));
+
+    Ok(())
+}
+
+pub async fn filter_customer(db: &DatabaseConnection) -> Result<(), DbErr> {
+    use customer::*;
+
+    // Composite values with null fields are never equal
+    let address = Entity::find_by_id(1).one(db).await?.unwrap().address;
+    assert_eq!(
+        Entity::find()
+            .filter(Column::Address.eq(address))
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|customer| customer.id)
+            .collect::<Vec<_>>(),
+        [1]
+    );
+
+    Ok(())
+}
@@ -48,7 +48,10 @@ pub async fn connection_ping_closed_mysql() {
         let txn = sea_orm::TransactionTrait::begin(db).await;
         assert_eq!(
             txn.expect_err("should be a time out"),
-            crate::DbErr::ConnectionAcquire(ConnAcquireErr::Timeout)
+            crate::DbErr::ConnAcquireTimeout {
+                max_connections: 1,
+                in_use: 1,
+            }
         )
     }
 
@@ -57,6 +60,27 @@ pub async fn connection_ping_closed_mysql() {
     ctx.delete().await;
 }
 
+#[sea_orm_macros::test]
+#[cfg(feature = "sqlx-sqlite")]
+pub async fn connection_in_memory_sqlite() -> Result<(), DbErr> {
+    use sea_orm::Set;
+
+    let db = sea_orm::Database::connect_in_memory().await?;
+    create_tables(&db).await?;
+
+    new_bakery("SeaSide Bakery").insert(&db).await?;
+
+    // the tables and their rows are there for every query, even concurrent ones
+    let (count, bakeries) = futures::try_join!(
+        bakery::Entity::find().count(&db),
+        bakery::Entity::find().all(&db)
+    )?;
+    assert_eq!(count, 1);
+    assert_eq!(bakeries[0].name, "SeaSide Bakery");
+
+    Ok(())
+}
+
 #[sea_orm_macros::test]
 #[cfg(feature = "sqlx-sqlite")]
 pub async fn connection_ping_closed_sqlite() {
@@ -90,7 +114,10 @@ pub async fn connection_ping_closed_sqlite() {
         let txn = sea_orm::TransactionTrait::begin(db).await;
         assert_eq!(
             txn.expect_err("should be a time out"),
-            crate::DbErr::ConnectionAcquire(ConnAcquireErr::Timeout)
+            crate::DbErr::ConnAcquireTimeout {
+                max_connections: 1,
+                in_use: 1,
+            }
         )
     }
 
@@ -132,7 +159,10 @@ pub async fn connection_ping_closed_postgres() {
         let txn = sea_orm::TransactionTrait::begin(db).await;
         assert_eq!(
             txn.expect_err("should be a time out"),
-            crate::DbErr::ConnectionAcquire(ConnAcquireErr::Timeout)
+            crate::DbErr::ConnAcquireTimeout {
+                max_connections: 1,
+                in_use: 1,
+            }
         )
     }
 
@@ -140,3 +170,45 @@ pub async fn connection_ping_closed_postgres() {
 
     ctx.delete().await;
 }
+
+#[sea_orm_macros::test]
+#[cfg(feature = "sqlx-postgres")]
+pub async fn connection_min_connections_postgres() {
+    let ctx = TestContext::new("connection_min_connections").await;
+
+    let base_url = std::env::var("DATABASE_URL").unwrap();
+    let mut opt = sea_orm::ConnectOptions::new(format!("{base_url}/connection_min_connections"));
+    opt.min_connections(3).test_before_acquire(false);
+    assert!(!opt.get_test_before_acquire());
+
+    // The connections are there as soon as the pool is
+    let db = sea_orm::Database::connect(opt).await.unwrap();
+    assert!(db.get_postgres_connection_pool().size() >= 3);
+    db.ping().await.unwrap();
+    db.close().await.unwrap();
+
+    ctx.delete().await;
+}
+
+#[sea_orm_macros::test]
+#[cfg(feature = "sqlx-postgres")]
+pub async fn connection_schema_search_path_postgres() -> Result<(), DbErr> {
+    let ctx = TestContext::new("connection_schema_search_path").await;
+    let default_search_path = ctx.db.schema_search_path().await?;
+
+    let conn = ctx.db.set_schema_search_path("pg_catalog, public").await?;
+    assert_eq!(conn.schema_search_path().await?, "pg_catalog, public");
+    // it lasts for the lifetime of the connection, transactions included
+    let txn = sea_orm::TransactionTrait::begin(&conn).await?;
+    assert_eq!(txn.schema_search_path().await?, "pg_catalog, public");
+    txn.commit().await?;
+    assert_eq!(conn.schema_search_path().await?, "pg_catalog, public");
+
+    // while the connections of the pool keep their own
+    assert_eq!(ctx.db.schema_search_path().await?, default_search_path);
+    conn.close().await?;
+
+    ctx.delete().await;
+
+    Ok(())
+}
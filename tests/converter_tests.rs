@@ -0,0 +1,142 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{features::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{entity::prelude::*, entity::*, DatabaseConnection, QueryOrder, QuerySelect};
+
+#[sea_orm_macros::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("converter_tests").await;
+    create_tables(&ctx.db).await?;
+    insert_vault(&ctx.db).await?;
+    update_vault(&ctx.db).await?;
+    invalid_vault(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn insert_vault(db: &DatabaseConnection) -> Result<(), DbErr> {
+    use vault::*;
+
+    let vaults = [
+        Model {
+            id: 1,
+            name: "Alice".to_owned(),
+            secret: "Hello, World!".to_owned(),
+            payload: Some(vec![0, 1, 254, 255]),
+        },
+        Model {
+            id: 2,
+            name: "Bob".to_owned(),
+            secret: String::new(),
+            payload: None,
+        },
+    ];
+
+    for vault in vaults.iter() {
+        assert_eq!(&vault.clone().into_active_model().insert(db).await?, vault);
+    }
+    assert_eq!(
+        Entity::find().order_by_asc(Column::Id).all(db).await?,
+        vaults
+    );
+
+    // The columns hold the converted values
+    let stored: Vec<(String, Option<String>)> = Entity::find()
+        .select_only()
+        .column(Column::Secret)
+        .column(Column::Payload)
+        .order_by_asc(Column::Id)
+        .into_tuple()
+        .all(db)
+        .await?;
+    assert_eq!(
+        stored,
+        [
+            ("Uryyb, Jbeyq!".to_owned(), Some("0001feff".to_owned())),
+            (String::new(), None),
+        ]
+    );
+
+    // Conditions compare the stored values
+    assert_eq!(
+        Entity::find()
+            .filter(Column::Secret.eq(Rot13::to_db("Hello, World!".to_owned())))
+            .one(db)
+            .await?,
+        Some(vaults[0].clone())
+    );
+
+    Ok(())
+}
+
+pub async fn update_vault(db: &DatabaseConnection) -> Result<(), DbErr> {
+    use vault::*;
+
+    let mut vault = Entity::find_by_id(2)
+        .one(db)
+        .await?
+        .unwrap()
+        .into_active_model();
+    vault.secret = Set("Open Sesame".to_owned());
+    vault.payload = Set(Some(vec![42]));
+    let vault = vault.update(db).await?;
+    assert_eq!(
+        vault,
+        Model {
+            id: 2,
+            name: "Bob".to_owned(),
+            secret: "Open Sesame".to_owned(),
+            payload: Some(vec![42]),
+        }
+    );
+    assert_eq!(Entity::find_by_id(2).one(db).await?, Some(vault));
+
+    let stored: Option<String> = Entity::find_by_id(2)
+        .select_only()
+        .column(Column::Secret)
+        .into_tuple()
+        .one(db)
+        .await?;
+    assert_eq!(stored, Some("Bcra Frfnzr".to_owned()));
+
+    Ok(())
+}
+
+pub async fn invalid_vault(db: &DatabaseConnection) -> Result<(), DbErr> {
+    use vault::*;
+
+    // A stored value which can not be converted fails the query
+    Entity::update_many()
+        .col_expr(Column::Payload, Expr::value("not hex"))
+        .filter(Column::Id.eq(1))
+        .exec(db)
+        .await?;
+    assert!(matches!(
+        Entity::find_by_id(1).one(db).await,
+        Err(DbErr::Type(_))
+    ));
+
+    // As does setting it, on an ActiveModel or a Model
+    let mut vault = Entity::find_by_id(2).one(db).await?.unwrap();
+    assert!(matches!(
+        vault.try_set(Column::Payload, "not hex".into()),
+        Err(DbErr::Type(_))
+    ));
+    let mut vault = vault.into_active_model();
+    assert!(matches!(
+        vault.try_set(Column::Payload, "not hex".into()),
+        Err(DbErr::Type(_))
+    ));
+    assert!(matches!(
+        vault.try_set(Column::Secret, 42.into()),
+        Err(DbErr::Type(_))
+    ));
+    vault.try_set(Column::Payload, "2b".into())?;
+    assert_eq!(vault.payload, Set(Some(vec![43])));
+
+    Ok(())
+}
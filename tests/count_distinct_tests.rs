@@ -0,0 +1,60 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{bakery_chain::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{prelude::*, Set};
+
+#[sea_orm_macros::test]
+pub async fn count_distinct_tests() -> Result<(), DbErr> {
+    let ctx = TestContext::new("count_distinct_tests").await;
+    create_tables(&ctx.db).await?;
+    seed_data(&ctx.db).await?;
+    count_distinct(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn seed_data(db: &DatabaseConnection) -> Result<(), DbErr> {
+    seed_bakeries(db, 3).await?;
+
+    let cakes = [
+        (Some(1), "Cheesecake"),
+        (Some(1), "Carrot cake"),
+        (Some(2), "Cheesecake"),
+        (Some(2), "Cheesecake"),
+        (None, "Carrot cake"),
+    ]
+    .map(|(bakery_id, name)| new_cake(name, 2.5, bakery_id));
+    Cake::insert_many(cakes).exec(db).await?;
+
+    Ok(())
+}
+
+pub async fn count_distinct(db: &DatabaseConnection) -> Result<(), DbErr> {
+    assert_eq!(Cake::find().count(db).await?, 5);
+    assert_eq!(
+        Cake::find().count_distinct(cake::Column::Name, db).await?,
+        2
+    );
+
+    // NULL values are not counted
+    assert_eq!(
+        Cake::find()
+            .count_distinct(cake::Column::BakeryId, db)
+            .await?,
+        2
+    );
+
+    assert_eq!(
+        Cake::find()
+            .filter(cake::Column::BakeryId.eq(2))
+            .count_distinct(cake::Column::Name, db)
+            .await?,
+        1
+    );
+
+    Ok(())
+}
@@ -0,0 +1,147 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{features::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{
+    entity::prelude::*,
+    sea_query::{Alias, Expr, Query, UnionType},
+    CursorTrait, PaginatorTrait, QueryOrder, QuerySelect, QueryTrait, Set,
+};
+
+#[sea_orm_macros::test]
+pub async fn cte_tests() -> Result<(), DbErr> {
+    let ctx = TestContext::new("cte_tests").await;
+    create_tables(&ctx.db).await?;
+    seed_data(&ctx.db).await?;
+    with_cte(&ctx.db).await?;
+    with_recursive_cte(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn seed_data(db: &DatabaseConnection) -> Result<(), DbErr> {
+    // 1 -> 2 -> 4, 1 -> 3, and 5 on its own
+    let rows = [
+        (1, None),
+        (2, Some(1)),
+        (3, Some(1)),
+        (4, Some(2)),
+        (5, None),
+    ]
+    .map(
+        |(uuid, uuid_ref): (u128, Option<u128>)| self_join::ActiveModel {
+            uuid: Set(Uuid::from_u128(uuid)),
+            uuid_ref: Set(uuid_ref.map(Uuid::from_u128)),
+            ..Default::default()
+        },
+    );
+    SelfJoin::insert_many(rows).exec(db).await?;
+
+    Ok(())
+}
+
+pub async fn with_cte(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let referenced = Alias::new("referenced");
+
+    let select = SelfJoin::find()
+        .with(
+            referenced.clone(),
+            Query::select()
+                .column(self_join::Column::UuidRef)
+                .from(SelfJoin)
+                .and_where(self_join::Column::UuidRef.is_not_null())
+                .to_owned(),
+        )
+        .filter(
+            self_join::Column::Uuid.in_subquery(
+                Query::select()
+                    .column(self_join::Column::UuidRef)
+                    .from(referenced)
+                    .to_owned(),
+            ),
+        )
+        .order_by_asc(self_join::Column::Uuid);
+
+    let rows = select.clone().all(db).await?;
+    let uuids: Vec<_> = rows.iter().map(|row| row.uuid).collect();
+    assert_eq!(uuids, [Uuid::from_u128(1), Uuid::from_u128(2)]);
+
+    // The WITH clause is kept when counting, paginating and as a subquery
+    assert_eq!(select.clone().count(db).await?, 2);
+    assert_eq!(
+        select.clone().paginate(db, 1).fetch_page(1).await?,
+        [rows[1].clone()]
+    );
+    assert_eq!(
+        select
+            .clone()
+            .cursor_by(self_join::Column::Uuid)
+            .after(Uuid::from_u128(1))
+            .all(db)
+            .await?,
+        [rows[1].clone()]
+    );
+    assert_eq!(
+        SelfJoin::find()
+            .filter(
+                self_join::Column::Uuid.in_subquery(
+                    select
+                        .select_only()
+                        .column(self_join::Column::Uuid)
+                        .into_query()
+                )
+            )
+            .count(db)
+            .await?,
+        2
+    );
+
+    Ok(())
+}
+
+pub async fn with_recursive_cte(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let descendants = Alias::new("descendants");
+
+    // Every row referencing the first one, at any depth, including itself
+    let mut base = Query::select()
+        .column(self_join::Column::Uuid)
+        .from(SelfJoin)
+        .and_where(self_join::Column::Uuid.eq(Uuid::from_u128(1)))
+        .to_owned();
+    let step = Query::select()
+        .column((SelfJoin, self_join::Column::Uuid))
+        .from(SelfJoin)
+        .inner_join(
+            descendants.clone(),
+            Expr::col((SelfJoin, self_join::Column::UuidRef))
+                .equals((descendants.clone(), self_join::Column::Uuid)),
+        )
+        .to_owned();
+
+    let uuids: Vec<Uuid> = SelfJoin::find()
+        .with_recursive(
+            descendants.clone(),
+            base.union(UnionType::All, step).to_owned(),
+        )
+        .select_only()
+        .column(self_join::Column::Uuid)
+        .filter(
+            self_join::Column::Uuid.in_subquery(
+                Query::select()
+                    .column(self_join::Column::Uuid)
+                    .from(descendants)
+                    .to_owned(),
+            ),
+        )
+        .order_by_asc(self_join::Column::Uuid)
+        .into_tuple()
+        .all(db)
+        .await?;
+
+    assert_eq!(uuids, (1..=4).map(Uuid::from_u128).collect::<Vec<_>>());
+
+    Ok(())
+}
@@ -4,8 +4,11 @@ pub mod common;
 
 pub use common::{features::*, setup::*, TestContext};
 use pretty_assertions::assert_eq;
-use sea_orm::{entity::prelude::*, DerivePartialModel, FromQueryResult, QuerySelect, Set};
+use sea_orm::{
+    entity::prelude::*, DerivePartialModel, FromQueryResult, QueryOrder, QuerySelect, Set,
+};
 use serde_json::json;
+use std::num::NonZeroU64;
 
 #[sea_orm_macros::test]
 async fn cursor_tests() -> Result<(), DbErr> {
@@ -13,9 +16,11 @@ async fn cursor_tests() -> Result<(), DbErr> {
     create_tables(&ctx.db).await?;
     create_insert_default(&ctx.db).await?;
     cursor_pagination(&ctx.db).await?;
+    cursor_stream_keyset(&ctx.db).await?;
     bakery_chain_schema::create_tables(&ctx.db).await?;
     create_baker_cake(&ctx.db).await?;
     cursor_related_pagination(&ctx.db).await?;
+    cursor_composite_pagination(&ctx.db).await?;
     ctx.delete().await;
 
     Ok(())
@@ -549,6 +554,32 @@ fn cakebaker(cake: char, baker: char) -> CakeBakerlite {
     }
 }
 
+pub async fn cursor_stream_keyset(db: &DatabaseConnection) -> Result<(), DbErr> {
+    use futures::TryStreamExt;
+    use insert_default::*;
+    use std::collections::HashSet;
+
+    // 10 rows in chunks of 3, so the last chunk is partially filled
+    let ids: Vec<i32> = Entity::find()
+        .stream_keyset(db, NonZeroU64::new(3).unwrap())
+        .map_ok(|model| model.id)
+        .try_collect()
+        .await?;
+    assert_eq!(ids, (1..=10).collect::<Vec<_>>());
+    assert_eq!(ids.iter().collect::<HashSet<_>>().len(), ids.len());
+
+    // The chunks evenly divide the rows, ending with an empty chunk
+    let ids: Vec<i32> = Entity::find()
+        .filter(Column::Id.lte(6))
+        .stream_keyset(db, NonZeroU64::new(2).unwrap())
+        .map_ok(|model| model.id)
+        .try_collect()
+        .await?;
+    assert_eq!(ids, [1, 2, 3, 4, 5, 6]);
+
+    Ok(())
+}
+
 pub async fn create_baker_cake(db: &DatabaseConnection) -> Result<(), DbErr> {
     let mut bakeries: Vec<bakery::ActiveModel> = vec![];
     // bakeries named from 1 to 10
@@ -939,3 +970,62 @@ pub async fn cursor_related_pagination(db: &DatabaseConnection) -> Result<(), Db
 
     Ok(())
 }
+
+pub async fn cursor_composite_pagination(db: &DatabaseConnection) -> Result<(), DbErr> {
+    use common::bakery_chain::*;
+
+    // 26 bakers spread over 10 bakeries, so `bakery_id` has duplicates
+    // and the ties have to be broken by `id`
+    let expected: Vec<(Option<i32>, i32)> = baker::Entity::find()
+        .order_by_asc(baker::Column::BakeryId)
+        .order_by_asc(baker::Column::Id)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|baker| (baker.bakery_id, baker.id))
+        .collect();
+    assert_eq!(expected.len(), 26);
+
+    // Page forward, resuming after the last row of the previous page
+    let mut forward = Vec::new();
+    let mut after = None;
+    loop {
+        let mut cursor =
+            baker::Entity::find().cursor_by((baker::Column::BakeryId, baker::Column::Id));
+        if let Some(key) = after {
+            cursor.after(key);
+        }
+        let page = cursor.first(4).all(db).await?;
+        match page.last() {
+            Some(last) => after = Some((last.bakery_id, last.id)),
+            None => break,
+        }
+        forward.extend(page.into_iter().map(|baker| (baker.bakery_id, baker.id)));
+    }
+    assert_eq!(forward, expected);
+
+    // Page backward, resuming before the first row of the previous page
+    let mut backward = Vec::new();
+    let mut before = None;
+    loop {
+        let mut cursor =
+            baker::Entity::find().cursor_by((baker::Column::BakeryId, baker::Column::Id));
+        if let Some(key) = before {
+            cursor.before(key);
+        }
+        let page = cursor.last(4).all(db).await?;
+        match page.first() {
+            Some(first) => before = Some((first.bakery_id, first.id)),
+            None => break,
+        }
+        let mut rows: Vec<_> = page
+            .into_iter()
+            .map(|baker| (baker.bakery_id, baker.id))
+            .collect();
+        rows.append(&mut backward);
+        backward = rows;
+    }
+    assert_eq!(backward, expected);
+
+    Ok(())
+}
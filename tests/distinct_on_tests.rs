@@ -0,0 +1,48 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{bakery_chain::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{prelude::*, DatabaseBackend, QueryOrder, QuerySelect, Set};
+
+#[sea_orm_macros::test]
+pub async fn distinct_on_tests() -> Result<(), DbErr> {
+    let ctx = TestContext::new("distinct_on_tests").await;
+    create_tables(&ctx.db).await?;
+    seed_priced_cakes(&ctx.db).await?;
+    if ctx.db.get_database_backend() == DatabaseBackend::Postgres {
+        most_expensive_cake_per_bakery(&ctx.db).await?;
+    } else {
+        distinct_on_unsupported(&ctx.db).await?;
+    }
+    ctx.delete().await;
+
+    Ok(())
+}
+
+fn most_expensive_cakes() -> Select<Cake> {
+    Cake::find()
+        .distinct_on([cake::Column::BakeryId])
+        .order_by_asc(cake::Column::BakeryId)
+        .order_by_desc(cake::Column::Price)
+}
+
+pub async fn most_expensive_cake_per_bakery(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let cakes: Vec<(Option<i32>, Decimal)> = most_expensive_cakes()
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|cake| (cake.bakery_id, cake.price))
+        .collect();
+
+    assert_eq!(cakes, [(Some(1), rust_dec(7.5)), (Some(2), rust_dec(3.0))]);
+
+    Ok(())
+}
+
+pub async fn distinct_on_unsupported(db: &DatabaseConnection) -> Result<(), DbErr> {
+    assert!(most_expensive_cakes().all(db).await.is_err());
+
+    Ok(())
+}
@@ -4,7 +4,7 @@ pub mod common;
 
 pub use common::{features::*, setup::*, TestContext};
 use pretty_assertions::assert_eq;
-use sea_orm::{entity::prelude::*, ConnectionTrait, DatabaseConnection};
+use sea_orm::{entity::prelude::*, ConnectionTrait, DatabaseConnection, DbBackend};
 
 #[sea_orm_macros::test]
 async fn main() -> Result<(), DbErr> {
@@ -19,20 +19,49 @@ async fn main() -> Result<(), DbErr> {
 pub async fn execute_unprepared(db: &DatabaseConnection) -> Result<(), DbErr> {
     use insert_default::*;
 
-    db.execute_unprepared(
-        [
-            "INSERT INTO insert_default (id) VALUES (1), (2), (3), (4), (5)",
-            "DELETE FROM insert_default WHERE id % 2 = 0",
-        ]
-        .join(";")
-        .as_str(),
-    )
-    .await?;
+    let res = db
+        .execute_unprepared(
+            [
+                "INSERT INTO insert_default (id) VALUES (1), (2), (3), (4), (5)",
+                "DELETE FROM insert_default WHERE id % 2 = 0",
+            ]
+            .join(";")
+            .as_str(),
+        )
+        .await?;
+    assert_eq!(res.rows_affected(), 7);
 
     assert_eq!(
         Entity::find().all(db).await?,
         [Model { id: 1 }, Model { id: 3 }, Model { id: 5 }]
     );
 
+    // The statements after the failing one are not executed, and those before it
+    // are only rolled back by the implicit transaction of Postgres
+    assert!(db
+        .execute_unprepared(
+            [
+                "INSERT INTO insert_default (id) VALUES (7)",
+                "INSERT INTO insert_default (id) VALUES (1)",
+                "INSERT INTO insert_default (id) VALUES (9)",
+            ]
+            .join(";")
+            .as_str(),
+        )
+        .await
+        .is_err());
+
+    let expected = if db.get_database_backend() == DbBackend::Postgres {
+        vec![Model { id: 1 }, Model { id: 3 }, Model { id: 5 }]
+    } else {
+        vec![
+            Model { id: 1 },
+            Model { id: 3 },
+            Model { id: 5 },
+            Model { id: 7 },
+        ]
+    };
+    assert_eq!(Entity::find().all(db).await?, expected);
+
     Ok(())
 }
@@ -0,0 +1,76 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{bakery_chain::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{
+    prelude::*,
+    sea_query::{Expr, Query},
+    DatabaseBackend, QueryFilter, QueryOrder, QueryTrait, Set,
+};
+
+#[sea_orm_macros::test]
+pub async fn exists_tests() -> Result<(), DbErr> {
+    let ctx = TestContext::new("exists_tests").await;
+    create_tables(&ctx.db).await?;
+    seed_data(&ctx.db).await?;
+    filter_exists(&ctx.db).await?;
+    filter_not_exists(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn seed_data(db: &DatabaseConnection) -> Result<(), DbErr> {
+    seed_bakeries(db, 3).await?;
+
+    // The second bakery has no cake
+    let cakes = [1, 1, 3]
+        .map(|bakery_id| new_cake(&format!("Cake of bakery {bakery_id}"), 2.5, Some(bakery_id)));
+    Cake::insert_many(cakes).exec(db).await?;
+
+    Ok(())
+}
+
+fn cakes_of_bakery() -> sea_orm::sea_query::SelectStatement {
+    Query::select()
+        .expr(Expr::val(1))
+        .from(Cake)
+        .and_where(Expr::col((Cake, cake::Column::BakeryId)).equals((Bakery, bakery::Column::Id)))
+        .to_owned()
+}
+
+pub async fn filter_exists(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let select = Bakery::find()
+        .filter_exists(cakes_of_bakery())
+        .order_by_asc(bakery::Column::Id);
+
+    assert!(select
+        .build(DatabaseBackend::Postgres)
+        .to_string()
+        .ends_with(r#"WHERE EXISTS(SELECT 1 FROM "cake" WHERE "cake"."bakery_id" = "bakery"."id") ORDER BY "bakery"."id" ASC"#));
+
+    let names: Vec<String> = select
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|bakery| bakery.name)
+        .collect();
+    assert_eq!(names, ["Bakery 1", "Bakery 3"]);
+
+    Ok(())
+}
+
+pub async fn filter_not_exists(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let names: Vec<String> = Bakery::find()
+        .filter_not_exists(cakes_of_bakery())
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|bakery| bakery.name)
+        .collect();
+    assert_eq!(names, ["Bakery 2"]);
+
+    Ok(())
+}
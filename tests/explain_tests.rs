@@ -0,0 +1,41 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{bakery_chain::*, setup::*, TestContext};
+use sea_orm::{entity::prelude::*, DatabaseConnection, DbBackend, IntoActiveModel, Set};
+
+#[sea_orm_macros::test]
+#[cfg(any(feature = "sqlx-postgres", feature = "sqlx-sqlite"))]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("explain_tests").await;
+    create_tables(&ctx.db).await?;
+    explain_select(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn explain_select(db: &DatabaseConnection) -> Result<(), DbErr> {
+    new_bakery("SeaSide Bakery").insert(db).await?;
+
+    let select = || Bakery::find().filter(bakery::Column::Name.eq("SeaSide Bakery"));
+
+    let plan = select().explain(db, false).await?;
+    assert!(plan.contains("bakery"), "{plan}");
+
+    if db.get_database_backend() == DbBackend::Sqlite {
+        assert!(select().explain(db, true).await.is_err());
+        return Ok(());
+    }
+
+    // The query is executed, so the plan has the actual number of rows
+    let plan = select().explain(db, true).await?;
+    assert!(plan.contains("actual"), "{plan}");
+
+    let plan = select().explain_json(db, true).await?;
+    assert_eq!(plan[0]["Plan"]["Relation Name"], "bakery");
+    assert_eq!(plan[0]["Plan"]["Actual Rows"], 1);
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{features::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{entity::prelude::*, DatabaseConnection, QueryOrder, QuerySelect, Set};
+
+#[sea_orm_macros::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("full_text_search_tests").await;
+    create_tables(&ctx.db).await?;
+    seed_data(&ctx.db).await?;
+    search_posts(&ctx.db).await?;
+    search_posts_by_rank(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn seed_data(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let posts = [
+        (1, "Baking", "How to bake bread with sourdough"),
+        (2, "Gardening", "Growing tomatoes on a balcony"),
+        (
+            3,
+            "More baking",
+            "Sourdough bread, sourdough pizza and sourdough pancakes",
+        ),
+        (4, "Cooking", "Cooking pasta for a crowd"),
+    ]
+    .map(|(id, title, body)| post::ActiveModel {
+        id: Set(id),
+        title: Set(title.to_owned()),
+        body: Set(body.to_owned()),
+    });
+    Post::insert_many(posts).exec(db).await?;
+
+    Ok(())
+}
+
+pub async fn search_posts(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let backend = db.get_database_backend();
+
+    let mut ids: Vec<i32> = Post::find()
+        .filter(post::Column::Body.matches(backend, "sourdough"))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|post| post.id)
+        .collect();
+    ids.sort();
+    assert_eq!(ids, [1, 3]);
+
+    let posts = Post::find()
+        .filter(post::Column::Body.matches(backend, "tomatoes"))
+        .all(db)
+        .await?;
+    assert_eq!(posts.len(), 1);
+    assert_eq!(posts[0].title, "Gardening");
+
+    Ok(())
+}
+
+pub async fn search_posts_by_rank(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let backend = db.get_database_backend();
+
+    let ranked: Vec<(i32, f64)> = Post::find()
+        .select_only()
+        .column(post::Column::Id)
+        .column_as(post::Column::Body.match_rank(backend, "sourdough"), "rank")
+        .filter(post::Column::Body.matches(backend, "sourdough"))
+        .order_by_desc(post::Column::Body.match_rank(backend, "sourdough"))
+        .into_tuple()
+        .all(db)
+        .await?;
+
+    // The post mentioning sourdough the most comes first
+    let ids: Vec<i32> = ranked.iter().map(|(id, _)| *id).collect();
+    assert_eq!(ids, [3, 1]);
+    assert!(ranked[0].1 > ranked[1].1);
+
+    Ok(())
+}
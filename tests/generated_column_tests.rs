@@ -0,0 +1,82 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{features::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{entity::prelude::*, IntoActiveModel, Set};
+
+#[sea_orm_macros::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("generated_column_tests").await;
+    create_tables(&ctx.db).await?;
+    generated_column(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn generated_column(db: &DatabaseConnection) -> Result<(), DbErr> {
+    // The area is computed by the database
+    let rectangle = rectangle::ActiveModel {
+        width: Set(3),
+        height: Set(4),
+        ..Default::default()
+    }
+    .insert(db)
+    .await?;
+    assert_eq!(rectangle.area, 12);
+
+    // And recomputed when the row is updated
+    let mut rectangle = rectangle.into_active_model();
+    rectangle.width = Set(5);
+    let rectangle = rectangle.update(db).await?;
+    assert_eq!(rectangle.area, 20);
+
+    // Inserting a Model leaves its generated columns out
+    let copy = rectangle::Model {
+        id: rectangle.id + 1,
+        width: 2,
+        height: 2,
+        area: 0,
+    };
+    Rectangle::insert(copy.clone().into_active_model())
+        .exec(db)
+        .await?;
+    assert_eq!(
+        Rectangle::find_by_id(copy.id).one(db).await?,
+        Some(rectangle::Model { area: 4, ..copy })
+    );
+
+    // Setting a generated column leaves it out as well, the database still computes it
+    let mut square = rectangle::ActiveModel {
+        id: Set(copy.id + 1),
+        width: Set(3),
+        height: Set(3),
+        area: Set(100),
+    }
+    .insert(db)
+    .await?
+    .into_active_model();
+    assert_eq!(square.area, sea_orm::Unchanged(9));
+    square.area = Set(100);
+    assert_eq!(square.update(db).await?.area, 9);
+    Rectangle::update_many()
+        .set(rectangle::ActiveModel {
+            area: Set(100),
+            ..Default::default()
+        })
+        .filter(rectangle::Column::Id.eq(copy.id))
+        .exec(db)
+        .await?;
+
+    assert_eq!(
+        Rectangle::find()
+            .filter(rectangle::Column::Area.gt(10))
+            .all(db)
+            .await?,
+        [rectangle]
+    );
+
+    Ok(())
+}
@@ -0,0 +1,109 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{features::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{entity::prelude::*, entity::*, DatabaseConnection, QueryOrder, QuerySelect};
+
+#[sea_orm_macros::test]
+#[cfg(feature = "sqlx-postgres")]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("hstore_tests").await;
+    create_tables(&ctx.db).await?;
+    insert_preference(&ctx.db).await?;
+    filter_preference(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn insert_preference(db: &DatabaseConnection) -> Result<(), DbErr> {
+    use preference::*;
+
+    let rows = [
+        Model {
+            id: 1,
+            settings: PgHstore::from_iter([
+                ("theme", Some("dark")),
+                ("language", Some("en")),
+                ("timezone", None),
+            ]),
+            overrides: None,
+        },
+        // Keys and values which have to be quoted and escaped
+        Model {
+            id: 2,
+            settings: PgHstore::from_iter([
+                ("theme", Some("light")),
+                (r#"say "hi""#, Some(r"C:\temp, =>")),
+            ]),
+            overrides: Some(PgHstore::default()),
+        },
+    ];
+
+    for row in rows.iter() {
+        assert_eq!(&row.clone().into_active_model().insert(db).await?, row);
+    }
+    assert_eq!(Entity::find().order_by_asc(Column::Id).all(db).await?, rows);
+    assert_eq!(
+        Entity::find_by_id(1).one(db).await?.unwrap().settings.0["timezone"],
+        None
+    );
+
+    let mut row = rows[1].clone().into_active_model();
+    row.overrides = Set(Some(PgHstore::from_iter([("theme", Some("dark"))])));
+    let row = row.update(db).await?;
+    assert_eq!(Entity::find_by_id(2).one(db).await?, Some(row));
+
+    Ok(())
+}
+
+pub async fn filter_preference(db: &DatabaseConnection) -> Result<(), DbErr> {
+    use preference::*;
+
+    let ids = |rows: Vec<Model>| rows.into_iter().map(|row| row.id).collect::<Vec<_>>();
+
+    // A key with a NULL value exists
+    assert_eq!(
+        ids(Entity::find()
+            .filter(Column::Settings.hstore_contains_key("timezone"))
+            .all(db)
+            .await?),
+        [1]
+    );
+    assert_eq!(
+        ids(Entity::find()
+            .filter(Column::Settings.hstore_contains_key("language"))
+            .all(db)
+            .await?),
+        [1]
+    );
+    assert_eq!(
+        ids(Entity::find()
+            .filter(Column::Settings.hstore_get("theme").eq("light"))
+            .all(db)
+            .await?),
+        [2]
+    );
+    // A missing key and a NULL value are both looked up as NULL
+    assert_eq!(
+        ids(Entity::find()
+            .filter(Column::Settings.hstore_get("timezone").is_null())
+            .order_by_asc(Column::Id)
+            .all(db)
+            .await?),
+        [1, 2]
+    );
+
+    let themes: Vec<Option<String>> = Entity::find()
+        .select_only()
+        .column_as(Column::Settings.hstore_get("theme"), "theme")
+        .order_by_asc(Column::Id)
+        .into_tuple()
+        .all(db)
+        .await?;
+    assert_eq!(themes, [Some("dark".to_owned()), Some("light".to_owned())]);
+
+    Ok(())
+}
@@ -0,0 +1,98 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{features::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{entity::prelude::*, entity::*, DatabaseConnection, QueryOrder};
+use std::time::Duration;
+
+#[sea_orm_macros::test]
+#[cfg(feature = "sqlx-postgres")]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("interval_tests").await;
+    create_tables(&ctx.db).await?;
+    insert_reminder(&ctx.db).await?;
+    filter_reminder(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn insert_reminder(db: &DatabaseConnection) -> Result<(), DbErr> {
+    use reminder::*;
+
+    let reminders = [
+        Model {
+            id: 1,
+            every: Duration::from_secs(90).try_into()?,
+            snooze: Some(Duration::from_millis(1500).try_into()?),
+        },
+        Model {
+            id: 2,
+            every: PgInterval::new(0, 7, 0),
+            snooze: Some(PgInterval::new(0, 1, 2 * 60 * 60 * 1_000_000)),
+        },
+        Model {
+            id: 3,
+            every: PgInterval::new(1, 0, 0),
+            snooze: None,
+        },
+        // Negative components are kept as they are
+        Model {
+            id: 4,
+            every: PgInterval::new(0, 1, -1),
+            snooze: Some(PgInterval::new(0, 0, 0)),
+        },
+    ];
+
+    for reminder in reminders.iter() {
+        assert_eq!(
+            &reminder.clone().into_active_model().insert(db).await?,
+            reminder
+        );
+    }
+    assert_eq!(
+        Entity::find().order_by_asc(Column::Id).all(db).await?,
+        reminders
+    );
+
+    let reminder = Entity::find_by_id(1).one(db).await?.unwrap();
+    assert_eq!(Duration::try_from(reminder.every)?, Duration::from_secs(90));
+    let reminder = Entity::find_by_id(2).one(db).await?.unwrap();
+    assert_eq!(
+        Duration::try_from(reminder.every)?,
+        Duration::from_secs(7 * 24 * 60 * 60)
+    );
+    let reminder = Entity::find_by_id(3).one(db).await?.unwrap();
+    assert!(Duration::try_from(reminder.every).is_err());
+
+    Ok(())
+}
+
+pub async fn filter_reminder(db: &DatabaseConnection) -> Result<(), DbErr> {
+    use reminder::*;
+
+    assert_eq!(
+        Entity::find()
+            .filter(Column::Every.eq(PgInterval::new(0, 7, 0)))
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|reminder| reminder.id)
+            .collect::<Vec<_>>(),
+        [2]
+    );
+    assert_eq!(
+        Entity::find()
+            .filter(Column::Every.lt(PgInterval::try_from(Duration::from_secs(60 * 60))?))
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|reminder| reminder.id)
+            .collect::<Vec<_>>(),
+        [1]
+    );
+
+    Ok(())
+}
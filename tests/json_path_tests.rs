@@ -0,0 +1,95 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{features::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{entity::prelude::*, entity::*, DatabaseBackend, DatabaseConnection};
+use serde_json::json;
+
+#[sea_orm_macros::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("json_path_tests").await;
+    create_tables(&ctx.db).await?;
+    seed_data(&ctx.db).await?;
+    json_path(&ctx.db).await?;
+    if ctx.db.get_database_backend() == DatabaseBackend::Postgres {
+        json_contains(&ctx.db).await?;
+    }
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn seed_data(db: &DatabaseConnection) -> Result<(), DbErr> {
+    use json_struct::*;
+
+    let fruits = [
+        (1, "apple", "NYC", ["fresh", "organic"]),
+        (2, "orange", "LA", ["fresh", "imported"]),
+        (3, "kiwi", "NYC", ["imported", "organic"]),
+    ];
+    for (id, name, city, tags) in fruits {
+        let value = KeyValue {
+            id,
+            name: name.into(),
+            price: 1.5,
+            notes: None,
+        };
+        ActiveModel {
+            id: Set(id),
+            json: Set(json!({
+                "name": name,
+                "origin": { "city": city },
+                "tags": tags,
+            })),
+            json_value: Set(value),
+            json_value_opt: Set(None),
+        }
+        .insert(db)
+        .await?;
+    }
+
+    Ok(())
+}
+
+pub async fn json_path(db: &DatabaseConnection) -> Result<(), DbErr> {
+    use json_struct::*;
+
+    let backend = db.get_database_backend();
+
+    let ids: Vec<i32> = Entity::find()
+        .filter(Column::Json.json_path(backend, "$.origin.city").eq("NYC"))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|model| model.id)
+        .collect();
+    assert_eq!(ids, [1, 3]);
+
+    let ids: Vec<i32> = Entity::find()
+        .filter(Column::Json.json_path(backend, "$.tags[1]").eq("organic"))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|model| model.id)
+        .collect();
+    assert_eq!(ids, [1, 3]);
+
+    Ok(())
+}
+
+pub async fn json_contains(db: &DatabaseConnection) -> Result<(), DbErr> {
+    use json_struct::*;
+
+    let ids: Vec<i32> = Entity::find()
+        .filter(Column::Json.json_contains(json!({ "tags": ["imported"] })))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|model| model.id)
+        .collect();
+    assert_eq!(ids, [2, 3]);
+
+    Ok(())
+}
@@ -140,6 +140,74 @@ async fn loader_load_many_multi() -> Result<(), DbErr> {
     Ok(())
 }
 
+#[sea_orm_macros::test]
+async fn loader_load_related() -> Result<(), DbErr> {
+    let ctx = TestContext::new("loader_test_load_related").await;
+    create_tables(&ctx.db).await?;
+
+    let bakery_1 = insert_bakery(&ctx.db, "SeaSide Bakery").await?;
+    let bakery_2 = insert_bakery(&ctx.db, "Offshore Bakery").await?;
+    let bakery_3 = insert_bakery(&ctx.db, "Rocky Bakery").await?;
+
+    let baker_1 = insert_baker(&ctx.db, "John", bakery_1.id).await?;
+    let baker_2 = insert_baker(&ctx.db, "Jane", bakery_1.id).await?;
+    let baker_3 = insert_baker(&ctx.db, "Peter", bakery_2.id).await?;
+
+    let cake_1 = insert_cake(&ctx.db, "Cheesecake", Some(bakery_1.id)).await?;
+    let cake_2 = insert_cake(&ctx.db, "Chocolate", Some(bakery_2.id)).await?;
+    let cake_3 = insert_cake(&ctx.db, "Chiffon", Some(bakery_2.id)).await?;
+    let cake_4 = insert_cake(&ctx.db, "Apple Pie", None).await?;
+
+    let bakeries = bakery::Entity::find().all(&ctx.db).await?;
+    let (bakers, cakes) = bakeries
+        .load_related(
+            (
+                baker::Entity,
+                cake::Entity::find().filter(cake::Column::Name.like("Ch%")),
+            ),
+            &ctx.db,
+        )
+        .await?;
+
+    assert_eq!(bakeries, [bakery_1.clone(), bakery_2.clone(), bakery_3]);
+    assert_eq!(
+        bakers,
+        [vec![baker_1.clone(), baker_2], vec![baker_3], vec![]]
+    );
+    assert_eq!(cakes, [vec![cake_1.clone()], vec![cake_2, cake_3], vec![]]);
+
+    // belongs to relations are loaded as well, wrapped in One
+
+    let cakes = cake::Entity::find().all(&ctx.db).await?;
+    let (bakeries,) = cakes.load_related((One(bakery::Entity),), &ctx.db).await?;
+
+    assert_eq!(cakes[0], cake_1);
+    assert_eq!(cakes[3], cake_4);
+    assert_eq!(
+        bakeries,
+        [
+            Some(bakery_1.clone()),
+            Some(bakery_2.clone()),
+            Some(bakery_2),
+            None
+        ]
+    );
+
+    // many to many relations are not, should use load_many_to_many instead
+    let loaded = cakes
+        .load_related((One(bakery::Entity), baker::Entity), &ctx.db)
+        .await;
+
+    assert_eq!(
+        loaded,
+        Err(DbErr::Query(RuntimeErr::Internal(
+            "Relation is ManyToMany, which should be loaded with load_many_to_many".to_string()
+        )))
+    );
+
+    Ok(())
+}
+
 #[sea_orm_macros::test]
 async fn loader_load_many_to_many() -> Result<(), DbErr> {
     let ctx = TestContext::new("loader_test_load_many_to_many").await;
@@ -0,0 +1,143 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{bakery_chain::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{
+    error::SqlErr, prelude::*, sea_query::LockBehavior, DatabaseBackend, QueryOrder, QuerySelect,
+    Set, TableLockMode, TransactionTrait,
+};
+
+#[sea_orm_macros::test]
+pub async fn lock_tests() -> Result<(), DbErr> {
+    let ctx = TestContext::new("lock_tests").await;
+    create_tables(&ctx.db).await?;
+    seed_bakeries(&ctx.db, 4).await?;
+    if ctx.db.get_database_backend() == DatabaseBackend::Sqlite {
+        lock_unsupported(&ctx.db).await?;
+    } else {
+        skip_locked(&ctx.db).await?;
+        nowait(&ctx.db).await?;
+        lock_tables(&ctx.db).await?;
+    }
+    ctx.delete().await;
+
+    Ok(())
+}
+
+fn next_jobs() -> Select<Bakery> {
+    Bakery::find()
+        .order_by_asc(bakery::Column::Id)
+        .limit(2)
+        .lock_exclusive_with_behavior(LockBehavior::SkipLocked)
+}
+
+pub async fn skip_locked(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let worker_1 = db.begin().await?;
+    let worker_2 = db.begin().await?;
+
+    // Each worker grabs the first rows not locked by the other one
+    let jobs_1: Vec<i32> = next_jobs()
+        .all(&worker_1)
+        .await?
+        .into_iter()
+        .map(|bakery| bakery.id)
+        .collect();
+    let jobs_2: Vec<i32> = next_jobs()
+        .all(&worker_2)
+        .await?
+        .into_iter()
+        .map(|bakery| bakery.id)
+        .collect();
+
+    assert_eq!(jobs_1, [1, 2]);
+    assert_eq!(jobs_2, [3, 4]);
+
+    worker_1.commit().await?;
+    worker_2.commit().await?;
+
+    Ok(())
+}
+
+pub async fn nowait(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let worker_1 = db.begin().await?;
+    let worker_2 = db.begin().await?;
+
+    assert!(Bakery::find_by_id(1)
+        .lock_exclusive_with_behavior(LockBehavior::Nowait)
+        .one(&worker_1)
+        .await?
+        .is_some());
+
+    // The row locked by the first worker is reported rather than waited on
+    assert!(Bakery::find_by_id(1)
+        .lock_exclusive_with_behavior(LockBehavior::Nowait)
+        .one(&worker_2)
+        .await
+        .is_err());
+
+    worker_1.commit().await?;
+    worker_2.rollback().await?;
+
+    Ok(())
+}
+
+pub async fn lock_tables(db: &DatabaseConnection) -> Result<(), DbErr> {
+    // Outside of a transaction, the lock would be released at once
+    assert!(db
+        .lock_tables([Bakery], TableLockMode::Exclusive)
+        .await
+        .is_err());
+    assert!(db.unlock_tables().await.is_err());
+
+    let worker_1 = db.begin().await?;
+    worker_1
+        .lock_tables([Bakery], TableLockMode::Exclusive)
+        .await?;
+    assert_eq!(Bakery::find().count(&worker_1).await?, 4);
+
+    match db.get_database_backend() {
+        DatabaseBackend::Postgres => {
+            // The table can still be read, but not locked by another transaction.
+            // NOWAIT only applies to row locks: wait on the table lock with a timeout instead
+            let worker_2 = db.begin().await?;
+            assert_eq!(Bakery::find().count(&worker_2).await?, 4);
+            worker_2
+                .execute_unprepared("SET LOCAL lock_timeout = '100ms'")
+                .await?;
+            let error = Bakery::find_by_id(1)
+                .lock_exclusive()
+                .one(&worker_2)
+                .await
+                .expect_err("the table is locked");
+            assert!(matches!(error.sql_err(), Some(SqlErr::LockNotAvailable(_))));
+            worker_2.rollback().await?;
+        }
+        DatabaseBackend::MySql => {
+            // Only the locked tables can be used until they are unlocked
+            assert!(Cake::find().count(&worker_1).await.is_err());
+        }
+        DatabaseBackend::Sqlite => unreachable!(),
+    }
+
+    worker_1.unlock_tables().await?;
+    worker_1.commit().await?;
+
+    Ok(())
+}
+
+pub async fn lock_unsupported(db: &DatabaseConnection) -> Result<(), DbErr> {
+    assert!(next_jobs().all(db).await.is_err());
+    assert!(Bakery::find().lock_shared().all(db).await.is_err());
+    let txn = db.begin().await?;
+    assert!(txn
+        .lock_tables([Bakery], TableLockMode::Share)
+        .await
+        .is_err());
+    assert!(txn.unlock_tables().await.is_err());
+    txn.commit().await?;
+    assert_eq!(Bakery::find().all(db).await?.len(), 4);
+
+    Ok(())
+}
@@ -0,0 +1,102 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{features::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{entity::prelude::*, entity::*, DatabaseConnection, QueryOrder};
+
+#[sea_orm_macros::test]
+#[cfg(feature = "sqlx-postgres")]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("network_tests").await;
+    create_tables(&ctx.db).await?;
+    insert_host(&ctx.db).await?;
+    filter_host(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+fn net(s: &str) -> IpNetwork {
+    s.parse().unwrap()
+}
+
+pub async fn insert_host(db: &DatabaseConnection) -> Result<(), DbErr> {
+    use host::*;
+
+    let hosts = [
+        Model {
+            id: 1,
+            address: net("10.1.2.3"),
+            subnet: net("10.1.0.0/16"),
+            mac: Some(MacAddress::new([0x08, 0x00, 0x2b, 0x01, 0x02, 0x03])),
+        },
+        Model {
+            id: 2,
+            address: net("192.168.0.10/24"),
+            subnet: net("192.168.0.0/24"),
+            mac: None,
+        },
+        Model {
+            id: 3,
+            address: net("2001:db8::1"),
+            subnet: net("2001:db8::/32"),
+            mac: Some(MacAddress::new([0xff; 6])),
+        },
+        Model {
+            id: 4,
+            address: net("fe80::1ff:fe23:4567:890a/64"),
+            subnet: net("fe80::/10"),
+            mac: None,
+        },
+    ];
+
+    for host in hosts.iter() {
+        assert_eq!(&host.clone().into_active_model().insert(db).await?, host);
+    }
+    assert_eq!(
+        Entity::find().order_by_asc(Column::Id).all(db).await?,
+        hosts
+    );
+
+    Ok(())
+}
+
+pub async fn filter_host(db: &DatabaseConnection) -> Result<(), DbErr> {
+    use host::*;
+
+    let ids = |hosts: Vec<Model>| hosts.into_iter().map(|host| host.id).collect::<Vec<_>>();
+
+    assert_eq!(
+        ids(Entity::find()
+            .filter(Column::Address.within_network(net("10.0.0.0/8")))
+            .all(db)
+            .await?),
+        [1]
+    );
+    assert_eq!(
+        ids(Entity::find()
+            .filter(Column::Address.within_network(net("2001:db8::/32")))
+            .all(db)
+            .await?),
+        [3]
+    );
+    assert_eq!(
+        ids(Entity::find()
+            .filter(Column::Subnet.within_network(net("192.168.0.0/16")))
+            .all(db)
+            .await?),
+        [2]
+    );
+    assert_eq!(
+        ids(Entity::find()
+            .filter(Column::Mac.is_null())
+            .order_by_asc(Column::Id)
+            .all(db)
+            .await?),
+        [2, 4]
+    );
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{features::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{entity::prelude::*, IntoActiveModel, Set};
+
+#[sea_orm_macros::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("optimistic_lock_tests").await;
+    create_tables(&ctx.db).await?;
+    concurrent_updates(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn concurrent_updates(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let inserted = document::ActiveModel {
+        title: Set("Draft".to_owned()),
+        version: Set(1),
+        ..Default::default()
+    }
+    .insert(db)
+    .await?;
+
+    // Two users read the same version of the document
+    let mut alice = inserted.clone().into_active_model();
+    let mut bob = inserted.into_active_model();
+
+    alice.title = Set("Edited by Alice".to_owned());
+    let updated = alice.update(db).await?;
+    assert_eq!(updated.title, "Edited by Alice");
+    assert_eq!(updated.version, 2);
+
+    // Bob's change is based on a stale version, so it is rejected rather than lost
+    bob.title = Set("Edited by Bob".to_owned());
+    assert_eq!(bob.update(db).await, Err(DbErr::RecordNotUpdated));
+
+    assert_eq!(
+        Document::find_by_id(updated.id).one(db).await?,
+        Some(updated.clone())
+    );
+
+    // Retrying on top of the latest version succeeds
+    let mut bob = updated.into_active_model();
+    bob.title = Set("Edited by Bob".to_owned());
+    let updated = bob.update(db).await?;
+    assert_eq!(updated.title, "Edited by Bob");
+    assert_eq!(updated.version, 3);
+
+    Ok(())
+}
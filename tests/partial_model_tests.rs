@@ -1,7 +1,10 @@
 #![allow(unused_imports, dead_code)]
 
 use entity::{Column, Entity};
-use sea_orm::{ColumnTrait, DerivePartialModel, EntityTrait, FromQueryResult, ModelTrait};
+use sea_orm::{
+    tests_cfg::{cake, fruit},
+    ColumnTrait, DerivePartialModel, EntityTrait, FromQueryResult, ModelTrait,
+};
 use sea_query::Expr;
 
 mod entity {
@@ -56,3 +59,26 @@ struct FieldFromExpr {
     #[sea_orm(from_expr = "Expr::col(Column::Id).equals(Column::Foo)")]
     _bar: bool,
 }
+
+#[derive(FromQueryResult, DerivePartialModel)]
+#[sea_orm(entity = "fruit::Entity")]
+struct FruitWithCake {
+    name: String,
+    #[sea_orm(from = "cake::Entity", from_col = "name")]
+    cake_name: String,
+    #[sea_orm(from = "cake::Entity")]
+    id: i32,
+}
+
+#[test]
+fn partial_model_from_joined_entity() {
+    use sea_orm::{DbBackend, PartialModelTrait, QuerySelect, QueryTrait};
+
+    let select =
+        FruitWithCake::select_cols(fruit::Entity::find().select_only()).left_join(cake::Entity);
+
+    assert_eq!(
+        select.build(DbBackend::Postgres).to_string(),
+        r#"SELECT "fruit"."name", "cake"."name" AS "cake_name", "cake"."id" AS "id" FROM "fruit" LEFT JOIN "cake" ON "fruit"."cake_id" = "cake"."id""#
+    );
+}
@@ -0,0 +1,95 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{features::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{entity::prelude::*, entity::*, DatabaseConnection, QueryOrder};
+
+#[sea_orm_macros::test]
+#[cfg(all(feature = "sqlx-postgres", feature = "postgis"))]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("postgis_tests").await;
+    create_tables(&ctx.db).await?;
+    insert_place(&ctx.db).await?;
+    filter_place(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+#[cfg(feature = "postgis")]
+pub async fn insert_place(db: &DatabaseConnection) -> Result<(), DbErr> {
+    use place::*;
+
+    let rows = [
+        Model {
+            id: 1,
+            name: "Notre-Dame".to_owned(),
+            location: PgPoint::new(2.3499, 48.853).with_srid(4326),
+            entrance: Some(PgPoint::new(2.3488, 48.8532).with_srid(4326)),
+        },
+        Model {
+            id: 2,
+            name: "Louvre".to_owned(),
+            location: PgPoint::new(2.3376, 48.8606).with_srid(4326),
+            entrance: None,
+        },
+        // Without a spatial reference system
+        Model {
+            id: 3,
+            name: "Origin".to_owned(),
+            location: PgPoint::new(0.0, 0.0),
+            entrance: Some(PgPoint::new(-1.5, 0.25)),
+        },
+    ];
+
+    for row in rows.iter() {
+        assert_eq!(&row.clone().into_active_model().insert(db).await?, row);
+    }
+    assert_eq!(Entity::find().order_by_asc(Column::Id).all(db).await?, rows);
+
+    let mut row = rows[1].clone().into_active_model();
+    row.entrance = Set(Some(PgPoint::new(2.3359, 48.861).with_srid(4326)));
+    let row = row.update(db).await?;
+    assert_eq!(Entity::find_by_id(2).one(db).await?, Some(row));
+
+    // Points of different spatial reference systems can't be compared
+    rows[2].clone().delete(db).await?;
+
+    Ok(())
+}
+
+#[cfg(feature = "postgis")]
+pub async fn filter_place(db: &DatabaseConnection) -> Result<(), DbErr> {
+    use place::*;
+
+    let ids = |rows: Vec<Model>| rows.into_iter().map(|row| row.id).collect::<Vec<_>>();
+    let city_hall = PgPoint::new(2.3522, 48.8566).with_srid(4326);
+
+    assert_eq!(
+        ids(Entity::find()
+            .filter(Column::Location.st_dwithin(city_hall, 0.01))
+            .order_by_asc(Column::Id)
+            .all(db)
+            .await?),
+        [1]
+    );
+    assert_eq!(
+        ids(Entity::find()
+            .filter(Column::Location.st_dwithin(city_hall, 0.02))
+            .order_by_asc(Column::Id)
+            .all(db)
+            .await?),
+        [1, 2]
+    );
+    assert_eq!(
+        ids(Entity::find()
+            .filter(Column::Entrance.st_dwithin(city_hall, 0.01))
+            .all(db)
+            .await?),
+        [1]
+    );
+
+    Ok(())
+}
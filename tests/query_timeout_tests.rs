@@ -0,0 +1,108 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{bakery_chain::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{prelude::*, DatabaseBackend, FromQueryResult, Set, Statement, TransactionTrait};
+use std::time::{Duration, Instant};
+
+#[sea_orm_macros::test]
+pub async fn query_timeout_tests() -> Result<(), DbErr> {
+    let ctx = TestContext::new("query_timeout_tests").await;
+    create_tables(&ctx.db).await?;
+    query_within_timeout(&ctx.db).await?;
+    query_timeout_in_transaction(&ctx.db).await?;
+    if ctx.db.get_database_backend() != DatabaseBackend::Sqlite {
+        query_timed_out(&ctx.db).await?;
+        query_timed_out_in_transaction(&ctx.db).await?;
+    }
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn query_within_timeout(db: &DatabaseConnection) -> Result<(), DbErr> {
+    new_bakery("SeaSide Bakery").insert(db).await?;
+
+    let bakeries = Bakery::find()
+        .query_timeout(Duration::from_secs(10))
+        .all(db)
+        .await?;
+    assert_eq!(bakeries.len(), 1);
+
+    let bakery = Bakery::find()
+        .query_timeout(Duration::from_secs(10))
+        .one(db)
+        .await?;
+    assert_eq!(
+        bakery.map(|bakery| bakery.name),
+        Some("SeaSide Bakery".to_owned())
+    );
+
+    Ok(())
+}
+
+#[derive(Debug, FromQueryResult)]
+struct Slept {
+    slept: i64,
+}
+
+pub async fn query_timed_out(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let sql = match db.get_database_backend() {
+        DatabaseBackend::Postgres => "SELECT CAST(1 AS BIGINT) AS slept FROM pg_sleep(5)",
+        _ => "SELECT CAST(SLEEP(5) AS SIGNED) AS slept",
+    };
+    let stmt = Statement::from_string(db.get_database_backend(), sql);
+
+    let started = Instant::now();
+    let res = Slept::find_by_statement(stmt.clone())
+        .query_timeout(Duration::from_millis(200))
+        .all(db)
+        .await;
+    assert_eq!(res.unwrap_err(), DbErr::Timeout(Duration::from_millis(200)));
+    assert!(started.elapsed() < Duration::from_secs(5));
+
+    let res = db
+        .execute_with_timeout(stmt, Duration::from_millis(200))
+        .await;
+    assert!(matches!(res, Err(DbErr::Timeout(_))));
+
+    // The connection pool is still usable after cancelling the statements
+    assert_eq!(Bakery::find().count(db).await?, 1);
+
+    Ok(())
+}
+
+pub async fn query_timeout_in_transaction(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let txn = db.begin().await?;
+    let bakeries = Bakery::find()
+        .query_timeout(Duration::from_secs(10))
+        .all(&txn)
+        .await?;
+    assert_eq!(bakeries.len(), 1);
+    txn.commit().await?;
+
+    Ok(())
+}
+
+pub async fn query_timed_out_in_transaction(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let sql = match db.get_database_backend() {
+        DatabaseBackend::Postgres => "SELECT CAST(1 AS BIGINT) AS slept FROM pg_sleep(1)",
+        _ => "SELECT CAST(SLEEP(1) AS SIGNED) AS slept",
+    };
+    let stmt = Statement::from_string(db.get_database_backend(), sql);
+
+    // The statement cannot be cancelled within a transaction, only abandoned
+    let txn = db.begin().await?;
+    let res = Slept::find_by_statement(stmt)
+        .query_timeout(Duration::from_millis(200))
+        .all(&txn)
+        .await;
+    assert_eq!(res.unwrap_err(), DbErr::Timeout(Duration::from_millis(200)));
+    // The next statement waits for the abandoned one
+    assert_eq!(Bakery::find().count(&txn).await?, 1);
+    txn.rollback().await?;
+
+    Ok(())
+}
@@ -0,0 +1,106 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{features::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{entity::prelude::*, entity::*, DatabaseConnection, QueryOrder};
+use std::ops::Bound;
+
+#[sea_orm_macros::test]
+#[cfg(feature = "sqlx-postgres")]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("range_tests").await;
+    create_tables(&ctx.db).await?;
+    insert_availability(&ctx.db).await?;
+    filter_availability(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+fn at(hour: u32) -> DateTime {
+    Date::from_ymd_opt(2024, 3, 1)
+        .unwrap()
+        .and_hms_opt(hour, 0, 0)
+        .unwrap()
+}
+
+pub async fn insert_availability(db: &DatabaseConnection) -> Result<(), DbErr> {
+    use availability::*;
+
+    let rows = [
+        Model {
+            id: 1,
+            seats: PgRange::from(1..10),
+            opening_hours: Some(PgRange::from(at(9)..at(17))),
+        },
+        Model {
+            id: 2,
+            seats: PgRange::new(Bound::Included(10), Bound::Unbounded),
+            opening_hours: Some(PgRange::new(Bound::Unbounded, Bound::Included(at(12)))),
+        },
+        Model {
+            id: 3,
+            seats: PgRange::from(4..6),
+            opening_hours: None,
+        },
+    ];
+
+    for row in rows.iter() {
+        assert_eq!(&row.clone().into_active_model().insert(db).await?, row);
+    }
+    assert_eq!(Entity::find().order_by_asc(Column::Id).all(db).await?, rows);
+
+    // Discrete ranges are read back in their canonical form
+    let row = ActiveModel {
+        id: Set(4),
+        seats: Set(PgRange::from(1..=3)),
+        opening_hours: Set(None),
+    }
+    .insert(db)
+    .await?;
+    assert_eq!(row.seats, PgRange::from(1..4));
+    Entity::delete_by_id(row.id).exec(db).await?;
+
+    Ok(())
+}
+
+pub async fn filter_availability(db: &DatabaseConnection) -> Result<(), DbErr> {
+    use availability::*;
+
+    let ids = |rows: Vec<Model>| rows.into_iter().map(|row| row.id).collect::<Vec<_>>();
+
+    assert_eq!(
+        ids(Entity::find()
+            .filter(Column::Seats.range_contains(5))
+            .order_by_asc(Column::Id)
+            .all(db)
+            .await?),
+        [1, 3]
+    );
+    assert_eq!(
+        ids(Entity::find()
+            .filter(Column::Seats.range_contains(PgRange::from(2..8)))
+            .all(db)
+            .await?),
+        [1]
+    );
+    assert_eq!(
+        ids(Entity::find()
+            .filter(Column::OpeningHours.range_contains(at(10)))
+            .order_by_asc(Column::Id)
+            .all(db)
+            .await?),
+        [1, 2]
+    );
+    assert_eq!(
+        ids(Entity::find()
+            .filter(Column::Seats.eq(PgRange::from(4..6)))
+            .all(db)
+            .await?),
+        [3]
+    );
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{bakery_chain::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{entity::prelude::*, query::*, DatabaseConnection, RecordingConnection};
+
+#[sea_orm_macros::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("recording_tests").await;
+    create_tables(&ctx.db).await?;
+    record_statements(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn record_statements(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let backend = db.get_database_backend();
+    let recorder = RecordingConnection::new(db);
+
+    new_bakery("SeaSide Bakery").insert(&recorder).await?;
+    assert!(!recorder.take_recorded_statements().is_empty());
+
+    let select = Bakery::find().filter(bakery::Column::Name.contains("Side"));
+    let bakeries = select.clone().all(&recorder).await?;
+    assert_eq!(bakeries.len(), 1);
+
+    Bakery::delete_by_id(bakeries[0].id).exec(&recorder).await?;
+    assert_eq!(select.clone().all(&recorder).await?, []);
+
+    assert_eq!(
+        recorder.recorded_statements(),
+        [
+            select.clone().build(backend),
+            Bakery::delete_by_id(bakeries[0].id).build(backend),
+            select.build(backend),
+        ]
+    );
+
+    Ok(())
+}
@@ -5,9 +5,9 @@ pub mod common;
 pub use chrono::offset::Utc;
 pub use common::{bakery_chain::*, setup::*, TestContext};
 use pretty_assertions::assert_eq;
-pub use rust_decimal::prelude::*;
+use rust_decimal::prelude::*;
 use sea_orm::{entity::*, query::*, DbErr, DerivePartialModel, FromQueryResult};
-use sea_query::{Expr, Func, SimpleExpr};
+use sea_query::{Expr, Func, IntoCondition, SimpleExpr};
 pub use uuid::Uuid;
 
 // Run the test locally:
@@ -480,6 +480,79 @@ pub async fn having() {
     ctx.delete().await;
 }
 
+#[sea_orm_macros::test]
+pub async fn group_by_partial_model() -> Result<(), DbErr> {
+    // customers who spent more than $1000
+    let ctx = TestContext::new("test_group_by_partial_model").await;
+    create_tables(&ctx.db).await?;
+
+    let bakery = new_bakery("SeaSide Bakery").insert(&ctx.db).await?;
+
+    let customer_kate = customer::ActiveModel {
+        name: Set("Kate".to_owned()),
+        ..Default::default()
+    }
+    .insert(&ctx.db)
+    .await?;
+
+    let customer_bob = customer::ActiveModel {
+        name: Set("Bob".to_owned()),
+        ..Default::default()
+    }
+    .insert(&ctx.db)
+    .await?;
+
+    for (customer, total) in [
+        (&customer_kate, 1200.0),
+        (&customer_kate, 300.0),
+        (&customer_bob, 100.0),
+        (&customer_bob, 200.0),
+    ] {
+        order::ActiveModel {
+            bakery_id: Set(bakery.id),
+            customer_id: Set(customer.id),
+            total: Set(rust_dec(total)),
+            placed_at: Set(Utc::now().naive_utc()),
+            ..Default::default()
+        }
+        .insert(&ctx.db)
+        .await?;
+    }
+
+    #[derive(Debug, PartialEq, FromQueryResult, DerivePartialModel)]
+    #[sea_orm(entity = "order::Entity")]
+    struct CustomerSpending {
+        customer_id: i32,
+        #[sea_orm(from_expr = "order::Column::Id.count()")]
+        number_orders: i64,
+        #[sea_orm(from_expr = "order::Column::Total.sum()")]
+        total_spent: Decimal,
+        #[sea_orm(from_expr = "order::Column::Total.avg()")]
+        average_spent: Decimal,
+    }
+
+    let results = order::Entity::find()
+        .group_by(order::Column::CustomerId)
+        .having(Expr::expr(order::Column::Total.sum()).gt(1000))
+        .into_partial_model::<CustomerSpending>()
+        .all(&ctx.db)
+        .await?;
+
+    assert_eq!(
+        results,
+        [CustomerSpending {
+            customer_id: customer_kate.id,
+            number_orders: 2,
+            total_spent: rust_dec(1500.0),
+            average_spent: rust_dec(750.0),
+        }]
+    );
+
+    ctx.delete().await;
+
+    Ok(())
+}
+
 #[sea_orm_macros::test]
 pub async fn related() -> Result<(), DbErr> {
     use sea_orm::{SelectA, SelectB};
@@ -606,6 +679,60 @@ pub async fn related() -> Result<(), DbErr> {
         ]
     );
 
+    // at most one baker per bakery, filtered in the join condition
+    let bob_in_bakery: Vec<(bakery::Model, Option<baker::Model>)> = Bakery::find()
+        .join(
+            JoinType::LeftJoin,
+            bakery::Relation::Baker.def().on_condition(|_left, right| {
+                Expr::col((right, baker::Column::Name))
+                    .eq("Baker Bob")
+                    .into_condition()
+            }),
+        )
+        .select_also(Baker)
+        .order_by_asc(bakery::Column::Id)
+        .all(&ctx.db)
+        .await?;
+
+    assert_eq!(
+        bob_in_bakery,
+        [
+            (
+                bakery::Model {
+                    id: 1,
+                    name: "SeaSide Bakery".to_owned(),
+                    profit_margin: 10.4,
+                },
+                Some(baker::Model {
+                    id: 1,
+                    name: "Baker Bob".to_owned(),
+                    contact_details: serde_json::json!({
+                        "mobile": "+61424000000",
+                        "home": "0395555555",
+                        "address": "12 Test St, Testville, Vic, Australia"
+                    }),
+                    bakery_id: Some(seaside_bakery_res.last_insert_id),
+                })
+            ),
+            (
+                bakery::Model {
+                    id: 2,
+                    name: "Terres Bakery".to_owned(),
+                    profit_margin: 13.5,
+                },
+                None
+            ),
+            (
+                bakery::Model {
+                    id: 3,
+                    name: "Stone Bakery".to_owned(),
+                    profit_margin: 13.5,
+                },
+                None
+            ),
+        ]
+    );
+
     let seaside_bakery = Bakery::find()
         .filter(bakery::Column::Id.eq(1))
         .one(&ctx.db)
@@ -68,16 +68,6 @@ async fn main() -> Result<(), DbErr> {
 }
 
 #[sea_orm_macros::test]
-#[cfg_attr(
-    any(
-        feature = "sqlx-mysql",
-        all(
-            feature = "sqlx-sqlite",
-            not(feature = "sqlite-use-returning-for-3_35")
-        )
-    ),
-    should_panic(expected = "Database backend doesn't support RETURNING")
-)]
 async fn update_many() {
     pub use common::{features::*, TestContext};
     use edit_log::*;
@@ -142,6 +132,15 @@ async fn update_many() {
             ]
         );
 
+        if !db.support_returning() {
+            let res = Entity::update_many()
+                .col_expr(Column::Action, Expr::value("after_save"))
+                .exec_with_returning(db)
+                .await;
+            assert!(matches!(res, Err(DbErr::Exec(_))));
+            return Ok(());
+        }
+
         // Update many with returning
         assert_eq!(
             Entity::update_many()
@@ -185,3 +184,75 @@ async fn update_many() {
 
     run().await.unwrap();
 }
+
+#[sea_orm_macros::test]
+async fn insert_many_returning_keys() -> Result<(), DbErr> {
+    let ctx = TestContext::new("returning_tests_insert_many_returning_keys").await;
+    let db = &ctx.db;
+
+    create_tables(db).await?;
+
+    let bakery_ids = Bakery::insert_many(["Top Bakery", "Bottom Bakery"].map(new_bakery))
+        .exec_with_returning_keys(db)
+        .await?;
+
+    assert_eq!(bakery_ids.len(), 2);
+    assert_ne!(bakery_ids[0], bakery_ids[1]);
+
+    // Associate a child row with each parent right away
+    let cake_ids = Cake::insert_many(bakery_ids.iter().map(|bakery_id| {
+        new_cake(
+            &format!("Cake of bakery {bakery_id}"),
+            10.25,
+            Some(*bakery_id),
+        )
+    }))
+    .exec_with_returning_keys(db)
+    .await?;
+
+    assert_eq!(cake_ids.len(), 2);
+    for (cake_id, bakery_id) in cake_ids.into_iter().zip(bakery_ids) {
+        let cake = Cake::find_by_id(cake_id).one(db).await?.unwrap();
+        assert_eq!(cake.bakery_id, Some(bakery_id));
+        assert_eq!(cake.name, format!("Cake of bakery {bakery_id}"));
+    }
+
+    ctx.delete().await;
+
+    Ok(())
+}
+
+#[sea_orm_macros::test]
+async fn delete_many_returning() -> Result<(), DbErr> {
+    let ctx = TestContext::new("returning_tests_delete_many_returning").await;
+    let db = &ctx.db;
+
+    create_tables(db).await?;
+
+    let bakeries = ["Top Bakery", "Bottom Bakery", "Side Bakery"].map(new_bakery);
+    Bakery::insert_many(bakeries).exec(db).await?;
+
+    let delete = Bakery::delete_many().filter(bakery::Column::Name.ne("Side Bakery"));
+
+    if db.support_returning() {
+        let mut deleted: Vec<String> = delete
+            .exec_with_returning(db)
+            .await?
+            .into_iter()
+            .map(|bakery| bakery.name)
+            .collect();
+        deleted.sort();
+        assert_eq!(deleted, ["Bottom Bakery", "Top Bakery"]);
+        assert_eq!(Bakery::find().count(db).await?, 1);
+    } else {
+        assert!(matches!(
+            delete.exec_with_returning(db).await,
+            Err(DbErr::Exec(_))
+        ));
+        assert_eq!(Bakery::find().count(db).await?, 3);
+    }
+
+    ctx.delete().await;
+
+    Ok(())
+}
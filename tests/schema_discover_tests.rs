@@ -0,0 +1,63 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{features::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{entity::prelude::*, sea_query::ColumnType, ForeignKeyInfo, Schema};
+
+#[sea_orm_macros::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("schema_discover_tests").await;
+    create_notebook_table(&ctx.db).await?;
+    create_note_table(&ctx.db).await?;
+    discover(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn discover(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let tables = Schema::discover(db).await?;
+    assert_eq!(
+        tables
+            .iter()
+            .map(|table| table.name.as_str())
+            .collect::<Vec<_>>(),
+        ["note", "notebook"]
+    );
+
+    let note = &tables[0];
+    assert_eq!(note.primary_key, ["id"]);
+    assert_eq!(
+        note.columns
+            .iter()
+            .map(|column| (column.name.as_str(), column.nullable, column.primary_key))
+            .collect::<Vec<_>>(),
+        [
+            ("id", false, true),
+            ("notebook_id", true, false),
+            ("text", false, false),
+            ("deleted_at", true, false),
+        ]
+    );
+    assert_eq!(note.columns[1].column_type, ColumnType::Integer);
+    assert_eq!(
+        note.foreign_keys
+            .iter()
+            .map(|foreign_key| ForeignKeyInfo {
+                name: None,
+                ..foreign_key.clone()
+            })
+            .collect::<Vec<_>>(),
+        [ForeignKeyInfo {
+            name: None,
+            columns: vec!["notebook_id".to_owned()],
+            referenced_table: "notebook".to_owned(),
+            referenced_columns: vec!["id".to_owned()],
+        }]
+    );
+    assert_eq!(Schema::get_foreign_keys(db, "notebook").await?, []);
+
+    Ok(())
+}
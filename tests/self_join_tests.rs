@@ -14,6 +14,7 @@ async fn main() -> Result<(), DbErr> {
     ctx.delete().await;
     find_linked_001();
     find_also_linked_001();
+    find_with_linked_001();
 
     Ok(())
 }
@@ -79,8 +80,33 @@ pub async fn create_metadata(db: &DatabaseConnection) -> Result<(), DbErr> {
             .await?,
         [
             (model.clone(), None),
-            (linked_model, Some(model)),
-            (not_linked_model, None),
+            (linked_model.clone(), Some(model.clone())),
+            (not_linked_model.clone(), None),
+        ]
+    );
+
+    let parent = SelfReferencing::new(self_join::Relation::SelfReferencing);
+
+    assert_eq!(
+        linked_model.find_linked(parent).all(db).await?,
+        std::slice::from_ref(&model)
+    );
+
+    assert_eq!(
+        model.find_linked(parent.rev()).all(db).await?,
+        std::slice::from_ref(&linked_model)
+    );
+
+    assert_eq!(
+        self_join::Entity::find()
+            .find_with_linked(parent.rev())
+            .order_by_asc(self_join::Column::Time)
+            .all(db)
+            .await?,
+        [
+            (model, vec![linked_model.clone()]),
+            (linked_model, vec![]),
+            (not_linked_model, vec![]),
         ]
     );
 
@@ -128,3 +154,21 @@ fn find_also_linked_001() {
         .join(" ")
     );
 }
+
+fn find_with_linked_001() {
+    use self_join::*;
+
+    assert_eq!(
+        Entity::find()
+            .find_with_linked(SelfReferencing::new(Relation::SelfReferencing).rev())
+            .build(DbBackend::MySql)
+            .to_string(),
+        [
+            r#"SELECT `self_join`.`uuid` AS `A_uuid`, `self_join`.`uuid_ref` AS `A_uuid_ref`, `self_join`.`time` AS `A_time`,"#,
+            r#"`r0`.`uuid` AS `B_uuid`, `r0`.`uuid_ref` AS `B_uuid_ref`, `r0`.`time` AS `B_time`"#,
+            r#"FROM `self_join`"#,
+            r#"LEFT JOIN `self_join` AS `r0` ON `self_join`.`uuid` = `r0`.`uuid_ref`"#,
+        ]
+        .join(" ")
+    );
+}
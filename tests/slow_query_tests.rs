@@ -0,0 +1,58 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{bakery_chain::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{prelude::*, ConnectOptions, Database, DatabaseBackend, Statement};
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+
+static SLOW_QUERIES: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+fn on_slow_query(sql: &str, _elapsed: Duration) {
+    SLOW_QUERIES.lock().unwrap().push(sql.to_owned());
+}
+
+#[sea_orm_macros::test]
+pub async fn slow_query_tests() -> Result<(), DbErr> {
+    let ctx = TestContext::new("slow_query_tests").await;
+
+    let base_url = std::env::var("DATABASE_URL").unwrap();
+    let url = match ctx.db.get_database_backend() {
+        DatabaseBackend::Sqlite => base_url,
+        _ => format!("{base_url}/slow_query_tests"),
+    };
+    let mut opt = ConnectOptions::new(url);
+    opt.slow_query_threshold(Duration::ZERO)
+        .on_slow_query(on_slow_query);
+    let mut db = Database::connect(opt).await?;
+
+    let sql = match db.get_database_backend() {
+        DatabaseBackend::Postgres => "SELECT $1 AS one",
+        _ => "SELECT ? AS one",
+    };
+    let stmt = Statement::from_sql_and_values(db.get_database_backend(), sql, [1i32.into()]);
+    db.query_one(stmt.clone()).await?;
+    assert_eq!(*SLOW_QUERIES.lock().unwrap(), [sql]);
+
+    // The slow query callback is kept along a metric callback
+    let metrics = Arc::new(AtomicUsize::new(0));
+    let counter = metrics.clone();
+    db.set_metric_callback(move |_| {
+        counter.fetch_add(1, Ordering::SeqCst);
+    });
+    db.query_one(stmt).await?;
+    assert_eq!(metrics.load(Ordering::SeqCst), 1);
+    assert_eq!(*SLOW_QUERIES.lock().unwrap(), [sql, sql]);
+
+    db.close().await?;
+    ctx.delete().await;
+
+    Ok(())
+}
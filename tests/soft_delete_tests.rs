@@ -0,0 +1,153 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{features::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{entity::prelude::*, LoaderTrait, QueryOrder, Set};
+
+#[sea_orm_macros::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("soft_delete_tests").await;
+    create_tables(&ctx.db).await?;
+    soft_delete(&ctx.db).await?;
+    soft_delete_related(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn soft_delete(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let notes = ["Buy milk", "Walk the dog", "Call mom"].map(|text| note::ActiveModel {
+        text: Set(text.to_owned()),
+        ..Default::default()
+    });
+    Note::insert_many(notes).exec(db).await?;
+
+    let milk = Note::find()
+        .filter(note::Column::Text.eq("Buy milk"))
+        .one(db)
+        .await?
+        .unwrap();
+    assert_eq!(milk.clone().delete(db).await?.rows_affected, 1);
+
+    // Deleted notes are hidden, but kept
+    assert_eq!(Note::find().count(db).await?, 2);
+    assert_eq!(Note::find_by_id(milk.id).one(db).await?, None);
+    let deleted = Note::with_deleted()
+        .filter(note::Column::Id.eq(milk.id))
+        .one(db)
+        .await?
+        .unwrap();
+    assert!(deleted.deleted_at.is_some());
+
+    // Deleting again does not touch the deletion time
+    assert_eq!(Note::delete_by_id(milk.id).exec(db).await?.rows_affected, 0);
+
+    let res = Note::delete_many()
+        .filter(note::Column::Text.eq("Walk the dog"))
+        .exec(db)
+        .await?;
+    assert_eq!(res.rows_affected, 1);
+    assert_eq!(Note::find().count(db).await?, 1);
+    assert_eq!(Note::with_deleted().count(db).await?, 3);
+
+    let res = Note::delete_many().really_delete().exec(db).await?;
+    assert_eq!(res.rows_affected, 3);
+    assert_eq!(Note::with_deleted().count(db).await?, 0);
+
+    Ok(())
+}
+
+pub async fn soft_delete_related(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let [chores, ideas] = ["Chores", "Ideas"].map(|name| notebook::ActiveModel {
+        name: Set(name.to_owned()),
+        ..Default::default()
+    });
+    let chores = chores.insert(db).await?;
+    let ideas = ideas.insert(db).await?;
+    let notes = [
+        (chores.id, "Buy milk"),
+        (chores.id, "Walk the dog"),
+        (ideas.id, "Learn Rust"),
+    ]
+    .map(|(notebook_id, text)| note::ActiveModel {
+        notebook_id: Set(Some(notebook_id)),
+        text: Set(text.to_owned()),
+        ..Default::default()
+    });
+    Note::insert_many(notes).exec(db).await?;
+    Note::delete_many()
+        .filter(note::Column::Text.is_in(["Buy milk", "Learn Rust"]))
+        .exec(db)
+        .await?;
+
+    let texts = |notes: Vec<note::Model>| -> Vec<String> {
+        notes.into_iter().map(|note| note.text).collect()
+    };
+
+    assert_eq!(
+        texts(chores.find_related(Note).all(db).await?),
+        ["Walk the dog"]
+    );
+    assert_eq!(
+        texts(ideas.find_related(Note).all(db).await?),
+        [] as [&str; 0]
+    );
+
+    // The deleted notes are not joined, so their notebooks are still selected
+    let notebooks_with_note: Vec<(String, Option<String>)> = Notebook::find()
+        .find_also_related(Note)
+        .order_by_asc(notebook::Column::Id)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|(notebook, note)| (notebook.name, note.map(|note| note.text)))
+        .collect();
+    assert_eq!(
+        notebooks_with_note,
+        [
+            ("Chores".to_owned(), Some("Walk the dog".to_owned())),
+            ("Ideas".to_owned(), None),
+        ]
+    );
+
+    let notebooks_with_notes: Vec<(String, Vec<String>)> = Notebook::find()
+        .find_with_related(Note)
+        .order_by_asc(notebook::Column::Id)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|(notebook, notes)| (notebook.name, texts(notes)))
+        .collect();
+    assert_eq!(
+        notebooks_with_notes,
+        [
+            ("Chores".to_owned(), vec!["Walk the dog".to_owned()]),
+            ("Ideas".to_owned(), vec![]),
+        ]
+    );
+
+    assert_eq!(Notebook::find().inner_join(Note).count(db).await?, 1);
+
+    let notebooks = Notebook::find()
+        .order_by_asc(notebook::Column::Id)
+        .all(db)
+        .await?;
+    let notes: Vec<Vec<String>> = notebooks
+        .load_many(Note, db)
+        .await?
+        .into_iter()
+        .map(texts)
+        .collect();
+    assert_eq!(notes, [vec!["Walk the dog".to_owned()], vec![]]);
+    let notes: Vec<usize> = notebooks
+        .load_many(Note::with_deleted(), db)
+        .await?
+        .into_iter()
+        .map(|notes| notes.len())
+        .collect();
+    assert_eq!(notes, [2, 1]);
+
+    Ok(())
+}
@@ -3,8 +3,9 @@
 pub mod common;
 pub use common::{bakery_chain::*, setup::*, TestContext};
 pub use sea_orm::{
-    entity::*, error::DbErr, error::SqlErr, tests_cfg, ConnectionTrait, DatabaseConnection,
-    DbBackend, EntityName, ExecResult,
+    entity::*, error::DbErr, error::SqlErr, sea_query::Expr, sea_query::LockBehavior, tests_cfg,
+    ConnectionTrait, DatabaseConnection, DatabaseTransaction, DbBackend, EntityName, ExecResult,
+    QueryFilter, QueryOrder, QuerySelect, TransactionTrait,
 };
 use uuid::Uuid;
 
@@ -13,6 +14,11 @@ async fn main() {
     let ctx = TestContext::new("bakery_chain_sql_err_tests").await;
     create_tables(&ctx.db).await.unwrap();
     test_error(&ctx.db).await;
+    test_foreign_key_error(&ctx.db).await;
+    if ctx.db.get_database_backend() != DbBackend::Sqlite {
+        test_deadlock(&ctx.db).await;
+        test_lock_not_available(&ctx.db).await;
+    }
     ctx.delete().await;
 }
 
@@ -41,6 +47,14 @@ pub async fn test_error(db: &DatabaseConnection) {
         error.sql_err(),
         Some(SqlErr::UniqueConstraintViolation(_))
     ));
+    assert_eq!(
+        error.constraint_name().as_deref(),
+        match db.get_database_backend() {
+            DbBackend::MySql => Some("PRIMARY"),
+            DbBackend::Postgres => Some("cake_pkey"),
+            DbBackend::Sqlite => None,
+        }
+    );
 
     let fk_cake = cake::ActiveModel {
         name: Set("fk error Cake".to_owned()),
@@ -64,3 +78,164 @@ pub async fn test_error(db: &DatabaseConnection) {
     let invalid_error = DbErr::Custom("random error".to_string());
     assert_eq!(invalid_error.sql_err(), None)
 }
+
+pub async fn test_foreign_key_error(db: &DatabaseConnection) {
+    let backend = db.get_database_backend();
+
+    let bakery = new_bakery("Corner Bakery")
+        .insert(db)
+        .await
+        .expect("could not insert bakery");
+    let baker = baker::ActiveModel {
+        name: Set("Baker Bob".to_owned()),
+        contact_details: Set(serde_json::json!({})),
+        bakery_id: Set(Some(bakery.id)),
+        ..Default::default()
+    }
+    .insert(db)
+    .await
+    .expect("could not insert baker");
+    let cake = new_cake("Chocolate Cake", 10.25, Some(bakery.id))
+        .insert(db)
+        .await
+        .expect("could not insert cake");
+
+    // A child row referencing a missing parent
+    let error = cakes_bakers::ActiveModel {
+        cake_id: Set(cake.id),
+        baker_id: Set(baker.id + 1000),
+    }
+    .insert(db)
+    .await
+    .expect_err("inserting should fail due to a missing baker");
+    assert!(matches!(
+        error.sql_err(),
+        Some(SqlErr::ForeignKeyConstraintViolation(_))
+    ));
+    if backend != DbBackend::Sqlite {
+        assert_eq!(
+            error.constraint_name().as_deref(),
+            Some("fk-cakes_bakers-baker_id")
+        );
+        assert_eq!(error.constraint_table().as_deref(), Some("cakes_bakers"));
+    }
+
+    // A parent row still referenced by a child row
+    cakes_bakers::ActiveModel {
+        cake_id: Set(cake.id),
+        baker_id: Set(baker.id),
+    }
+    .insert(db)
+    .await
+    .expect("could not insert cakes_bakers");
+    let error = baker
+        .delete(db)
+        .await
+        .expect_err("deleting should fail as the baker is referenced");
+    assert!(matches!(
+        error.sql_err(),
+        Some(SqlErr::ForeignKeyConstraintViolation(_))
+    ));
+    if backend == DbBackend::Sqlite {
+        // SQLite does not report the constraint
+        assert_eq!(error.constraint_name(), None);
+        assert_eq!(error.constraint_table(), None);
+    } else {
+        assert_eq!(
+            error.constraint_name().as_deref(),
+            Some("fk-cakes_bakers-baker_id")
+        );
+        assert_eq!(error.constraint_table().as_deref(), Some("cakes_bakers"));
+    }
+}
+
+pub async fn test_deadlock(db: &DatabaseConnection) {
+    for name in ["Top Bakery", "Bottom Bakery"] {
+        new_bakery(name)
+            .insert(db)
+            .await
+            .expect("could not insert bakery");
+    }
+    let ids: Vec<i32> = bakery::Entity::find()
+        .select_only()
+        .column(bakery::Column::Id)
+        .order_by_asc(bakery::Column::Id)
+        .into_tuple()
+        .all(db)
+        .await
+        .unwrap();
+
+    // Each transaction locks one bakery, then waits for the one locked by the other
+    let txn1 = db.begin().await.unwrap();
+    let txn2 = db.begin().await.unwrap();
+    lock_bakery(&txn1, ids[0]).await.unwrap();
+    lock_bakery(&txn2, ids[1]).await.unwrap();
+    let (res1, res2) = futures::join!(
+        lock_bakery_then_commit(txn1, ids[1]),
+        lock_bakery_then_commit(txn2, ids[0]),
+    );
+
+    // Only one of the transactions is rolled back to break the deadlock
+    let error = match (res1, res2) {
+        (Err(error), Ok(())) | (Ok(()), Err(error)) => error,
+        (res1, res2) => panic!("expected one deadlock, got {res1:?} and {res2:?}"),
+    };
+    assert!(matches!(error.sql_err(), Some(SqlErr::Deadlock(_))));
+}
+
+pub async fn test_lock_not_available(db: &DatabaseConnection) {
+    let ids: Vec<i32> = bakery::Entity::find()
+        .select_only()
+        .column(bakery::Column::Id)
+        .order_by_asc(bakery::Column::Id)
+        .into_tuple()
+        .all(db)
+        .await
+        .unwrap();
+    assert!(ids.len() >= 2);
+
+    let txn1 = db.begin().await.unwrap();
+    let txn2 = db.begin().await.unwrap();
+    lock_bakery(&txn1, ids[0]).await.unwrap();
+
+    // A worker skips the locked row, and moves on to the next one
+    let next = bakery::Entity::find()
+        .order_by_asc(bakery::Column::Id)
+        .lock_exclusive_with_behavior(LockBehavior::SkipLocked)
+        .one(&txn2)
+        .await
+        .unwrap()
+        .expect("an unlocked bakery");
+    assert_eq!(next.id, ids[1]);
+
+    // Instead of waiting for the locked row
+    let error = bakery::Entity::find_by_id(ids[0])
+        .lock_exclusive_with_behavior(LockBehavior::Nowait)
+        .one(&txn2)
+        .await
+        .expect_err("the bakery is locked");
+    assert!(matches!(error.sql_err(), Some(SqlErr::LockNotAvailable(_))));
+
+    txn2.rollback().await.unwrap();
+    txn1.commit().await.unwrap();
+}
+
+async fn lock_bakery(txn: &DatabaseTransaction, id: i32) -> Result<(), DbErr> {
+    bakery::Entity::update_many()
+        .col_expr(bakery::Column::ProfitMargin, Expr::value(20.0))
+        .filter(bakery::Column::Id.eq(id))
+        .exec(txn)
+        .await?;
+    Ok(())
+}
+
+async fn lock_bakery_then_commit(txn: DatabaseTransaction, id: i32) -> Result<(), DbErr> {
+    match lock_bakery(&txn, id).await {
+        Ok(()) => txn.commit().await,
+        Err(err) => {
+            // Release the locks so the other transaction can proceed
+            txn.rollback().await?;
+            Err(err)
+        }
+    }
+}
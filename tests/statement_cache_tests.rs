@@ -0,0 +1,69 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{bakery_chain::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{
+    prelude::*, ConnectOptions, Database, DatabaseBackend, Statement, StatementCacheStats,
+};
+
+#[sea_orm_macros::test]
+pub async fn statement_cache_tests() -> Result<(), DbErr> {
+    let ctx = TestContext::new("statement_cache_tests").await;
+
+    let cached = cached_statements(&ctx, 100).await?;
+    assert_eq!(
+        cached,
+        Some(StatementCacheStats {
+            cached_statements: 2
+        })
+    );
+
+    let cached = cached_statements(&ctx, 0).await?;
+    assert_eq!(
+        cached,
+        Some(StatementCacheStats {
+            cached_statements: 0
+        })
+    );
+
+    ctx.delete().await;
+
+    Ok(())
+}
+
+async fn cached_statements(
+    ctx: &TestContext,
+    capacity: usize,
+) -> Result<Option<StatementCacheStats>, DbErr> {
+    let base_url = std::env::var("DATABASE_URL").unwrap();
+    let url = match ctx.db.get_database_backend() {
+        DatabaseBackend::Sqlite => base_url,
+        _ => format!("{base_url}/statement_cache_tests"),
+    };
+    let mut opt = ConnectOptions::new(url);
+    opt
+        // Every statement is run on the same connection, and so cached by it
+        .max_connections(1)
+        .statement_cache_capacity(capacity);
+    let db = Database::connect(opt).await?;
+
+    let placeholder = match db.get_database_backend() {
+        DatabaseBackend::Postgres => "$1",
+        _ => "?",
+    };
+    // Running a statement again reuses the cached one
+    for _ in 0..3 {
+        for column in ["one", "two"] {
+            let sql = format!("SELECT {placeholder} AS {column}");
+            let stmt =
+                Statement::from_sql_and_values(db.get_database_backend(), sql, [1i32.into()]);
+            db.query_all(stmt).await?;
+        }
+    }
+
+    let stats = db.statement_cache_stats().await;
+    db.close().await?;
+    stats
+}
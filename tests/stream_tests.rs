@@ -34,3 +34,56 @@ pub async fn stream() -> Result<(), DbErr> {
 
     Ok(())
 }
+
+#[sea_orm_macros::test]
+pub async fn stream_with_fetch_size() -> Result<(), DbErr> {
+    use futures::StreamExt;
+    use sea_orm::{DbBackend, QueryOrder, Statement};
+
+    let ctx = TestContext::new("stream_with_fetch_size").await;
+    create_tables(&ctx.db).await?;
+
+    let rows = 100_000;
+    for chunk in (0..rows).collect::<Vec<_>>().chunks(1_000) {
+        Bakery::insert_many(chunk.iter().map(|i| new_bakery(&format!("Bakery {i}"))))
+            .exec(&ctx.db)
+            .await?;
+    }
+
+    let mut stream = Bakery::find()
+        .order_by_asc(bakery::Column::Id)
+        .fetch_size(500)
+        .stream(&ctx.db)
+        .await?;
+
+    let first = stream.next().await.unwrap()?;
+    assert_eq!(first.name, "Bakery 0");
+
+    // The rows are fetched through a cursor, 500 at a time
+    if ctx.db.get_database_backend() == DbBackend::Postgres {
+        let fetching: i64 = ctx
+            .db
+            .query_one(Statement::from_string(
+                DbBackend::Postgres,
+                "SELECT COUNT(*) AS count FROM pg_stat_activity WHERE query LIKE 'FETCH 500 FROM sea_orm_cursor_%'",
+            ))
+            .await?
+            .unwrap()
+            .try_get("", "count")?;
+        assert_eq!(fetching, 1);
+    }
+
+    let mut count = 1;
+    let mut last_id = first.id;
+    while let Some(bakery) = stream.next().await {
+        let bakery = bakery?;
+        assert!(bakery.id > last_id);
+        last_id = bakery.id;
+        count += 1;
+    }
+    assert_eq!(count, rows);
+
+    ctx.delete().await;
+
+    Ok(())
+}
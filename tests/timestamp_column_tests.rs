@@ -0,0 +1,48 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{features::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{entity::prelude::*, IntoActiveModel, Set};
+
+#[sea_orm_macros::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("timestamp_column_tests").await;
+    create_tables(&ctx.db).await?;
+    auto_timestamps(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn auto_timestamps(db: &DatabaseConnection) -> Result<(), DbErr> {
+    // Neither timestamp is set by the caller
+    let inserted = article::ActiveModel {
+        title: Set("Hello".to_owned()),
+        ..Default::default()
+    }
+    .insert(db)
+    .await?;
+
+    let found = Article::find_by_id(inserted.id).one(db).await?.unwrap();
+    assert_eq!(found, inserted);
+
+    // A timestamp given by the caller is kept
+    let long_ago = "2000-01-01T00:00:00Z".parse::<DateTimeUtc>().unwrap();
+    let mut article = inserted.clone().into_active_model();
+    article.title = Set("Hello, world".to_owned());
+    article.updated_at = Set(long_ago);
+    let updated = article.update(db).await?;
+    assert_eq!(updated.updated_at, long_ago);
+
+    // Otherwise only the update time moves on
+    let mut article = updated.into_active_model();
+    article.title = Set("Goodbye".to_owned());
+    let updated = article.update(db).await?;
+    assert_eq!(updated.title, "Goodbye");
+    assert_eq!(updated.created_at, inserted.created_at);
+    assert!(updated.updated_at > long_ago);
+
+    Ok(())
+}
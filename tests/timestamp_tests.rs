@@ -3,7 +3,7 @@
 pub mod common;
 pub use common::{features::*, setup::*, TestContext};
 use pretty_assertions::assert_eq;
-use sea_orm::{entity::prelude::*, DatabaseConnection, IntoActiveModel};
+use sea_orm::{entity::prelude::*, DatabaseConnection, IntoActiveModel, Set, TransactionTrait};
 
 #[sea_orm_macros::test]
 async fn main() -> Result<(), DbErr> {
@@ -114,3 +114,46 @@ pub async fn create_satellites_log(db: &DatabaseConnection) -> Result<(), DbErr>
 
     Ok(())
 }
+
+#[sea_orm_macros::test]
+async fn insert_with_defaults() -> Result<(), DbErr> {
+    let ctx = TestContext::new("timestamp_tests_insert_with_defaults").await;
+    create_tables(&ctx.db).await?;
+    insert_satellite_with_defaults(&ctx.db).await?;
+
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn insert_satellite_with_defaults(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let new_satellite = || satellite::ActiveModel {
+        satellite_name: Set("Sea-00002-2022".to_owned()),
+        ..Default::default()
+    };
+
+    // The primary key and the dates are filled in by the database
+    let inserted = Satellite::insert(new_satellite())
+        .exec_with_returning(db)
+        .await?;
+    assert_eq!(inserted.satellite_name, "Sea-00002-2022");
+    assert_eq!(inserted.launch_date, inserted.deployment_date);
+    assert_eq!(
+        Satellite::find_by_id(inserted.id).one(db).await?,
+        Some(inserted.clone())
+    );
+
+    let txn = db.begin().await?;
+    let inserted_in_txn = Satellite::insert(new_satellite())
+        .exec_with_returning(&txn)
+        .await?;
+    txn.commit().await?;
+    assert_ne!(inserted_in_txn.id, inserted.id);
+    assert_eq!(inserted_in_txn.launch_date, inserted.launch_date);
+    assert_eq!(
+        Satellite::find_by_id(inserted_in_txn.id).one(db).await?,
+        Some(inserted_in_txn)
+    );
+
+    Ok(())
+}
@@ -4,7 +4,14 @@ pub mod common;
 
 pub use common::{bakery_chain::*, setup::*, TestContext};
 use pretty_assertions::assert_eq;
-use sea_orm::{prelude::*, AccessMode, DatabaseTransaction, IsolationLevel, Set, TransactionTrait};
+use sea_orm::{
+    prelude::*, AccessMode, DatabaseBackend, DatabaseTransaction, IsolationLevel, Set, SqlErr,
+    TransactionError, TransactionTrait,
+};
+use std::sync::{
+    atomic::{AtomicU32, Ordering},
+    Arc,
+};
 
 #[sea_orm_macros::test]
 pub async fn transaction() {
@@ -218,6 +225,44 @@ pub async fn transaction_begin_rollback() -> Result<(), DbErr> {
     Ok(())
 }
 
+#[sea_orm_macros::test]
+pub async fn transaction_begin_nested_rollback() -> Result<(), DbErr> {
+    let ctx = TestContext::new("transaction_begin_nested_rollback_test").await;
+    create_tables(&ctx.db).await?;
+
+    let txn = ctx.db.begin().await?;
+    new_bakery("SeaSide Bakery").save(&txn).await?;
+
+    {
+        // Only the changes of the nested transaction are rolled back
+        let nested = txn.begin().await?;
+        new_bakery("Top Bakery").save(&nested).await?;
+        assert_eq!(bakery::Entity::find().all(&nested).await?.len(), 2);
+        nested.rollback().await?;
+    }
+    assert_eq!(bakery::Entity::find().all(&txn).await?.len(), 1);
+
+    {
+        let nested = txn.begin().await?;
+        new_bakery("Bottom Bakery").save(&nested).await?;
+        nested.commit().await?;
+    }
+    assert_eq!(bakery::Entity::find().all(&txn).await?.len(), 2);
+
+    txn.commit().await?;
+
+    let names: Vec<String> = bakery::Entity::find()
+        .all(&ctx.db)
+        .await?
+        .into_iter()
+        .map(|bakery| bakery.name)
+        .collect();
+    assert_eq!(names, ["SeaSide Bakery", "Bottom Bakery"]);
+
+    ctx.delete().await;
+    Ok(())
+}
+
 #[sea_orm_macros::test]
 pub async fn transaction_closure_commit() -> Result<(), DbErr> {
     let ctx = TestContext::new("transaction_closure_commit_test").await;
@@ -698,6 +743,86 @@ pub async fn transaction_with_config() {
     ctx.delete().await;
 }
 
+#[sea_orm_macros::test]
+pub async fn transaction_with_retry() -> Result<(), DbErr> {
+    let ctx = TestContext::new("transaction_with_retry").await;
+    create_tables(&ctx.db).await?;
+
+    let attempts = Arc::new(AtomicU32::new(0));
+
+    if ctx.db.get_database_backend() == DatabaseBackend::Postgres {
+        // Only the first attempt fails with a serialization failure
+        let counter = attempts.clone();
+        ctx.db
+            .transaction_with_retry::<_, _, DbErr>(3, move |txn| {
+                let attempts = counter.clone();
+                Box::pin(async move {
+                    if attempts.fetch_add(1, Ordering::SeqCst) == 0 {
+                        raise_serialization_failure(txn).await?;
+                    }
+                    new_bakery("SeaSide Bakery").save(txn).await?;
+                    Ok(())
+                })
+            })
+            .await
+            .unwrap();
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+        assert_eq!(Bakery::find().count(&ctx.db).await?, 1);
+
+        // Every attempt fails, the last error is returned
+        attempts.store(0, Ordering::SeqCst);
+        let counter = attempts.clone();
+        let res = ctx
+            .db
+            .transaction_with_retry::<_, (), DbErr>(3, move |txn| {
+                let attempts = counter.clone();
+                Box::pin(async move {
+                    attempts.fetch_add(1, Ordering::SeqCst);
+                    raise_serialization_failure(txn).await?;
+                    Ok(())
+                })
+            })
+            .await;
+        match res {
+            Err(TransactionError::Transaction(err)) => assert!(matches!(
+                err.sql_err(),
+                Some(SqlErr::SerializationFailure(_))
+            )),
+            _ => panic!("expected a serialization failure"),
+        }
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    // Other errors are not retried
+    attempts.store(0, Ordering::SeqCst);
+    let counter = attempts.clone();
+    let res = ctx
+        .db
+        .transaction_with_retry::<_, (), DbErr>(3, move |_| {
+            let attempts = counter.clone();
+            Box::pin(async move {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Err(DbErr::Custom("no retry".to_owned()))
+            })
+        })
+        .await;
+    assert!(matches!(
+        res,
+        Err(TransactionError::Transaction(DbErr::Custom(_)))
+    ));
+    assert_eq!(attempts.load(Ordering::SeqCst), 1);
+
+    ctx.delete().await;
+
+    Ok(())
+}
+
+async fn raise_serialization_failure(txn: &DatabaseTransaction) -> Result<(), DbErr> {
+    txn.execute_unprepared("DO $$ BEGIN RAISE SQLSTATE '40001'; END $$")
+        .await?;
+    Ok(())
+}
+
 fn _transaction_with_config<'a>(
     txn: &'a DatabaseTransaction,
     name1: String,
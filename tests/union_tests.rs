@@ -0,0 +1,63 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{bakery_chain::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{prelude::*, Set};
+
+#[sea_orm_macros::test]
+pub async fn union_tests() -> Result<(), DbErr> {
+    let ctx = TestContext::new("union_tests").await;
+    create_tables(&ctx.db).await?;
+    seed_data(&ctx.db).await?;
+    union(&ctx.db).await?;
+    union_all(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn seed_data(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let bakeries = (1..=4).map(|i| bakery::ActiveModel {
+        profit_margin: Set(i as f64),
+        ..new_bakery(&format!("Bakery {i}"))
+    });
+    Bakery::insert_many(bakeries).exec(db).await?;
+
+    Ok(())
+}
+
+fn sorted_names(bakeries: Vec<bakery::Model>) -> Vec<String> {
+    let mut names: Vec<String> = bakeries.into_iter().map(|bakery| bakery.name).collect();
+    names.sort();
+    names
+}
+
+pub async fn union(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let bakeries = Bakery::find()
+        .filter(bakery::Column::ProfitMargin.lte(2.0))
+        .union(Bakery::find().filter(bakery::Column::Id.is_in([2, 4])))
+        .all(db)
+        .await?;
+
+    // "Bakery 2" matches both queries, but is only returned once
+    assert_eq!(sorted_names(bakeries), ["Bakery 1", "Bakery 2", "Bakery 4"]);
+
+    Ok(())
+}
+
+pub async fn union_all(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let bakeries = Bakery::find()
+        .filter(bakery::Column::ProfitMargin.lte(2.0))
+        .union_all(Bakery::find().filter(bakery::Column::Id.is_in([2, 4])))
+        .all(db)
+        .await?;
+
+    assert_eq!(
+        sorted_names(bakeries),
+        ["Bakery 1", "Bakery 2", "Bakery 2", "Bakery 4"]
+    );
+
+    Ok(())
+}
@@ -13,6 +13,8 @@ async fn main() -> Result<(), DbErr> {
     let ctx = TestContext::new("upsert_tests").await;
     create_tables(&ctx.db).await?;
     create_insert_default(&ctx.db).await?;
+    upsert_with_returning(&ctx.db).await?;
+    try_insert_outcome(&ctx.db).await?;
     ctx.delete().await;
 
     Ok(())
@@ -75,3 +77,90 @@ pub async fn create_insert_default(db: &DatabaseConnection) -> Result<(), DbErr>
 
     Ok(())
 }
+
+pub async fn upsert_with_returning(db: &DatabaseConnection) -> Result<(), DbErr> {
+    use inventory::*;
+
+    let on_conflict = OnConflict::column(Column::Sku)
+        .update_column(Column::Quantity)
+        .to_owned();
+
+    let upsert = |id: Option<i32>, sku: &str, quantity: i32| {
+        Entity::insert(ActiveModel {
+            id: id.map(Set).unwrap_or_default(),
+            sku: Set(sku.to_owned()),
+            quantity: Set(quantity),
+        })
+        .on_conflict(on_conflict.clone())
+    };
+    let model = |id: i32, sku: &str, quantity: i32| Model {
+        id,
+        sku: sku.to_owned(),
+        quantity,
+    };
+
+    // Inserted
+    assert_eq!(
+        upsert(None, "apple", 10).exec_with_returning(db).await?,
+        model(1, "apple", 10)
+    );
+    assert_eq!(
+        upsert(None, "pear", 5).exec_with_returning(db).await?,
+        model(2, "pear", 5)
+    );
+
+    // Updated
+    assert_eq!(
+        upsert(None, "apple", 7).exec_with_returning(db).await?,
+        model(1, "apple", 7)
+    );
+
+    // Set to its current values, which MySQL reports as no affected rows
+    assert_eq!(
+        upsert(None, "apple", 7).exec_with_returning(db).await?,
+        model(1, "apple", 7)
+    );
+
+    // Conflicted on the unique column, not on the primary key given
+    assert_eq!(
+        upsert(Some(3), "pear", 1).exec_with_returning(db).await?,
+        model(2, "pear", 1)
+    );
+
+    assert_eq!(Entity::find().count(db).await?, 2);
+
+    Ok(())
+}
+
+pub async fn try_insert_outcome(db: &DatabaseConnection) -> Result<(), DbErr> {
+    use insert_default::*;
+
+    let on_conflict = OnConflict::column(Column::Id)
+        .do_nothing_on([Column::Id])
+        .to_owned();
+    let insert = |id: i32| {
+        Entity::insert(ActiveModel { id: Set(id) })
+            .on_conflict(on_conflict.clone())
+            .do_nothing()
+    };
+
+    assert!(matches!(
+        insert(1).exec_with_returning(db).await?,
+        TryInsertResult::Conflicted
+    ));
+    assert!(matches!(
+        insert(5).exec_with_returning(db).await?,
+        TryInsertResult::Inserted(Model { id: 5 })
+    ));
+
+    assert!(matches!(
+        insert(1).exec_without_returning(db).await?,
+        TryInsertResult::Conflicted
+    ));
+    assert!(matches!(
+        insert(6).exec_without_returning(db).await?,
+        TryInsertResult::Inserted(1)
+    ));
+
+    Ok(())
+}
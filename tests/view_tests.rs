@@ -0,0 +1,75 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{features::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{entity::prelude::*, query::*, ConnectionTrait, DatabaseConnection, Set};
+
+#[sea_orm_macros::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("view_tests").await;
+    create_tables(&ctx.db).await?;
+    create_live_note_view(&ctx.db).await?;
+    find_view(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn create_live_note_view(db: &DatabaseConnection) -> Result<(), DbErr> {
+    db.execute_unprepared(
+        "CREATE VIEW live_note AS SELECT id, text FROM note WHERE deleted_at IS NULL",
+    )
+    .await?;
+
+    Ok(())
+}
+
+pub async fn find_view(db: &DatabaseConnection) -> Result<(), DbErr> {
+    for text in ["Buy milk", "Call mum", "Water plants"] {
+        note::ActiveModel {
+            text: Set(text.to_owned()),
+            ..Default::default()
+        }
+        .insert(db)
+        .await?;
+    }
+    note::Entity::delete_by_id(2).exec(db).await?;
+
+    assert_eq!(
+        live_note::Entity::find()
+            .order_by_asc(live_note::Column::Id)
+            .all(db)
+            .await?,
+        [
+            live_note::Model {
+                id: 1,
+                text: "Buy milk".to_owned(),
+            },
+            live_note::Model {
+                id: 3,
+                text: "Water plants".to_owned(),
+            },
+        ]
+    );
+
+    assert_eq!(
+        live_note::Entity::find()
+            .filter(live_note::Column::Text.contains("plants"))
+            .one(db)
+            .await?
+            .map(|model| model.id),
+        Some(3)
+    );
+
+    let (live, note) = live_note::Entity::find_by_id(1)
+        .find_also_related(note::Entity)
+        .one(db)
+        .await?
+        .unwrap();
+    assert_eq!(live.text, "Buy milk");
+    assert_eq!(note.map(|note| note.deleted_at), Some(None));
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{bakery_chain::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{
+    prelude::*,
+    sea_query::{self, Alias, Asterisk, Query, WindowStatement},
+    FromQueryResult, QueryOrder, QuerySelect, QueryTrait, Set,
+};
+
+#[sea_orm_macros::test]
+pub async fn window_function_tests() -> Result<(), DbErr> {
+    let ctx = TestContext::new("window_function_tests").await;
+    create_tables(&ctx.db).await?;
+    seed_priced_cakes(&ctx.db).await?;
+    rank_per_bakery(&ctx.db).await?;
+    first_per_bakery(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+/// Cakes ranked by price within their bakery, the most expensive first
+fn ranked_cakes() -> Select<Cake> {
+    Cake::find()
+        .select_only()
+        .column(cake::Column::Id)
+        .column(cake::Column::BakeryId)
+        .expr_window_as(
+            Expr::cust("ROW_NUMBER()"),
+            WindowStatement::partition_by(cake::Column::BakeryId)
+                .order_by(cake::Column::Price, sea_query::Order::Desc)
+                .to_owned(),
+            "rn",
+        )
+}
+
+pub async fn rank_per_bakery(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let ranks: Vec<(i32, i32, i64)> = ranked_cakes()
+        .order_by_asc(cake::Column::BakeryId)
+        .order_by_desc(cake::Column::Price)
+        .into_tuple()
+        .all(db)
+        .await?;
+    let ranks: Vec<(i32, i64)> = ranks
+        .into_iter()
+        .map(|(_, bakery_id, rn)| (bakery_id, rn))
+        .collect();
+
+    assert_eq!(ranks, [(1, 1), (1, 2), (1, 3), (2, 1), (2, 2)]);
+
+    Ok(())
+}
+
+#[derive(Debug, PartialEq, FromQueryResult)]
+struct Ranked {
+    id: i32,
+    bakery_id: i32,
+}
+
+pub async fn first_per_bakery(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let db_backend = db.get_database_backend();
+    let statement = Query::select()
+        .columns([Alias::new("id"), Alias::new("bakery_id")])
+        .from_subquery(ranked_cakes().into_query(), Alias::new("ranked"))
+        .and_where(Expr::col(Alias::new("rn")).eq(1))
+        .order_by(Alias::new("bakery_id"), sea_query::Order::Asc)
+        .to_owned();
+
+    let firsts = Ranked::find_by_statement(db_backend.build(&statement))
+        .all(db)
+        .await?;
+    let most_expensive: Vec<Decimal> = Cake::find()
+        .filter(cake::Column::Id.is_in(firsts.iter().map(|first| first.id)))
+        .order_by_asc(cake::Column::BakeryId)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|cake| cake.price)
+        .collect();
+
+    assert_eq!(
+        firsts
+            .iter()
+            .map(|first| first.bakery_id)
+            .collect::<Vec<_>>(),
+        [1, 2]
+    );
+    assert_eq!(most_expensive, [rust_dec(7.5), rust_dec(3.0)]);
+
+    Ok(())
+}